@@ -2,14 +2,13 @@
 
 use anyhow::Result;
 use h_5n1p3r::oracle::{
-    DecisionLedger, TransactionRecord, Outcome, ScoredCandidate,
+    DecisionLedger, TransactionRecord, Outcome, Score, ScoredCandidate, TradeAction,
     PerformanceMonitor, StrategyOptimizer, FeatureWeights, ScoreThresholds,
 };
 use h_5n1p3r::types::PremintCandidate;
 use std::collections::HashMap;
 use tokio::sync::mpsc;
 use tracing::{info, Level};
-use tracing_subscriber;
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -22,7 +21,7 @@ async fn main() -> Result<()> {
 
     // Create channels
     let (decision_record_sender, decision_record_receiver) = mpsc::channel(100);
-    let (outcome_update_sender, outcome_update_receiver) = mpsc::channel(100);
+    let (_outcome_update_sender, outcome_update_receiver) = mpsc::channel(100);
     let (perf_report_sender, perf_report_receiver) = mpsc::channel(16);
     let (opt_params_sender, mut opt_params_receiver) = mpsc::channel(16);
 
@@ -77,6 +76,7 @@ async fn main() -> Result<()> {
                     average_loss_sol: 0.4,
                     net_profit_sol: -1.5,   // Net loss
                     max_drawdown_percent: 15.0,
+                    high_score_variance_mints: Vec::new(),
                 }
             } else {
                 report
@@ -93,6 +93,7 @@ async fn main() -> Result<()> {
         opt_params_sender,
         FeatureWeights::default(),
         ScoreThresholds::default(),
+        0, // No cooldown in manual test
     );
 
     let _opt_handle = tokio::spawn(async move {
@@ -133,17 +134,23 @@ async fn create_poor_performance_data(sender: mpsc::Sender<TransactionRecord>) -
             timestamp: chrono::Utc::now().timestamp_millis() as u64,
             instruction_summary: Some("Losing token".to_string()),
             is_jito_bundle: Some(false),
+            funding_source: None,
         };
 
         let scored_candidate = ScoredCandidate {
             base: candidate.clone(),
             mint: candidate.mint.clone(),
-            predicted_score: 65, // Mediocre score
+            predicted_score: Score::clamped(65), // Mediocre score
             reason: format!("Token with low liquidity #{}", i),
             feature_scores: feature_scores.clone(),
             calculation_time: 120_000,
             anomaly_detected: false,
             timestamp: candidate.timestamp,
+            data_source_timestamps: HashMap::new(),
+            scoring_trace: None,
+            reason_codes: Vec::new(),
+            anomaly_report: None,
+            action: TradeAction::Buy,
         };
 
         // Create a losing transaction record
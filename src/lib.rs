@@ -5,6 +5,7 @@
 
 pub mod types;
 pub mod oracle;
+pub mod quantum_selector; // Legacy selector, whose config converts into `oracle::types::OracleConfig`
 
 // Re-export main types for convenience
 pub use types::{PremintCandidate, QuantumCandidateGui};
\ No newline at end of file
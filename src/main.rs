@@ -7,17 +7,16 @@ use anyhow::Result;
 use h_5n1p3r::oracle::{
     DecisionLedger, TransactionMonitor, TransactionRecord, Outcome, MonitoredTransaction,
     DecisionRecordSender, PerformanceMonitor, StrategyOptimizer,
-    FeatureWeights, ScoreThresholds, PredictiveOracle, ScoredCandidate,
+    FeatureWeights, ScoreThresholds, ScoredCandidate, TradeAction,
     // Pillar III imports
-    MarketRegimeDetector, OracleDataSources, MarketRegime, OracleConfig,
+    MarketRegimeDetector, MarketRegime, MarketContextSnapshot, OracleConfig,
+    ShutdownToken, PredictiveOracle, QueuedCandidate,
 };
 use h_5n1p3r::types::PremintCandidate;
-use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::{mpsc, RwLock};
 use tracing::{info, warn, error, Level};
-use tracing_subscriber;
 use solana_client::nonblocking::rpc_client::RpcClient;
 
 #[tokio::main]
@@ -29,6 +28,18 @@ async fn main() -> Result<()> {
 
     info!("Starting H-5N1P3R Oracle System with Pillar II (OODA Loop)");
 
+    // Ctrl+C asks every component to drain and stop instead of being aborted
+    let shutdown = ShutdownToken::new();
+    tokio::spawn({
+        let shutdown = shutdown.clone();
+        async move {
+            if tokio::signal::ctrl_c().await.is_ok() {
+                info!("Received SIGINT, shutting down gracefully...");
+                shutdown.trigger();
+            }
+        }
+    });
+
     // Create communication channels for DecisionLedger (Pillar I)
     let (decision_record_sender, decision_record_receiver) = mpsc::channel::<TransactionRecord>(100);
     let (outcome_update_sender, outcome_update_receiver) = mpsc::channel(100);
@@ -42,7 +53,9 @@ async fn main() -> Result<()> {
     let decision_ledger = DecisionLedger::new(
         decision_record_receiver,
         outcome_update_receiver,
-    ).await?;
+    ).await?
+    .with_spill_file("decisions.spill.jsonl")
+    .with_shutdown(shutdown.clone());
 
     // Get database pool for Pillar II components
     let db_pool = decision_ledger.get_db_pool()
@@ -60,14 +73,52 @@ async fn main() -> Result<()> {
     
     // Placeholder wallet pubkey - in real implementation this would come from config
     let wallet_pubkey = "11111111111111111111111111111112".to_string(); // System program as placeholder
-    
+
+    // --- Initialize PredictiveOracle for Hot-Swap Demonstration ---
+    info!("Initializing PredictiveOracle with hot-swap capability");
+
+    // Shared Oracle configuration; the OODA loop hot-swaps weights and thresholds in place,
+    // and the metrics server reports the effective values at /config
+    let oracle_config = Arc::new(RwLock::new(OracleConfig {
+        metrics_http_listen: Some("127.0.0.1:9090".to_string()),
+        // Stop buying after 5 losing trades in a row, until the kill switch is reset
+        kill_switch_consecutive_losses: Some(5),
+        ..OracleConfig::default()
+    }));
+
+    // Create channels for Oracle communication
+    let (candidate_sender, candidate_receiver) = mpsc::channel::<QueuedCandidate>(100);
+    let (oracle_scored_sender, oracle_scored_receiver) = mpsc::channel::<ScoredCandidate>(100);
+
+    let mut oracle = PredictiveOracle::new(
+        candidate_receiver,
+        oracle_scored_sender,
+        oracle_config.clone(),
+    )?;
+    oracle.set_shutdown_token(shutdown.clone());
+    // Creators' funding sources and launch history are checked against the ledger
+    oracle.set_ledger(storage.clone());
+    // Skipped candidates are recorded too, so every launch a creator makes reaches the ledger
+    oracle.set_decision_sender(decision_record_sender.clone());
+    // Rejections are logged with their reason code for later analysis
+    oracle.set_rejection_storage(storage.clone());
+    // The kill switch watches closed trades in the ledger and saves its resets there
+    oracle.set_kill_switch_storage(storage.clone());
+
+    // The oracle's data sources also back market regime detection and the monitor's price fallback
+    let data_sources = oracle.data_sources();
+
+    info!("PredictiveOracle initialized with hot-swap capability");
+
     let transaction_monitor = TransactionMonitor::new(
-        storage,
+        storage.clone(),
         outcome_update_sender.clone(),
         1000, // Check every 1 second
         rpc_client,
         wallet_pubkey,
-    );
+    )
+    .with_last_price_fallback(data_sources.clone())
+    .with_shutdown(shutdown.clone());
 
     // Initialize Pillar II components
     let initial_weights = FeatureWeights::default();
@@ -78,7 +129,8 @@ async fn main() -> Result<()> {
         perf_report_sender,
         1, // Analyze every 1 minute for demo (normally would be 15+ minutes)
         1, // Look at last 1 hour of data (normally 24+ hours)
-    );
+    )
+    .with_shutdown(shutdown.clone());
 
     let strategy_optimizer = StrategyOptimizer::new(
         db_pool,
@@ -86,50 +138,29 @@ async fn main() -> Result<()> {
         opt_params_sender,
         initial_weights.clone(),
         initial_thresholds.clone(),
-    );
-
-    // --- Initialize PredictiveOracle for Hot-Swap Demonstration ---
-    info!("Initializing PredictiveOracle with hot-swap capability");
-    
-    // Create channels for Oracle communication
-    let (candidate_sender, candidate_receiver) = mpsc::channel::<PremintCandidate>(100);
-    let (oracle_scored_sender, mut oracle_scored_receiver) = mpsc::channel::<h_5n1p3r::oracle::quantum_oracle::ScoredCandidate>(100);
-    
-    // Create shared Oracle configuration for hot-swap
-    let shared_oracle_config = Arc::new(RwLock::new(h_5n1p3r::oracle::quantum_oracle::SimpleOracleConfig::default()));
-    
-    // Create PredictiveOracle instance with hot-swap capability
-    let oracle = Arc::new(PredictiveOracle::new(
-        candidate_receiver,
-        oracle_scored_sender,
-        shared_oracle_config.clone(),
-    )?);
-    
-    info!("PredictiveOracle initialized with hot-swap capability");
+        5, // Emit new parameters at most every 5 minutes
+    )
+    .with_shutdown(shutdown.clone());
 
     // --- Pillar III: Initialize MarketRegimeDetector ---
     info!("Initializing Pillar III: MarketRegimeDetector");
     
     // Create shared state for current market regime
     let current_market_regime = Arc::new(RwLock::new(MarketRegime::LowActivity));
-    
-    // Initialize Oracle configuration with regime-specific parameters
-    let oracle_config = OracleConfig::default();
-    
-    // Initialize data sources for market regime detection
-    let http_client = reqwest::Client::new();
-    let data_sources_for_regime = Arc::new(OracleDataSources::new(
-        vec![], // Empty RPC clients for now (placeholder)
-        http_client,
-        oracle_config,
-    ));
+    let current_market_context = Arc::new(RwLock::new(MarketContextSnapshot::default()));
+
+    // The oracle scores against the regime the detector maintains
+    oracle.set_regime_handle(current_market_regime.clone());
     
     // Create MarketRegimeDetector
     let regime_detector = MarketRegimeDetector::new(
-        data_sources_for_regime,
+        data_sources,
         current_market_regime.clone(),
         60, // Analyze market regime every 60 seconds
-    );
+    )
+    .with_context_snapshot(current_market_context.clone())
+    // Regime changes retune the oracle's RPC quota
+    .with_rate_limiter(oracle.rate_limiter(), &*oracle_config.read().await);
     
     info!("MarketRegimeDetector initialized successfully");
 
@@ -155,24 +186,26 @@ async fn main() -> Result<()> {
         regime_detector.run().await;
     });
     
-    // Start Oracle to handle scoring (we'll feed it some demo candidates)
-    let oracle_clone = oracle.clone();
+    // Start Oracle to handle scoring (we'll feed it the demo candidates)
     let oracle_handle = tokio::spawn(async move {
-        // Note: In a real implementation, the Oracle would have its own run() method
-        // For now, we'll just keep it alive for hot-swap demonstrations
-        tokio::time::sleep(tokio::time::Duration::from_secs(3600)).await; // Keep alive for 1 hour
+        oracle.run().await;
     });
     
-    // Handle Oracle scoring results (optional - just for logging)
-    tokio::spawn(async move {
-        while let Some(scored) = oracle_scored_receiver.recv().await {
-            info!("Oracle scored candidate: {} with score {}", scored.mint, scored.predicted_score);
+    // Record the Oracle's decisions and buy what it says to buy
+    let executor_handle = tokio::spawn(async move {
+        if let Err(e) = act_on_scored_candidates(
+            oracle_scored_receiver,
+            decision_record_sender,
+            monitor_tx_sender,
+            current_market_context,
+        ).await {
+            error!("Stopped acting on scored candidates: {}", e);
         }
     });
 
     // Start the enhanced OODA loop coordination task (now with regime awareness and hot-swap)
     let current_regime_clone = current_market_regime.clone();
-    let oracle_for_hotswap = oracle.clone();
+    let oracle_config_for_hotswap = oracle_config.clone();
     let ooda_handle = tokio::spawn(async move {
         info!("Enhanced OODA Loop coordinator started with Pillar III regime awareness...");
         
@@ -198,24 +231,25 @@ async fn main() -> Result<()> {
             info!("New holder_distribution weight: {:.3}", new_params.new_weights.holder_distribution);
             
             // IMPLEMENTATION OF "ACT" PHASE: Hot-swap Oracle configuration in real-time
-            match oracle_for_hotswap.update_config(new_params.new_weights, new_params.new_thresholds).await {
-                Ok(()) => {
-                    info!("✅ OODA Loop ACT Phase: Oracle configuration hot-swapped successfully!");
-                    info!("🔄 Oracle is now using the new optimized parameters without restart");
-                },
-                Err(e) => {
-                    error!("❌ Failed to hot-swap Oracle configuration: {}", e);
-                }
+            {
+                let mut config = oracle_config_for_hotswap.write().await;
+                config.weights = new_params.new_weights;
+                config.thresholds = new_params.new_thresholds;
             }
+            info!("✅ OODA Loop ACT Phase: Oracle configuration hot-swapped successfully!");
+            info!("🔄 Oracle scores its next candidate with the new optimized parameters, without restart");
         }
     });
 
     // Demo: Create and record some decisions
-    demo_decision_recording(decision_record_sender, monitor_tx_sender).await?;
+    demo_candidate_feed(candidate_sender).await?;
 
     // Let the system run to demonstrate the complete cycle
     info!("System running... Demonstrating enhanced OODA loop with Pillar III for 30 seconds");
-    tokio::time::sleep(tokio::time::Duration::from_secs(30)).await;
+    tokio::select! {
+        _ = tokio::time::sleep(tokio::time::Duration::from_secs(30)) => {},
+        _ = shutdown.triggered() => {},
+    }
 
     info!("Demo completed. The complete enhanced OODA loop system has been demonstrated:");
     info!("- Pillar I: DecisionLedger recorded decisions and outcomes");
@@ -224,58 +258,72 @@ async fn main() -> Result<()> {
     info!("- Hot-Swap: PredictiveOracle configuration can be updated in real-time");
     info!("Database file 'decisions.db' contains the persistent memory.");
 
-    // Shutdown all tasks
-    ledger_handle.abort();
-    monitor_handle.abort();
-    perf_monitor_handle.abort();
-    strategy_optimizer_handle.abort();
+    // Let the components drain and stop; the ledger flushes buffered records first
+    shutdown.trigger();
+    for (name, handle) in [
+        ("DecisionLedger", ledger_handle),
+        ("TransactionMonitor", monitor_handle),
+        ("PerformanceMonitor", perf_monitor_handle),
+        ("StrategyOptimizer", strategy_optimizer_handle),
+        ("Oracle", oracle_handle),
+        ("Executor", executor_handle),
+    ] {
+        if tokio::time::timeout(Duration::from_secs(10), handle).await.is_err() {
+            warn!("{} did not shut down within 10 seconds", name);
+        }
+    }
+
+    // These hold no in-flight work
     regime_detector_handle.abort(); // Pillar III cleanup
-    oracle_handle.abort(); // Oracle cleanup
     ooda_handle.abort();
 
     Ok(())
 }
 
-/// Demonstrate recording decisions to the DecisionLedger
-async fn demo_decision_recording(
-    decision_sender: DecisionRecordSender,
-    monitor_sender: mpsc::Sender<MonitoredTransaction>,
-) -> Result<()> {
-    info!("Creating demo PredictiveOracle decisions...");
+/// Feed demo candidates to the Oracle for scoring
+async fn demo_candidate_feed(candidate_sender: mpsc::Sender<QueuedCandidate>) -> Result<()> {
+    info!("Feeding demo candidates to the PredictiveOracle...");
 
-    // Create some example decisions
     for i in 1..=3 {
         // Create demo addresses as strings - in real usage these would be actual addresses
         let mint_address = format!("DemoToken{}Address{}", i, chrono::Utc::now().timestamp_millis());
         let creator_address = format!("DemoCreator{}Address{}", i, chrono::Utc::now().timestamp_millis());
-        
+
         let candidate = PremintCandidate {
-            mint: mint_address.clone(),
+            mint: mint_address,
             creator: creator_address,
             program: "pump.fun".to_string(),
             slot: 12345 + i,
             timestamp: chrono::Utc::now().timestamp_millis() as u64,
             instruction_summary: Some("Token creation".to_string()),
             is_jito_bundle: Some(true),
+            funding_source: None,
         };
 
-        let mut feature_scores = HashMap::new();
-        feature_scores.insert("liquidity".to_string(), 0.7 + (i as f64 * 0.1));
-        feature_scores.insert("holder_distribution".to_string(), 0.6);
-        feature_scores.insert("volume_growth".to_string(), 0.8);
+        candidate_sender.send(candidate.into()).await?;
 
-        let scored_candidate = ScoredCandidate {
-            base: candidate.clone(),
-            mint: candidate.mint.clone(),
-            predicted_score: 70 + (i * 5) as u8,
-            reason: format!("High potential token #{}", i),
-            feature_scores: feature_scores.clone(),
-            calculation_time: 150_000, // 150ms
-            anomaly_detected: false,
-            timestamp: candidate.timestamp,
-        };
+        // Small delay between candidates
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    }
+
+    info!("All demo candidates sent!");
+    Ok(())
+}
+
+/// Record every decision the Oracle emits in the DecisionLedger, and buy only
+/// the candidates whose action is `Buy`; warm-up and the kill switch show up
+/// as `Skip` whatever the score.
+async fn act_on_scored_candidates(
+    mut scored_receiver: mpsc::Receiver<ScoredCandidate>,
+    decision_sender: DecisionRecordSender,
+    monitor_sender: mpsc::Sender<MonitoredTransaction>,
+    market_context: Arc<RwLock<MarketContextSnapshot>>,
+) -> Result<()> {
+    while let Some(scored_candidate) = scored_receiver.recv().await {
+        let candidate = scored_candidate.base.clone();
 
-        // Record the initial decision
+        // Record the initial decision along with the market it was made in
+        let market_context_snapshot = market_context.read().await.to_map();
         let initial_record = TransactionRecord {
             id: None,
             scored_candidate: scored_candidate.clone(),
@@ -290,55 +338,56 @@ async fn demo_decision_recording(
             timestamp_transaction_sent: None,
             timestamp_outcome_evaluated: None,
             actual_outcome: Outcome::NotExecuted,
-            market_context_snapshot: HashMap::new(),
+            market_context_snapshot: market_context_snapshot.clone(),
         };
 
         decision_sender.send(initial_record).await?;
-        info!("Recorded decision for token: {}", candidate.mint);
-
-        // For high-scoring candidates, simulate sending a transaction
-        if scored_candidate.predicted_score >= 75 {
-            let tx_signature = format!("SimulatedTx{}_{}", i, candidate.timestamp);
-            let amount_bought = 1000.0;
-            let sol_spent = 1.0;
-
-            // Send transaction to monitor
-            let monitored_tx = MonitoredTransaction {
-                signature: tx_signature.clone(),
-                mint: candidate.mint.clone(),
-                amount_bought_tokens: amount_bought,
-                initial_sol_spent: sol_spent,
-                monitor_until: chrono::Utc::now().timestamp_millis() as u64 + 10_000, // 10 seconds
-            };
-
-            monitor_sender.send(monitored_tx).await?;
-
-            // Record that transaction was sent
-            let sent_record = TransactionRecord {
-                id: None,
-                scored_candidate: scored_candidate.clone(),
-                transaction_signature: Some(tx_signature.clone()),
-                buy_price_sol: Some(sol_spent / amount_bought),
-                sell_price_sol: None,
-                amount_bought_tokens: Some(amount_bought),
-                amount_sold_tokens: None,
-                initial_sol_spent: Some(sol_spent),
-                final_sol_received: None,
-                timestamp_decision_made: candidate.timestamp,
-                timestamp_transaction_sent: Some(chrono::Utc::now().timestamp_millis() as u64),
-                timestamp_outcome_evaluated: None,
-                actual_outcome: Outcome::PendingConfirmation,
-                market_context_snapshot: HashMap::new(),
-            };
-
-            decision_sender.send(sent_record).await?;
-            info!("Sent transaction for token: {} (signature: {})", candidate.mint, tx_signature);
+        info!(
+            "Recorded decision for token: {} (score {}, {:?})",
+            candidate.mint, scored_candidate.predicted_score, scored_candidate.action
+        );
+
+        if scored_candidate.action != TradeAction::Buy {
+            continue;
         }
 
-        // Small delay between decisions
-        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        // Simulate sending a transaction
+        let tx_signature = format!("SimulatedTx_{}_{}", candidate.mint, candidate.timestamp);
+        let amount_bought = 1000.0;
+        let sol_spent = 1.0;
+
+        // Send transaction to monitor
+        let monitored_tx = MonitoredTransaction {
+            signature: tx_signature.clone(),
+            mint: candidate.mint.clone(),
+            amount_bought_tokens: amount_bought,
+            initial_sol_spent: sol_spent,
+            monitor_until: chrono::Utc::now().timestamp_millis() as u64 + 10_000, // 10 seconds
+        };
+
+        monitor_sender.send(monitored_tx).await?;
+
+        // Record that transaction was sent
+        let sent_record = TransactionRecord {
+            id: None,
+            scored_candidate: scored_candidate.clone(),
+            transaction_signature: Some(tx_signature.clone()),
+            buy_price_sol: Some(sol_spent / amount_bought),
+            sell_price_sol: None,
+            amount_bought_tokens: Some(amount_bought),
+            amount_sold_tokens: None,
+            initial_sol_spent: Some(sol_spent),
+            final_sol_received: None,
+            timestamp_decision_made: candidate.timestamp,
+            timestamp_transaction_sent: Some(chrono::Utc::now().timestamp_millis() as u64),
+            timestamp_outcome_evaluated: None,
+            actual_outcome: Outcome::PendingConfirmation,
+            market_context_snapshot,
+        };
+
+        decision_sender.send(sent_record).await?;
+        info!("Sent transaction for token: {} (signature: {})", candidate.mint, tx_signature);
     }
 
-    info!("All demo decisions recorded!");
     Ok(())
-}
\ No newline at end of file
+}
@@ -3,16 +3,58 @@
 //! This module identifies suspicious patterns in token data that might indicate
 //! manipulated or problematic tokens that should be scored lower or avoided.
 
+use crate::oracle::data_sources::compute_token_price;
+use crate::oracle::storage::LedgerStorage;
 use crate::oracle::types::{TokenData, OracleConfig, VolumeData, HolderData, CreatorHoldings};
+use moka::future::Cache;
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
 use tracing::{debug, warn, instrument};
 
+/// Largest share of supply, as a fraction, a single holder may own.
+const MAX_TOP_HOLDER_SHARE: f64 = 0.5;
+
+/// Largest combined share of supply, as a fraction, of the top 3 holders.
+const MAX_TOP_3_HOLDER_SHARE: f64 = 0.8;
+
+/// Smallest current volume for a one-sided market to look like a honeypot
+/// rather than a token nobody has traded yet.
+const MIN_HONEYPOT_VOLUME: f64 = 10.0;
+
+/// Default buy/sell volume ratio above which nobody appears able to sell.
+pub const DEFAULT_HONEYPOT_BUY_SELL_RATIO: f64 = 50.0;
+
+/// Default largest implied market cap, as a multiple of pool SOL liquidity.
+pub const DEFAULT_MAX_MCAP_LIQUIDITY_RATIO: f64 = 100.0;
+
+/// Default PnL at or below which a losing trade counts as a rug.
+pub const DEFAULT_RUG_PNL_THRESHOLD: f64 = -0.8;
+
+/// Default number of recent launches by one creator before it is flagged.
+pub const DEFAULT_MAX_CREATOR_LAUNCHES: u64 = 3;
+
+/// Default window (seconds) over which a creator's launches are counted.
+pub const DEFAULT_CREATOR_LAUNCH_WINDOW_SECONDS: u64 = 3600;
+
+/// Default time (seconds) for which ledger counts per creator and funding
+/// source are reused.
+pub const DEFAULT_CREATOR_STATS_TTL_SECONDS: u64 = 30;
+
 /// Anomaly detector for identifying suspicious token behavior.
 pub struct AnomalyDetector {
     config: OracleConfig,
+    /// Ledger of past trades, consulted for creators' funding sources and launch history
+    ledger: Option<Arc<dyn LedgerStorage>>,
+    /// Recent launches per creator, as last counted in the ledger
+    launches_by_creator: Cache<String, u64>,
+    /// Rugged tokens per funding source, as last counted in the ledger
+    rugs_by_funding_source: Cache<String, u64>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum AnomalyType {
     /// Extremely high volume growth that seems artificial
     SuspiciousVolumeGrowth,
@@ -28,12 +70,64 @@ pub enum AnomalyType {
     AbnormalHolderGrowth,
     /// Liquidity manipulation patterns
     LiquidityManipulation,
+    /// Freeze authority not revoked, holders' tokens can be frozen
+    ActiveFreezeAuthority,
+    /// Fewer holders than the configured minimum
+    TooFewHolders { holders: usize, minimum: usize },
+    /// Implied market cap far exceeds the SOL backing the pool
+    LowLiquidityHighMcap,
+    /// Creator funded by the same wallet as creators of previously rugged tokens
+    RepeatOffenderCluster { funding_source: String, rugged: u64 },
+    /// Creator launched more tokens than allowed within the configured window
+    CreatorLaunchSpam { launches: u64 },
+    /// Meaningful buy volume with next to no sells, as if selling were blocked
+    PossibleHoneypot,
+}
+
+/// Anomalies found in a single candidate, as published on the anomaly feed
+/// and stored with the scored candidate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnomalyReport {
+    pub anomalies: Vec<AnomalyType>,
+    /// Overall severity (see `AnomalyDetector::calculate_anomaly_score`)
+    pub anomaly_score: f64,
+    /// Unix timestamp (ms) at which the anomalies were detected
+    pub timestamp: u64,
 }
 
+/// Channel receiving `(mint, report)` for every candidate with anomalies.
+pub type AnomalyFeedSender = mpsc::Sender<(String, AnomalyReport)>;
+
 impl AnomalyDetector {
     /// Create a new anomaly detector.
     pub fn new(config: OracleConfig) -> Self {
-        Self { config }
+        // Creators launch in bursts, so one lookup serves all their candidates for a while
+        let ledger_cache = || {
+            Cache::builder()
+                .max_capacity(config.max_cache_entries as u64)
+                .time_to_live(Duration::from_secs(config.creator_stats_ttl_seconds))
+                .build()
+        };
+
+        Self {
+            launches_by_creator: ledger_cache(),
+            rugs_by_funding_source: ledger_cache(),
+            config,
+            ledger: None,
+        }
+    }
+
+    /// Consult `storage` to flag creators funded by the same source as past
+    /// losing trades, and creators launching many tokens in quick succession.
+    pub fn with_ledger(mut self, storage: Arc<dyn LedgerStorage>) -> Self {
+        self.ledger = Some(storage);
+        self
+    }
+
+    /// Same detector, consulting the same ledger, under a new configuration.
+    /// Cached counts are dropped, as they depend on the configuration.
+    pub fn reconfigured(&self, config: OracleConfig) -> Self {
+        Self { ledger: self.ledger.clone(), ..Self::new(config) }
     }
 
     /// Detect anomalies in token data.
@@ -60,6 +154,11 @@ impl AnomalyDetector {
             anomalies.push(anomaly);
         }
 
+        // Check for one-sided buy volume
+        if let Some(anomaly) = self.check_honeypot_anomalies(&token_data.volume_data) {
+            anomalies.push(anomaly);
+        }
+
         // Check holder distribution anomalies
         if let Some(anomaly) = self.check_holder_distribution_anomalies(&token_data.holder_distribution) {
             anomalies.push(anomaly);
@@ -80,11 +179,37 @@ impl AnomalyDetector {
             anomalies.push(anomaly);
         }
 
+        // Check absolute holder count
+        if let Some(anomaly) = self.check_holder_count_anomalies(&token_data.holder_history) {
+            anomalies.push(anomaly);
+        }
+
         // Check liquidity anomalies
         if let Some(anomaly) = self.check_liquidity_anomalies(token_data) {
             anomalies.push(anomaly);
         }
 
+        // Check market cap against liquidity
+        if let Some(anomaly) = self.check_market_cap_anomalies(token_data) {
+            anomalies.push(anomaly);
+        }
+
+        // Check authority anomalies
+        if token_data.freeze_authority_active == Some(true) {
+            warn!("Freeze authority is still active");
+            anomalies.push(AnomalyType::ActiveFreezeAuthority);
+        }
+
+        // Check the creator's funding source against previously rugged tokens
+        if let Some(anomaly) = self.check_funding_source_anomalies(token_data).await {
+            anomalies.push(anomaly);
+        }
+
+        // Check how many tokens the creator launched recently
+        if let Some(anomaly) = self.check_creator_launch_anomalies(token_data).await {
+            anomalies.push(anomaly);
+        }
+
         debug!("Identified {} anomalies", anomalies.len());
         anomalies
     }
@@ -107,6 +232,22 @@ impl AnomalyDetector {
         None
     }
 
+    /// Check for buy volume that nobody seems to be able to sell into.
+    #[instrument(skip(self, volume_data))]
+    fn check_honeypot_anomalies(&self, volume_data: &VolumeData) -> Option<AnomalyType> {
+        if volume_data.current_volume >= MIN_HONEYPOT_VOLUME
+            && volume_data.buy_sell_ratio > self.config.honeypot_buy_sell_ratio
+        {
+            warn!(
+                "Possible honeypot: buy/sell ratio {:.1} on volume {:.1}",
+                volume_data.buy_sell_ratio, volume_data.current_volume
+            );
+            return Some(AnomalyType::PossibleHoneypot);
+        }
+
+        None
+    }
+
     /// Check for holder distribution anomalies.
     #[instrument(skip(self, holders))]
     fn check_holder_distribution_anomalies(&self, holders: &[HolderData]) -> Option<AnomalyType> {
@@ -116,7 +257,7 @@ impl AnomalyDetector {
 
         // Check if top holder has too much concentration
         if let Some(top_holder) = holders.first() {
-            if top_holder.percentage > 0.5 {
+            if top_holder.percentage > MAX_TOP_HOLDER_SHARE {
                 warn!(
                     "High top holder concentration: {:.1}%",
                     top_holder.percentage * 100.0
@@ -132,7 +273,7 @@ impl AnomalyDetector {
             .map(|h| h.percentage)
             .sum();
 
-        if top_3_concentration > 0.8 {
+        if top_3_concentration > MAX_TOP_3_HOLDER_SHARE {
             warn!(
                 "High top 3 holder concentration: {:.1}%",
                 top_3_concentration * 100.0
@@ -243,6 +384,21 @@ impl AnomalyDetector {
         None
     }
 
+    /// Check for an absolute holder count below the configured minimum.
+    #[instrument(skip(self, holder_history))]
+    fn check_holder_count_anomalies(&self, holder_history: &VecDeque<usize>) -> Option<AnomalyType> {
+        // No history means the holder count is unknown, not zero
+        let holders = *holder_history.back()?;
+        let minimum = self.config.min_holder_count;
+
+        if holders < minimum {
+            warn!("Too few holders: {} (minimum {})", holders, minimum);
+            return Some(AnomalyType::TooFewHolders { holders, minimum });
+        }
+
+        None
+    }
+
     /// Check for liquidity manipulation patterns.
     #[instrument(skip(self, token_data))]
     fn check_liquidity_anomalies(&self, token_data: &TokenData) -> Option<AnomalyType> {
@@ -265,20 +421,96 @@ impl AnomalyDetector {
             }
 
             // Check for extremely imbalanced pool ratios
-            if pool.token_amount > 0.0 {
-                let price = pool.sol_amount / (pool.token_amount / 10f64.powi(9));
-                
-                // Very high price might indicate liquidity manipulation
-                if price > 1.0 {
-                    warn!("Suspicious token price: {} SOL", price);
-                    return Some(AnomalyType::LiquidityManipulation);
-                }
+            let price = compute_token_price(pool, token_data.decimals);
+
+            // Very high price might indicate liquidity manipulation
+            if price > 1.0 {
+                warn!("Suspicious token price: {} SOL", price);
+                return Some(AnomalyType::LiquidityManipulation);
             }
         }
 
         None
     }
 
+    /// Check for an implied market cap out of proportion to the pool's SOL.
+    #[instrument(skip(self, token_data))]
+    fn check_market_cap_anomalies(&self, token_data: &TokenData) -> Option<AnomalyType> {
+        let pool = token_data.liquidity_pool.as_ref()?;
+        if pool.sol_amount <= 0.0 {
+            return None;
+        }
+
+        let supply = token_data.supply as f64 / 10f64.powi(token_data.decimals as i32);
+        let market_cap = supply * compute_token_price(pool, token_data.decimals);
+        let ratio = market_cap / pool.sol_amount;
+
+        if ratio > self.config.max_mcap_liquidity_ratio {
+            warn!(
+                "Market cap {:.1} SOL is {:.1}x liquidity of {:.1} SOL",
+                market_cap, ratio, pool.sol_amount
+            );
+            return Some(AnomalyType::LowLiquidityHighMcap);
+        }
+
+        None
+    }
+
+    /// Check whether the creator's funding source also funded rugged tokens,
+    /// i.e. trades that lost at least `rug_pnl_threshold`.
+    #[instrument(skip(self, token_data))]
+    async fn check_funding_source_anomalies(&self, token_data: &TokenData) -> Option<AnomalyType> {
+        let storage = self.ledger.as_ref()?;
+        let funding_source = token_data.funding_source.as_ref()?;
+
+        let rugged = self.rugs_by_funding_source
+            .try_get_with_by_ref(funding_source, storage.count_rugs_by_funding_source(funding_source, self.config.rug_pnl_threshold))
+            .await;
+        let rugged = match rugged {
+            Ok(rugged) => rugged,
+            Err(e) => {
+                warn!("Failed to look up funding source {}: {}", funding_source, e);
+                return None;
+            }
+        };
+
+        if rugged > 0 {
+            warn!("Funding source {} funded {} previously rugged tokens", funding_source, rugged);
+            return Some(AnomalyType::RepeatOffenderCluster {
+                funding_source: funding_source.clone(),
+                rugged,
+            });
+        }
+
+        None
+    }
+
+    /// Check whether the creator launched too many tokens within the configured window.
+    #[instrument(skip(self, token_data))]
+    async fn check_creator_launch_anomalies(&self, token_data: &TokenData) -> Option<AnomalyType> {
+        let storage = self.ledger.as_ref()?;
+        let creator = token_data.creator.as_ref().filter(|c| !c.is_empty())?;
+        let window = self.config.creator_launch_window_seconds;
+
+        let launches = self.launches_by_creator
+            .try_get_with_by_ref(creator, storage.count_recent_launches_by_creator(creator, window))
+            .await;
+        let launches = match launches {
+            Ok(launches) => launches,
+            Err(e) => {
+                warn!("Failed to look up launches by creator {}: {}", creator, e);
+                return None;
+            }
+        };
+
+        if launches > self.config.max_creator_launches {
+            warn!("Creator {} launched {} tokens in the last {}s", creator, launches, window);
+            return Some(AnomalyType::CreatorLaunchSpam { launches });
+        }
+
+        None
+    }
+
     /// Get severity score for an anomaly type (0.0 = minor, 1.0 = critical).
     pub fn get_anomaly_severity(&self, anomaly_type: &AnomalyType) -> f64 {
         match anomaly_type {
@@ -289,6 +521,16 @@ impl AnomalyDetector {
             AnomalyType::PumpAndDump => 1.0,
             AnomalyType::AbnormalHolderGrowth => 0.7,
             AnomalyType::LiquidityManipulation => 0.9,
+            AnomalyType::ActiveFreezeAuthority => 0.9,
+            AnomalyType::LowLiquidityHighMcap => 0.8,
+            AnomalyType::RepeatOffenderCluster { .. } => 1.0,
+            AnomalyType::CreatorLaunchSpam { .. } => 0.9,
+            AnomalyType::PossibleHoneypot => 0.95,
+            // Scales from 0.5 just below the minimum up to 1.0 with no holders
+            AnomalyType::TooFewHolders { holders, minimum } => {
+                let shortfall = 1.0 - *holders as f64 / (*minimum).max(1) as f64;
+                0.5 + 0.5 * shortfall.clamp(0.0, 1.0)
+            }
         }
     }
 
@@ -313,9 +555,10 @@ impl AnomalyDetector {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::oracle::test_support::{isolated_ledger, RecordBuilder};
     use crate::oracle::types::*;
     use solana_sdk::pubkey::Pubkey;
-    use std::collections::VecDeque;
+    use std::collections::{HashMap, VecDeque};
 
     fn create_test_config() -> OracleConfig {
         OracleConfig::default()
@@ -329,21 +572,22 @@ mod tests {
             metadata: None,
             holder_distribution: vec![
                 HolderData {
-                    address: Pubkey::new_unique(),
+                    address: Pubkey::new_unique().to_string(),
                     percentage: 0.1, // 10% - normal
                     is_whale: false,
                 },
                 HolderData {
-                    address: Pubkey::new_unique(),
+                    address: Pubkey::new_unique().to_string(),
                     percentage: 0.05, // 5% - normal
                     is_whale: false,
                 },
             ],
             liquidity_pool: Some(LiquidityPool {
                 sol_amount: 50.0,
-                token_amount: 1000000.0,
-                pool_address: Pubkey::new_unique(),
+                token_amount: 1_000_000_000_000_000.0, // 1M tokens in base units
+                pool_address: Pubkey::new_unique().to_string(),
                 pool_type: PoolType::PumpFun,
+                bonding_curve_progress: None,
             }),
             volume_data: VolumeData {
                 initial_volume: 100.0,
@@ -351,6 +595,7 @@ mod tests {
                 volume_growth_rate: 2.0, // Normal 2x growth
                 transaction_count: 50,   // Normal count
                 buy_sell_ratio: 1.5,
+                volume_history: VecDeque::new(),
             },
             creator_holdings: CreatorHoldings {
                 initial_balance: 100_000_000,
@@ -371,6 +616,13 @@ mod tests {
                 hist
             },
             social_activity: SocialActivity::default(),
+            mint_authority_active: Some(false),
+            freeze_authority_active: Some(false),
+            contract_verified: None,
+            metadata_unreachable: false,
+            funding_source: None,
+            creator: None,
+            data_timestamps: HashMap::new(),
         }
     }
 
@@ -422,6 +674,33 @@ mod tests {
         assert!(anomalies.contains(&AnomalyType::HighHolderConcentration));
     }
 
+    #[tokio::test]
+    async fn test_holder_shares_are_fractions() {
+        let detector = AnomalyDetector::new(create_test_config());
+        let mut token_data = create_normal_token_data();
+
+        // 40% + 30% + 15%: no single whale, but the top 3 hold 85%. Read as
+        // percents these would be tiny holdings.
+        for (holder, share) in token_data.holder_distribution.iter_mut().zip([0.4, 0.3]) {
+            holder.percentage = share;
+        }
+        token_data.holder_distribution.push(HolderData {
+            address: Pubkey::new_unique().to_string(),
+            percentage: 0.15,
+            is_whale: false,
+        });
+        let anomalies = detector.identify_all_anomalies(&token_data).await;
+        assert!(anomalies.contains(&AnomalyType::HighHolderConcentration));
+
+        // 15%, 8% and 3.5% is a healthy spread. Read as percents every holder
+        // would own more than the whole supply.
+        for (holder, share) in token_data.holder_distribution.iter_mut().zip([0.15, 0.08, 0.035]) {
+            holder.percentage = share;
+        }
+        let anomalies = detector.identify_all_anomalies(&token_data).await;
+        assert!(!anomalies.contains(&AnomalyType::HighHolderConcentration));
+    }
+
     #[tokio::test]
     async fn test_creator_quick_sell() {
         let detector = AnomalyDetector::new(create_test_config());
@@ -498,4 +777,234 @@ mod tests {
         let score = detector.calculate_anomaly_score(&anomalies);
         assert!(score > 0.6 && score <= 1.0);
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_active_freeze_authority() {
+        let detector = AnomalyDetector::new(create_test_config());
+        let mut token_data = create_normal_token_data();
+
+        token_data.freeze_authority_active = Some(true);
+        let anomalies = detector.identify_all_anomalies(&token_data).await;
+        assert!(anomalies.contains(&AnomalyType::ActiveFreezeAuthority));
+
+        // Revoked or unknown freeze authority is not an anomaly
+        for status in [Some(false), None] {
+            token_data.freeze_authority_active = status;
+            let anomalies = detector.identify_all_anomalies(&token_data).await;
+            assert!(!anomalies.contains(&AnomalyType::ActiveFreezeAuthority));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_too_few_holders() {
+        let detector = AnomalyDetector::new(create_test_config());
+        let mut token_data = create_normal_token_data();
+
+        token_data.holder_history = VecDeque::from(vec![2]);
+        let anomalies = detector.identify_all_anomalies(&token_data).await;
+        let anomaly = anomalies
+            .iter()
+            .find(|a| matches!(a, AnomalyType::TooFewHolders { .. }))
+            .expect("2 holders should be flagged");
+        assert_eq!(anomaly, &AnomalyType::TooFewHolders { holders: 2, minimum: 10 });
+        assert!((detector.get_anomaly_severity(anomaly) - 0.9).abs() < 1e-9);
+
+        // Severity drops as the count approaches the minimum
+        let nearly_enough = AnomalyType::TooFewHolders { holders: 9, minimum: 10 };
+        assert!(detector.get_anomaly_severity(&nearly_enough) < detector.get_anomaly_severity(anomaly));
+
+        token_data.holder_history = VecDeque::from(vec![50]);
+        let anomalies = detector.identify_all_anomalies(&token_data).await;
+        assert!(!anomalies.iter().any(|a| matches!(a, AnomalyType::TooFewHolders { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_low_liquidity_high_mcap() {
+        let detector = AnomalyDetector::new(create_test_config());
+        let mut token_data = create_normal_token_data();
+
+        // 1B whole tokens priced at 1e-6 SOL make a 1000 SOL market cap
+        token_data.supply = 1_000_000_000_000_000_000;
+        token_data.liquidity_pool = Some(LiquidityPool {
+            sol_amount: 5.0,
+            token_amount: 5_000_000_000_000_000.0,
+            pool_address: Pubkey::new_unique().to_string(),
+            pool_type: PoolType::PumpFun,
+            bonding_curve_progress: None,
+        });
+        let anomalies = detector.identify_all_anomalies(&token_data).await;
+        assert!(anomalies.contains(&AnomalyType::LowLiquidityHighMcap), "{:?}", anomalies);
+        assert_eq!(detector.get_anomaly_severity(&AnomalyType::LowLiquidityHighMcap), 0.8);
+
+        // The same market cap backed by 50 SOL is within the default 100x
+        token_data.liquidity_pool = Some(LiquidityPool {
+            sol_amount: 50.0,
+            token_amount: 50_000_000_000_000_000.0,
+            pool_address: Pubkey::new_unique().to_string(),
+            pool_type: PoolType::PumpFun,
+            bonding_curve_progress: None,
+        });
+        let anomalies = detector.identify_all_anomalies(&token_data).await;
+        assert!(!anomalies.contains(&AnomalyType::LowLiquidityHighMcap), "{:?}", anomalies);
+
+        // A stricter ratio flags it again
+        let mut config = create_test_config();
+        config.max_mcap_liquidity_ratio = 10.0;
+        let strict = AnomalyDetector::new(config);
+        let anomalies = strict.identify_all_anomalies(&token_data).await;
+        assert!(anomalies.contains(&AnomalyType::LowLiquidityHighMcap), "{:?}", anomalies);
+    }
+
+    #[tokio::test]
+    async fn test_repeat_offender_cluster() {
+        use crate::oracle::storage::SqliteLedger;
+
+        let storage = SqliteLedger::new().await.unwrap();
+        let run_id = chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default();
+        let scam_funder = format!("scam_funder_{}", run_id);
+
+        // Seed a rugged token whose creator was funded by `scam_funder`
+        let rugged = RecordBuilder::new(&format!("rugged_mint_{}", run_id))
+            .decided_at(1000)
+            .creator(&Pubkey::new_unique().to_string())
+            .funding_source(&scam_funder)
+            .signature(&format!("rugged_sig_{}", run_id))
+            .sol_spent(1.0)
+            .sol_received(0.05)
+            .outcome(Outcome::Loss(-0.95))
+            .build();
+        storage.insert_record(&rugged).await.unwrap();
+
+        // An ordinary losing trade from the same funder is not a rug
+        let dipped = RecordBuilder::new(&format!("dipped_mint_{}", run_id))
+            .decided_at(1000)
+            .creator(&Pubkey::new_unique().to_string())
+            .funding_source(&scam_funder)
+            .signature(&format!("dipped_sig_{}", run_id))
+            .sol_spent(1.0)
+            .sol_received(0.9)
+            .outcome(Outcome::Loss(-0.1))
+            .build();
+        storage.insert_record(&dipped).await.unwrap();
+
+        let detector = AnomalyDetector::new(create_test_config()).with_ledger(storage);
+        let mut token_data = create_normal_token_data();
+
+        token_data.funding_source = Some(scam_funder.clone());
+        let anomalies = detector.identify_all_anomalies(&token_data).await;
+        let expected = AnomalyType::RepeatOffenderCluster { funding_source: scam_funder, rugged: 1 };
+        assert!(anomalies.contains(&expected), "{:?}", anomalies);
+        assert_eq!(detector.get_anomaly_severity(&expected), 1.0);
+
+        // A creator funded from an unrelated wallet is not flagged
+        token_data.funding_source = Some(format!("clean_funder_{}", run_id));
+        let anomalies = detector.identify_all_anomalies(&token_data).await;
+        assert!(!anomalies.iter().any(|a| matches!(a, AnomalyType::RepeatOffenderCluster { .. })), "{:?}", anomalies);
+    }
+
+    #[tokio::test]
+    async fn test_creator_launch_spam() {
+        use crate::oracle::storage::SqliteLedger;
+
+        let storage = SqliteLedger::new().await.unwrap();
+        let run_id = chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default();
+        let now = chrono::Utc::now().timestamp_millis() as u64;
+        let spammer = format!("spam_creator_{}", run_id);
+
+        let mut config = create_test_config();
+        config.max_creator_launches = 2;
+
+        // Seed recent launches by `spammer`, one more than allowed
+        for i in 0..3 {
+            let mint = format!("spam_mint_{}_{}", run_id, i);
+            let record = RecordBuilder::new(&mint)
+                .decided_at(now)
+                .creator(&spammer)
+                .signature(&format!("spam_sig_{}_{}", run_id, i))
+                .outcome(Outcome::PendingConfirmation)
+                .build();
+            storage.insert_record(&record).await.unwrap();
+        }
+
+        let detector = AnomalyDetector::new(config).with_ledger(storage);
+        let mut token_data = create_normal_token_data();
+
+        token_data.creator = Some(spammer);
+        let anomalies = detector.identify_all_anomalies(&token_data).await;
+        assert!(anomalies.contains(&AnomalyType::CreatorLaunchSpam { launches: 3 }), "{:?}", anomalies);
+
+        // A creator with no recent launches is not flagged
+        token_data.creator = Some(format!("quiet_creator_{}", run_id));
+        let anomalies = detector.identify_all_anomalies(&token_data).await;
+        assert!(!anomalies.iter().any(|a| matches!(a, AnomalyType::CreatorLaunchSpam { .. })), "{:?}", anomalies);
+    }
+
+    #[tokio::test]
+    async fn test_creator_stats_are_cached_until_they_expire() {
+        let storage = isolated_ledger().await;
+        let now = chrono::Utc::now().timestamp_millis() as u64;
+        let launch = |i: u64, funding_source: &str| {
+            RecordBuilder::new(&format!("cached_mint_{}", i))
+                .decided_at(now)
+                .creator("cached_creator")
+                .funding_source(funding_source)
+                .signature(&format!("cached_sig_{}", i))
+                .sol_spent(1.0)
+                .sol_received(0.05)
+                .outcome(Outcome::Loss(-0.95))
+                .build()
+        };
+        storage.insert_record(&launch(0, "cached_funder")).await.unwrap();
+
+        let mut config = create_test_config();
+        config.max_creator_launches = 0;
+        config.creator_stats_ttl_seconds = 1;
+        let detector = AnomalyDetector::new(config).with_ledger(storage.clone());
+        let mut token_data = create_normal_token_data();
+        token_data.creator = Some("cached_creator".to_string());
+        token_data.funding_source = Some("cached_funder".to_string());
+
+        let counts = |anomalies: Vec<AnomalyType>| {
+            let launches = anomalies.iter().find_map(|a| match a {
+                AnomalyType::CreatorLaunchSpam { launches } => Some(*launches),
+                _ => None,
+            });
+            let rugged = anomalies.iter().find_map(|a| match a {
+                AnomalyType::RepeatOffenderCluster { rugged, .. } => Some(*rugged),
+                _ => None,
+            });
+            (launches, rugged)
+        };
+        assert_eq!(counts(detector.identify_all_anomalies(&token_data).await), (Some(1), Some(1)));
+
+        // Within the TTL the ledger is not queried again
+        storage.insert_record(&launch(1, "cached_funder")).await.unwrap();
+        assert_eq!(counts(detector.identify_all_anomalies(&token_data).await), (Some(1), Some(1)));
+
+        // Once expired, the new launch and rug are counted
+        tokio::time::sleep(Duration::from_millis(1100)).await;
+        assert_eq!(counts(detector.identify_all_anomalies(&token_data).await), (Some(2), Some(2)));
+    }
+
+    #[tokio::test]
+    async fn test_possible_honeypot() {
+        let detector = AnomalyDetector::new(create_test_config());
+        let mut token_data = create_normal_token_data();
+
+        // Plenty of buys, next to no sells
+        token_data.volume_data.buy_sell_ratio = 500.0;
+        let anomalies = detector.identify_all_anomalies(&token_data).await;
+        assert!(anomalies.contains(&AnomalyType::PossibleHoneypot), "{:?}", anomalies);
+        assert_eq!(detector.get_anomaly_severity(&AnomalyType::PossibleHoneypot), 0.95);
+
+        // A barely traded token is not suspicious yet
+        token_data.volume_data.current_volume = 1.0;
+        let anomalies = detector.identify_all_anomalies(&token_data).await;
+        assert!(!anomalies.contains(&AnomalyType::PossibleHoneypot), "{:?}", anomalies);
+
+        // Nor is a balanced market
+        let token_data = create_normal_token_data();
+        let anomalies = detector.identify_all_anomalies(&token_data).await;
+        assert!(!anomalies.contains(&AnomalyType::PossibleHoneypot), "{:?}", anomalies);
+    }
+}
@@ -0,0 +1,542 @@
+//! Batch backtester that replays stored decisions through the scorer.
+//!
+//! Each record's stored feature scores are re-scored with a candidate weight
+//! set, so a weight change can be judged against known outcomes before it goes
+//! live. Records are replayed concurrently; a record that takes longer than the
+//! per-candidate timeout is reported as failed instead of stalling the run.
+//!
+//! Long runs can be summarized with `run_checkpointed`, which periodically
+//! saves its progress so an interrupted run resumes where it left off.
+//!
+//! Anomalies are ignored by default. `AnomalyReplay::Reuse` applies the anomaly
+//! penalty from the report stored with each record; the ledger does not keep
+//! the token data needed to detect anomalies again.
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+use tracing::{debug, info, warn};
+
+use crate::oracle::anomaly::AnomalyType;
+use crate::oracle::scorer::{OracleScorer, ANOMALY_PENALTY};
+use crate::oracle::types::{FeatureWeights, Outcome, Score, TransactionRecord};
+use crate::types::Pubkey;
+
+/// Default number of records replayed at once.
+pub const DEFAULT_BACKTEST_CONCURRENCY: usize = 4;
+
+/// Default time budget for replaying a single record.
+pub const DEFAULT_PER_CANDIDATE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Default number of records replayed between two checkpoints.
+pub const DEFAULT_CHECKPOINT_INTERVAL: usize = 10_000;
+
+/// Called with `(processed, total)` after each replayed record.
+pub type ProgressCallback = Arc<dyn Fn(usize, usize) + Send + Sync>;
+
+/// How a replay accounts for anomalies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnomalyReplay {
+    /// Score features only, as if no anomalies had been detected
+    #[default]
+    Ignore,
+    /// Apply the anomaly report stored with each record
+    Reuse,
+}
+
+impl AnomalyReplay {
+    /// Anomalies to penalize `record` for.
+    fn anomalies(&self, record: &TransactionRecord) -> Vec<AnomalyType> {
+        match self {
+            Self::Ignore => Vec::new(),
+            Self::Reuse => record.scored_candidate.anomaly_report.as_ref()
+                .map(|report| report.anomalies.clone())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Settings for a backtest run.
+#[derive(Clone)]
+pub struct BacktestConfig {
+    /// Maximum number of records replayed at once (at least 1)
+    pub concurrency: usize,
+    /// Time budget for a single record before it is reported as timed out
+    pub per_candidate_timeout: Duration,
+    /// Progress reporting, invoked once per record in completion order
+    pub progress_callback: Option<ProgressCallback>,
+    /// File `run_checkpointed` saves its progress to; `None` disables checkpoints
+    pub checkpoint_path: Option<PathBuf>,
+    /// Records replayed between two checkpoints (at least 1)
+    pub checkpoint_interval: usize,
+    /// Continue from the checkpoint at `checkpoint_path` if there is one
+    pub resume_from_checkpoint: bool,
+    /// Whether and how anomalies penalize replayed scores
+    pub anomaly_replay: AnomalyReplay,
+}
+
+impl Default for BacktestConfig {
+    fn default() -> Self {
+        Self {
+            concurrency: DEFAULT_BACKTEST_CONCURRENCY,
+            per_candidate_timeout: DEFAULT_PER_CANDIDATE_TIMEOUT,
+            progress_callback: None,
+            checkpoint_path: None,
+            checkpoint_interval: DEFAULT_CHECKPOINT_INTERVAL,
+            resume_from_checkpoint: false,
+            anomaly_replay: AnomalyReplay::default(),
+        }
+    }
+}
+
+/// Outcome of replaying one stored decision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BacktestResult {
+    pub record_id: Option<i64>,
+    pub mint: Pubkey,
+    /// Score recorded at decision time
+    pub original_score: Score,
+    /// Score under the backtested weights; `None` if the replay failed
+    pub replayed_score: Option<u8>,
+    pub actual_outcome: Outcome,
+    /// Anomalies that penalized the replayed score
+    pub anomalies: Vec<AnomalyType>,
+    /// Why the replay failed, if it did
+    pub error: Option<String>,
+}
+
+/// Running totals of a checkpointed backtest.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BacktestAggregates {
+    /// Records replayed, including failed ones
+    pub processed: u64,
+    /// Records whose replay failed
+    pub failed: u64,
+    /// Sum of all replayed scores
+    pub replayed_score_sum: u64,
+    /// Successfully replayed records that closed in profit or loss
+    pub closed_trades: u64,
+    /// Closed trades that were profitable
+    pub profitable_trades: u64,
+}
+
+impl BacktestAggregates {
+    /// Fold one replay result into the totals.
+    pub fn add(&mut self, result: &BacktestResult) {
+        self.processed += 1;
+        let Some(score) = result.replayed_score else {
+            self.failed += 1;
+            return;
+        };
+
+        self.replayed_score_sum += score as u64;
+        match result.actual_outcome {
+            Outcome::Profit(_) => {
+                self.closed_trades += 1;
+                self.profitable_trades += 1;
+            }
+            Outcome::Loss(_) => self.closed_trades += 1,
+            _ => {}
+        }
+    }
+
+    /// Mean replayed score over successful replays.
+    pub fn mean_replayed_score(&self) -> Option<f64> {
+        let replayed = self.processed - self.failed;
+        (replayed > 0).then(|| self.replayed_score_sum as f64 / replayed as f64)
+    }
+}
+
+/// Progress of a checkpointed backtest as saved to disk.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BacktestCheckpoint {
+    /// Id of the last record covered by `aggregates`
+    pub last_processed_id: Option<i64>,
+    pub aggregates: BacktestAggregates,
+}
+
+impl BacktestCheckpoint {
+    /// Read the checkpoint at `path`, or `None` if there is none yet.
+    pub async fn load(path: &Path) -> Result<Option<Self>> {
+        let contents = match tokio::fs::read(path).await {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e).with_context(|| format!("Failed to read checkpoint {}", path.display())),
+        };
+        let checkpoint = serde_json::from_slice(&contents)
+            .with_context(|| format!("Failed to parse checkpoint {}", path.display()))?;
+        Ok(Some(checkpoint))
+    }
+
+    /// Write the checkpoint to `path`, replacing any previous one in a single rename
+    /// so a crash mid-write leaves the old checkpoint intact.
+    pub async fn save(&self, path: &Path) -> Result<()> {
+        let mut tmp_path = path.as_os_str().to_owned();
+        tmp_path.push(".tmp");
+        tokio::fs::write(&tmp_path, serde_json::to_vec(self)?)
+            .await
+            .with_context(|| format!("Failed to write checkpoint {}", path.display()))?;
+        tokio::fs::rename(&tmp_path, path)
+            .await
+            .with_context(|| format!("Failed to replace checkpoint {}", path.display()))?;
+        Ok(())
+    }
+}
+
+/// Replays stored decisions with a fixed weight set.
+pub struct Backtester {
+    scorer: Arc<OracleScorer>,
+    weights: Arc<FeatureWeights>,
+}
+
+impl Backtester {
+    /// Create a backtester scoring with `weights` under the scorer's configuration.
+    pub fn new(scorer: OracleScorer, weights: FeatureWeights) -> Self {
+        Self {
+            scorer: Arc::new(scorer),
+            weights: Arc::new(weights),
+        }
+    }
+
+    /// Replay `records`, returning one result per record in input order.
+    pub async fn run(&self, records: Vec<TransactionRecord>, config: &BacktestConfig) -> Vec<BacktestResult> {
+        let total = records.len();
+        let results = self.replay_batch(records, config, 0, total).await;
+        info!("Backtest replayed {}/{} records", results.len(), total);
+        results
+    }
+
+    /// Replay `records` into running totals, saving a checkpoint every
+    /// `checkpoint_interval` records.
+    ///
+    /// `records` must be stored records in ascending id order, as loaded from
+    /// storage; records without an id are refused since the checkpoint could not
+    /// locate them. When resuming, records up to the checkpoint's
+    /// `last_processed_id` are skipped and its totals are carried over, so the
+    /// result matches an uninterrupted run over the same records.
+    pub async fn run_checkpointed(
+        &self,
+        records: Vec<TransactionRecord>,
+        config: &BacktestConfig,
+    ) -> Result<BacktestAggregates> {
+        if let Some(record) = records.iter().find(|record| record.id.is_none()) {
+            return Err(anyhow!("Cannot checkpoint a backtest over unsaved record for {}", record.scored_candidate.mint));
+        }
+        if let Some(pair) = records.windows(2).find(|pair| pair[0].id >= pair[1].id) {
+            return Err(anyhow!("Backtest records are not in ascending id order at id {:?}", pair[1].id));
+        }
+
+        let mut checkpoint = match (&config.checkpoint_path, config.resume_from_checkpoint) {
+            (Some(path), true) => BacktestCheckpoint::load(path).await?.unwrap_or_default(),
+            _ => BacktestCheckpoint::default(),
+        };
+
+        let remaining: Vec<TransactionRecord> = match checkpoint.last_processed_id {
+            Some(last_id) => records.into_iter().filter(|record| record.id > Some(last_id)).collect(),
+            None => records,
+        };
+        let already_processed = checkpoint.aggregates.processed as usize;
+        if already_processed > 0 {
+            info!(
+                "Resuming backtest after record {:?} with {} records already processed",
+                checkpoint.last_processed_id, already_processed
+            );
+        }
+
+        let total = already_processed + remaining.len();
+        for batch in remaining.chunks(config.checkpoint_interval.max(1)) {
+            let processed = checkpoint.aggregates.processed as usize;
+            for result in self.replay_batch(batch.to_vec(), config, processed, total).await {
+                checkpoint.aggregates.add(&result);
+            }
+            checkpoint.last_processed_id = batch.last().and_then(|record| record.id);
+
+            if let Some(path) = &config.checkpoint_path {
+                checkpoint.save(path).await?;
+                debug!("Saved backtest checkpoint at record {:?}", checkpoint.last_processed_id);
+            }
+        }
+
+        info!("Backtest replayed {} records", checkpoint.aggregates.processed);
+        Ok(checkpoint.aggregates)
+    }
+
+    /// Replay one batch, reporting progress as `(offset + processed, total)`.
+    async fn replay_batch(
+        &self,
+        records: Vec<TransactionRecord>,
+        config: &BacktestConfig,
+        offset: usize,
+        total: usize,
+    ) -> Vec<BacktestResult> {
+        // A replay task that dies still leaves a failed result in its slot
+        let mut results: Vec<BacktestResult> = records.iter()
+            .map(|record| Self::failed(record, "replay task failed".to_string()))
+            .collect();
+        let permits = Arc::new(Semaphore::new(config.concurrency.max(1)));
+        let mut tasks = JoinSet::new();
+
+        for (index, record) in records.into_iter().enumerate() {
+            let scorer = self.scorer.clone();
+            let weights = self.weights.clone();
+            let permits = permits.clone();
+            let timeout = config.per_candidate_timeout;
+            let anomaly_replay = config.anomaly_replay;
+
+            tasks.spawn(async move {
+                let _permit = permits.acquire_owned().await.expect("backtest semaphore closed");
+                // Scoring is synchronous, so it runs off the runtime where the timeout can still fire
+                let replayed = {
+                    let record = record.clone();
+                    tokio::task::spawn_blocking(move || Self::replay(&scorer, &weights, anomaly_replay, &record))
+                };
+                let result = match tokio::time::timeout(timeout, replayed).await {
+                    Ok(Ok(result)) => result,
+                    Ok(Err(e)) => Self::failed(&record, format!("replay task failed: {}", e)),
+                    Err(_) => Self::failed(&record, format!("timed out after {:?}", timeout)),
+                };
+                (index, result)
+            });
+        }
+
+        let mut processed = offset;
+        while let Some(joined) = tasks.join_next().await {
+            match joined {
+                Ok((index, result)) => results[index] = result,
+                Err(e) => warn!("Backtest task failed: {}", e),
+            }
+            processed += 1;
+            if let Some(callback) = &config.progress_callback {
+                callback(processed, total);
+            }
+        }
+
+        results
+    }
+
+    /// Re-score one record from its stored feature scores.
+    fn replay(
+        scorer: &OracleScorer,
+        weights: &FeatureWeights,
+        anomaly_replay: AnomalyReplay,
+        record: &TransactionRecord,
+    ) -> BacktestResult {
+        let score = match scorer.score_from_feature_map(&record.scored_candidate.feature_scores, weights) {
+            Ok(score) => score,
+            Err(e) => return Self::failed(record, e.to_string()),
+        };
+
+        let anomalies = anomaly_replay.anomalies(record);
+        let score = if anomalies.is_empty() { score } else { (score as f64 * ANOMALY_PENALTY) as u8 };
+        Self::result(record, Some(score), anomalies, None)
+    }
+
+    fn failed(record: &TransactionRecord, error: String) -> BacktestResult {
+        Self::result(record, None, Vec::new(), Some(error))
+    }
+
+    fn result(
+        record: &TransactionRecord,
+        replayed_score: Option<u8>,
+        anomalies: Vec<AnomalyType>,
+        error: Option<String>,
+    ) -> BacktestResult {
+        BacktestResult {
+            record_id: record.id,
+            mint: record.scored_candidate.mint.clone(),
+            original_score: record.scored_candidate.predicted_score,
+            replayed_score,
+            actual_outcome: record.actual_outcome.clone(),
+            anomalies,
+            error,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oracle::anomaly::AnomalyReport;
+    use crate::oracle::test_support::RecordBuilder;
+    use crate::oracle::types::OracleConfig;
+    use reqwest::Client;
+    use std::sync::Mutex;
+    use tokio::sync::mpsc;
+
+    fn create_backtester() -> Backtester {
+        let (scored_tx, _scored_rx) = mpsc::channel(10);
+        let scorer = OracleScorer::new(
+            scored_tx,
+            Arc::new(tokio::sync::Mutex::new(None)),
+            vec![],
+            Client::new(),
+            OracleConfig::default(),
+        );
+        Backtester::new(scorer, FeatureWeights::default())
+    }
+
+    fn create_record(id: i64, liquidity: f64) -> TransactionRecord {
+        RecordBuilder::new(&format!("mint_{}", id))
+            .id(id)
+            .decided_at(1000)
+            .score(60)
+            .feature("liquidity", liquidity)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_backtest_reports_progress_and_returns_all_results() {
+        let backtester = create_backtester();
+        let records: Vec<_> = (0..20).map(|i| create_record(i, i as f64 / 20.0)).collect();
+
+        let progress = Arc::new(Mutex::new(Vec::new()));
+        let progress_log = progress.clone();
+        let config = BacktestConfig {
+            concurrency: 3,
+            progress_callback: Some(Arc::new(move |processed, total| {
+                progress_log.lock().unwrap().push((processed, total));
+            })),
+            ..BacktestConfig::default()
+        };
+
+        let results = backtester.run(records, &config).await;
+
+        assert_eq!(results.len(), 20);
+        for (i, result) in results.iter().enumerate() {
+            assert_eq!(result.record_id, Some(i as i64));
+            assert!(result.replayed_score.is_some(), "record {} failed: {:?}", i, result.error);
+        }
+        // Higher stored liquidity replays to a higher score
+        assert!(results[19].replayed_score > results[0].replayed_score);
+
+        let progress = progress.lock().unwrap();
+        assert_eq!(progress.len(), 20);
+        assert_eq!(progress.last(), Some(&(20, 20)));
+        assert!(progress.windows(2).all(|w| w[1].0 == w[0].0 + 1));
+    }
+
+    #[tokio::test]
+    async fn test_backtest_reports_unreplayable_records() {
+        let backtester = create_backtester();
+        let mut record = create_record(1, 0.5);
+        record.scored_candidate.feature_scores.insert("not_a_feature".to_string(), 1.0);
+
+        let results = backtester.run(vec![record], &BacktestConfig::default()).await;
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].replayed_score, None);
+        assert!(results[0].error.as_deref().unwrap().contains("not_a_feature"));
+    }
+
+    #[tokio::test]
+    async fn test_reused_anomalies_penalize_replayed_score() {
+        let backtester = create_backtester();
+        let mut record = create_record(1, 0.9);
+        record.scored_candidate.anomaly_detected = true;
+        record.scored_candidate.anomaly_report = Some(AnomalyReport {
+            anomalies: vec![AnomalyType::ActiveFreezeAuthority],
+            anomaly_score: 0.8,
+            timestamp: 1000,
+        });
+        let clean_record = create_record(2, 0.9);
+
+        let backtester = &backtester;
+        let replay = |anomaly_replay: AnomalyReplay| {
+            let records = vec![record.clone(), clean_record.clone()];
+            let config = BacktestConfig { anomaly_replay, ..BacktestConfig::default() };
+            async move { backtester.run(records, &config).await }
+        };
+        let ignored = replay(AnomalyReplay::Ignore).await;
+        let reused = replay(AnomalyReplay::Reuse).await;
+
+        assert_eq!(reused[0].anomalies, vec![AnomalyType::ActiveFreezeAuthority]);
+        assert!(reused[0].replayed_score < ignored[0].replayed_score);
+        assert!(ignored[0].anomalies.is_empty());
+
+        // Records without anomalies replay the same in every mode
+        assert_eq!(reused[1], ignored[1]);
+    }
+
+    #[tokio::test]
+    async fn test_resumed_backtest_matches_uninterrupted_run() {
+        let records = || -> Vec<TransactionRecord> {
+            (0..30)
+                .map(|i| {
+                    let mut record = create_record(i, i as f64 / 30.0);
+                    record.actual_outcome = if i % 3 == 0 { Outcome::Profit(1.0) } else { Outcome::Loss(-1.0) };
+                    if i % 7 == 0 {
+                        record.scored_candidate.feature_scores.insert("not_a_feature".to_string(), 1.0);
+                    }
+                    record
+                })
+                .collect()
+        };
+        let path = std::env::temp_dir().join(format!("backtest_checkpoint_{}.json", rand::random::<u64>()));
+        let config = BacktestConfig {
+            checkpoint_path: Some(path.clone()),
+            checkpoint_interval: 10,
+            ..BacktestConfig::default()
+        };
+
+        let uninterrupted = create_backtester()
+            .run_checkpointed(records(), &BacktestConfig::default())
+            .await
+            .unwrap();
+        assert_eq!(uninterrupted.processed, 30);
+        assert_eq!(uninterrupted.failed, 5);
+
+        // Crash halfway through the second batch
+        let crashing_config = BacktestConfig {
+            progress_callback: Some(Arc::new(|processed, _| {
+                if processed == 15 {
+                    panic!("simulated crash");
+                }
+            })),
+            ..config.clone()
+        };
+        let backtester = create_backtester();
+        let crashed = tokio::spawn(async move { backtester.run_checkpointed(records(), &crashing_config).await }).await;
+        assert!(crashed.is_err());
+
+        let checkpoint = BacktestCheckpoint::load(&path).await.unwrap().unwrap();
+        assert_eq!(checkpoint.last_processed_id, Some(9));
+        assert_eq!(checkpoint.aggregates.processed, 10);
+
+        let progress = Arc::new(Mutex::new(Vec::new()));
+        let progress_log = progress.clone();
+        let resume_config = BacktestConfig {
+            resume_from_checkpoint: true,
+            progress_callback: Some(Arc::new(move |processed, total| {
+                progress_log.lock().unwrap().push((processed, total));
+            })),
+            ..config
+        };
+        let resumed = create_backtester().run_checkpointed(records(), &resume_config).await.unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(resumed, uninterrupted);
+        // Progress continues from the checkpoint rather than restarting at zero
+        let progress = progress.lock().unwrap();
+        assert_eq!(progress.len(), 20);
+        assert_eq!(progress.first(), Some(&(11, 30)));
+        assert_eq!(progress.last(), Some(&(30, 30)));
+    }
+
+    #[tokio::test]
+    async fn test_checkpointed_backtest_refuses_records_it_cannot_locate() {
+        let backtester = create_backtester();
+
+        let mut unsaved = create_record(2, 0.5);
+        unsaved.id = None;
+        let result = backtester.run_checkpointed(vec![create_record(1, 0.5), unsaved], &BacktestConfig::default()).await;
+        assert!(result.unwrap_err().to_string().contains("unsaved record"));
+
+        let out_of_order = vec![create_record(2, 0.5), create_record(1, 0.5)];
+        let result = backtester.run_checkpointed(out_of_order, &BacktestConfig::default()).await;
+        assert!(result.unwrap_err().to_string().contains("ascending id order"));
+    }
+}
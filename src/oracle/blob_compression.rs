@@ -0,0 +1,139 @@
+//! Optional compression of the JSON blobs in the flat ledger table.
+//!
+//! `feature_scores` and `market_context_snapshot` in `transaction_records`
+//! are stored as JSON text by default. With compression enabled they are
+//! stored as gzip or zstd compressed BLOBs instead, and the row's
+//! `blob_compression` column names the codec; rows without it hold plain
+//! JSON. Reads decompress according to that column, so rows written under
+//! different settings can be mixed in one table.
+
+use anyhow::{anyhow, Context, Result};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use serde::{Deserialize, Serialize};
+use sqlx::encode::{Encode, IsNull};
+use sqlx::error::BoxDynError;
+use sqlx::sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo};
+use sqlx::Type;
+use std::io::{Read, Write};
+
+/// Codec for the flat table's JSON blobs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BlobCompression {
+    /// Plain JSON text
+    #[default]
+    None,
+    Gzip,
+    Zstd,
+}
+
+impl BlobCompression {
+    /// Value of the `blob_compression` column; `None` for plain JSON.
+    pub fn column_value(self) -> Option<&'static str> {
+        match self {
+            BlobCompression::None => None,
+            BlobCompression::Gzip => Some("gzip"),
+            BlobCompression::Zstd => Some("zstd"),
+        }
+    }
+
+    /// Codec named by a `blob_compression` column value.
+    pub fn from_column(value: Option<&str>) -> Result<Self> {
+        match value {
+            None => Ok(BlobCompression::None),
+            Some("gzip") => Ok(BlobCompression::Gzip),
+            Some("zstd") => Ok(BlobCompression::Zstd),
+            Some(other) => Err(anyhow!("Unknown blob compression: {}", other)),
+        }
+    }
+
+    /// `json` as it is stored under this codec.
+    pub fn compress(self, json: String) -> Result<StoredBlob> {
+        let compressed = match self {
+            BlobCompression::None => return Ok(StoredBlob::Text(json)),
+            BlobCompression::Gzip => {
+                let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::default());
+                encoder.write_all(json.as_bytes())?;
+                encoder.finish()?
+            }
+            BlobCompression::Zstd => zstd::encode_all(json.as_bytes(), zstd::DEFAULT_COMPRESSION_LEVEL)?,
+        };
+        Ok(StoredBlob::Compressed(compressed))
+    }
+
+    /// The JSON text of a blob stored under this codec.
+    pub fn decompress(self, stored: Vec<u8>) -> Result<String> {
+        let json = match self {
+            BlobCompression::None => stored,
+            BlobCompression::Gzip => {
+                let mut json = Vec::new();
+                GzDecoder::new(stored.as_slice())
+                    .read_to_end(&mut json)
+                    .context("Failed to decompress gzip blob")?;
+                json
+            }
+            BlobCompression::Zstd => zstd::decode_all(stored.as_slice()).context("Failed to decompress zstd blob")?,
+        };
+        String::from_utf8(json).context("Stored JSON blob is not valid UTF-8")
+    }
+}
+
+/// A JSON blob ready to bind: plain text, or compressed bytes stored as a BLOB.
+#[derive(Debug, Clone)]
+pub enum StoredBlob {
+    Text(String),
+    Compressed(Vec<u8>),
+}
+
+impl Type<Sqlite> for StoredBlob {
+    fn type_info() -> SqliteTypeInfo {
+        <Vec<u8> as Type<Sqlite>>::type_info()
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        <Vec<u8> as Type<Sqlite>>::compatible(ty)
+    }
+}
+
+impl<'q> Encode<'q, Sqlite> for StoredBlob {
+    fn encode_by_ref(&self, args: &mut Vec<SqliteArgumentValue<'q>>) -> Result<IsNull, BoxDynError> {
+        match self {
+            StoredBlob::Text(json) => <String as Encode<Sqlite>>::encode_by_ref(json, args),
+            StoredBlob::Compressed(bytes) => <Vec<u8> as Encode<Sqlite>>::encode_by_ref(bytes, args),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_codec_round_trips() {
+        let json = serde_json::json!({ "liquidity": 0.8, "holder_distribution": 0.25 }).to_string().repeat(20);
+
+        for compression in [BlobCompression::None, BlobCompression::Gzip, BlobCompression::Zstd] {
+            let stored = match compression.compress(json.clone()).unwrap() {
+                StoredBlob::Text(text) => {
+                    assert_eq!(compression, BlobCompression::None);
+                    text.into_bytes()
+                }
+                StoredBlob::Compressed(bytes) => {
+                    assert!(bytes.len() < json.len(), "{:?} did not compress", compression);
+                    bytes
+                }
+            };
+
+            let codec = BlobCompression::from_column(compression.column_value()).unwrap();
+            assert_eq!(codec, compression);
+            assert_eq!(codec.decompress(stored).unwrap(), json);
+        }
+    }
+
+    #[test]
+    fn test_unknown_codec_is_an_error() {
+        assert!(BlobCompression::from_column(Some("lz4")).is_err());
+        assert!(BlobCompression::Zstd.decompress(b"{}".to_vec()).is_err());
+    }
+}
@@ -3,9 +3,11 @@
 //! This module provides circuit breaker functionality to temporarily 
 //! quarantine unhealthy RPC endpoints and retry them after cooldown.
 
-use std::collections::HashMap;
-use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
-use tracing::{debug, warn, instrument};
+use crate::oracle::types::OracleConfig;
+use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn, instrument};
 
 /// State of an RPC endpoint in the circuit breaker.
 #[derive(Debug, Clone, PartialEq)]
@@ -16,8 +18,22 @@ pub enum EndpointState {
     Degraded,
     /// Endpoint is in cooldown period after too many failures
     CoolingDown,
+    /// Endpoint went into cooldown too often and is out of rotation until re-admitted
+    Evicted,
 }
 
+/// Default number of recent attempts the success rate is computed over.
+pub const DEFAULT_SUCCESS_WINDOW: usize = 100;
+
+/// Default number of cooldowns an endpoint may go through before it is evicted.
+pub const DEFAULT_MAX_COOLDOWNS_BEFORE_EVICTION: u32 = 10;
+
+/// Default time an endpoint must stay healthy before its cooldown count is forgotten.
+pub const DEFAULT_COOLDOWN_RESET_SECONDS: u64 = 3600;
+
+/// Smoothing factor for the per-endpoint latency average.
+const LATENCY_EWMA_ALPHA: f64 = 0.2;
+
 /// Health tracking for individual RPC endpoints.
 #[derive(Debug, Clone)]
 pub struct EndpointHealth {
@@ -35,8 +51,17 @@ pub struct EndpointHealth {
     pub last_failure: Option<Instant>,
     /// Timestamp when cooldown started
     pub cooldown_start: Option<Instant>,
-    /// Recent attempt history (success=true, failure=false)
-    pub recent_attempts: Vec<bool>,
+    /// Recent attempt history (success=true, failure=false), oldest first
+    pub recent_attempts: VecDeque<bool>,
+    /// Maximum number of attempts kept in `recent_attempts`
+    window_size: usize,
+    /// Exponentially weighted average request latency, if any was recorded
+    pub average_latency: Option<Duration>,
+    /// Cooldowns entered since the endpoint was added, re-admitted or last
+    /// stayed healthy for the cooldown reset period
+    pub cooldown_count: u32,
+    /// When the endpoint last became healthy, if it is healthy now
+    healthy_since: Option<Instant>,
 }
 
 /// Circuit breaker for managing RPC endpoint health.
@@ -51,6 +76,12 @@ pub struct CircuitBreaker {
     sample_size: usize,
     /// Minimum success rate to keep endpoint healthy
     min_success_rate: f64,
+    /// Number of recent attempts the success rate is computed over
+    window_size: usize,
+    /// Cooldowns an endpoint may go through before it is evicted
+    max_cooldowns_before_eviction: u32,
+    /// How long an endpoint must stay healthy for its cooldown count to reset
+    cooldown_reset_period: Duration,
 }
 
 impl CircuitBreaker {
@@ -66,16 +97,49 @@ impl CircuitBreaker {
             cooldown_duration: Duration::from_secs(cooldown_seconds),
             sample_size,
             min_success_rate: 0.3, // 30% minimum success rate
+            window_size: DEFAULT_SUCCESS_WINDOW,
+            max_cooldowns_before_eviction: DEFAULT_MAX_COOLDOWNS_BEFORE_EVICTION,
+            cooldown_reset_period: Duration::from_secs(DEFAULT_COOLDOWN_RESET_SECONDS),
         }
     }
 
+    /// Create a circuit breaker with the thresholds and windows from `config`.
+    pub fn from_config(config: &OracleConfig) -> Self {
+        Self::new(
+            config.circuit_breaker_failure_threshold,
+            config.circuit_breaker_cooldown_seconds,
+            config.endpoint_success_sample_size,
+        )
+        .with_window_size(config.endpoint_success_window)
+        .with_max_cooldowns_before_eviction(config.circuit_breaker_max_cooldowns_before_eviction)
+        .with_cooldown_reset_period(Duration::from_secs(config.circuit_breaker_cooldown_reset_seconds))
+    }
+
+    /// Compute success rates over the last `window_size` attempts (at least 1).
+    pub fn with_window_size(mut self, window_size: usize) -> Self {
+        self.window_size = window_size.max(1);
+        self
+    }
+
+    /// Evict endpoints once they would enter cooldown more than `max_cooldowns` times.
+    pub fn with_max_cooldowns_before_eviction(mut self, max_cooldowns: u32) -> Self {
+        self.max_cooldowns_before_eviction = max_cooldowns;
+        self
+    }
+
+    /// Forget an endpoint's past cooldowns once it has stayed healthy for `period`.
+    pub fn with_cooldown_reset_period(mut self, period: Duration) -> Self {
+        self.cooldown_reset_period = period;
+        self
+    }
+
     /// Record a successful request to an endpoint.
     #[instrument(skip(self), fields(endpoint = %endpoint))]
     pub fn record_success(&mut self, endpoint: &str) {
         {
             let health = self.endpoint_health
                 .entry(endpoint.to_string())
-                .or_insert_with(EndpointHealth::new);
+                .or_insert_with(|| EndpointHealth::new(self.window_size));
 
             health.record_success();
         }
@@ -93,7 +157,7 @@ impl CircuitBreaker {
         {
             let health = self.endpoint_health
                 .entry(endpoint.to_string())
-                .or_insert_with(EndpointHealth::new);
+                .or_insert_with(|| EndpointHealth::new(self.window_size));
 
             health.record_failure();
         }
@@ -105,6 +169,15 @@ impl CircuitBreaker {
               endpoint, health.consecutive_failures, health.success_rate * 100.0);
     }
 
+    /// Record the observed latency of a request to an endpoint.
+    pub fn record_latency(&mut self, endpoint: &str, latency: Duration) {
+        let health = self.endpoint_health
+            .entry(endpoint.to_string())
+            .or_insert_with(|| EndpointHealth::new(self.window_size));
+
+        health.record_latency(latency);
+    }
+
     /// Check if an endpoint is available for use.
     #[instrument(skip(self), fields(endpoint = %endpoint))]
     pub fn is_available(&mut self, endpoint: &str) -> bool {
@@ -113,7 +186,7 @@ impl CircuitBreaker {
 
         let health = self.endpoint_health
             .entry(endpoint.to_string())
-            .or_insert_with(EndpointHealth::new);
+            .or_insert_with(|| EndpointHealth::new(self.window_size));
 
         match health.state {
             EndpointState::Healthy => true,
@@ -135,6 +208,7 @@ impl CircuitBreaker {
                     true
                 }
             }
+            EndpointState::Evicted => false,
         }
     }
 
@@ -146,7 +220,11 @@ impl CircuitBreaker {
             .unwrap_or(EndpointState::Healthy)
     }
 
-    /// Get all healthy endpoints.
+    /// Get all healthy endpoints, best first.
+    ///
+    /// Endpoints are ordered by success rate (descending), then average
+    /// latency (ascending, endpoints without latency samples last), then
+    /// name, so the result is stable across runs for the same health stats.
     pub fn get_healthy_endpoints(&mut self) -> Vec<String> {
         let mut healthy = Vec::new();
         
@@ -159,10 +237,13 @@ impl CircuitBreaker {
             }
         }
         
+        self.sort_by_preference(&mut healthy);
         healthy
     }
 
     /// Get all available endpoints (healthy + degraded).
+    ///
+    /// Uses the same ordering as [`Self::get_healthy_endpoints`].
     pub fn get_available_endpoints(&mut self) -> Vec<String> {
         let mut available = Vec::new();
         
@@ -172,37 +253,102 @@ impl CircuitBreaker {
             }
         }
         
+        self.sort_by_preference(&mut available);
         available
     }
 
+    /// Endpoints currently evicted from rotation, sorted by name.
+    pub fn evicted_endpoints(&self) -> Vec<String> {
+        let mut evicted: Vec<String> = self.endpoint_health
+            .iter()
+            .filter(|(_, health)| health.state == EndpointState::Evicted)
+            .map(|(endpoint, _)| endpoint.clone())
+            .collect();
+        evicted.sort();
+        evicted
+    }
+
+    /// Put an evicted endpoint back into rotation with a clean health record.
+    ///
+    /// Returns false if the endpoint was not evicted.
+    pub fn readmit(&mut self, endpoint: &str) -> bool {
+        match self.endpoint_health.get_mut(endpoint) {
+            Some(health) if health.state == EndpointState::Evicted => {
+                *health = EndpointHealth::new(self.window_size);
+                info!("Re-admitted evicted endpoint {}", endpoint);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Sort endpoints by success rate, then latency, then name.
+    fn sort_by_preference(&self, endpoints: &mut [String]) {
+        endpoints.sort_by(|a, b| {
+            let (ha, hb) = (&self.endpoint_health[a], &self.endpoint_health[b]);
+            hb.success_rate
+                .partial_cmp(&ha.success_rate)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| match (ha.average_latency, hb.average_latency) {
+                    (Some(la), Some(lb)) => la.cmp(&lb),
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => Ordering::Equal,
+                })
+                .then_with(|| a.cmp(b))
+        });
+    }
+
     /// Update the state of an endpoint based on its health metrics.
     fn update_endpoint_state(&mut self, endpoint: &str) {
-        let health = self.endpoint_health.get_mut(endpoint).unwrap();
+        // Endpoints we have never seen are implicitly healthy
+        let Some(health) = self.endpoint_health.get_mut(endpoint) else {
+            return;
+        };
 
         match health.state {
             EndpointState::Healthy => {
                 if health.consecutive_failures >= self.failure_threshold {
                     health.state = EndpointState::Degraded;
+                    health.healthy_since = None;
                     debug!("Endpoint {} degraded: {} consecutive failures", endpoint, health.consecutive_failures);
+                } else if health.cooldown_count > 0
+                    && health.healthy_since.is_some_and(|since| since.elapsed() >= self.cooldown_reset_period)
+                {
+                    // Occasional cooldowns spread over a long time shouldn't add up to an eviction
+                    debug!("Endpoint {} healthy for {:?}, forgetting {} cooldowns",
+                           endpoint, self.cooldown_reset_period, health.cooldown_count);
+                    health.cooldown_count = 0;
                 }
             },
             EndpointState::Degraded => {
                 // Move to cooling down if still failing and success rate is too low
                 if health.consecutive_failures >= self.failure_threshold * 2 ||
                    (health.total_attempts >= self.sample_size && health.success_rate < self.min_success_rate) {
-                    health.state = EndpointState::CoolingDown;
-                    health.cooldown_start = Some(Instant::now());
-                    warn!("Endpoint {} entering cooldown: {} failures, {:.2}% success rate", 
-                          endpoint, health.consecutive_failures, health.success_rate * 100.0);
+                    health.cooldown_count += 1;
+                    if health.cooldown_count > self.max_cooldowns_before_eviction {
+                        health.state = EndpointState::Evicted;
+                        health.cooldown_start = None;
+                        warn!("Endpoint {} evicted after {} cooldowns", endpoint, health.cooldown_count - 1);
+                    } else {
+                        health.state = EndpointState::CoolingDown;
+                        health.cooldown_start = Some(Instant::now());
+                        warn!("Endpoint {} entering cooldown: {} failures, {:.2}% success rate",
+                              endpoint, health.consecutive_failures, health.success_rate * 100.0);
+                    }
                 } else if health.consecutive_failures == 0 && health.success_rate > 0.7 {
                     // Recover to healthy if we're doing well
                     health.state = EndpointState::Healthy;
+                    health.healthy_since = Some(Instant::now());
                     debug!("Endpoint {} recovered to healthy state", endpoint);
                 }
             },
             EndpointState::CoolingDown => {
                 // State transitions handled in is_available()
             }
+            EndpointState::Evicted => {
+                // Only `readmit` brings an endpoint back
+            }
         }
     }
 
@@ -217,6 +363,8 @@ impl CircuitBreaker {
                     success_rate: health.success_rate,
                     total_attempts: health.total_attempts,
                     successful_attempts: health.successful_attempts,
+                    average_latency: health.average_latency,
+                    cooldown_count: health.cooldown_count,
                 })
             })
             .collect()
@@ -232,6 +380,9 @@ impl CircuitBreaker {
             health.total_attempts = 0;
             health.successful_attempts = 0;
             health.success_rate = 1.0;
+            health.average_latency = None;
+            health.cooldown_count = 0;
+            health.healthy_since = Some(Instant::now());
         }
         debug!("Reset all endpoints to healthy state");
     }
@@ -239,7 +390,7 @@ impl CircuitBreaker {
 
 impl EndpointHealth {
     /// Create new endpoint health tracker.
-    fn new() -> Self {
+    fn new(window_size: usize) -> Self {
         Self {
             state: EndpointState::Healthy,
             consecutive_failures: 0,
@@ -248,25 +399,28 @@ impl EndpointHealth {
             successful_attempts: 0,
             last_failure: None,
             cooldown_start: None,
-            recent_attempts: Vec::new(),
+            recent_attempts: VecDeque::with_capacity(window_size),
+            window_size,
+            average_latency: None,
+            cooldown_count: 0,
+            healthy_since: Some(Instant::now()),
         }
     }
 
+    /// Fold a latency sample into the running average.
+    fn record_latency(&mut self, latency: Duration) {
+        self.average_latency = Some(match self.average_latency {
+            Some(avg) => avg.mul_f64(1.0 - LATENCY_EWMA_ALPHA) + latency.mul_f64(LATENCY_EWMA_ALPHA),
+            None => latency,
+        });
+    }
+
     /// Record a successful request.
     fn record_success(&mut self) {
         self.consecutive_failures = 0;
         self.last_failure = None;
         
-        self.total_attempts += 1;
-        self.successful_attempts += 1;
-        self.recent_attempts.push(true);
-        
-        // Keep only recent attempts for rolling window
-        if self.recent_attempts.len() > 100 {
-            self.recent_attempts.remove(0);
-        }
-        
-        self.update_success_rate();
+        self.push_attempt(true);
     }
 
     /// Record a failed request.
@@ -274,14 +428,24 @@ impl EndpointHealth {
         self.consecutive_failures += 1;
         self.last_failure = Some(Instant::now());
         
-        self.total_attempts += 1;
-        self.recent_attempts.push(false);
-        
-        // Keep only recent attempts for rolling window
-        if self.recent_attempts.len() > 100 {
-            self.recent_attempts.remove(0);
+        self.push_attempt(false);
+    }
+
+    /// Append an attempt, evicting the oldest once the window is full.
+    /// Window counts are maintained incrementally, so this is O(1).
+    fn push_attempt(&mut self, success: bool) {
+        self.recent_attempts.push_back(success);
+        if success {
+            self.successful_attempts += 1;
         }
-        
+
+        while self.recent_attempts.len() > self.window_size {
+            if self.recent_attempts.pop_front() == Some(true) {
+                self.successful_attempts -= 1;
+            }
+        }
+        self.total_attempts = self.recent_attempts.len();
+
         self.update_success_rate();
     }
 
@@ -292,10 +456,7 @@ impl EndpointHealth {
             return;
         }
 
-        let successes = self.recent_attempts.iter().filter(|&&success| success).count();
-        self.success_rate = successes as f64 / self.recent_attempts.len() as f64;
-        self.successful_attempts = successes;
-        self.total_attempts = self.recent_attempts.len();
+        self.success_rate = self.successful_attempts as f64 / self.total_attempts as f64;
     }
 }
 
@@ -307,12 +468,13 @@ pub struct EndpointHealthStats {
     pub success_rate: f64,
     pub total_attempts: usize,
     pub successful_attempts: usize,
+    pub average_latency: Option<Duration>,
+    pub cooldown_count: u32,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::thread;
     use std::time::Duration;
 
     #[test]
@@ -454,4 +616,101 @@ mod tests {
         assert_eq!(test_stats.successful_attempts, 2);
         assert!((test_stats.success_rate - 0.666).abs() < 0.01);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_healthy_endpoints_have_stable_order() {
+        let mut cb = CircuitBreaker::new(5, 60, 50);
+
+        // "reliable" has a perfect record; the rest drop one request each
+        for endpoint in ["zeta", "alpha", "fast", "slow", "reliable"] {
+            for _ in 0..9 {
+                cb.record_success(endpoint);
+            }
+            if endpoint != "reliable" {
+                cb.record_failure(endpoint);
+            }
+            cb.record_success(endpoint);
+        }
+        cb.record_latency("fast", Duration::from_millis(20));
+        cb.record_latency("slow", Duration::from_millis(200));
+
+        let expected = vec!["reliable", "fast", "slow", "alpha", "zeta"];
+        for _ in 0..5 {
+            assert_eq!(cb.get_healthy_endpoints(), expected);
+        }
+    }
+
+    #[test]
+    fn test_success_rate_uses_configured_window() {
+        let mut cb = CircuitBreaker::new(100, 60, 50).with_window_size(4);
+
+        // Two failures then three successes: the first failure has left the window
+        cb.record_failure("endpoint");
+        cb.record_failure("endpoint");
+        for _ in 0..3 {
+            cb.record_success("endpoint");
+        }
+
+        let stats = &cb.get_health_stats()["endpoint"];
+        assert_eq!(stats.total_attempts, 4);
+        assert_eq!(stats.successful_attempts, 3);
+        assert!((stats.success_rate - 0.75).abs() < 1e-9);
+
+        // One more success pushes out the second failure
+        cb.record_success("endpoint");
+        let stats = &cb.get_health_stats()["endpoint"];
+        assert_eq!(stats.total_attempts, 4);
+        assert_eq!(stats.successful_attempts, 4);
+        assert_eq!(stats.success_rate, 1.0);
+        assert_eq!(cb.endpoint_health["endpoint"].recent_attempts.len(), 4);
+    }
+
+    #[test]
+    fn test_endpoint_evicted_after_repeated_cooldowns() {
+        // Zero cooldown so every cooldown expires on the next availability check
+        let mut cb = CircuitBreaker::new(1, 0, 50).with_max_cooldowns_before_eviction(2);
+        cb.record_success("steady");
+
+        for round in 0..3 {
+            cb.record_failure("flaky");
+            cb.record_failure("flaky");
+            if round < 2 {
+                assert_eq!(cb.get_endpoint_state("flaky"), EndpointState::CoolingDown);
+                assert!(cb.is_available("flaky"));
+            }
+        }
+
+        assert_eq!(cb.get_endpoint_state("flaky"), EndpointState::Evicted);
+        assert!(!cb.is_available("flaky"));
+        assert_eq!(cb.evicted_endpoints(), vec!["flaky".to_string()]);
+        assert_eq!(cb.get_available_endpoints(), vec!["steady".to_string()]);
+
+        assert!(cb.readmit("flaky"));
+        assert!(!cb.readmit("flaky"));
+        assert!(cb.evicted_endpoints().is_empty());
+        assert_eq!(cb.get_endpoint_state("flaky"), EndpointState::Healthy);
+        assert_eq!(cb.get_health_stats()["flaky"].cooldown_count, 0);
+    }
+
+    #[test]
+    fn test_cooldown_count_resets_after_healthy_period() {
+        let mut cb = CircuitBreaker::new(1, 0, 50)
+            .with_max_cooldowns_before_eviction(2)
+            .with_cooldown_reset_period(Duration::ZERO);
+
+        // Each time the endpoint recovers before failing again, so its past cooldowns are forgotten
+        for _ in 0..5 {
+            cb.record_failure("flaky");
+            cb.record_failure("flaky");
+            assert_eq!(cb.get_endpoint_state("flaky"), EndpointState::CoolingDown);
+            assert!(cb.is_available("flaky"));
+            for _ in 0..20 {
+                cb.record_success("flaky");
+            }
+            assert_eq!(cb.get_endpoint_state("flaky"), EndpointState::Healthy);
+            assert!(cb.is_available("flaky"));
+            assert_eq!(cb.get_health_stats()["flaky"].cooldown_count, 0);
+        }
+        assert!(cb.evicted_endpoints().is_empty());
+    }
+}
@@ -3,41 +3,371 @@
 //! This module handles all external data fetching including RPC calls,
 //! API requests, and metadata retrieval with retry logic and caching.
 
+use crate::oracle::circuit_breaker::CircuitBreaker;
 use crate::oracle::types::{OracleConfig}; // Use new OracleConfig from types.rs
 // Import token data types from types_old.rs where they're actually defined
 use crate::oracle::types_old::{
     TokenData, Metadata, HolderData, LiquidityPool, VolumeData, CreatorHoldings,
-    SocialActivity, PoolType, Attribute,
+    SocialActivity, PoolType, Feature,
 };
 use crate::types::{PremintCandidate, Pubkey};
 use anyhow::{anyhow, Context, Result};
 use chrono::Timelike; // For .hour() method
+use moka::future::Cache;
 use reqwest::Client;
 // Temporarily commented out due to Solana dependency issues
 // TODO: Restore when Solana dependencies are properly configured
 // use solana_client::nonblocking::rpc_client::RpcClient;
 // use solana_sdk::pubkey::Pubkey;
 
+use solana_sdk::commitment_config::CommitmentConfig;
+
 // Placeholder RpcClient for now - will be replaced with actual Solana RPC client
-#[derive(Debug)]
-pub struct RpcClient;
+#[derive(Debug, Default)]
+pub struct RpcClient {
+    /// URL of the RPC endpoint, also its key in the circuit breaker
+    endpoint: String,
+    /// Commitment of every request issued through this client; only kept in
+    /// tests so a long-running client doesn't grow without bound
+    #[cfg(test)]
+    requested_commitments: std::sync::Mutex<Vec<CommitmentConfig>>,
+}
 
 impl RpcClient {
-    pub fn new(_endpoint: &str) -> Self {
-        Self
+    pub fn new(endpoint: &str) -> Self {
+        Self {
+            endpoint: endpoint.to_string(),
+            #[cfg(test)]
+            requested_commitments: Default::default(),
+        }
+    }
+
+    /// URL of the RPC endpoint this client talks to.
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Placeholder for `get_account_with_commitment`; returns no account data.
+    pub async fn get_account_with_commitment(
+        &self,
+        _pubkey: &Pubkey,
+        commitment: CommitmentConfig,
+    ) -> Result<Option<Vec<u8>>> {
+        self.record_commitment(commitment);
+        Ok(None)
     }
+
+    /// Placeholder for `get_token_largest_accounts_with_commitment`; returns no accounts.
+    pub async fn get_token_largest_accounts_with_commitment(
+        &self,
+        _mint: &Pubkey,
+        commitment: CommitmentConfig,
+    ) -> Result<Vec<HolderData>> {
+        self.record_commitment(commitment);
+        Ok(Vec::new())
+    }
+
+    /// Commitment levels of all requests issued so far, oldest first.
+    #[cfg(test)]
+    pub fn requested_commitments(&self) -> Vec<CommitmentConfig> {
+        self.requested_commitments.lock().unwrap().clone()
+    }
+
+    #[cfg(test)]
+    fn record_commitment(&self, commitment: CommitmentConfig) {
+        self.requested_commitments.lock().unwrap().push(commitment);
+    }
+
+    #[cfg(not(test))]
+    fn record_commitment(&self, _commitment: CommitmentConfig) {}
 }
-use std::collections::VecDeque;
-use std::sync::Arc;
+use moka::notification::RemovalCause;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
-use tokio_retry::{strategy::ExponentialBackoff, Retry};
-use tracing::{debug, warn, error, instrument};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio_retry::Retry;
+use tracing::{debug, instrument};
+
+// Keys used in `TokenData::data_timestamps` for each data source.
+pub const SOURCE_SUPPLY: &str = "supply";
+pub const SOURCE_METADATA: &str = "metadata";
+pub const SOURCE_HOLDERS: &str = "holders";
+pub const SOURCE_LIQUIDITY: &str = "liquidity";
+pub const SOURCE_VOLUME: &str = "volume";
+pub const SOURCE_CREATOR: &str = "creator";
+pub const SOURCE_SOCIAL: &str = "social";
+
+/// A part of `TokenData` fetched on its own, so scoring can stop before the
+/// expensive parts are fetched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenDataPart {
+    /// Supply, decimals and authorities from the mint account
+    MintAccount,
+    /// Metadata URI and content
+    Metadata,
+    /// Holder distribution
+    Holders,
+    /// Liquidity pool
+    Liquidity,
+    /// Volume and transaction data
+    Volume,
+    /// Creator holdings and sell activity
+    Creator,
+    /// Social activity (off-chain APIs)
+    Social,
+}
+
+impl TokenDataPart {
+    /// Every part, in the order a full fetch retrieves them.
+    pub const ALL: [TokenDataPart; 7] = [
+        TokenDataPart::MintAccount,
+        TokenDataPart::Metadata,
+        TokenDataPart::Holders,
+        TokenDataPart::Liquidity,
+        TokenDataPart::Volume,
+        TokenDataPart::Creator,
+        TokenDataPart::Social,
+    ];
+}
+
+/// Token data for `candidate` before any part has been fetched.
+pub(crate) fn empty_token_data(candidate: &PremintCandidate) -> TokenData {
+    TokenData {
+        supply: 0,
+        decimals: 0,
+        metadata_uri: String::new(),
+        metadata: None,
+        holder_distribution: Vec::new(),
+        liquidity_pool: None,
+        volume_data: VolumeData::default(),
+        creator_holdings: CreatorHoldings::default(),
+        holder_history: VecDeque::new(),
+        price_history: VecDeque::new(),
+        social_activity: SocialActivity::default(),
+        mint_authority_active: None,
+        freeze_authority_active: None,
+        contract_verified: None,
+        metadata_unreachable: false,
+        funding_source: candidate.funding_source.clone(),
+        creator: Some(candidate.creator.clone()),
+        data_timestamps: HashMap::new(),
+    }
+}
+
+/// Seed holder and price history with the fetched data, once every part is in.
+pub(crate) fn finish_token_data(token_data: &mut TokenData) {
+    token_data.holder_history = VecDeque::from([token_data.holder_distribution.len()]);
+    token_data.price_history = token_data
+        .liquidity_pool
+        .iter()
+        .map(|pool| compute_token_price(pool, token_data.decimals))
+        .collect();
+}
+
+/// Rounding slack allowed when holder shares are summed.
+const HOLDER_SHARE_EPSILON: f64 = 1e-6;
+
+/// Size of an SPL Token mint account.
+const MINT_ACCOUNT_LEN: usize = 82;
+
+/// Fields of an SPL Token mint account relevant for scoring.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MintAccountInfo {
+    pub supply: u64,
+    pub decimals: u8,
+    /// `None` when the authority status could not be determined
+    pub mint_authority_active: Option<bool>,
+    pub freeze_authority_active: Option<bool>,
+}
+
+/// Parse the raw data of an SPL Token mint account.
+///
+/// Layout: mint authority `COption<Pubkey>` (36), supply `u64` (8), decimals `u8`,
+/// is_initialized `bool`, freeze authority `COption<Pubkey>` (36).
+pub fn parse_mint_account(data: &[u8]) -> Result<MintAccountInfo> {
+    if data.len() < MINT_ACCOUNT_LEN {
+        return Err(anyhow!("Mint account too short: {} bytes", data.len()));
+    }
+
+    let coption_is_some = |offset: usize| -> Result<bool> {
+        match u32::from_le_bytes(data[offset..offset + 4].try_into()?) {
+            0 => Ok(false),
+            1 => Ok(true),
+            tag => Err(anyhow!("Invalid COption tag {} at offset {}", tag, offset)),
+        }
+    };
+
+    Ok(MintAccountInfo {
+        mint_authority_active: Some(coption_is_some(0)?),
+        supply: u64::from_le_bytes(data[36..44].try_into()?),
+        decimals: data[44],
+        freeze_authority_active: Some(coption_is_some(46)?),
+    })
+}
+
+/// Pump.fun program, owner of every bonding-curve account.
+pub const PUMP_FUN_PROGRAM_ID: &str = "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P";
+
+/// Anchor discriminator of the Pump.fun `BondingCurve` account.
+const BONDING_CURVE_DISCRIMINATOR: [u8; 8] = [23, 183, 248, 55, 96, 216, 172, 96];
+
+/// Size of a bonding-curve account up to the `complete` flag; newer accounts
+/// append the creator, which is not needed here.
+const BONDING_CURVE_ACCOUNT_LEN: usize = 49;
+
+/// Real token reserves of a fresh bonding curve (793.1M tokens, 6 decimals);
+/// the curve graduates once they are all sold.
+const PUMP_FUN_INITIAL_REAL_TOKEN_RESERVES: u64 = 793_100_000_000_000;
+
+const LAMPORTS_PER_SOL: f64 = 1_000_000_000.0;
+
+/// State of a Pump.fun bonding curve; reserves in lamports and token base units.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BondingCurveState {
+    pub virtual_token_reserves: u64,
+    pub virtual_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub token_total_supply: u64,
+    /// Set once the curve has graduated and its liquidity migrated
+    pub complete: bool,
+}
+
+impl BondingCurveState {
+    /// Fraction of the curve's tokens sold, from 0.0 at launch to 1.0 at graduation.
+    pub fn graduation_progress(&self) -> f64 {
+        if self.complete {
+            return 1.0;
+        }
+        let sold = PUMP_FUN_INITIAL_REAL_TOKEN_RESERVES.saturating_sub(self.real_token_reserves);
+        (sold as f64 / PUMP_FUN_INITIAL_REAL_TOKEN_RESERVES as f64).clamp(0.0, 1.0)
+    }
+
+    /// Pool holding the curve's real reserves.
+    pub fn to_liquidity_pool(&self, pool_address: Pubkey) -> LiquidityPool {
+        LiquidityPool {
+            sol_amount: self.real_sol_reserves as f64 / LAMPORTS_PER_SOL,
+            token_amount: self.real_token_reserves as f64,
+            pool_address,
+            pool_type: PoolType::PumpFun,
+            bonding_curve_progress: Some(self.graduation_progress()),
+        }
+    }
+}
+
+/// Parse the raw data of a Pump.fun bonding-curve account.
+///
+/// Layout: discriminator (8), virtual token reserves `u64`, virtual SOL reserves
+/// `u64`, real token reserves `u64`, real SOL reserves `u64`, token total supply
+/// `u64`, complete `bool`.
+pub fn parse_bonding_curve_account(data: &[u8]) -> Result<BondingCurveState> {
+    if data.len() < BONDING_CURVE_ACCOUNT_LEN {
+        return Err(anyhow!("Bonding curve account too short: {} bytes", data.len()));
+    }
+    if data[..8] != BONDING_CURVE_DISCRIMINATOR {
+        return Err(anyhow!("Not a bonding curve account"));
+    }
+
+    let read_u64 = |offset: usize| -> Result<u64> {
+        Ok(u64::from_le_bytes(data[offset..offset + 8].try_into()?))
+    };
+
+    Ok(BondingCurveState {
+        virtual_token_reserves: read_u64(8)?,
+        virtual_sol_reserves: read_u64(16)?,
+        real_token_reserves: read_u64(24)?,
+        real_sol_reserves: read_u64(32)?,
+        token_total_supply: read_u64(40)?,
+        complete: match data[48] {
+            0 => false,
+            1 => true,
+            flag => return Err(anyhow!("Invalid complete flag {}", flag)),
+        },
+    })
+}
+
+/// Address of the Pump.fun bonding curve of `mint`, derived from the seeds
+/// `["bonding-curve", mint]`.
+pub fn bonding_curve_address(mint: &Pubkey) -> Result<Pubkey> {
+    use solana_sdk::pubkey::Pubkey as SolanaPubkey;
+    use std::str::FromStr;
+
+    let mint = SolanaPubkey::from_str(mint).map_err(|e| anyhow!("Invalid mint {}: {}", mint, e))?;
+    let program = SolanaPubkey::from_str(PUMP_FUN_PROGRAM_ID)?;
+    let (address, _bump) = SolanaPubkey::find_program_address(&[b"bonding-curve", mint.as_ref()], &program);
+    Ok(address.to_string())
+}
+
+/// Launchpad verification flag from a `verified` metadata attribute, if present.
+///
+/// Launchpads that verify contracts publish it as `{"trait_type": "verified",
+/// "value": "true"}`; any other value counts as unverified.
+pub fn contract_verified_from_metadata(metadata: &Metadata) -> Option<bool> {
+    metadata
+        .attributes
+        .iter()
+        .find(|attribute| attribute.trait_type.eq_ignore_ascii_case("verified"))
+        .map(|attribute| attribute.value.eq_ignore_ascii_case("true"))
+}
+
+/// Check that holder shares are fractions of supply.
+///
+/// `HolderData::percentage` is a fraction in `[0, 1]`. A source reporting
+/// percents instead (`15.0` for 15%) would trip every concentration check, so
+/// such data is rejected rather than scored.
+pub fn validate_holder_distribution(holders: &[HolderData]) -> Result<()> {
+    if let Some(holder) = holders.iter().find(|h| !(0.0..=1.0).contains(&h.percentage)) {
+        return Err(anyhow!(
+            "Holder {} has share {} outside [0, 1]",
+            holder.address,
+            holder.percentage
+        ));
+    }
+
+    let total: f64 = holders.iter().map(|h| h.percentage).sum();
+    if total > 1.0 + HOLDER_SHARE_EPSILON {
+        return Err(anyhow!("Holder shares sum to {:.4}, more than the whole supply", total));
+    }
+
+    Ok(())
+}
+
+fn placeholder_holder_distribution() -> Vec<HolderData> {
+    vec![
+        HolderData {
+            address: "Creator".to_string(),
+            percentage: 0.15,
+            is_whale: true,
+        },
+        HolderData {
+            address: "LargeHolder".to_string(),
+            percentage: 0.08,
+            is_whale: true,
+        },
+        HolderData {
+            address: "MediumHolder1".to_string(),
+            percentage: 0.035,
+            is_whale: false,
+        },
+    ]
+}
 
 /// Data source manager for fetching token information.
 pub struct OracleDataSources {
     rpc_clients: Vec<Arc<RpcClient>>,
+    /// In-flight request slots per RPC endpoint, parallel to `rpc_clients`
+    endpoint_permits: Vec<Arc<Semaphore>>,
     http_client: Client,
     config: OracleConfig,
+    /// Recently fetched token data, keyed by mint
+    token_cache: Cache<Pubkey, CachedTokenData>,
+    /// Hit and eviction ages of `token_cache` entries
+    cache_observer: Arc<Mutex<CacheObserver>>,
+    /// Commitment level for RPC reads, from `OracleConfig::rpc_commitment`
+    commitment: CommitmentConfig,
+    /// Health of each RPC endpoint; endpoints in cooldown or evicted get no requests
+    circuit_breaker: Arc<tokio::sync::Mutex<CircuitBreaker>>,
 }
 
 impl OracleDataSources {
@@ -47,109 +377,271 @@ impl OracleDataSources {
         http_client: Client,
         config: OracleConfig,
     ) -> Self {
+        let ttl = Duration::from_secs(config.cache_ttl_seconds);
+        let cache_observer = Arc::new(Mutex::new(CacheObserver::new(ttl)));
+        let token_cache = Cache::builder()
+            .max_capacity(config.max_cache_entries as u64)
+            .time_to_live(ttl)
+            .eviction_listener({
+                let cache_observer = cache_observer.clone();
+                move |_mint, entry: CachedTokenData, cause| {
+                    cache_observer.lock().unwrap().record_removal(entry.inserted_at.elapsed(), cause);
+                }
+            })
+            .build();
+
+        let per_endpoint = config.max_requests_per_endpoint.max(1);
+        let endpoint_permits = rpc_clients
+            .iter()
+            .map(|_| Arc::new(Semaphore::new(per_endpoint)))
+            .collect();
+
+        let commitment = config.rpc_commitment.to_commitment_config();
+        let circuit_breaker = Arc::new(tokio::sync::Mutex::new(CircuitBreaker::from_config(&config)));
+
         Self {
             rpc_clients,
+            endpoint_permits,
             http_client,
             config,
+            token_cache,
+            cache_observer,
+            commitment,
+            circuit_breaker,
         }
     }
 
+    /// Circuit breaker consulted before every RPC request, shared so callers
+    /// can record the outcome of the requests they make.
+    pub fn circuit_breaker(&self) -> Arc<tokio::sync::Mutex<CircuitBreaker>> {
+        self.circuit_breaker.clone()
+    }
+
+    /// Cached token data for `mint`, recording the hit or miss.
+    async fn cache_get(&self, mint: &Pubkey) -> Option<TokenData> {
+        let cached = self.token_cache.get(mint).await;
+        let mut observer = self.cache_observer.lock().unwrap();
+        match cached {
+            Some(entry) => {
+                observer.record_hit(entry.inserted_at.elapsed());
+                Some(entry.data)
+            }
+            None => {
+                observer.misses += 1;
+                None
+            }
+        }
+    }
+
+    async fn cache_insert(&self, mint: Pubkey, data: TokenData) {
+        let entry = CachedTokenData { data, inserted_at: Instant::now() };
+        self.token_cache.insert(mint, entry).await;
+    }
+
+    /// Token cache hit rate and entry ages, to judge whether `cache_ttl_seconds`
+    /// is too short (many misses, evictions near the TTL) or too long (hits on
+    /// old entries).
+    pub async fn get_cache_stats(&self) -> CacheStats {
+        // Expired entries are only reported to the eviction listener by pending tasks
+        self.token_cache.run_pending_tasks().await;
+        self.cache_observer.lock().unwrap().stats()
+    }
+
+    /// Cached token data for `mint`, without counting towards the cache stats.
+    pub async fn peek_cached_token_data(&self, mint: &Pubkey) -> Option<TokenData> {
+        self.token_cache.get(mint).await.map(|entry| entry.data)
+    }
+
+    /// Cached token data for `candidate`, if it was fetched recently.
+    pub(crate) async fn cached_token_data(&self, candidate: &PremintCandidate) -> Option<TokenData> {
+        self.cache_get(&candidate.mint).await
+    }
+
+    /// Cache token data completed part by part.
+    pub(crate) async fn cache_token_data(&self, candidate: &PremintCandidate, token_data: TokenData) {
+        self.cache_insert(candidate.mint.clone(), token_data).await;
+    }
+
+    /// Fetch one part of the candidate's token data into `token_data`, with retries.
+    #[instrument(skip(self, token_data), fields(mint = %candidate.mint))]
+    pub async fn fetch_token_data_part(
+        &self,
+        part: TokenDataPart,
+        candidate: &PremintCandidate,
+        token_data: &mut TokenData,
+    ) -> Result<()> {
+        let (endpoint, _permit) = self.acquire_endpoint().await?;
+        let rpc = &self.rpc_clients[endpoint];
+
+        *token_data = Retry::start(self.config.rpc_retry.delays(), || {
+            let mut attempt = token_data.clone();
+            async move {
+                self.fetch_part(part, candidate, rpc, &mut attempt).await?;
+                Ok::<_, anyhow::Error>(attempt)
+            }
+        })
+        .await?;
+        Ok(())
+    }
+
     /// Fetch complete token data with retries.
+    ///
+    /// Recently fetched data is served from cache; its `data_timestamps` keep
+    /// the time of the original fetch so callers can tell how stale it is.
     #[instrument(skip(self), fields(mint = %candidate.mint))]
     pub async fn fetch_token_data_with_retries(
         &self,
         candidate: &PremintCandidate,
     ) -> Result<TokenData> {
-        let retry_strategy = ExponentialBackoff::from_millis(100)
-            .max_delay(Duration::from_secs(5))
-            .take(self.config.rpc_retry_attempts);
+        if let Some(cached) = self.cache_get(&candidate.mint).await {
+            debug!("Using cached token data for {}", candidate.mint);
+            return Ok(cached);
+        }
+
+        let token_data = Retry::start(self.config.rpc_retry.delays(), || self.fetch_token_data(candidate)).await?;
+        self.cache_insert(candidate.mint.clone(), token_data.clone()).await;
 
-        Retry::spawn(retry_strategy, || self.fetch_token_data(candidate)).await
+        Ok(token_data)
     }
 
     /// Fetch complete token data from multiple sources.
     #[instrument(skip(self), fields(mint = %candidate.mint))]
     async fn fetch_token_data(&self, candidate: &PremintCandidate) -> Result<TokenData> {
-        // Use first available RPC client
-        let rpc = self.rpc_clients
-            .first()
-            .ok_or_else(|| anyhow!("No RPC clients available"))?;
+        // Hold a slot on the least loaded RPC endpoint for the whole fetch
+        let (endpoint, _permit) = self.acquire_endpoint().await?;
+        let rpc = &self.rpc_clients[endpoint];
 
-        // Fetch basic token information
-        let token_supply = self.fetch_token_supply(candidate, rpc).await?;
-        let (supply, decimals) = token_supply;
-
-        // Fetch metadata URI and content
-        let metadata_uri = self.resolve_metadata_uri(&candidate.mint, rpc).await
-            .unwrap_or_else(|_| "".to_string());
-        
-        let metadata = if !metadata_uri.is_empty() {
-            self.fetch_metadata_from_uri(&metadata_uri).await.ok()
-        } else {
-            None
-        };
-
-        // Fetch holder distribution
-        let holder_distribution = self.fetch_holder_distribution(candidate, rpc).await
-            .unwrap_or_default();
-
-        // Fetch liquidity information
-        let liquidity_pool = self.fetch_liquidity_data(candidate, rpc).await
-            .unwrap_or(None);
+        let mut token_data = empty_token_data(candidate);
+        for part in TokenDataPart::ALL {
+            self.fetch_part(part, candidate, rpc, &mut token_data).await?;
+        }
+        finish_token_data(&mut token_data);
 
-        // Fetch volume and transaction data
-        let volume_data = self.fetch_volume_data(candidate, rpc).await
-            .unwrap_or_default();
+        debug!("Fetched complete token data for {}", candidate.mint);
+        Ok(token_data)
+    }
 
-        // Fetch creator holdings and sell activity
-        let creator_holdings = self.fetch_creator_holdings(candidate, &()).await
-            .unwrap_or_default();
+    /// Fetch one part of the token data into `token_data`, recording when its
+    /// source was fetched. Only a failed mint account read is an error; other
+    /// parts fall back to defaults.
+    async fn fetch_part(
+        &self,
+        part: TokenDataPart,
+        candidate: &PremintCandidate,
+        rpc: &RpcClient,
+        token_data: &mut TokenData,
+    ) -> Result<()> {
+        let source = match part {
+            TokenDataPart::MintAccount => {
+                let MintAccountInfo { supply, decimals, mint_authority_active, freeze_authority_active } =
+                    self.fetch_mint_account(candidate, rpc).await?;
+                token_data.supply = supply;
+                token_data.decimals = decimals;
+                token_data.mint_authority_active = mint_authority_active;
+                token_data.freeze_authority_active = freeze_authority_active;
+                SOURCE_SUPPLY
+            }
+            TokenDataPart::Metadata => {
+                let metadata_uri = self.resolve_metadata_uri(&candidate.mint, rpc).await
+                    .unwrap_or_else(|_| "".to_string());
 
-        // Fetch social activity (if API keys available)
-        let social_activity = self.fetch_social_activity(candidate).await
-            .unwrap_or_default();
+                let (metadata, metadata_unreachable) = if !metadata_uri.is_empty() {
+                    self.fetch_metadata_from_uri(&metadata_uri).await
+                } else {
+                    (None, false)
+                };
+                token_data.contract_verified = metadata.as_ref().and_then(contract_verified_from_metadata);
+                token_data.metadata_uri = metadata_uri;
+                token_data.metadata = metadata;
+                token_data.metadata_unreachable = metadata_unreachable;
+                SOURCE_METADATA
+            }
+            TokenDataPart::Holders => {
+                token_data.holder_distribution = self.fetch_holder_distribution(candidate, rpc).await
+                    .unwrap_or_default();
+                SOURCE_HOLDERS
+            }
+            TokenDataPart::Liquidity => {
+                token_data.liquidity_pool = self.fetch_liquidity_data(candidate, rpc).await
+                    .unwrap_or(None);
+                SOURCE_LIQUIDITY
+            }
+            TokenDataPart::Volume => {
+                token_data.volume_data = self.fetch_volume_data(candidate, rpc).await
+                    .unwrap_or_default();
+                SOURCE_VOLUME
+            }
+            TokenDataPart::Creator => {
+                token_data.creator_holdings = self.fetch_creator_holdings(candidate, &()).await
+                    .unwrap_or_default();
+                SOURCE_CREATOR
+            }
+            TokenDataPart::Social => {
+                // Only fetched if not disabled (and API keys are available)
+                if !self.config.is_feature_enabled(Feature::SocialActivity) {
+                    return Ok(());
+                }
+                token_data.social_activity = self.fetch_social_activity(candidate).await
+                    .unwrap_or_default();
+                SOURCE_SOCIAL
+            }
+        };
 
-        // Create holder and price history with current data
-        let mut holder_history = VecDeque::new();
-        holder_history.push_back(holder_distribution.len());
+        token_data.data_timestamps.insert(source.to_string(), current_timestamp_ms());
+        Ok(())
+    }
 
-        let mut price_history = VecDeque::new();
-        if let Some(pool) = &liquidity_pool {
-            let price = pool.sol_amount / (pool.token_amount / 10f64.powi(decimals as i32));
-            price_history.push_back(price);
+    /// Reserve an in-flight slot on the RPC endpoint with the most free slots.
+    ///
+    /// Endpoints whose circuit breaker is open are skipped. Waits for the first
+    /// endpoint when every remaining endpoint is at `max_requests_per_endpoint`.
+    /// Returns the endpoint's index in `rpc_clients`.
+    async fn acquire_endpoint(&self) -> Result<(usize, OwnedSemaphorePermit)> {
+        let mut by_free_slots: Vec<usize> = {
+            let mut circuit_breaker = self.circuit_breaker.lock().await;
+            (0..self.endpoint_permits.len())
+                .filter(|&i| circuit_breaker.is_available(self.rpc_clients[i].endpoint()))
+                .collect()
+        };
+        if by_free_slots.is_empty() && !self.rpc_clients.is_empty() {
+            return Err(anyhow!("All RPC endpoints are cooling down or evicted"));
         }
+        by_free_slots.sort_by_key(|&i| std::cmp::Reverse(self.endpoint_permits[i].available_permits()));
 
-        let token_data = TokenData {
-            supply,
-            decimals,
-            metadata_uri,
-            metadata,
-            holder_distribution,
-            liquidity_pool,
-            volume_data,
-            creator_holdings,
-            holder_history,
-            price_history,
-            social_activity,
-        };
+        for &i in &by_free_slots {
+            if let Ok(permit) = self.endpoint_permits[i].clone().try_acquire_owned() {
+                return Ok((i, permit));
+            }
+        }
 
-        debug!("Fetched complete token data for {}", candidate.mint);
-        Ok(token_data)
+        let i = *by_free_slots
+            .first()
+            .ok_or_else(|| anyhow!("No RPC clients available"))?;
+        debug!("All RPC endpoints saturated, waiting for endpoint {}", i);
+        let permit = self.endpoint_permits[i].clone().acquire_owned().await?;
+        Ok((i, permit))
     }
 
-    /// Fetch token supply and decimals.
+    /// Fetch token supply, decimals and authority status from the mint account.
     #[instrument(skip(self), fields(mint = %candidate.mint))]
-    async fn fetch_token_supply(
+    async fn fetch_mint_account(
         &self,
         candidate: &PremintCandidate,
-        __rpc: &RpcClient,
-    ) -> Result<(u64, u8)> {
-        debug!("Fetching token supply and decimals");
-        
-        // Placeholder implementation - would use actual RPC calls
-        // let mint_account = rpc.get_account(&candidate.mint).await?;
-        
-        Ok((1_000_000_000, 9)) // 1B supply, 9 decimals
+        rpc: &RpcClient,
+    ) -> Result<MintAccountInfo> {
+        debug!("Fetching mint account");
+
+        if let Some(data) = rpc.get_account_with_commitment(&candidate.mint, self.commitment).await? {
+            return parse_mint_account(&data);
+        }
+
+        // Placeholder values until the RPC client returns real account data
+        Ok(MintAccountInfo {
+            supply: 1_000_000_000, // 1B supply
+            decimals: 9,
+            mint_authority_active: None, // Unknown without the account data
+            freeze_authority_active: None,
+        })
     }
 
     /// Resolve metadata URI from mint account.
@@ -165,28 +657,37 @@ impl OracleDataSources {
         Ok(format!("https://example.com/metadata/{}.json", mint_address))
     }
 
-    /// Fetch metadata from URI.
+    /// Fetch metadata from URI, returning it along with whether the URI was unreachable.
+    ///
+    /// A failed request or non-success status makes the URI unreachable; a
+    /// reachable URI serving unparseable content yields `(None, false)`.
     #[instrument(skip(self), fields(uri = %uri))]
-    async fn fetch_metadata_from_uri(&self, uri: &str) -> Result<Metadata> {
+    pub(crate) async fn fetch_metadata_from_uri(&self, uri: &str) -> (Option<Metadata>, bool) {
         let response = self
             .http_client
             .get(uri)
             .timeout(Duration::from_secs(10))
             .send()
             .await
-            .context("Failed to fetch metadata")?;
+            .and_then(|response| response.error_for_status());
+        let response = match response {
+            Ok(response) => response,
+            Err(e) => {
+                debug!("Metadata URI unreachable: {}", e);
+                return (None, true);
+            }
+        };
 
-        if !response.status().is_success() {
-            return Err(anyhow!("Failed to fetch metadata: {}", response.status()));
+        match response.json::<Metadata>().await {
+            Ok(metadata) => {
+                debug!("Fetched metadata: {}", metadata.name);
+                (Some(metadata), false)
+            }
+            Err(e) => {
+                debug!("Failed to parse metadata: {}", e);
+                (None, false)
+            }
         }
-
-        let metadata: Metadata = response
-            .json()
-            .await
-            .context("Failed to parse metadata")?;
-
-        debug!("Fetched metadata: {}", metadata.name);
-        Ok(metadata)
     }
 
     /// Fetch holder distribution data.
@@ -194,29 +695,19 @@ impl OracleDataSources {
     async fn fetch_holder_distribution(
         &self,
         candidate: &PremintCandidate,
-        __rpc: &RpcClient,
+        rpc: &RpcClient,
     ) -> Result<Vec<HolderData>> {
         debug!("Fetching token holder distribution");
-        
-        // Placeholder implementation - would use actual RPC calls
-        let holders = vec![
-            HolderData {
-                address: "Creator".to_string(),
-                percentage: 15.0,
-                is_whale: true,
-            },
-            HolderData {
-                address: "LargeHolder".to_string(),
-                percentage: 8.0,
-                is_whale: true,
-            },
-            HolderData {
-                address: "MediumHolder1".to_string(),
-                percentage: 3.5,
-                is_whale: false,
-            },
-        ];
 
+        let mut holders = rpc
+            .get_token_largest_accounts_with_commitment(&candidate.mint, self.commitment)
+            .await?;
+        if holders.is_empty() {
+            // Placeholder implementation until the RPC client returns real accounts
+            holders = placeholder_holder_distribution();
+        }
+
+        validate_holder_distribution(&holders)?;
         debug!("Fetched {} holders", holders.len());
         Ok(holders)
     }
@@ -272,23 +763,29 @@ impl OracleDataSources {
     async fn find_pump_fun_pool(
         &self,
         candidate: &PremintCandidate,
-        _rpc: &RpcClient,
+        rpc: &RpcClient,
     ) -> Result<Option<LiquidityPool>> {
         debug!("Searching for Pump.fun pool");
-        
-        // Simplified implementation
-        // Would use Pump.fun API if key is available
-        if self.config.pump_fun_api_key.is_some() {
-            // Mock pool for demonstration
-            Ok(Some(LiquidityPool {
-                sol_amount: 25.0,
-                token_amount: 1000000.0,
-                pool_address: "MockPumpFunPool".to_string(),
-                pool_type: PoolType::PumpFun,
-            }))
-        } else {
-            Ok(None)
+
+        let address = bonding_curve_address(&candidate.mint)?;
+        let Some(data) = rpc.get_account_with_commitment(&address, self.commitment).await? else {
+            return Ok(None);
+        };
+
+        let curve = parse_bonding_curve_account(&data)?;
+        if curve.complete {
+            // Liquidity has migrated off the curve
+            debug!("Bonding curve {} has graduated", address);
+            return Ok(None);
         }
+
+        debug!(
+            "Bonding curve {} holds {:.3} SOL, {:.1}% to graduation",
+            address,
+            curve.real_sol_reserves as f64 / LAMPORTS_PER_SOL,
+            curve.graduation_progress() * 100.0
+        );
+        Ok(Some(curve.to_liquidity_pool(address)))
     }
 
     /// Find Orca pools.
@@ -319,9 +816,8 @@ impl OracleDataSources {
         let transaction_count = 150_u32; // Mock data
 
         // Analyze transactions for volume calculation
-        let mut total_volume = 0.0;
-        let mut buy_volume = 0.0;
-        let mut sell_volume = 0.0;
+        let buy_volume = 0.0;
+        let sell_volume = 0.0;
 
         // Simplified volume calculation from transaction count
         let estimated_volume = transaction_count as f64 * 10.0; // Rough estimate
@@ -347,6 +843,7 @@ impl OracleDataSources {
             volume_growth_rate,
             transaction_count,
             buy_sell_ratio,
+            volume_history: VecDeque::new(),
         })
     }
 
@@ -389,11 +886,7 @@ impl OracleDataSources {
     pub async fn fetch_sol_price_usd(&self) -> Result<f64> {
         let url = "https://api.coingecko.com/api/v3/simple/price?ids=solana&vs_currencies=usd";
         
-        let retry_strategy = ExponentialBackoff::from_millis(500)
-            .max_delay(Duration::from_secs(3))
-            .take(3);
-
-        Retry::spawn(retry_strategy, || async {
+        Retry::start(self.config.api_retry.delays(), || async {
             let response = self.http_client
                 .get(url)
                 .send()
@@ -413,20 +906,7 @@ impl OracleDataSources {
     /// Calculate simple volatility indicator based on price history.
     #[instrument(skip(self, price_history))]
     pub async fn calculate_sol_volatility(&self, price_history: &[f64]) -> Result<f64> {
-        if price_history.len() < 2 {
-            return Ok(0.0);
-        }
-
-        let mean = price_history.iter().sum::<f64>() / price_history.len() as f64;
-        let variance = price_history.iter()
-            .map(|&p| (p - mean).powi(2))
-            .sum::<f64>() / price_history.len() as f64;
-        
-        let std_dev = variance.sqrt();
-        let volatility_percentage = (std_dev / mean) * 100.0; // Volatility as % of price
-        
-        debug!("Calculated volatility: {:.2}%", volatility_percentage);
-        Ok(volatility_percentage)
+        Ok(sol_volatility_percent(price_history))
     }
 
     /// Fetch network TPS (Transactions Per Second) as a measure of network load.
@@ -470,36 +950,137 @@ impl OracleDataSources {
     }
 }
 
-impl Default for VolumeData {
-    fn default() -> Self {
-        Self {
-            initial_volume: 0.0,
-            current_volume: 0.0,
-            volume_growth_rate: 1.0,
-            transaction_count: 0,
-            buy_sell_ratio: 1.0,
+/// Token data in the cache, with the time it was cached.
+#[derive(Clone)]
+struct CachedTokenData {
+    data: TokenData,
+    inserted_at: Instant,
+}
+
+/// Running totals behind `CacheStats`.
+#[derive(Debug, Default)]
+struct CacheObserver {
+    ttl: Duration,
+    hits: u64,
+    misses: u64,
+    total_hit_age: Duration,
+    hit_age_quartiles: [u64; 4],
+    expired: u64,
+    evicted: u64,
+    total_removal_age: Duration,
+}
+
+impl CacheObserver {
+    fn new(ttl: Duration) -> Self {
+        Self { ttl, ..Self::default() }
+    }
+
+    fn record_hit(&mut self, age: Duration) {
+        self.hits += 1;
+        self.total_hit_age += age;
+        let fraction = if self.ttl.is_zero() { 1.0 } else { age.as_secs_f64() / self.ttl.as_secs_f64() };
+        self.hit_age_quartiles[((fraction * 4.0) as usize).min(3)] += 1;
+    }
+
+    fn record_removal(&mut self, age: Duration, cause: RemovalCause) {
+        match cause {
+            RemovalCause::Expired => self.expired += 1,
+            RemovalCause::Size => self.evicted += 1,
+            // Replaced or invalidated entries say nothing about the TTL
+            RemovalCause::Replaced | RemovalCause::Explicit => return,
         }
+        self.total_removal_age += age;
     }
-}
 
-impl Default for CreatorHoldings {
-    fn default() -> Self {
-        Self {
-            initial_balance: 0,
-            current_balance: 0,
-            first_sell_timestamp: None,
-            sell_transactions: 0,
+    fn stats(&self) -> CacheStats {
+        let mean_ms = |total: Duration, count: u64| {
+            if count == 0 { 0.0 } else { total.as_secs_f64() * 1000.0 / count as f64 }
+        };
+
+        CacheStats {
+            hits: self.hits,
+            misses: self.misses,
+            mean_hit_age_ms: mean_ms(self.total_hit_age, self.hits),
+            hit_age_quartiles: self.hit_age_quartiles,
+            expired: self.expired,
+            evicted: self.evicted,
+            mean_removal_age_ms: mean_ms(self.total_removal_age, self.expired + self.evicted),
         }
     }
 }
 
-impl Default for SocialActivity {
+/// How well the token cache TTL fits the traffic, from `OracleDataSources::get_cache_stats`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    /// Mean age of the entries served on hits
+    pub mean_hit_age_ms: f64,
+    /// Hits by entry age as a quarter of the TTL: `[0, 25%)`, `[25%, 50%)`,
+    /// `[50%, 75%)` and `[75%, 100%]`
+    pub hit_age_quartiles: [u64; 4],
+    /// Entries removed because their TTL ran out
+    pub expired: u64,
+    /// Entries removed to stay within `max_cache_entries`
+    pub evicted: u64,
+    /// Mean age of expired and evicted entries at removal
+    pub mean_removal_age_ms: f64,
+}
+
+impl CacheStats {
+    /// Fraction of lookups served from the cache.
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 { 0.0 } else { self.hits as f64 / lookups as f64 }
+    }
+}
+
+/// Token price in SOL per whole token, from pool reserves in base units.
+///
+/// Returns 0.0 for an empty pool.
+pub fn compute_token_price(pool: &LiquidityPool, decimals: u8) -> f64 {
+    if pool.token_amount <= 0.0 {
+        return 0.0;
+    }
+
+    pool.sol_amount / (pool.token_amount / 10f64.powi(decimals as i32))
+}
+
+/// Volatility of a price series as the standard deviation in % of its mean.
+pub fn sol_volatility_percent(price_history: &[f64]) -> f64 {
+    if price_history.len() < 2 {
+        return 0.0;
+    }
+
+    let mean = price_history.iter().sum::<f64>() / price_history.len() as f64;
+    let variance = price_history.iter()
+        .map(|&p| (p - mean).powi(2))
+        .sum::<f64>() / price_history.len() as f64;
+    
+    let std_dev = variance.sqrt();
+    let volatility_percentage = (std_dev / mean) * 100.0; // Volatility as % of price
+    
+    debug!("Calculated volatility: {:.2}%", volatility_percentage);
+    volatility_percentage
+}
+
+/// Current Unix timestamp in milliseconds.
+pub(crate) fn current_timestamp_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+impl Default for VolumeData {
     fn default() -> Self {
         Self {
-            twitter_mentions: 0,
-            telegram_members: 0,
-            discord_members: 0,
-            social_score: 0.0,
+            initial_volume: 0.0,
+            current_volume: 0.0,
+            volume_growth_rate: 1.0,
+            transaction_count: 0,
+            buy_sell_ratio: 1.0,
+            volume_history: VecDeque::new(),
         }
     }
 }
@@ -507,26 +1088,13 @@ impl Default for SocialActivity {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::oracle::types::OracleConfig;
-    use crate::types::{PremintCandidate, Pubkey};
+    use crate::oracle::types::{OracleConfig, RpcCommitment};
     use reqwest::Client;
 
     fn create_test_config() -> OracleConfig {
         OracleConfig::default()
     }
 
-    fn create_test_candidate() -> PremintCandidate {
-        PremintCandidate {
-            mint: "TestMintAddress".to_string(),
-            creator: "TestCreatorAddress".to_string(),
-            program: "test".to_string(),
-            slot: 12345,
-            timestamp: 1640995200,
-            instruction_summary: None,
-            is_jito_bundle: Some(true),
-        }
-    }
-
     #[test]
     fn test_volume_data_default() {
         let volume_data = VolumeData::default();
@@ -569,4 +1137,320 @@ mod tests {
         // Just verify construction works
         assert_eq!(data_sources.rpc_clients.len(), 0);
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_requests_spread_across_endpoints_under_cap() {
+        let mut config = create_test_config();
+        config.max_requests_per_endpoint = 2;
+        let rpc_clients = vec![
+            Arc::new(RpcClient::new("http://rpc-a:8899")),
+            Arc::new(RpcClient::new("http://rpc-b:8899")),
+        ];
+        let data_sources = OracleDataSources::new(rpc_clients, Client::new(), config);
+
+        let mut held = Vec::new();
+        let mut per_endpoint = [0usize; 2];
+        for _ in 0..4 {
+            let (endpoint, permit) = data_sources.acquire_endpoint().await.unwrap();
+            per_endpoint[endpoint] += 1;
+            held.push(permit);
+        }
+        assert_eq!(per_endpoint, [2, 2]);
+
+        // Both endpoints are at their cap, so the next request waits
+        let blocked = tokio::time::timeout(Duration::from_millis(50), data_sources.acquire_endpoint()).await;
+        assert!(blocked.is_err());
+
+        held.pop();
+        let (_, permit) = tokio::time::timeout(Duration::from_millis(50), data_sources.acquire_endpoint())
+            .await
+            .expect("slot freed")
+            .unwrap();
+        drop(permit);
+    }
+
+    #[tokio::test]
+    async fn test_requests_skip_endpoints_with_open_circuit() {
+        let mut config = create_test_config();
+        config.circuit_breaker_failure_threshold = 1;
+        let rpc_clients = vec![
+            Arc::new(RpcClient::new("http://rpc-a:8899")),
+            Arc::new(RpcClient::new("http://rpc-b:8899")),
+        ];
+        let data_sources = OracleDataSources::new(rpc_clients, Client::new(), config);
+
+        // Two consecutive failures put rpc-a into cooldown
+        {
+            let circuit_breaker = data_sources.circuit_breaker();
+            let mut circuit_breaker = circuit_breaker.lock().await;
+            circuit_breaker.record_failure("http://rpc-a:8899");
+            circuit_breaker.record_failure("http://rpc-a:8899");
+            assert!(!circuit_breaker.is_available("http://rpc-a:8899"));
+        }
+
+        let mut held = Vec::new();
+        for _ in 0..3 {
+            let (endpoint, permit) = data_sources.acquire_endpoint().await.unwrap();
+            assert_eq!(endpoint, 1);
+            held.push(permit);
+        }
+
+        data_sources.circuit_breaker().lock().await.record_failure("http://rpc-b:8899");
+        data_sources.circuit_breaker().lock().await.record_failure("http://rpc-b:8899");
+        assert!(data_sources.acquire_endpoint().await.is_err());
+    }
+
+    fn create_test_candidate(mint: &str) -> PremintCandidate {
+        PremintCandidate {
+            mint: mint.to_string(),
+            creator: "creator".to_string(),
+            program: "pump.fun".to_string(),
+            slot: 12345,
+            timestamp: 1640995200,
+            instruction_summary: None,
+            is_jito_bundle: Some(true),
+            funding_source: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cached_data_reports_older_timestamps() {
+        let rpc_clients = vec![Arc::new(RpcClient::new("http://localhost:8899"))];
+        let data_sources = OracleDataSources::new(rpc_clients, Client::new(), create_test_config());
+
+        let first = data_sources
+            .fetch_token_data_with_retries(&create_test_candidate("cached_mint"))
+            .await
+            .unwrap();
+        for source in [SOURCE_SUPPLY, SOURCE_LIQUIDITY, SOURCE_HOLDERS, SOURCE_SOCIAL] {
+            assert!(first.data_timestamps.contains_key(source), "missing timestamp for {}", source);
+        }
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        // Second fetch of the same mint is served from cache
+        let cached = data_sources
+            .fetch_token_data_with_retries(&create_test_candidate("cached_mint"))
+            .await
+            .unwrap();
+        let fresh = data_sources
+            .fetch_token_data_with_retries(&create_test_candidate("fresh_mint"))
+            .await
+            .unwrap();
+
+        assert_eq!(cached.data_timestamps, first.data_timestamps);
+        for source in [SOURCE_SUPPLY, SOURCE_LIQUIDITY, SOURCE_HOLDERS, SOURCE_SOCIAL] {
+            assert!(cached.data_timestamps[source] < fresh.data_timestamps[source]);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rpc_reads_use_configured_commitment() {
+        let mut config = create_test_config();
+        config.rpc_commitment = RpcCommitment::Processed;
+        let rpc = Arc::new(RpcClient::new("http://localhost:8899"));
+        let data_sources = OracleDataSources::new(vec![rpc.clone()], Client::new(), config);
+
+        data_sources
+            .fetch_token_data_with_retries(&create_test_candidate("commitment_mint"))
+            .await
+            .unwrap();
+
+        let requested = rpc.requested_commitments();
+        assert!(!requested.is_empty());
+        assert!(requested.iter().all(|c| *c == CommitmentConfig::processed()));
+    }
+
+    fn mint_account_bytes(mint_authority: bool, freeze_authority: bool) -> Vec<u8> {
+        let mut data = vec![0u8; MINT_ACCOUNT_LEN];
+        data[0..4].copy_from_slice(&(mint_authority as u32).to_le_bytes());
+        data[36..44].copy_from_slice(&1_000_000u64.to_le_bytes());
+        data[44] = 6;
+        data[45] = 1;
+        data[46..50].copy_from_slice(&(freeze_authority as u32).to_le_bytes());
+        data
+    }
+
+    fn create_test_pool(sol_amount: f64, token_amount: f64) -> LiquidityPool {
+        LiquidityPool {
+            sol_amount,
+            token_amount,
+            pool_address: "TestPool".to_string(),
+            pool_type: PoolType::Raydium,
+            bonding_curve_progress: None,
+        }
+    }
+
+    #[test]
+    fn test_compute_token_price_uses_token_decimals() {
+        // 1,000 whole tokens against 10 SOL is 0.01 SOL per token either way
+        let nine_decimals = create_test_pool(10.0, 1_000.0 * 1e9);
+        assert!((compute_token_price(&nine_decimals, 9) - 0.01).abs() < 1e-12);
+
+        let six_decimals = create_test_pool(10.0, 1_000.0 * 1e6);
+        assert!((compute_token_price(&six_decimals, 6) - 0.01).abs() < 1e-12);
+
+        assert_eq!(compute_token_price(&create_test_pool(10.0, 0.0), 9), 0.0);
+    }
+
+    #[test]
+    fn test_contract_verified_from_metadata() {
+        let metadata = |attributes: Vec<(&str, &str)>| Metadata {
+            name: "Token".to_string(),
+            symbol: "TKN".to_string(),
+            description: String::new(),
+            image: String::new(),
+            attributes: attributes
+                .into_iter()
+                .map(|(trait_type, value)| crate::oracle::types_old::Attribute {
+                    trait_type: trait_type.to_string(),
+                    value: value.to_string(),
+                })
+                .collect(),
+        };
+
+        assert_eq!(contract_verified_from_metadata(&metadata(vec![("Verified", "true")])), Some(true));
+        assert_eq!(contract_verified_from_metadata(&metadata(vec![("verified", "no")])), Some(false));
+        assert_eq!(contract_verified_from_metadata(&metadata(vec![("rarity", "true")])), None);
+    }
+
+    #[test]
+    fn test_validate_holder_distribution() {
+        let holders = |shares: &[f64]| -> Vec<HolderData> {
+            shares
+                .iter()
+                .enumerate()
+                .map(|(i, &percentage)| HolderData {
+                    address: format!("Holder{}", i),
+                    percentage,
+                    is_whale: false,
+                })
+                .collect()
+        };
+
+        assert!(validate_holder_distribution(&placeholder_holder_distribution()).is_ok());
+        assert!(validate_holder_distribution(&holders(&[0.5, 0.3, 0.2])).is_ok());
+        assert!(validate_holder_distribution(&[]).is_ok());
+
+        // Percents instead of fractions
+        assert!(validate_holder_distribution(&holders(&[15.0, 8.0, 3.5])).is_err());
+        // More than the whole supply
+        assert!(validate_holder_distribution(&holders(&[0.6, 0.5])).is_err());
+        assert!(validate_holder_distribution(&holders(&[f64::NAN])).is_err());
+    }
+
+    /// Bonding curve 10 SOL into its run, with the creator appended as in newer accounts.
+    const BONDING_CURVE_FIXTURE: [u8; 81] = [
+        0x17, 0xb7, 0xf8, 0x37, 0x60, 0xd8, 0xac, 0x60, 0x00, 0x0c, 0xe2, 0x75, 0xea, 0xdb, 0x02, 0x00,
+        0x00, 0x90, 0x2f, 0x50, 0x09, 0x00, 0x00, 0x00, 0x00, 0x74, 0xcf, 0x29, 0x59, 0xdd, 0x01, 0x00,
+        0x00, 0xe4, 0x0b, 0x54, 0x02, 0x00, 0x00, 0x00, 0x00, 0x80, 0xc6, 0xa4, 0x7e, 0x8d, 0x03, 0x00,
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+        0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+        0x20,
+    ];
+
+    #[test]
+    fn test_parse_bonding_curve_account() {
+        let curve = parse_bonding_curve_account(&BONDING_CURVE_FIXTURE).unwrap();
+        assert_eq!(curve, BondingCurveState {
+            virtual_token_reserves: 804_750_000_000_000,
+            virtual_sol_reserves: 40_000_000_000,
+            real_token_reserves: 524_850_000_000_000,
+            real_sol_reserves: 10_000_000_000,
+            token_total_supply: 1_000_000_000_000_000,
+            complete: false,
+        });
+        assert!((curve.graduation_progress() - 268.25 / 793.1).abs() < 1e-9);
+
+        let pool = curve.to_liquidity_pool("curve".to_string());
+        assert_eq!(pool.sol_amount, 10.0);
+        assert_eq!(pool.token_amount, 524_850_000_000_000.0);
+        assert!(matches!(pool.pool_type, PoolType::PumpFun));
+        assert_eq!(pool.bonding_curve_progress, Some(curve.graduation_progress()));
+
+        let mut graduated = BONDING_CURVE_FIXTURE;
+        graduated[48] = 1;
+        assert_eq!(parse_bonding_curve_account(&graduated).unwrap().graduation_progress(), 1.0);
+
+        let mut wrong_account = BONDING_CURVE_FIXTURE;
+        wrong_account[0] = 0;
+        assert!(parse_bonding_curve_account(&wrong_account).is_err());
+        assert!(parse_bonding_curve_account(&BONDING_CURVE_FIXTURE[..40]).is_err());
+    }
+
+    #[test]
+    fn test_bonding_curve_address_is_derived_from_mint() {
+        let mint = solana_sdk::pubkey::Pubkey::new_unique().to_string();
+        let address = bonding_curve_address(&mint).unwrap();
+        assert_eq!(address, bonding_curve_address(&mint).unwrap());
+        assert_ne!(address, mint);
+        assert!(bonding_curve_address(&"not a pubkey".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_parse_mint_account_authorities() {
+        let active = parse_mint_account(&mint_account_bytes(true, true)).unwrap();
+        assert_eq!(active.supply, 1_000_000);
+        assert_eq!(active.decimals, 6);
+        assert_eq!(active.mint_authority_active, Some(true));
+        assert_eq!(active.freeze_authority_active, Some(true));
+
+        let revoked = parse_mint_account(&mint_account_bytes(false, false)).unwrap();
+        assert_eq!(revoked.mint_authority_active, Some(false));
+        assert_eq!(revoked.freeze_authority_active, Some(false));
+
+        assert!(parse_mint_account(&[0u8; 10]).is_err());
+    }
+
+    fn create_cached_token_data() -> TokenData {
+        TokenData {
+            supply: 1_000_000_000,
+            decimals: 9,
+            metadata_uri: String::new(),
+            metadata: None,
+            holder_distribution: vec![],
+            liquidity_pool: None,
+            volume_data: VolumeData::default(),
+            creator_holdings: CreatorHoldings::default(),
+            holder_history: VecDeque::new(),
+            price_history: VecDeque::new(),
+            social_activity: SocialActivity::default(),
+            mint_authority_active: None,
+            freeze_authority_active: None,
+            contract_verified: None,
+            metadata_unreachable: false,
+            funding_source: None,
+            creator: None,
+            data_timestamps: HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cache_stats_track_hit_and_expiry_ages() {
+        let mut config = create_test_config();
+        config.cache_ttl_seconds = 1;
+        let data_sources = OracleDataSources::new(vec![], Client::new(), config);
+
+        assert!(data_sources.cache_get(&"MissingMint".to_string()).await.is_none());
+        data_sources.cache_insert("CachedMint".to_string(), create_cached_token_data()).await;
+        assert!(data_sources.cache_get(&"CachedMint".to_string()).await.is_some());
+        tokio::time::sleep(Duration::from_millis(600)).await;
+        assert!(data_sources.cache_get(&"CachedMint".to_string()).await.is_some());
+
+        let stats = data_sources.get_cache_stats().await;
+        assert_eq!((stats.hits, stats.misses), (2, 1));
+        assert_eq!(stats.hit_rate(), 2.0 / 3.0);
+        // One fresh hit, one in the third quarter of the TTL
+        assert_eq!(stats.hit_age_quartiles, [1, 0, 1, 0]);
+        assert!(stats.mean_hit_age_ms >= 300.0);
+        assert_eq!(stats.expired, 0);
+
+        tokio::time::sleep(Duration::from_millis(600)).await;
+        assert!(data_sources.cache_get(&"CachedMint".to_string()).await.is_none());
+
+        let stats = data_sources.get_cache_stats().await;
+        assert_eq!(stats.misses, 2);
+        assert_eq!((stats.expired, stats.evicted), (1, 0));
+        assert!(stats.mean_removal_age_ms >= 1000.0, "{:?}", stats);
+    }
+}
@@ -0,0 +1,82 @@
+//! Exponential time decay shared by the modules that age out old data.
+//!
+//! Ages and half-lives only need to share a unit; the names speak of seconds
+//! because that is what most callers measure, but a decay per observation
+//! works the same way with ages counted in steps.
+
+/// Weight of something `age_seconds` old when its weight halves every
+/// `half_life_seconds`: 1.0 at age zero, 0.5 after one half-life, 0.25 after two.
+///
+/// Non-positive ages weigh 1.0. A non-positive half-life decays everything
+/// older than zero instantly; an infinite one never decays.
+pub fn exponential_weight(age_seconds: f64, half_life_seconds: f64) -> f64 {
+    if age_seconds <= 0.0 {
+        return 1.0;
+    }
+    if half_life_seconds <= 0.0 {
+        return 0.0;
+    }
+    0.5f64.powf(age_seconds / half_life_seconds)
+}
+
+/// Half-life, in steps, of a weight that keeps `factor` of itself per step.
+///
+/// Factors of 1.0 or more never decay (infinite half-life); factors of 0.0 or
+/// less decay instantly (zero half-life).
+pub fn half_life_for_factor(factor: f64) -> f64 {
+    if factor >= 1.0 {
+        f64::INFINITY
+    } else if factor <= 0.0 {
+        0.0
+    } else {
+        0.5f64.ln() / factor.ln()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-12, "expected {}, got {}", expected, actual);
+    }
+
+    #[test]
+    fn test_exponential_weight() {
+        assert_eq!(exponential_weight(0.0, 60.0), 1.0);
+        assert_close(exponential_weight(60.0, 60.0), 0.5);
+        assert_close(exponential_weight(120.0, 60.0), 0.25);
+        assert_close(exponential_weight(30.0, 60.0), 0.5f64.sqrt());
+
+        // Weights only ever shrink with age
+        let mut previous = 1.0;
+        for age in 1..100 {
+            let weight = exponential_weight(age as f64, 10.0);
+            assert!(weight < previous && weight > 0.0);
+            previous = weight;
+        }
+    }
+
+    #[test]
+    fn test_exponential_weight_edge_cases() {
+        // Clock skew can make data look like it comes from the future
+        assert_eq!(exponential_weight(-5.0, 60.0), 1.0);
+        assert_eq!(exponential_weight(0.0, 0.0), 1.0);
+        assert_eq!(exponential_weight(1.0, 0.0), 0.0);
+        assert_eq!(exponential_weight(1e9, f64::INFINITY), 1.0);
+    }
+
+    #[test]
+    fn test_half_life_for_factor() {
+        assert_close(half_life_for_factor(0.5), 1.0);
+        assert_close(half_life_for_factor(0.25), 0.5);
+        assert_eq!(half_life_for_factor(1.0), f64::INFINITY);
+        assert_eq!(half_life_for_factor(0.0), 0.0);
+
+        // Decaying by the half-life reproduces repeated multiplication by the factor
+        let half_life = half_life_for_factor(0.9);
+        for steps in 0..20 {
+            assert_close(exponential_weight(steps as f64, half_life), 0.9f64.powi(steps));
+        }
+    }
+}
@@ -3,18 +3,54 @@
 //! This module implements the first pillar of the "genius" system: persistent memory
 //! of all decisions made by the PredictiveOracle and their actual outcomes.
 
-use anyhow::{Result, Context};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use tracing::{info, error, debug};
-use crate::oracle::types::{TransactionRecord, Outcome, DecisionRecordReceiver, OutcomeUpdateReceiver};
+use std::time::Duration;
+use tracing::{debug, info, warn, error};
+use crate::oracle::metrics::OracleMetricsCollector;
+use crate::oracle::shutdown::ShutdownToken;
+use crate::oracle::types::{TransactionRecord, DecisionRecordReceiver, Outcome, OutcomeUpdate, OutcomeUpdateReceiver};
 use crate::oracle::storage::{LedgerStorage, SqliteLedger, SqliteLedgerNormalized};
 
+/// Default number of records held in memory while the storage is unavailable.
+pub const DEFAULT_SPILL_CAPACITY: usize = 1000;
+
+/// Default interval between attempts to flush spilled records.
+pub const DEFAULT_SPILL_RETRY_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Most outcome updates written to storage in one batch.
+const MAX_OUTCOME_BATCH: usize = 100;
+
+/// Period of the compaction timer when compaction is disabled; it never fires.
+const COMPACTION_DISABLED_PERIOD: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// A write held in the spill buffer, replayed in order once the storage recovers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum SpilledWrite {
+    Record(Box<TransactionRecord>),
+    Outcomes(Vec<OutcomeUpdate>),
+}
+
 /// DecisionLedger provides persistent storage for Oracle decisions and outcomes
 /// Now using the storage abstraction for clean separation of concerns
 pub struct DecisionLedger {
     storage: Arc<dyn LedgerStorage>,
     record_receiver: DecisionRecordReceiver,
     outcome_update_receiver: OutcomeUpdateReceiver,
+    /// Writes that failed, or arrived while earlier ones were waiting, retried
+    /// in order once the storage is healthy again
+    spill_buffer: VecDeque<SpilledWrite>,
+    spill_capacity: usize,
+    spill_retry_interval: Duration,
+    /// Where writes still buffered at exit are kept until the next start
+    spill_file: Option<PathBuf>,
+    /// How often to compact the storage, if at all
+    compaction_interval: Option<Duration>,
+    metrics: Option<Arc<OracleMetricsCollector>>,
+    shutdown: ShutdownToken,
 }
 
 impl DecisionLedger {
@@ -27,11 +63,7 @@ impl DecisionLedger {
         
         info!("DecisionLedger initialized with flat storage schema");
 
-        Ok(Self { 
-            storage,
-            record_receiver, 
-            outcome_update_receiver 
-        })
+        Ok(Self::new_with_storage(storage, record_receiver, outcome_update_receiver))
     }
 
     /// Create a new DecisionLedger with normalized SQLite backend
@@ -43,11 +75,7 @@ impl DecisionLedger {
         
         info!("DecisionLedger initialized with normalized storage schema");
 
-        Ok(Self { 
-            storage,
-            record_receiver, 
-            outcome_update_receiver 
-        })
+        Ok(Self::new_with_storage(storage, record_receiver, outcome_update_receiver))
     }
 
     /// Create a new DecisionLedger with custom storage implementation
@@ -60,9 +88,52 @@ impl DecisionLedger {
             storage,
             record_receiver,
             outcome_update_receiver,
+            spill_buffer: VecDeque::new(),
+            spill_capacity: DEFAULT_SPILL_CAPACITY,
+            spill_retry_interval: DEFAULT_SPILL_RETRY_INTERVAL,
+            spill_file: None,
+            compaction_interval: None,
+            metrics: None,
+            shutdown: ShutdownToken::new(),
         }
     }
 
+    /// Set how many failed writes are held in memory and how often they are retried.
+    ///
+    /// Once `capacity` writes are buffered the ledger stops reading its
+    /// channels, so senders wait for the storage instead of writes being dropped.
+    pub fn with_spill_buffer(mut self, capacity: usize, retry_interval: Duration) -> Self {
+        self.spill_capacity = capacity.max(1);
+        self.spill_retry_interval = retry_interval;
+        self
+    }
+
+    /// Save writes that are still buffered when the ledger stops to `path`, and
+    /// replay them from there on the next start.
+    pub fn with_spill_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.spill_file = Some(path.into());
+        self
+    }
+
+    /// Compact the storage every `interval` while running.
+    pub fn with_compaction_interval(mut self, interval: Duration) -> Self {
+        self.compaction_interval = Some(interval);
+        self
+    }
+
+    /// Report the `ledger_spill_buffer_size` gauge to the given collector.
+    pub fn with_metrics(mut self, metrics: Arc<OracleMetricsCollector>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Stop `run` once `shutdown` is triggered, after persisting queued and
+    /// buffered records.
+    pub fn with_shutdown(mut self, shutdown: ShutdownToken) -> Self {
+        self.shutdown = shutdown;
+        self
+    }
+
     /// Get a reference to the storage for use by other components
     pub fn get_storage(&self) -> Arc<dyn LedgerStorage> {
         Arc::clone(&self.storage)
@@ -84,28 +155,517 @@ impl DecisionLedger {
     /// Main execution loop - processes incoming decisions and outcome updates
     pub async fn run(mut self) {
         info!("DecisionLedger is running...");
+        self.load_spill_file().await;
+        let mut spill_retry = tokio::time::interval(self.spill_retry_interval);
+        let compaction_period = self.compaction_interval.unwrap_or(COMPACTION_DISABLED_PERIOD);
+        let mut compaction = tokio::time::interval_at(
+            tokio::time::Instant::now() + compaction_period,
+            compaction_period,
+        );
+        let shutdown = self.shutdown.clone();
+
         loop {
+            // Once every sender is gone there is nothing left to drain
+            let channels_open = !self.record_receiver.is_closed() || !self.outcome_update_receiver.is_closed();
+            // A full buffer holds the senders back until the storage recovers
+            let accepting = self.spill_buffer.len() < self.spill_capacity;
+
             tokio::select! {
-                Some(record) = self.record_receiver.recv() => {
-                    if let Err(e) = self.storage.insert_record(&record).await {
-                        error!("Failed to insert transaction record: {:?}", e);
-                    }
+                Some(record) = self.record_receiver.recv(), if accepting => {
+                    self.store_record(record).await;
+                },
+                _ = spill_retry.tick(), if !self.spill_buffer.is_empty() => {
+                    self.flush_spill_buffer().await;
                 },
-                Some((signature, outcome, buy_price, sell_price, sol_spent, sol_received, evaluated_at, is_verified)) = self.outcome_update_receiver.recv() => {
-                    if let Err(e) = self.storage.update_outcome(&signature, outcome, buy_price, sell_price, sol_spent, sol_received, evaluated_at, is_verified).await {
-                        error!("Failed to update outcome for signature {}: {:?}", signature, e);
+                _ = compaction.tick(), if self.compaction_interval.is_some() => {
+                    if let Err(e) = self.storage.compact().await {
+                        warn!("Failed to compact ledger storage: {:?}", e);
                     }
                 },
-                else => {
+                Some(update) = self.outcome_update_receiver.recv(), if accepting => {
+                    let updates = self.with_queued_outcome_updates(update);
+                    self.apply_outcome_updates(updates).await;
+                },
+                _ = shutdown.triggered(), if channels_open => {
+                    info!("DecisionLedger shutdown requested, draining...");
+                    self.drain().await;
+                    break;
+                },
+                _ = std::future::ready(()), if !channels_open => {
                     info!("DecisionLedger channels closed. Shutting down.");
+                    self.drain().await;
                     break;
                 }
             }
         }
     }
 
+    /// Insert a record, or buffer it while the storage is failing.
+    async fn store_record(&mut self, record: TransactionRecord) {
+        if !self.spill_buffer.is_empty() {
+            // Keep insertion order: queue behind the writes still waiting
+            self.spill(SpilledWrite::Record(Box::new(record))).await;
+        } else if let Err(e) = self.storage.insert_record(&record).await {
+            error!("Failed to insert transaction record, buffering in memory: {:?}", e);
+            self.spill(SpilledWrite::Record(Box::new(record))).await;
+        }
+    }
+
+    /// `update` followed by the updates already waiting behind it, so that
+    /// outcomes resolved together are written together.
+    fn with_queued_outcome_updates(&mut self, update: OutcomeUpdate) -> Vec<OutcomeUpdate> {
+        let mut updates = vec![update];
+        while updates.len() < MAX_OUTCOME_BATCH {
+            match self.outcome_update_receiver.try_recv() {
+                Ok(update) => updates.push(update),
+                Err(_) => break,
+            }
+        }
+        updates
+    }
+
+    /// Write outcome updates in one batch, or buffer them while the storage is
+    /// failing or still holds records they may refer to.
+    async fn apply_outcome_updates(&mut self, updates: Vec<OutcomeUpdate>) {
+        if !self.spill_buffer.is_empty() {
+            self.spill(SpilledWrite::Outcomes(updates)).await;
+        } else if let Err(e) = self.storage.update_outcomes_batch(&updates).await {
+            error!("Failed to write {} outcome updates, buffering in memory: {:?}", updates.len(), e);
+            self.spill(SpilledWrite::Outcomes(updates)).await;
+        } else {
+            self.record_outcome_latencies(&updates).await;
+        }
+    }
+
+    /// Persist everything still queued or buffered before shutting down.
+    async fn drain(&mut self) {
+        self.record_receiver.close();
+        self.outcome_update_receiver.close();
+
+        // Records first, so outcome updates find the decisions they refer to
+        while let Ok(record) = self.record_receiver.try_recv() {
+            self.store_record(record).await;
+        }
+        while let Ok(update) = self.outcome_update_receiver.try_recv() {
+            let updates = self.with_queued_outcome_updates(update);
+            self.apply_outcome_updates(updates).await;
+        }
+
+        if !self.spill_buffer.is_empty() {
+            self.flush_spill_buffer().await;
+        }
+        if !self.spill_buffer.is_empty() {
+            self.save_spill_file().await;
+        }
+    }
+
+    /// Hold a write in memory until the storage accepts it.
+    async fn spill(&mut self, write: SpilledWrite) {
+        self.spill_buffer.push_back(write);
+        if self.spill_buffer.len() == self.spill_capacity {
+            warn!("Ledger spill buffer full, holding new writes until the storage recovers");
+        }
+        self.report_spill_buffer_size().await;
+    }
+
+    /// Persist buffered writes in order once the storage reports healthy.
+    async fn flush_spill_buffer(&mut self) {
+        match self.storage.health_check().await {
+            Ok(true) => {}
+            Ok(false) | Err(_) => {
+                warn!("Storage still unavailable, {} writes buffered", self.spill_buffer.len());
+                return;
+            }
+        }
+
+        while let Some(write) = self.spill_buffer.front() {
+            let written = match write {
+                SpilledWrite::Record(record) => self.storage.insert_record(record).await.map(|_| ()),
+                SpilledWrite::Outcomes(updates) => self.storage.update_outcomes_batch(updates).await,
+            };
+            if let Err(e) = written {
+                warn!("Failed to flush buffered write, will retry: {:?}", e);
+                break;
+            }
+            if let Some(SpilledWrite::Outcomes(updates)) = self.spill_buffer.pop_front() {
+                self.record_outcome_latencies(&updates).await;
+            }
+        }
+
+        if self.spill_buffer.is_empty() {
+            info!("Ledger spill buffer flushed");
+        }
+        self.report_spill_buffer_size().await;
+    }
+
+    /// Write the buffered writes to the spill file, if there is one, so the
+    /// next start can replay them.
+    async fn save_spill_file(&mut self) {
+        let Some(path) = &self.spill_file else {
+            error!("Shutting down with {} writes not persisted", self.spill_buffer.len());
+            return;
+        };
+        match write_spill_file(path, &self.spill_buffer).await {
+            Ok(()) => {
+                info!("Saved {} unpersisted writes to {}", self.spill_buffer.len(), path.display());
+                self.spill_buffer.clear();
+            }
+            Err(e) => error!("Shutting down with {} writes not persisted: {:?}", self.spill_buffer.len(), e),
+        }
+    }
+
+    /// Queue the writes saved by a previous run ahead of everything else.
+    async fn load_spill_file(&mut self) {
+        let Some(path) = &self.spill_file else {
+            return;
+        };
+        match read_spill_file(path).await {
+            Ok(writes) if writes.is_empty() => {}
+            Ok(writes) => {
+                info!("Replaying {} writes saved by the previous run", writes.len());
+                self.spill_buffer.extend(writes);
+                self.report_spill_buffer_size().await;
+                self.flush_spill_buffer().await;
+            }
+            Err(e) => error!("Failed to load the ledger spill file {}: {:?}", path.display(), e),
+        }
+    }
+
+    async fn report_spill_buffer_size(&self) {
+        if let Some(metrics) = &self.metrics {
+            metrics.set_gauge("ledger_spill_buffer_size", self.spill_buffer.len() as f64).await;
+        }
+    }
+
+    /// Observe the decision-to-outcome latency of every resolved update.
+    async fn record_outcome_latencies(&self, updates: &[OutcomeUpdate]) {
+        for update in updates {
+            if let (true, Some(evaluated_at)) = (update.outcome != Outcome::PendingConfirmation, update.timestamp_evaluated) {
+                self.record_outcome_latency(&update.signature, evaluated_at).await;
+            }
+        }
+    }
+
+    /// Observe the time from decision to resolved outcome for a signature.
+    async fn record_outcome_latency(&self, signature: &str, evaluated_at_ms: u64) {
+        let Some(metrics) = &self.metrics else {
+            return;
+        };
+
+        match self.storage.get_record_by_signature(signature).await {
+            Ok(Some(record)) => {
+                let latency_ms = evaluated_at_ms.saturating_sub(record.timestamp_decision_made);
+                metrics
+                    .record_histogram("ledger_decision_to_outcome_seconds", latency_ms as f64 / 1000.0)
+                    .await;
+            }
+            Ok(None) => debug!("No decision recorded for signature {}", signature),
+            Err(e) => warn!("Failed to look up decision for signature {}: {:?}", signature, e),
+        }
+    }
+
     /// Retrieve historical records since a given timestamp (for analysis)
     pub async fn get_records_since(&self, timestamp: u64) -> Result<Vec<TransactionRecord>> {
         self.storage.get_records_since(timestamp).await
     }
-}
\ No newline at end of file
+
+    /// Decisions still awaiting confirmation that were made before `older_than` (ms), oldest first
+    pub async fn get_unresolved_decisions(&self, older_than: u64) -> Result<Vec<TransactionRecord>> {
+        self.storage.get_unresolved_decisions(older_than).await
+    }
+}
+
+/// Write `writes` to `path` as JSON lines, replacing the file in a single
+/// rename so a crash mid-write leaves no partial file behind.
+async fn write_spill_file(path: &Path, writes: &VecDeque<SpilledWrite>) -> Result<()> {
+    let mut contents = String::new();
+    for write in writes {
+        contents.push_str(&serde_json::to_string(write)?);
+        contents.push('\n');
+    }
+    let mut tmp_path = path.as_os_str().to_owned();
+    tmp_path.push(".tmp");
+    tokio::fs::write(&tmp_path, contents)
+        .await
+        .with_context(|| format!("Failed to write spill file {}", path.display()))?;
+    tokio::fs::rename(&tmp_path, path)
+        .await
+        .with_context(|| format!("Failed to replace spill file {}", path.display()))?;
+    Ok(())
+}
+
+/// Take the writes saved at `path`, removing the file; none if there is no file.
+async fn read_spill_file(path: &Path) -> Result<Vec<SpilledWrite>> {
+    let contents = match tokio::fs::read_to_string(path).await {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e).with_context(|| format!("Failed to read spill file {}", path.display())),
+    };
+    let writes = contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(serde_json::from_str)
+        .collect::<serde_json::Result<Vec<_>>>()
+        .with_context(|| format!("Failed to parse spill file {}", path.display()))?;
+    tokio::fs::remove_file(path)
+        .await
+        .with_context(|| format!("Failed to remove spill file {}", path.display()))?;
+    Ok(writes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oracle::storage_conformance::FlakyStorage;
+    use crate::oracle::test_support::RecordBuilder;
+    use std::sync::atomic::Ordering;
+    use tokio::sync::mpsc;
+
+    fn create_test_record(mint: &str) -> TransactionRecord {
+        RecordBuilder::new(mint).decided_at(1000).build()
+    }
+
+    #[tokio::test]
+    async fn test_spilled_records_are_persisted_after_recovery() {
+        let storage = Arc::new(FlakyStorage::default());
+        let metrics = Arc::new(OracleMetricsCollector::new());
+        let (record_tx, record_rx) = mpsc::channel(10);
+        let (outcome_tx, outcome_rx) = mpsc::channel(10);
+
+        let ledger = DecisionLedger::new_with_storage(storage.clone(), record_rx, outcome_rx)
+            .with_spill_buffer(10, Duration::from_millis(10))
+            .with_metrics(metrics.clone());
+        let handle = tokio::spawn(ledger.run());
+
+        record_tx.send(create_test_record("first")).await.unwrap();
+        record_tx.send(create_test_record("second")).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        assert!(storage.records.lock().await.is_empty());
+        let snapshot = metrics.get_metrics_snapshot().await;
+        assert_eq!(snapshot.gauges.get("ledger_spill_buffer_size"), Some(&2.0));
+
+        storage.available.store(true, Ordering::SeqCst);
+        drop(record_tx);
+        drop(outcome_tx);
+        tokio::time::timeout(Duration::from_secs(1), handle).await.unwrap().unwrap();
+
+        let mints: Vec<_> = storage.records.lock().await
+            .iter()
+            .map(|r| r.scored_candidate.mint.clone())
+            .collect();
+        assert_eq!(mints, vec!["first", "second"]);
+        let snapshot = metrics.get_metrics_snapshot().await;
+        assert_eq!(snapshot.gauges.get("ledger_spill_buffer_size"), Some(&0.0));
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_flushes_buffered_records() {
+        let storage = Arc::new(FlakyStorage::default());
+        let shutdown = ShutdownToken::new();
+        let (record_tx, record_rx) = mpsc::channel(10);
+        let (_outcome_tx, outcome_rx) = mpsc::channel(10);
+
+        // The retry interval is far longer than the test, so only shutdown can flush
+        let ledger = DecisionLedger::new_with_storage(storage.clone(), record_rx, outcome_rx)
+            .with_spill_buffer(10, Duration::from_secs(3600))
+            .with_shutdown(shutdown.clone());
+        let handle = tokio::spawn(ledger.run());
+
+        record_tx.send(create_test_record("spilled")).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(storage.records.lock().await.is_empty());
+
+        storage.available.store(true, Ordering::SeqCst);
+        shutdown.trigger();
+        tokio::time::timeout(Duration::from_secs(1), handle).await.unwrap().unwrap();
+
+        let mints: Vec<_> = storage.records.lock().await
+            .iter()
+            .map(|r| r.scored_candidate.mint.clone())
+            .collect();
+        assert_eq!(mints, vec!["spilled"]);
+        // The senders are still alive, yet the ledger stopped accepting records
+        assert!(record_tx.send(create_test_record("late")).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_outcome_updates_wait_behind_spilled_records() {
+        let storage = Arc::new(FlakyStorage::default());
+        let (record_tx, record_rx) = mpsc::channel(10);
+        let (outcome_tx, outcome_rx) = mpsc::channel(10);
+        let ledger = DecisionLedger::new_with_storage(storage.clone(), record_rx, outcome_rx)
+            .with_spill_buffer(10, Duration::from_millis(10));
+        let handle = tokio::spawn(ledger.run());
+
+        record_tx.send(RecordBuilder::new("spilled").signature("sig_spilled").build()).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        outcome_tx
+            .send(OutcomeUpdate::unverified("sig_spilled".to_string(), Outcome::ConfirmationTimeout, 2_000))
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(storage.outcomes.lock().await.is_empty());
+
+        // Replayed in order: the update finds the record it refers to
+        storage.available.store(true, Ordering::SeqCst);
+        drop(record_tx);
+        drop(outcome_tx);
+        tokio::time::timeout(Duration::from_secs(1), handle).await.unwrap().unwrap();
+
+        let records = storage.records.lock().await;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].actual_outcome, Outcome::ConfirmationTimeout);
+    }
+
+    #[tokio::test]
+    async fn test_full_spill_buffer_holds_back_senders() {
+        let storage = Arc::new(FlakyStorage::default());
+        let (record_tx, record_rx) = mpsc::channel(1);
+        let (outcome_tx, outcome_rx) = mpsc::channel(1);
+        let ledger = DecisionLedger::new_with_storage(storage.clone(), record_rx, outcome_rx)
+            .with_spill_buffer(1, Duration::from_millis(10));
+        let handle = tokio::spawn(ledger.run());
+
+        record_tx.send(create_test_record("buffered")).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        record_tx.send(create_test_record("queued")).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        // The buffer is full, so the channel is not read and nothing is evicted
+        assert!(record_tx.try_send(create_test_record("refused")).is_err());
+
+        storage.available.store(true, Ordering::SeqCst);
+        drop(record_tx);
+        drop(outcome_tx);
+        tokio::time::timeout(Duration::from_secs(1), handle).await.unwrap().unwrap();
+
+        let mints: Vec<_> = storage.records.lock().await
+            .iter()
+            .map(|r| r.scored_candidate.mint.clone())
+            .collect();
+        assert_eq!(mints, vec!["buffered", "queued"]);
+    }
+
+    #[tokio::test]
+    async fn test_unpersisted_writes_are_replayed_from_spill_file() {
+        let spill_file = std::env::temp_dir().join(format!("ledger_spill_{}.jsonl", rand::random::<u64>()));
+        let storage = Arc::new(FlakyStorage::default());
+        let shutdown = ShutdownToken::new();
+        let (record_tx, record_rx) = mpsc::channel(10);
+        let (outcome_tx, outcome_rx) = mpsc::channel(10);
+        let ledger = DecisionLedger::new_with_storage(storage.clone(), record_rx, outcome_rx)
+            .with_spill_buffer(10, Duration::from_secs(3600))
+            .with_spill_file(&spill_file)
+            .with_shutdown(shutdown.clone());
+        let handle = tokio::spawn(ledger.run());
+
+        record_tx.send(RecordBuilder::new("saved").signature("sig_saved").build()).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        outcome_tx.send(OutcomeUpdate::unverified("sig_saved".to_string(), Outcome::Neutral, 2_000)).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        shutdown.trigger();
+        tokio::time::timeout(Duration::from_secs(1), handle).await.unwrap().unwrap();
+        assert!(spill_file.exists());
+
+        // The next run replays the saved writes before anything else
+        storage.available.store(true, Ordering::SeqCst);
+        let (_record_tx, record_rx) = mpsc::channel(10);
+        let (_outcome_tx, outcome_rx) = mpsc::channel(10);
+        let ledger = DecisionLedger::new_with_storage(storage.clone(), record_rx, outcome_rx)
+            .with_spill_file(&spill_file);
+        let handle = tokio::spawn(ledger.run());
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        handle.abort();
+
+        let records = storage.records.lock().await;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].actual_outcome, Outcome::Neutral);
+        assert!(!spill_file.exists());
+    }
+
+    #[tokio::test]
+    async fn test_queued_outcome_updates_are_written_in_one_batch() {
+        let storage = Arc::new(FlakyStorage::available());
+        let (_record_tx, record_rx) = mpsc::channel(10);
+        let (outcome_tx, outcome_rx) = mpsc::channel(10);
+        for i in 0..3 {
+            outcome_tx
+                .send(OutcomeUpdate::unverified(format!("sig_{}", i), Outcome::ConfirmationTimeout, 2_000))
+                .await
+                .unwrap();
+        }
+
+        let ledger = DecisionLedger::new_with_storage(storage.clone(), record_rx, outcome_rx);
+        let handle = tokio::spawn(ledger.run());
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        handle.abort();
+
+        assert_eq!(*storage.outcome_batches.lock().await, vec![3]);
+    }
+
+    #[tokio::test]
+    async fn test_outcome_update_is_applied_to_storage() {
+        let storage = Arc::new(FlakyStorage::available());
+        let (_record_tx, record_rx) = mpsc::channel(10);
+        let (outcome_tx, outcome_rx) = mpsc::channel(10);
+        let ledger = DecisionLedger::new_with_storage(storage.clone(), record_rx, outcome_rx);
+        let handle = tokio::spawn(ledger.run());
+
+        let update = OutcomeUpdate {
+            signature: "sig_1".to_string(),
+            outcome: Outcome::Profit(0.4),
+            buy_price_sol: Some(0.001),
+            sell_price_sol: Some(0.0014),
+            initial_sol_spent: Some(1.0),
+            final_sol_received: Some(1.4),
+            timestamp_evaluated: Some(1_700_000_000),
+            is_verified: true,
+            outcome_reason: None,
+            low_confidence: false,
+        };
+        outcome_tx.send(update.clone()).await.unwrap();
+        outcome_tx
+            .send(OutcomeUpdate::unverified("sig_2".to_string(), Outcome::ConfirmationTimeout, 1_700_000_100))
+            .await
+            .unwrap();
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        handle.abort();
+
+        let outcomes = storage.outcomes.lock().await;
+        assert_eq!(outcomes.len(), 2);
+        assert_eq!(outcomes[0], update);
+        assert_eq!(outcomes[1].outcome, Outcome::ConfirmationTimeout);
+        assert_eq!(outcomes[1].timestamp_evaluated, Some(1_700_000_100));
+        assert!(!outcomes[1].is_verified);
+    }
+
+    #[tokio::test]
+    async fn test_resolved_outcome_records_decision_latency() {
+        let storage = Arc::new(FlakyStorage::default());
+        storage.available.store(true, Ordering::SeqCst);
+        let metrics = Arc::new(OracleMetricsCollector::new());
+        let (record_tx, record_rx) = mpsc::channel(10);
+        let (outcome_tx, outcome_rx) = mpsc::channel(10);
+        let ledger = DecisionLedger::new_with_storage(storage.clone(), record_rx, outcome_rx)
+            .with_metrics(metrics.clone());
+        let handle = tokio::spawn(ledger.run());
+
+        let mut record = create_test_record("latency");
+        record.transaction_signature = Some("sig_latency".to_string());
+        record.timestamp_decision_made = 1_000_000;
+        record_tx.send(record).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        outcome_tx
+            .send(OutcomeUpdate::unverified("sig_latency".to_string(), Outcome::ConfirmationTimeout, 1_030_000))
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        handle.abort();
+
+        let snapshot = metrics.get_metrics_snapshot().await;
+        assert_eq!(snapshot.histograms.get("ledger_decision_to_outcome_seconds"), Some(&vec![30.0]));
+    }
+}
@@ -3,15 +3,25 @@
 //! This module contains all the feature computation functions that analyze
 //! different aspects of tokens to produce normalized scores (0.0-1.0).
 
+use crate::oracle::data_sources::{
+    current_timestamp_ms, SOURCE_CREATOR, SOURCE_HOLDERS, SOURCE_LIQUIDITY, SOURCE_METADATA,
+    SOURCE_SOCIAL, SOURCE_SUPPLY, SOURCE_VOLUME,
+};
+use crate::oracle::decay::exponential_weight;
 use crate::oracle::types::{
-    Feature, FeatureScores, TokenData, OracleConfig, VolumeData, LiquidityPool,
-    HolderData, CreatorHoldings, SocialActivity, Metadata,
+    Feature, FeatureScores, JitoBundleScores, TokenData, OracleConfig, ScoreThresholds,
 };
 use crate::types::PremintCandidate;
 use anyhow::Result;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use tracing::{debug, warn, instrument};
 
+/// Default direction of the graduation proximity feature: closer scores higher.
+pub const DEFAULT_PREFER_NEAR_GRADUATION: bool = true;
+
+/// Default transactions per holder up to which activity looks organic.
+pub const DEFAULT_MAX_TX_PER_HOLDER: f64 = 20.0;
+
 /// Feature computer responsible for calculating all feature scores.
 pub struct OracleFeatureComputer {
     config: OracleConfig,
@@ -20,6 +30,9 @@ pub struct OracleFeatureComputer {
 impl OracleFeatureComputer {
     /// Create a new feature computer with the given configuration.
     pub fn new(config: OracleConfig) -> Self {
+        if !config.feature_overrides.is_empty() {
+            warn!("Feature overrides active, computed scores are replaced: {:?}", config.feature_overrides);
+        }
         Self { config }
     }
 
@@ -30,38 +43,119 @@ impl OracleFeatureComputer {
         candidate: &PremintCandidate,
         token_data: &TokenData,
     ) -> Result<FeatureScores> {
-        let mut scores = FeatureScores::new();
+        self.compute_all_features_with_thresholds(candidate, token_data, &self.config.thresholds)
+            .await
+    }
 
-        // Compute each feature score
-        scores.set(Feature::Liquidity, self.compute_liquidity_score(token_data)?);
-        scores.set(
-            Feature::HolderDistribution,
-            self.compute_holder_distribution_score(token_data)?,
-        );
-        scores.set(Feature::VolumeGrowth, self.compute_volume_growth_score(token_data)?);
-        scores.set(Feature::HolderGrowth, self.compute_holder_growth_score(token_data)?);
-        scores.set(Feature::PriceChange, self.compute_price_change_score(token_data)?);
-        scores.set(
-            Feature::JitoBundlePresence,
-            self.compute_jito_bundle_score(candidate)?,
-        );
-        scores.set(
-            Feature::CreatorSellSpeed,
-            self.compute_creator_sell_score(token_data)?,
-        );
-        scores.set(
-            Feature::MetadataQuality,
-            self.compute_metadata_quality_score(token_data)?,
-        );
-        scores.set(Feature::SocialActivity, self.compute_social_activity_score(token_data)?);
+    /// Compute all features using the given thresholds (e.g. regime-specific ones).
+    #[instrument(skip(self, token_data, thresholds), fields(mint = %candidate.mint))]
+    pub async fn compute_all_features_with_thresholds(
+        &self,
+        candidate: &PremintCandidate,
+        token_data: &TokenData,
+        thresholds: &ScoreThresholds,
+    ) -> Result<FeatureScores> {
+        let mut scores = self.compute_unadjusted_features(
+            candidate,
+            token_data,
+            thresholds,
+            &JitoBundleScores::default(),
+        )?;
+        self.apply_data_age_penalty(&mut scores, token_data, current_timestamp_ms());
+        self.apply_overrides(&mut scores);
 
         debug!("Computed feature scores: {:?}", scores.to_hashmap());
         Ok(scores)
     }
 
+    /// Compute all features without scaling down stale data.
+    pub fn compute_unadjusted_features(
+        &self,
+        candidate: &PremintCandidate,
+        token_data: &TokenData,
+        thresholds: &ScoreThresholds,
+        jito_bundle_scores: &JitoBundleScores,
+    ) -> Result<FeatureScores> {
+        let mut scores = FeatureScores::new();
+
+        // Compute each enabled feature score; disabled ones stay at zero
+        for feature in Feature::all() {
+            if !self.config.is_feature_enabled(feature) {
+                continue;
+            }
+
+            let score = match feature {
+                Feature::Liquidity => self.compute_liquidity_score(token_data, thresholds)?,
+                Feature::HolderDistribution => self.compute_holder_distribution_score(token_data)?,
+                Feature::VolumeGrowth => self.compute_volume_growth_score(token_data, thresholds)?,
+                Feature::HolderGrowth => self.compute_holder_growth_score(token_data, thresholds)?,
+                Feature::PriceChange => self.compute_price_change_score(token_data)?,
+                Feature::JitoBundlePresence => self.compute_jito_bundle_score(candidate, jito_bundle_scores)?,
+                Feature::CreatorSellSpeed => self.compute_creator_sell_score(token_data, thresholds)?,
+                Feature::MetadataQuality => self.compute_metadata_quality_score(token_data)?,
+                Feature::SocialActivity => self.compute_social_activity_score(token_data, thresholds)?,
+                Feature::AuthorityStatus => self.compute_authority_status_score(token_data)?,
+                Feature::ContractVerified => self.compute_contract_verified_score(token_data)?,
+                Feature::GraduationProximity => self.compute_graduation_proximity_score(token_data)?,
+                Feature::TxPerHolder => self.compute_tx_per_holder_score(token_data)?,
+            };
+            scores.set(feature, score);
+        }
+
+        Ok(scores)
+    }
+
+    /// Scale down features whose input data is older than `max_data_age_seconds`.
+    ///
+    /// A feature fed by data `age` old keeps `max_age / age` of its score, so a
+    /// stale cached reading cannot keep propping up a token that has since died.
+    /// With `social_half_life_seconds` set, social activity instead decays
+    /// continuously with that half-life.
+    /// Features without a recorded fetch timestamp are left untouched.
+    pub fn apply_data_age_penalty(&self, scores: &mut FeatureScores, token_data: &TokenData, now_ms: u64) {
+        let max_age_ms = self.config.max_data_age_seconds.saturating_mul(1000);
+
+        for feature in Feature::all() {
+            let Some(fetched_at) = feature_data_source(feature)
+                .and_then(|source| token_data.data_timestamps.get(source))
+            else {
+                continue;
+            };
+
+            let age_ms = now_ms.saturating_sub(*fetched_at);
+            if let (Feature::SocialActivity, Some(half_life)) = (feature, self.config.social_half_life_seconds) {
+                let freshness = exponential_weight(age_ms as f64 / 1000.0, half_life as f64);
+                scores.set(feature, scores.get(feature) * freshness);
+                continue;
+            }
+            if age_ms <= max_age_ms {
+                continue;
+            }
+
+            let confidence = max_age_ms as f64 / age_ms as f64;
+            let score = scores.get(feature);
+            scores.set(feature, score * confidence);
+            warn!(
+                "{} data is {}s old (max {}s), scaling score {:.3} by {:.3}",
+                feature.as_str(), age_ms / 1000, self.config.max_data_age_seconds, score, confidence
+            );
+        }
+    }
+
+    /// Replace the scores of enabled features pinned in `feature_overrides`.
+    pub fn apply_overrides(&self, scores: &mut FeatureScores) {
+        for (&feature, &score) in &self.config.feature_overrides {
+            if !self.config.is_feature_enabled(feature) {
+                continue;
+            }
+            debug!("Overriding {} score {:.3} with {:.3}", feature.as_str(), scores.get(feature), score);
+            scores.set(feature, score.clamp(0.0, 1.0));
+        }
+    }
+
     /// Compute liquidity score based on SOL amount in pools.
-    #[instrument(skip(self, token_data))]
-    fn compute_liquidity_score(&self, token_data: &TokenData) -> Result<f64> {
+    #[instrument(skip(self, token_data, thresholds))]
+    fn compute_liquidity_score(&self, token_data: &TokenData, thresholds: &ScoreThresholds) -> Result<f64> {
         let liquidity_sol = match &token_data.liquidity_pool {
             Some(pool) => pool.sol_amount,
             None => {
@@ -70,11 +164,13 @@ impl OracleFeatureComputer {
             }
         };
 
-        // Normalize liquidity score: 0.0 at min_liquidity_sol, 1.0 at 10x min_liquidity_sol
-        let min_liquidity = self.config.thresholds.min_liquidity_sol;
+        // Normalize liquidity score: 0.0 at min_liquidity_sol, 1.0 at 10x min_liquidity_sol.
+        // The minimum is checked first so that with a zero minimum an empty pool
+        // scores 0.0 rather than falling into the top band.
+        let min_liquidity = thresholds.min_liquidity_sol;
         let max_liquidity = min_liquidity * 10.0;
 
-        let score = if liquidity_sol < min_liquidity {
+        let score = if liquidity_sol <= min_liquidity {
             0.0
         } else if liquidity_sol >= max_liquidity {
             1.0
@@ -116,13 +212,17 @@ impl OracleFeatureComputer {
     }
 
     /// Compute volume growth score.
-    #[instrument(skip(self, token_data))]
-    fn compute_volume_growth_score(&self, token_data: &TokenData) -> Result<f64> {
+    ///
+    /// Uses the weighted trend of `volume_history` when available, so a single
+    /// spike counts for less than growth sustained over several samples.
+    #[instrument(skip(self, token_data, thresholds))]
+    fn compute_volume_growth_score(&self, token_data: &TokenData, thresholds: &ScoreThresholds) -> Result<f64> {
         let volume_data = &token_data.volume_data;
-        let growth_rate = volume_data.volume_growth_rate;
+        let growth_rate = volume_trend_growth_rate(&volume_data.volume_history)
+            .unwrap_or(volume_data.volume_growth_rate);
 
         // Normalize growth rate to 0-1 range
-        let threshold = self.config.thresholds.volume_growth_threshold;
+        let threshold = thresholds.volume_growth_threshold;
         let score = if growth_rate <= 1.0 {
             0.0 // No growth or decline
         } else if growth_rate >= threshold * 5.0 {
@@ -136,8 +236,8 @@ impl OracleFeatureComputer {
     }
 
     /// Compute holder growth score.
-    #[instrument(skip(self, token_data))]
-    fn compute_holder_growth_score(&self, token_data: &TokenData) -> Result<f64> {
+    #[instrument(skip(self, token_data, thresholds))]
+    fn compute_holder_growth_score(&self, token_data: &TokenData, thresholds: &ScoreThresholds) -> Result<f64> {
         if token_data.holder_history.len() < 2 {
             return Ok(0.5); // Default for insufficient data
         }
@@ -146,7 +246,7 @@ impl OracleFeatureComputer {
         let initial_holders = token_data.holder_history.front().unwrap_or(&1);
 
         let growth_rate = *current_holders as f64 / (*initial_holders).max(1) as f64;
-        let threshold = self.config.thresholds.holder_growth_threshold;
+        let threshold = thresholds.holder_growth_threshold;
 
         let score = if growth_rate <= 1.0 {
             0.0
@@ -173,11 +273,12 @@ impl OracleFeatureComputer {
 
         let price_change = (current_price - initial_price) / initial_price.max(0.0001);
 
-        // Positive price change is good, but extremely high changes might be suspicious
+        // Positive price change is good, but extremely high changes might be suspicious.
+        // A flat price is neutral, and moderate gains rise to meet the good-gain band
         let score = match price_change {
             x if x <= -0.5 => 0.0,  // Major decline
-            x if x <= 0.0 => 0.2,   // Any decline
-            x if x <= 0.5 => 0.5 + x, // Moderate gain
+            x if x < 0.0 => 0.2,    // Any decline
+            x if x <= 0.5 => 0.5 + 0.6 * x, // Flat to moderate gain
             x if x <= 2.0 => 0.8,   // Good gain
             x if x <= 10.0 => 0.9,  // Excellent but not suspicious
             _ => 0.7, // Very high gains might be suspicious
@@ -188,12 +289,12 @@ impl OracleFeatureComputer {
     }
 
     /// Compute Jito bundle presence score.
-    #[instrument(skip(self, candidate))]
-    fn compute_jito_bundle_score(&self, candidate: &PremintCandidate) -> Result<f64> {
+    #[instrument(skip(self, candidate, scores))]
+    fn compute_jito_bundle_score(&self, candidate: &PremintCandidate, scores: &JitoBundleScores) -> Result<f64> {
         let score = match candidate.is_jito_bundle {
-            Some(true) => 0.8,  // Jito bundles are generally positive
-            Some(false) => 0.3, // Non-bundle is neutral/slightly negative
-            None => 0.5,        // Unknown
+            Some(true) => scores.bundled,
+            Some(false) => scores.not_bundled,
+            None => scores.unknown,
         };
 
         debug!("Jito bundle presence: {:?} -> score {}", candidate.is_jito_bundle, score);
@@ -201,17 +302,17 @@ impl OracleFeatureComputer {
     }
 
     /// Compute creator sell speed score (lower score for fast selling).
-    #[instrument(skip(self, token_data))]
-    fn compute_creator_sell_score(&self, token_data: &TokenData) -> Result<f64> {
+    #[instrument(skip(self, token_data, thresholds))]
+    fn compute_creator_sell_score(&self, token_data: &TokenData, thresholds: &ScoreThresholds) -> Result<f64> {
         let creator = &token_data.creator_holdings;
         
         if creator.sell_transactions == 0 {
             return Ok(1.0); // No selling is good
         }
 
-        // Calculate sell percentage
+        // Calculate sell percentage; a creator whose balance grew has sold nothing on net
         let sell_percentage = if creator.initial_balance > 0 {
-            (creator.initial_balance - creator.current_balance) as f64 / creator.initial_balance as f64
+            creator.initial_balance.saturating_sub(creator.current_balance) as f64 / creator.initial_balance as f64
         } else {
             0.0
         };
@@ -224,7 +325,7 @@ impl OracleFeatureComputer {
                 .as_secs()
                 .saturating_sub(first_sell);
             
-            if time_diff < self.config.thresholds.creator_sell_penalty_threshold {
+            if time_diff < thresholds.creator_sell_penalty_threshold {
                 0.5 // Penalty for quick selling
             } else {
                 1.0 // No penalty for later selling
@@ -292,23 +393,111 @@ impl OracleFeatureComputer {
         Ok(final_score.clamp(0.0, 1.0))
     }
 
-    /// Compute social activity score.
+    /// Compute authority status score (higher when mint/freeze authorities are revoked).
+    ///
+    /// An active freeze authority lets the creator freeze holders' tokens, so it
+    /// zeroes the score on its own. Unknown status counts as neutral.
+    #[instrument(skip(self, token_data))]
+    fn compute_authority_status_score(&self, token_data: &TokenData) -> Result<f64> {
+        if token_data.freeze_authority_active == Some(true) {
+            debug!("Freeze authority still active");
+            return Ok(0.0);
+        }
+
+        let authority_score = |active: Option<bool>| match active {
+            Some(true) => 0.0,
+            Some(false) => 1.0,
+            None => 0.5,
+        };
+
+        let score = (authority_score(token_data.mint_authority_active)
+            + authority_score(token_data.freeze_authority_active))
+            / 2.0;
+
+        debug!(
+            "Authority status: mint={:?}, freeze={:?} -> {}",
+            token_data.mint_authority_active, token_data.freeze_authority_active, score
+        );
+        Ok(score)
+    }
+
+    /// Compute contract verification score (1.0 when the launchpad verified the contract).
+    ///
+    /// Unknown verification status counts as neutral.
+    #[instrument(skip(self, token_data))]
+    fn compute_contract_verified_score(&self, token_data: &TokenData) -> Result<f64> {
+        let score = match token_data.contract_verified {
+            Some(true) => 1.0,
+            Some(false) => 0.0,
+            None => 0.5,
+        };
+
+        debug!("Contract verified: {:?} -> {}", token_data.contract_verified, score);
+        Ok(score)
+    }
+
+    /// Compute graduation proximity score from the bonding curve's progress.
+    ///
+    /// With `prefer_near_graduation` the score is the progress itself, otherwise
+    /// its complement. Tokens not on a bonding curve count as neutral.
+    #[instrument(skip(self, token_data))]
+    fn compute_graduation_proximity_score(&self, token_data: &TokenData) -> Result<f64> {
+        let progress = token_data
+            .liquidity_pool
+            .as_ref()
+            .and_then(|pool| pool.bonding_curve_progress);
+
+        let score = match progress {
+            Some(progress) if self.config.prefer_near_graduation => progress.clamp(0.0, 1.0),
+            Some(progress) => 1.0 - progress.clamp(0.0, 1.0),
+            None => 0.5,
+        };
+
+        debug!("Bonding curve progress: {:?} -> {}", progress, score);
+        Ok(score)
+    }
+
+    /// Compute transactions-per-holder score.
+    ///
+    /// Ratios up to `max_tx_per_holder` score 1.0; above it the score falls in
+    /// proportion, so thousands of transactions between a handful of wallets
+    /// score near zero. Tokens without holder data count as neutral.
     #[instrument(skip(self, token_data))]
-    fn compute_social_activity_score(&self, token_data: &TokenData) -> Result<f64> {
+    fn compute_tx_per_holder_score(&self, token_data: &TokenData) -> Result<f64> {
+        let holder_count = token_data.holder_distribution.len();
+        if holder_count == 0 {
+            return Ok(0.5);
+        }
+
+        let ratio = token_data.volume_data.transaction_count as f64 / holder_count as f64;
+        let score = if ratio <= self.config.max_tx_per_holder {
+            1.0
+        } else {
+            self.config.max_tx_per_holder.max(0.0) / ratio
+        };
+
+        debug!("Transactions per holder: {:.1} -> {}", ratio, score);
+        Ok(score)
+    }
+
+    /// Compute social activity score.
+    #[instrument(skip(self, token_data, thresholds))]
+    fn compute_social_activity_score(&self, token_data: &TokenData, thresholds: &ScoreThresholds) -> Result<f64> {
         let social = &token_data.social_activity;
-        let threshold = self.config.thresholds.social_activity_threshold;
+        let threshold = thresholds.social_activity_threshold;
 
         // Combine different social metrics
         let total_activity = social.twitter_mentions as f64 * 0.4
             + social.telegram_members as f64 * 0.3
             + social.discord_members as f64 * 0.3;
 
+        // Linear up to 1.0 at 5x the threshold. Activity below the threshold
+        // earns partial credit on the same line, so the score never falls as
+        // activity grows
         let score = if total_activity >= threshold * 5.0 {
             1.0
-        } else if total_activity >= threshold {
-            total_activity / (threshold * 5.0)
         } else {
-            total_activity / threshold * 0.5 // Partial credit for low activity
+            total_activity / (threshold * 5.0)
         };
 
         debug!("Social activity: twitter={}, telegram={}, discord={}, total={:.1} -> score {}",
@@ -318,13 +507,142 @@ impl OracleFeatureComputer {
     }
 }
 
+/// Per-sample volume growth as a weighted moving average over the history.
+///
+/// Averages the log growth between consecutive samples, weighting the i-th step
+/// by i so recent steps count more, and returns it as a ratio (1.0 = flat).
+/// A spike followed by a fall cancels out instead of reading as growth.
+/// Returns `None` when there are fewer than two usable (positive) samples.
+pub fn volume_trend_growth_rate(history: &VecDeque<f64>) -> Option<f64> {
+    let mut weighted_log_growth = 0.0;
+    let mut total_weight = 0.0;
+
+    for (i, (prev, next)) in history.iter().zip(history.iter().skip(1)).enumerate() {
+        if *prev <= 0.0 || *next <= 0.0 {
+            continue;
+        }
+        let weight = (i + 1) as f64;
+        weighted_log_growth += weight * (next / prev).ln();
+        total_weight += weight;
+    }
+
+    (total_weight > 0.0).then(|| (weighted_log_growth / total_weight).exp())
+}
+
+/// Input values a feature is computed from, for scoring traces.
+///
+/// Optional flags are reported as 1.0/0.0 and omitted when unknown.
+pub fn feature_raw_inputs(
+    feature: Feature,
+    candidate: &PremintCandidate,
+    token_data: &TokenData,
+) -> HashMap<String, f64> {
+    let flag = |value: bool| if value { 1.0 } else { 0.0 };
+    let mut inputs = HashMap::new();
+
+    match feature {
+        Feature::Liquidity => {
+            if let Some(pool) = &token_data.liquidity_pool {
+                inputs.insert("sol_amount".to_string(), pool.sol_amount);
+            }
+        }
+        Feature::HolderDistribution => {
+            let top_10: f64 = token_data.holder_distribution.iter().take(10).map(|h| h.percentage).sum();
+            inputs.insert("holder_count".to_string(), token_data.holder_distribution.len() as f64);
+            inputs.insert("top_10_concentration".to_string(), top_10);
+        }
+        Feature::VolumeGrowth => {
+            inputs.insert("volume_growth_rate".to_string(), token_data.volume_data.volume_growth_rate);
+            if let Some(trend) = volume_trend_growth_rate(&token_data.volume_data.volume_history) {
+                inputs.insert("volume_trend_growth_rate".to_string(), trend);
+            }
+        }
+        Feature::HolderGrowth => {
+            if let (Some(first), Some(last)) = (token_data.holder_history.front(), token_data.holder_history.back()) {
+                inputs.insert("initial_holders".to_string(), *first as f64);
+                inputs.insert("current_holders".to_string(), *last as f64);
+            }
+        }
+        Feature::PriceChange => {
+            if let (Some(first), Some(last)) = (token_data.price_history.front(), token_data.price_history.back()) {
+                inputs.insert("initial_price".to_string(), *first);
+                inputs.insert("current_price".to_string(), *last);
+            }
+        }
+        Feature::JitoBundlePresence => {
+            if let Some(is_jito_bundle) = candidate.is_jito_bundle {
+                inputs.insert("is_jito_bundle".to_string(), flag(is_jito_bundle));
+            }
+        }
+        Feature::CreatorSellSpeed => {
+            let creator = &token_data.creator_holdings;
+            inputs.insert("initial_balance".to_string(), creator.initial_balance as f64);
+            inputs.insert("current_balance".to_string(), creator.current_balance as f64);
+            inputs.insert("sell_transactions".to_string(), creator.sell_transactions as f64);
+        }
+        Feature::MetadataQuality => {
+            inputs.insert("has_metadata".to_string(), flag(token_data.metadata.is_some()));
+        }
+        Feature::SocialActivity => {
+            let social = &token_data.social_activity;
+            inputs.insert("twitter_mentions".to_string(), social.twitter_mentions as f64);
+            inputs.insert("telegram_members".to_string(), social.telegram_members as f64);
+            inputs.insert("discord_members".to_string(), social.discord_members as f64);
+        }
+        Feature::AuthorityStatus => {
+            if let Some(active) = token_data.mint_authority_active {
+                inputs.insert("mint_authority_active".to_string(), flag(active));
+            }
+            if let Some(active) = token_data.freeze_authority_active {
+                inputs.insert("freeze_authority_active".to_string(), flag(active));
+            }
+        }
+        Feature::ContractVerified => {
+            if let Some(verified) = token_data.contract_verified {
+                inputs.insert("contract_verified".to_string(), flag(verified));
+            }
+        }
+        Feature::GraduationProximity => {
+            if let Some(progress) = token_data.liquidity_pool.as_ref().and_then(|pool| pool.bonding_curve_progress) {
+                inputs.insert("bonding_curve_progress".to_string(), progress);
+            }
+        }
+        Feature::TxPerHolder => {
+            inputs.insert("transaction_count".to_string(), token_data.volume_data.transaction_count as f64);
+            inputs.insert("holder_count".to_string(), token_data.holder_distribution.len() as f64);
+        }
+    }
+
+    inputs
+}
+
+/// Data source a feature is computed from, if its freshness is tracked.
+fn feature_data_source(feature: Feature) -> Option<&'static str> {
+    match feature {
+        // Price history and bonding curve progress are derived from pool reserves
+        Feature::Liquidity | Feature::PriceChange | Feature::GraduationProximity => Some(SOURCE_LIQUIDITY),
+        Feature::HolderDistribution | Feature::HolderGrowth => Some(SOURCE_HOLDERS),
+        Feature::VolumeGrowth => Some(SOURCE_VOLUME),
+        // Transactions are counted with the volume, which changes faster than holders
+        Feature::TxPerHolder => Some(SOURCE_VOLUME),
+        Feature::CreatorSellSpeed => Some(SOURCE_CREATOR),
+        Feature::MetadataQuality => Some(SOURCE_METADATA),
+        Feature::SocialActivity => Some(SOURCE_SOCIAL),
+        // Authorities are read from the mint account together with the supply
+        Feature::AuthorityStatus => Some(SOURCE_SUPPLY),
+        // Launchpad verification is read from the metadata attributes
+        Feature::ContractVerified => Some(SOURCE_METADATA),
+        Feature::JitoBundlePresence => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::oracle::types::*;
     use crate::types::PremintCandidate;
     use solana_sdk::pubkey::Pubkey;
-    use std::collections::VecDeque;
+    use std::collections::{HashMap, VecDeque};
 
     fn create_test_config() -> OracleConfig {
         OracleConfig::default()
@@ -332,13 +650,14 @@ mod tests {
 
     fn create_test_candidate() -> PremintCandidate {
         PremintCandidate {
-            mint: Pubkey::new_unique(),
-            creator: Pubkey::new_unique(),
+            mint: Pubkey::new_unique().to_string(),
+            creator: Pubkey::new_unique().to_string(),
             program: "test".to_string(),
             slot: 12345,
             timestamp: 1640995200, // 2022-01-01
             instruction_summary: None,
             is_jito_bundle: Some(true),
+            funding_source: None,
         }
     }
 
@@ -356,7 +675,7 @@ mod tests {
             }),
             holder_distribution: vec![
                 HolderData {
-                    address: Pubkey::new_unique(),
+                    address: Pubkey::new_unique().to_string(),
                     percentage: 0.1,
                     is_whale: false,
                 },
@@ -364,8 +683,9 @@ mod tests {
             liquidity_pool: Some(LiquidityPool {
                 sol_amount: 50.0,
                 token_amount: 1000.0,
-                pool_address: Pubkey::new_unique(),
+                pool_address: Pubkey::new_unique().to_string(),
                 pool_type: PoolType::PumpFun,
+                bonding_curve_progress: None,
             }),
             volume_data: VolumeData {
                 initial_volume: 100.0,
@@ -373,6 +693,7 @@ mod tests {
                 volume_growth_rate: 3.0,
                 transaction_count: 50,
                 buy_sell_ratio: 1.5,
+                volume_history: VecDeque::new(),
             },
             creator_holdings: CreatorHoldings {
                 initial_balance: 100_000_000,
@@ -398,6 +719,13 @@ mod tests {
                 discord_members: 100,
                 social_score: 0.7,
             },
+            mint_authority_active: Some(false),
+            freeze_authority_active: Some(false),
+            contract_verified: None,
+            metadata_unreachable: false,
+            funding_source: None,
+            creator: None,
+            data_timestamps: HashMap::new(),
         }
     }
 
@@ -406,7 +734,7 @@ mod tests {
         let computer = OracleFeatureComputer::new(create_test_config());
         let token_data = create_test_token_data();
         
-        let score = computer.compute_liquidity_score(&token_data).unwrap();
+        let score = computer.compute_liquidity_score(&token_data, &computer.config.thresholds).unwrap();
         
         // With 50 SOL and min threshold of 10 SOL, max at 100 SOL
         // Score should be (50-10)/(100-10) = 40/90 ≈ 0.44
@@ -429,7 +757,7 @@ mod tests {
         let computer = OracleFeatureComputer::new(create_test_config());
         let token_data = create_test_token_data();
         
-        let score = computer.compute_volume_growth_score(&token_data).unwrap();
+        let score = computer.compute_volume_growth_score(&token_data, &computer.config.thresholds).unwrap();
         
         // 3x growth should give a good score
         assert!(score > 0.0);
@@ -440,12 +768,36 @@ mod tests {
         let computer = OracleFeatureComputer::new(create_test_config());
         let candidate = create_test_candidate();
         
-        let score = computer.compute_jito_bundle_score(&candidate).unwrap();
+        let score = computer.compute_jito_bundle_score(&candidate, &JitoBundleScores::default()).unwrap();
         
         // Jito bundle should give positive score
         assert_eq!(score, 0.8);
     }
 
+    #[test]
+    fn test_jito_bundle_score_uses_regime_values() {
+        let computer = OracleFeatureComputer::new(create_test_config());
+        let config = create_test_config();
+        let token_data = create_test_token_data();
+
+        let jito_score = |regime: MarketRegime, is_jito_bundle: Option<bool>| {
+            let params = &config.regime_parameters[&regime];
+            let candidate = PremintCandidate { is_jito_bundle, ..create_test_candidate() };
+            computer
+                .compute_unadjusted_features(&candidate, &token_data, &params.thresholds, &params.jito_bundle_scores)
+                .unwrap()
+                .get(Feature::JitoBundlePresence)
+        };
+
+        // Congested network: bundles strongly rewarded over unbundled launches
+        assert_eq!(jito_score(MarketRegime::HighCongestion, Some(true)), 1.0);
+        assert_eq!(jito_score(MarketRegime::HighCongestion, Some(false)), 0.1);
+
+        // Low activity: absence of a bundle is neutral
+        assert_eq!(jito_score(MarketRegime::LowActivity, Some(false)), 0.5);
+        assert_eq!(jito_score(MarketRegime::LowActivity, None), 0.5);
+    }
+
     #[test]
     fn test_metadata_quality_score() {
         let computer = OracleFeatureComputer::new(create_test_config());
@@ -497,7 +849,367 @@ mod tests {
         // Verify all features have been computed
         for feature in Feature::all() {
             let score = scores.get(feature);
-            assert!(score >= 0.0 && score <= 1.0, "Feature {:?} score {} is out of range", feature, score);
+            assert!((0.0..=1.0).contains(&score), "Feature {:?} score {} is out of range", feature, score);
+        }
+    }
+
+    #[test]
+    fn test_authority_status_score() {
+        let computer = OracleFeatureComputer::new(create_test_config());
+        let mut token_data = create_test_token_data();
+
+        // Both revoked
+        assert_eq!(computer.compute_authority_status_score(&token_data).unwrap(), 1.0);
+
+        // Mint authority still active
+        token_data.mint_authority_active = Some(true);
+        assert_eq!(computer.compute_authority_status_score(&token_data).unwrap(), 0.5);
+
+        // Active freeze authority is disqualifying regardless of mint authority
+        token_data.mint_authority_active = Some(false);
+        token_data.freeze_authority_active = Some(true);
+        assert_eq!(computer.compute_authority_status_score(&token_data).unwrap(), 0.0);
+
+        // Unknown status is neutral
+        token_data.mint_authority_active = None;
+        token_data.freeze_authority_active = None;
+        assert_eq!(computer.compute_authority_status_score(&token_data).unwrap(), 0.5);
+    }
+
+    #[test]
+    fn test_contract_verified_score() {
+        let computer = OracleFeatureComputer::new(create_test_config());
+        let mut token_data = create_test_token_data();
+
+        token_data.contract_verified = Some(true);
+        assert_eq!(computer.compute_contract_verified_score(&token_data).unwrap(), 1.0);
+
+        token_data.contract_verified = Some(false);
+        assert_eq!(computer.compute_contract_verified_score(&token_data).unwrap(), 0.0);
+
+        // Unknown status is neutral, and independent of the authority status
+        token_data.contract_verified = None;
+        token_data.freeze_authority_active = Some(true);
+        assert_eq!(computer.compute_contract_verified_score(&token_data).unwrap(), 0.5);
+    }
+
+    #[test]
+    fn test_graduation_proximity_score() {
+        let computer = OracleFeatureComputer::new(create_test_config());
+        let mut token_data = create_test_token_data();
+        let mut on_curve = |progress: f64| {
+            let pool = token_data.liquidity_pool.as_mut().unwrap();
+            pool.pool_type = PoolType::PumpFun;
+            pool.bonding_curve_progress = Some(progress);
+            token_data.clone()
+        };
+        let near_graduation = on_curve(0.95);
+        let just_launched = on_curve(0.02);
+
+        assert_eq!(computer.compute_graduation_proximity_score(&near_graduation).unwrap(), 0.95);
+        assert_eq!(computer.compute_graduation_proximity_score(&just_launched).unwrap(), 0.02);
+
+        // Early entries can be preferred instead
+        let mut config = create_test_config();
+        config.prefer_near_graduation = false;
+        let early = OracleFeatureComputer::new(config);
+        assert!((early.compute_graduation_proximity_score(&near_graduation).unwrap() - 0.05).abs() < 1e-9);
+        assert_eq!(early.compute_graduation_proximity_score(&just_launched).unwrap(), 0.98);
+
+        // Tokens not on a bonding curve are neutral either way
+        let mut off_curve = create_test_token_data();
+        off_curve.liquidity_pool.as_mut().unwrap().bonding_curve_progress = None;
+        assert_eq!(computer.compute_graduation_proximity_score(&off_curve).unwrap(), 0.5);
+        off_curve.liquidity_pool = None;
+        assert_eq!(early.compute_graduation_proximity_score(&off_curve).unwrap(), 0.5);
+    }
+
+    #[test]
+    fn test_tx_per_holder_score() {
+        let computer = OracleFeatureComputer::new(create_test_config());
+        let with_activity = |transactions: u32, holders: usize| {
+            let mut token_data = create_test_token_data();
+            token_data.volume_data.transaction_count = transactions;
+            token_data.holder_distribution = (0..holders)
+                .map(|_| HolderData {
+                    address: Pubkey::new_unique().to_string(),
+                    percentage: 1.0 / holders as f64,
+                    is_whale: false,
+                })
+                .collect();
+            token_data
+        };
+
+        // A few trades per holder is organic
+        let healthy = with_activity(400, 150);
+        assert_eq!(computer.compute_tx_per_holder_score(&healthy).unwrap(), 1.0);
+
+        // Thousands of transactions among a handful of wallets is bot churn
+        let churn = with_activity(5_000, 10);
+        let score = computer.compute_tx_per_holder_score(&churn).unwrap();
+        assert!((score - DEFAULT_MAX_TX_PER_HOLDER / 500.0).abs() < 1e-9, "score {}", score);
+
+        // The organic ceiling is configurable
+        let strict = OracleFeatureComputer::new(OracleConfig { max_tx_per_holder: 1.0, ..create_test_config() });
+        assert!((strict.compute_tx_per_holder_score(&healthy).unwrap() - 150.0 / 400.0).abs() < 1e-9);
+
+        // Without holder data the ratio is unknown
+        assert_eq!(computer.compute_tx_per_holder_score(&with_activity(5_000, 0)).unwrap(), 0.5);
+    }
+
+    #[test]
+    fn test_stale_liquidity_data_is_downscored() {
+        let computer = OracleFeatureComputer::new(create_test_config());
+        let max_age_ms = computer.config.max_data_age_seconds * 1000;
+        let now_ms = 10 * max_age_ms;
+
+        let mut token_data = create_test_token_data();
+        token_data.liquidity_pool.as_mut().unwrap().sol_amount = 1_000.0;
+        token_data.data_timestamps.insert(SOURCE_LIQUIDITY.to_string(), now_ms);
+        token_data.data_timestamps.insert(SOURCE_HOLDERS.to_string(), now_ms);
+
+        let thresholds = computer.config.thresholds.clone();
+        let mut fresh = FeatureScores::new();
+        fresh.set(Feature::Liquidity, computer.compute_liquidity_score(&token_data, &thresholds).unwrap());
+        fresh.set(Feature::HolderDistribution, 0.8);
+        let mut aged = fresh.clone();
+
+        computer.apply_data_age_penalty(&mut fresh, &token_data, now_ms);
+        assert_eq!(fresh.get(Feature::Liquidity), 1.0);
+
+        // Liquidity fetched 4x the max age ago keeps a quarter of its score
+        token_data.data_timestamps.insert(SOURCE_LIQUIDITY.to_string(), now_ms - 4 * max_age_ms);
+        computer.apply_data_age_penalty(&mut aged, &token_data, now_ms);
+
+        assert!((aged.get(Feature::Liquidity) - 0.25).abs() < 1e-9);
+        assert_eq!(aged.get(Feature::HolderDistribution), 0.8);
+    }
+
+    #[test]
+    fn test_sustained_volume_growth_beats_single_spike() {
+        let computer = OracleFeatureComputer::new(create_test_config());
+        let thresholds = computer.config.thresholds.clone();
+
+        let mut sustained = create_test_token_data();
+        sustained.volume_data.volume_history = VecDeque::from(vec![100.0, 200.0, 400.0, 800.0, 1600.0]);
+
+        // A single burst that faded back to roughly where it started
+        let mut spike = create_test_token_data();
+        spike.volume_data.volume_history = VecDeque::from(vec![100.0, 100.0, 2000.0, 150.0, 120.0]);
+
+        assert!((volume_trend_growth_rate(&sustained.volume_data.volume_history).unwrap() - 2.0).abs() < 1e-9);
+        assert!(volume_trend_growth_rate(&spike.volume_data.volume_history).unwrap() < 1.0);
+
+        let sustained_score = computer.compute_volume_growth_score(&sustained, &thresholds).unwrap();
+        let spike_score = computer.compute_volume_growth_score(&spike, &thresholds).unwrap();
+        assert!(sustained_score > 0.0);
+        assert_eq!(spike_score, 0.0);
+    }
+
+    #[test]
+    fn test_volume_growth_without_history_uses_growth_rate() {
+        let computer = OracleFeatureComputer::new(create_test_config());
+        let thresholds = computer.config.thresholds.clone();
+        let mut token_data = create_test_token_data();
+
+        let legacy_score = computer.compute_volume_growth_score(&token_data, &thresholds).unwrap();
+        let expected = (token_data.volume_data.volume_growth_rate - 1.0)
+            / (thresholds.volume_growth_threshold * 5.0 - 1.0);
+        assert!((legacy_score - expected).abs() < 1e-9);
+
+        // A single sample carries no trend
+        token_data.volume_data.volume_history.push_back(500.0);
+        assert_eq!(computer.compute_volume_growth_score(&token_data, &thresholds).unwrap(), legacy_score);
+    }
+
+    fn assert_score(actual: f64, expected: f64, case: impl std::fmt::Display) {
+        assert!((actual - expected).abs() < 1e-9, "{}: expected {}, got {}", case, expected, actual);
+    }
+
+    #[test]
+    fn test_liquidity_score_band_edges() {
+        let computer = OracleFeatureComputer::new(create_test_config());
+        let mut thresholds = computer.config.thresholds.clone();
+        let min = thresholds.min_liquidity_sol;
+        let mut token_data = create_test_token_data();
+
+        for (liquidity, expected) in [
+            (0.0, 0.0),
+            (min / 2.0, 0.0),
+            (min, 0.0),
+            (min * 5.5, 0.5),
+            (min * 10.0, 1.0),
+            (min * 20.0, 1.0),
+        ] {
+            token_data.liquidity_pool.as_mut().unwrap().sol_amount = liquidity;
+            let score = computer.compute_liquidity_score(&token_data, &thresholds).unwrap();
+            assert_score(score, expected, format!("{} SOL", liquidity));
         }
+
+        // With no minimum an empty pool still scores nothing
+        thresholds.min_liquidity_sol = 0.0;
+        token_data.liquidity_pool.as_mut().unwrap().sol_amount = 0.0;
+        assert_eq!(computer.compute_liquidity_score(&token_data, &thresholds).unwrap(), 0.0);
+        token_data.liquidity_pool.as_mut().unwrap().sol_amount = 0.1;
+        assert_eq!(computer.compute_liquidity_score(&token_data, &thresholds).unwrap(), 1.0);
+
+        token_data.liquidity_pool = None;
+        assert_eq!(computer.compute_liquidity_score(&token_data, &thresholds).unwrap(), 0.0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_volume_growth_score_band_edges() {
+        let computer = OracleFeatureComputer::new(create_test_config());
+        let thresholds = computer.config.thresholds.clone();
+        let full = thresholds.volume_growth_threshold * 5.0;
+        assert!(full > 1.0);
+        let mut token_data = create_test_token_data();
+
+        for (growth_rate, expected) in [
+            (0.5, 0.0),
+            (1.0, 0.0),
+            ((1.0 + full) / 2.0, 0.5),
+            (full, 1.0),
+            (full * 2.0, 1.0),
+        ] {
+            token_data.volume_data.volume_growth_rate = growth_rate;
+            let score = computer.compute_volume_growth_score(&token_data, &thresholds).unwrap();
+            assert_score(score, expected, format!("{}x growth", growth_rate));
+        }
+    }
+
+    #[test]
+    fn test_price_change_score_band_edges() {
+        let computer = OracleFeatureComputer::new(create_test_config());
+        let mut token_data = create_test_token_data();
+        let mut score_for = |change: f64| {
+            token_data.price_history = VecDeque::from(vec![1.0, 1.0 + change]);
+            computer.compute_price_change_score(&token_data).unwrap()
+        };
+
+        for (change, expected) in [
+            (-0.75, 0.0),
+            (-0.5, 0.0),
+            (-0.25, 0.2),
+            (0.0, 0.5),
+            (0.25, 0.65),
+            (0.5, 0.8),
+            (1.0, 0.8),
+            (2.0, 0.8),
+            (5.0, 0.9),
+            (10.0, 0.9),
+            (11.0, 0.7),
+        ] {
+            assert_score(score_for(change), expected, format!("{:+} price change", change));
+        }
+
+        // Up to the suspicious band, a larger gain never scores lower
+        let mut previous = 0.0;
+        for step in 0..=1100 {
+            let score = score_for(-1.0 + step as f64 / 100.0);
+            assert!(score >= previous, "score fell to {} at step {}", score, step);
+            previous = score;
+        }
+
+        token_data.price_history = VecDeque::from(vec![1.0]);
+        assert_eq!(computer.compute_price_change_score(&token_data).unwrap(), 0.5);
+    }
+
+    #[test]
+    fn test_social_activity_score_band_edges() {
+        let computer = OracleFeatureComputer::new(create_test_config());
+        let thresholds = computer.config.thresholds.clone();
+        let mut token_data = create_test_token_data();
+        token_data.social_activity = SocialActivity::default();
+
+        // Twitter mentions alone count 0.4 each towards the activity total
+        let mut score_for = |activity: f64| {
+            token_data.social_activity.twitter_mentions = (activity / 0.4).round() as u32;
+            computer.compute_social_activity_score(&token_data, &thresholds).unwrap()
+        };
+        let threshold = thresholds.social_activity_threshold;
+
+        assert_score(score_for(0.0), 0.0, "no activity");
+        assert_score(score_for(threshold), 0.2, "activity at the threshold");
+        assert_score(score_for(threshold * 5.0), 1.0, "activity at 5x the threshold");
+        assert_score(score_for(threshold * 10.0), 1.0, "activity at 10x the threshold");
+
+        // No drop when activity crosses the threshold
+        let mut previous = 0.0;
+        for mentions in 0..=(threshold * 6.0 / 0.4) as u32 {
+            let score = score_for(mentions as f64 * 0.4);
+            assert!(score >= previous, "score fell to {} at {} mentions", score, mentions);
+            previous = score;
+        }
+    }
+
+    #[test]
+    fn test_creator_sell_score_when_balance_grew() {
+        let computer = OracleFeatureComputer::new(create_test_config());
+        let mut token_data = create_test_token_data();
+        token_data.creator_holdings = CreatorHoldings {
+            initial_balance: 1_000,
+            current_balance: 1_500,
+            first_sell_timestamp: None,
+            sell_transactions: 1,
+        };
+
+        let score = computer.compute_creator_sell_score(&token_data, &computer.config.thresholds).unwrap();
+        assert_eq!(score, 1.0);
+    }
+
+    #[test]
+    fn test_social_activity_decays_with_half_life() {
+        let config = OracleConfig { social_half_life_seconds: Some(300), ..create_test_config() };
+        let computer = OracleFeatureComputer::new(config);
+        let half_life_ms = 300 * 1000;
+        let now_ms = 10 * half_life_ms;
+
+        let mut token_data = create_test_token_data();
+        let mut scores = FeatureScores::new();
+        scores.set(Feature::SocialActivity, 0.8);
+
+        token_data.data_timestamps.insert(SOURCE_SOCIAL.to_string(), now_ms);
+        let mut fresh = scores.clone();
+        computer.apply_data_age_penalty(&mut fresh, &token_data, now_ms);
+        assert_eq!(fresh.get(Feature::SocialActivity), 0.8);
+
+        // Well within the generic max data age, social data has already halved
+        token_data.data_timestamps.insert(SOURCE_SOCIAL.to_string(), now_ms - half_life_ms);
+        let mut aged = scores.clone();
+        computer.apply_data_age_penalty(&mut aged, &token_data, now_ms);
+        assert!((aged.get(Feature::SocialActivity) - 0.4).abs() < 1e-9);
+
+        token_data.data_timestamps.insert(SOURCE_SOCIAL.to_string(), now_ms - 2 * half_life_ms);
+        let mut older = scores.clone();
+        computer.apply_data_age_penalty(&mut older, &token_data, now_ms);
+        assert!((older.get(Feature::SocialActivity) - 0.2).abs() < 1e-9);
+
+        // By default social data only ages out past the generic max data age
+        let computer = OracleFeatureComputer::new(create_test_config());
+        let mut default_aged = scores.clone();
+        computer.apply_data_age_penalty(&mut default_aged, &token_data, now_ms);
+        assert_eq!(default_aged.get(Feature::SocialActivity), 0.8);
+    }
+
+    #[tokio::test]
+    async fn test_feature_override_pins_score() {
+        let plain = OracleFeatureComputer::new(create_test_config());
+        let mut config = create_test_config();
+        config.feature_overrides.insert(Feature::Liquidity, 1.0);
+        let pinned = OracleFeatureComputer::new(config);
+        let candidate = create_test_candidate();
+
+        let illiquid = TokenData { liquidity_pool: None, ..create_test_token_data() };
+        for token_data in [create_test_token_data(), illiquid] {
+            let computed = plain.compute_all_features(&candidate, &token_data).await.unwrap();
+            let overridden = pinned.compute_all_features(&candidate, &token_data).await.unwrap();
+
+            assert!(computed.get(Feature::Liquidity) < 1.0);
+            assert_eq!(overridden.get(Feature::Liquidity), 1.0);
+            for feature in Feature::all().into_iter().filter(|f| *f != Feature::Liquidity) {
+                assert_eq!(overridden.get(feature), computed.get(feature), "{:?} should be untouched", feature);
+            }
+        }
+    }
+}
@@ -0,0 +1,228 @@
+//! Consecutive-loss kill switch.
+//!
+//! Once the ledger shows `threshold` closed trades in a row that ended at a
+//! loss, the switch trips and the oracle skips every candidate until an
+//! operator resets it. Only trades decided after the last reset count, so the
+//! losing streak that tripped the switch cannot trip it again right away. The
+//! reset time is saved to the ledger, so this also holds across restarts.
+
+use crate::oracle::storage::{window_start_ms, LedgerStorage};
+use crate::oracle::types::{Outcome, TransactionRecord};
+use anyhow::Result;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// How often the oracle re-reads closed trades from the ledger.
+pub const DEFAULT_REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Default window (seconds) of decisions read on each refresh.
+pub const DEFAULT_LOOKBACK_SECONDS: u64 = 24 * 3600;
+
+/// Trips after a run of losing trades and stays tripped until `reset`.
+pub struct KillSwitch {
+    /// Consecutive losses that trip the switch, `None` disables it
+    threshold: Option<u32>,
+    /// Only trades decided within this many seconds are read on refresh
+    lookback_seconds: u64,
+    /// Ledger closed trades are read from and resets are saved to
+    storage: Mutex<Option<Arc<dyn LedgerStorage>>>,
+    tripped: AtomicBool,
+    /// Decision timestamp (ms) before which trades no longer count
+    reset_at: AtomicU64,
+}
+
+impl KillSwitch {
+    /// Kill switch tripping after `threshold` consecutive losses; `None` disables it.
+    pub fn new(threshold: Option<u32>) -> Self {
+        Self {
+            threshold,
+            lookback_seconds: DEFAULT_LOOKBACK_SECONDS,
+            storage: Mutex::new(None),
+            tripped: AtomicBool::new(false),
+            reset_at: AtomicU64::new(0),
+        }
+    }
+
+    /// Read only trades decided within the last `lookback_seconds` on refresh.
+    pub fn with_lookback_seconds(mut self, lookback_seconds: u64) -> Self {
+        self.lookback_seconds = lookback_seconds;
+        self
+    }
+
+    /// Read closed trades from, and save resets to, `storage`.
+    pub fn set_storage(&self, storage: Arc<dyn LedgerStorage>) {
+        *self.storage.lock().unwrap() = Some(storage);
+    }
+
+    fn storage(&self) -> Option<Arc<dyn LedgerStorage>> {
+        self.storage.lock().unwrap().clone()
+    }
+
+    /// Whether a threshold is configured.
+    pub fn is_enabled(&self) -> bool {
+        self.threshold.is_some()
+    }
+
+    /// Whether the switch is enabled and has a ledger to watch.
+    pub fn is_watching(&self) -> bool {
+        self.is_enabled() && self.storage.lock().unwrap().is_some()
+    }
+
+    /// Whether candidates must be skipped.
+    pub fn is_tripped(&self) -> bool {
+        self.tripped.load(Ordering::SeqCst)
+    }
+
+    /// Length of the losing streak that ends `records` (in decision order),
+    /// counting only closed trades decided since the last reset. Trips the
+    /// switch once the streak reaches the threshold.
+    pub fn observe(&self, records: &[TransactionRecord]) -> u32 {
+        let reset_at = self.reset_at.load(Ordering::SeqCst);
+        let streak = records
+            .iter()
+            .filter(|r| r.timestamp_decision_made >= reset_at)
+            .filter(|r| matches!(r.actual_outcome, Outcome::Profit(_) | Outcome::Loss(_)))
+            .rev()
+            .take_while(|r| matches!(r.actual_outcome, Outcome::Loss(_)))
+            .count() as u32;
+
+        if let Some(threshold) = self.threshold {
+            if streak >= threshold && !self.tripped.swap(true, Ordering::SeqCst) {
+                warn!("Kill switch tripped after {} consecutive losses, skipping all candidates until reset", streak);
+            }
+        }
+        streak
+    }
+
+    /// Pick up the last reset saved in the ledger, e.g. after a restart.
+    pub async fn load(&self) -> Result<()> {
+        let Some(storage) = self.storage() else {
+            return Ok(());
+        };
+        if let Some(reset_at) = storage.get_kill_switch_reset_at().await? {
+            self.reset_at.fetch_max(reset_at, Ordering::SeqCst);
+        }
+        Ok(())
+    }
+
+    /// Re-read the trades decided since the last reset, within the lookback
+    /// window, from the ledger.
+    pub async fn refresh(&self) -> Result<u32> {
+        let Some(storage) = self.storage() else {
+            return Ok(0);
+        };
+        let since = self.reset_at.load(Ordering::SeqCst).max(window_start_ms(self.lookback_seconds));
+        let records = storage.get_records_since(since).await?;
+        Ok(self.observe(&records))
+    }
+
+    /// Resume normal decisions, forgetting every trade decided so far, and
+    /// save the reset to the ledger.
+    pub async fn reset(&self) -> Result<()> {
+        let now = chrono::Utc::now().timestamp_millis() as u64;
+        self.reset_at.store(now, Ordering::SeqCst);
+        if self.tripped.swap(false, Ordering::SeqCst) {
+            info!("Kill switch reset");
+        }
+
+        if let Some(storage) = self.storage() {
+            storage.set_kill_switch_reset_at(now).await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oracle::storage_conformance::FlakyStorage;
+    use crate::oracle::test_support::RecordBuilder;
+
+    fn closed_trade(decided_at: u64, outcome: Outcome) -> TransactionRecord {
+        RecordBuilder::new(&format!("mint_{}", decided_at)).decided_at(decided_at).outcome(outcome).build()
+    }
+
+    #[tokio::test]
+    async fn test_consecutive_losses_trip_until_reset() {
+        let storage = Arc::new(FlakyStorage::available());
+        let kill_switch = KillSwitch::new(Some(3));
+        kill_switch.set_storage(storage.clone());
+        let start = chrono::Utc::now().timestamp_millis() as u64 - 60_000;
+
+        for (i, outcome) in [
+            Outcome::Loss(-0.1),
+            Outcome::Loss(-0.1),
+            Outcome::Profit(0.3),
+            Outcome::Loss(-0.1),
+            Outcome::NotExecuted,
+            Outcome::Loss(-0.2),
+        ].into_iter().enumerate() {
+            storage.insert_record(&closed_trade(start + i as u64, outcome)).await.unwrap();
+        }
+        // The profit broke the first streak and unexecuted decisions do not count
+        assert_eq!(kill_switch.refresh().await.unwrap(), 2);
+        assert!(!kill_switch.is_tripped());
+
+        storage.insert_record(&closed_trade(start + 10, Outcome::Loss(-0.5))).await.unwrap();
+        assert_eq!(kill_switch.refresh().await.unwrap(), 3);
+        assert!(kill_switch.is_tripped());
+
+        // A later win does not lift the switch, only a reset does
+        storage.insert_record(&closed_trade(start + 11, Outcome::Profit(1.0))).await.unwrap();
+        kill_switch.refresh().await.unwrap();
+        assert!(kill_switch.is_tripped());
+
+        kill_switch.reset().await.unwrap();
+        assert!(!kill_switch.is_tripped());
+        storage.insert_record(&closed_trade(start + 12, Outcome::Loss(-0.5))).await.unwrap();
+        assert_eq!(kill_switch.refresh().await.unwrap(), 0);
+        assert!(!kill_switch.is_tripped());
+    }
+
+    #[tokio::test]
+    async fn test_reset_survives_restart() {
+        let storage = Arc::new(FlakyStorage::available());
+        let start = chrono::Utc::now().timestamp_millis() as u64 - 60_000;
+        for i in 0..3 {
+            storage.insert_record(&closed_trade(start + i, Outcome::Loss(-0.5))).await.unwrap();
+        }
+
+        let kill_switch = KillSwitch::new(Some(3));
+        kill_switch.set_storage(storage.clone());
+        kill_switch.load().await.unwrap();
+        kill_switch.refresh().await.unwrap();
+        assert!(kill_switch.is_tripped());
+        kill_switch.reset().await.unwrap();
+
+        // After a restart the streak that tripped the switch stays forgotten
+        let restarted = KillSwitch::new(Some(3));
+        restarted.set_storage(storage);
+        restarted.load().await.unwrap();
+        assert_eq!(restarted.refresh().await.unwrap(), 0);
+        assert!(!restarted.is_tripped());
+    }
+
+    #[tokio::test]
+    async fn test_trades_outside_lookback_are_not_read() {
+        let storage = Arc::new(FlakyStorage::available());
+        let now = chrono::Utc::now().timestamp_millis() as u64;
+        for i in 0..3 {
+            storage.insert_record(&closed_trade(now - 7_200_000 + i, Outcome::Loss(-0.5))).await.unwrap();
+        }
+
+        let kill_switch = KillSwitch::new(Some(3)).with_lookback_seconds(3600);
+        kill_switch.set_storage(storage);
+        assert_eq!(kill_switch.refresh().await.unwrap(), 0);
+        assert!(!kill_switch.is_tripped());
+    }
+
+    #[test]
+    fn test_disabled_kill_switch_never_trips() {
+        let kill_switch = KillSwitch::new(None);
+        let losses: Vec<_> = (0..100).map(|i| closed_trade(i, Outcome::Loss(-0.1))).collect();
+        assert_eq!(kill_switch.observe(&losses), 100);
+        assert!(!kill_switch.is_tripped());
+    }
+}
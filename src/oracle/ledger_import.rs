@@ -0,0 +1,188 @@
+//! Importer for seeding the DecisionLedger with trade history from other bots.
+//!
+//! Each line of the input file is a JSON object describing one closed or open
+//! fill. Fields:
+//!
+//! | field            | type   | required | meaning                                    |
+//! |------------------|--------|----------|--------------------------------------------|
+//! | `mint`           | string | yes      | Token mint address                         |
+//! | `signature`      | string | yes      | Buy transaction signature                  |
+//! | `timestamp`      | u64    | yes      | Buy time, Unix milliseconds                |
+//! | `sol_spent`      | f64    | yes      | SOL spent on the buy (> 0)                 |
+//! | `sol_received`   | f64    | no       | SOL received on the sell; absent when open |
+//! | `buy_price_sol`  | f64    | no       | Price per token at buy                     |
+//! | `sell_price_sol` | f64    | no       | Price per token at sell                    |
+//! | `amount_tokens`  | f64    | no       | Tokens bought                              |
+//! | `closed_at`      | u64    | no       | Sell time, Unix milliseconds               |
+//!
+//! Blank lines are ignored; lines that fail to parse or validate are skipped.
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+use tracing::{info, warn};
+
+use crate::oracle::storage::LedgerStorage;
+use crate::oracle::types::{Outcome, Score, ScoredCandidate, TradeAction, TransactionRecord};
+use crate::types::PremintCandidate;
+
+/// One fill in the import schema.
+#[derive(Debug, Deserialize)]
+struct ImportedFill {
+    mint: String,
+    signature: String,
+    timestamp: u64,
+    sol_spent: f64,
+    sol_received: Option<f64>,
+    buy_price_sol: Option<f64>,
+    sell_price_sol: Option<f64>,
+    amount_tokens: Option<f64>,
+    closed_at: Option<u64>,
+}
+
+impl ImportedFill {
+    fn validate(&self) -> Result<()> {
+        if self.mint.trim().is_empty() {
+            bail!("mint is empty");
+        }
+        if self.signature.trim().is_empty() {
+            bail!("signature is empty");
+        }
+        if !self.sol_spent.is_finite() || self.sol_spent <= 0.0 {
+            bail!("sol_spent must be positive, got {}", self.sol_spent);
+        }
+        if let Some(received) = self.sol_received {
+            if !received.is_finite() || received < 0.0 {
+                bail!("sol_received must be non-negative, got {}", received);
+            }
+        }
+        Ok(())
+    }
+
+    fn into_record(self) -> TransactionRecord {
+        let actual_outcome = match self.sol_received {
+            Some(received) => {
+                let pnl = received - self.sol_spent;
+                if pnl > 0.0 {
+                    Outcome::Profit(pnl)
+                } else if pnl < 0.0 {
+                    Outcome::Loss(pnl)
+                } else {
+                    Outcome::Neutral
+                }
+            }
+            None => Outcome::PendingConfirmation,
+        };
+
+        let scored_candidate = ScoredCandidate {
+            base: PremintCandidate {
+                mint: self.mint.clone(),
+                creator: String::new(),
+                program: "import".to_string(),
+                slot: 0,
+                timestamp: self.timestamp,
+                instruction_summary: None,
+                is_jito_bundle: None,
+                funding_source: None,
+            },
+            mint: self.mint,
+            predicted_score: Score::default(),
+            feature_scores: HashMap::new(),
+            reason: "Imported from external trade history".to_string(),
+            calculation_time: 0,
+            anomaly_detected: false,
+            timestamp: self.timestamp,
+            data_source_timestamps: HashMap::new(),
+            scoring_trace: None,
+            reason_codes: Vec::new(),
+            anomaly_report: None,
+            // Every imported trade was bought
+            action: TradeAction::Buy,
+        };
+
+        TransactionRecord {
+            id: None,
+            scored_candidate,
+            transaction_signature: Some(self.signature),
+            buy_price_sol: self.buy_price_sol,
+            sell_price_sol: self.sell_price_sol,
+            amount_bought_tokens: self.amount_tokens,
+            amount_sold_tokens: self.sol_received.and(self.amount_tokens),
+            initial_sol_spent: Some(self.sol_spent),
+            final_sol_received: self.sol_received,
+            timestamp_decision_made: self.timestamp,
+            timestamp_transaction_sent: Some(self.timestamp),
+            timestamp_outcome_evaluated: self.closed_at,
+            actual_outcome,
+            market_context_snapshot: HashMap::new(),
+        }
+    }
+}
+
+/// Import fills from a JSONL file into the ledger.
+///
+/// Returns the number of records imported; malformed lines are logged and skipped.
+pub async fn import_from_jsonl(storage: &dyn LedgerStorage, path: &Path) -> Result<usize> {
+    let contents = tokio::fs::read_to_string(path)
+        .await
+        .with_context(|| format!("Failed to read import file {}", path.display()))?;
+
+    let mut records = Vec::new();
+    let mut skipped = 0;
+
+    for (index, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let fill = serde_json::from_str::<ImportedFill>(line)
+            .map_err(anyhow::Error::from)
+            .and_then(|fill| fill.validate().map(|_| fill));
+
+        match fill {
+            Ok(fill) => records.push(fill.into_record()),
+            Err(e) => {
+                warn!("Skipping line {} of {}: {}", index + 1, path.display(), e);
+                skipped += 1;
+            }
+        }
+    }
+
+    let imported = storage.insert_records(&records).await?.len();
+    info!("Imported {} records from {} ({} skipped)", imported, path.display(), skipped);
+
+    Ok(imported)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oracle::storage::SqliteLedger;
+
+    #[tokio::test]
+    async fn test_import_skips_malformed_line() {
+        let storage = SqliteLedger::new().await.unwrap();
+        let run_id = chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default();
+        let path = std::env::temp_dir().join(format!("ledger_import_{}.jsonl", run_id));
+
+        let lines = [
+            format!(r#"{{"mint":"mint_a","signature":"import_win_{run_id}","timestamp":1000,"sol_spent":1.0,"sol_received":1.5}}"#),
+            r#"{"mint":"mint_b","signature":"broken","timestamp":"#.to_string(),
+            format!(r#"{{"mint":"mint_c","signature":"import_open_{run_id}","timestamp":2000,"sol_spent":0.5}}"#),
+        ];
+        tokio::fs::write(&path, lines.join("\n")).await.unwrap();
+
+        let imported = import_from_jsonl(storage.as_ref(), &path).await.unwrap();
+        tokio::fs::remove_file(&path).await.unwrap();
+
+        assert_eq!(imported, 2);
+
+        let win = storage.get_record_by_signature(&format!("import_win_{run_id}")).await.unwrap().unwrap();
+        assert_eq!(win.actual_outcome, Outcome::Profit(0.5));
+        assert_eq!(win.final_sol_received, Some(1.5));
+
+        let open = storage.get_record_by_signature(&format!("import_open_{run_id}")).await.unwrap().unwrap();
+        assert_eq!(open.actual_outcome, Outcome::PendingConfirmation);
+    }
+}
@@ -4,14 +4,76 @@
 //! analyzes macro-economic indicators to identify the current market state and
 //! adapt Oracle behavior accordingly.
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, RwLock};
+use tokio::time::{timeout_at, Instant};
 use tracing::{info, debug, warn, instrument};
 
-use crate::oracle::data_sources::OracleDataSources;
-use crate::oracle::types::MarketRegime;
+use crate::oracle::data_sources::{sol_volatility_percent, OracleDataSources};
+use crate::oracle::rate_limit::AdaptiveRateLimiter;
+use crate::oracle::types::{MarketContextSnapshot, MarketRegime, OracleConfig};
+
+/// Default upper bound on how long one detection cycle waits for its inputs.
+pub const DEFAULT_FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Source of the macro-economic inputs used for regime detection.
+#[async_trait]
+pub trait MarketDataSource: Send + Sync {
+    /// Current SOL price in USD.
+    async fn fetch_sol_price_usd(&self) -> Result<f64>;
+
+    /// Current network throughput in transactions per second.
+    async fn fetch_network_tps(&self) -> Result<f64>;
+
+    /// Aggregated 24h DEX volume in USD.
+    async fn fetch_global_dex_volume(&self) -> Result<f64>;
+}
+
+#[async_trait]
+impl MarketDataSource for OracleDataSources {
+    async fn fetch_sol_price_usd(&self) -> Result<f64> {
+        OracleDataSources::fetch_sol_price_usd(self).await
+    }
+
+    async fn fetch_network_tps(&self) -> Result<f64> {
+        OracleDataSources::fetch_network_tps(self).await
+    }
+
+    async fn fetch_global_dex_volume(&self) -> Result<f64> {
+        OracleDataSources::fetch_global_dex_volume(self).await
+    }
+}
+
+/// Most recent successfully fetched inputs, used when a fetch fails or times out.
+#[derive(Debug, Clone, Copy)]
+struct LastKnownInputs {
+    sol_price: f64,
+    network_tps: f64,
+    dex_volume: f64,
+}
+
+impl Default for LastKnownInputs {
+    fn default() -> Self {
+        Self {
+            sol_price: 0.0,           // Unknown price, history is not updated
+            network_tps: 1000.0,      // Moderate TPS
+            dex_volume: 50_000_000.0, // Typical daily volume
+        }
+    }
+}
+
+/// Rate limiter whose quota follows the current regime.
+struct RegimeRateLimit {
+    limiter: Arc<Mutex<AdaptiveRateLimiter>>,
+    /// Requests per second in regimes without an override
+    default_requests_per_second: u32,
+    /// Per-regime `rate_limit_requests_per_second` overrides
+    overrides: HashMap<MarketRegime, u32>,
+}
 
 /// Autonomous market regime detector that runs in the background.
 /// 
@@ -20,10 +82,13 @@ use crate::oracle::types::MarketRegime;
 /// It updates a shared state that other components can read to adapt their behavior.
 pub struct MarketRegimeDetector {
     /// Data sources for fetching macro-economic data
-    data_sources: Arc<OracleDataSources>,
+    data_sources: Arc<dyn MarketDataSource>,
     
     /// Shared state holding the current market regime
     current_regime: Arc<RwLock<MarketRegime>>,
+
+    /// Shared state holding the inputs behind the current regime
+    current_context: Arc<RwLock<MarketContextSnapshot>>,
     
     /// How often to analyze market conditions
     detection_interval: Duration,
@@ -33,24 +98,63 @@ pub struct MarketRegimeDetector {
     
     /// Maximum number of price points to keep in history
     max_price_history: usize,
+
+    /// Upper bound on how long a cycle waits for all inputs
+    fetch_timeout: Duration,
+
+    /// Fallback values for inputs that could not be fetched this cycle
+    last_known: LastKnownInputs,
+
+    /// Rate limiter to re-quota on regime changes, if any
+    rate_limit: Option<RegimeRateLimit>,
 }
 
 impl MarketRegimeDetector {
     /// Create a new MarketRegimeDetector.
     pub fn new(
-        data_sources: Arc<OracleDataSources>,
+        data_sources: Arc<dyn MarketDataSource>,
         current_regime: Arc<RwLock<MarketRegime>>,
         detection_interval_seconds: u64,
     ) -> Self {
         Self {
             data_sources,
             current_regime,
+            current_context: Arc::new(RwLock::new(MarketContextSnapshot::default())),
             detection_interval: Duration::from_secs(detection_interval_seconds),
             sol_price_history: Vec::with_capacity(60), // Hold up to 60 data points
             max_price_history: 60,
+            fetch_timeout: DEFAULT_FETCH_TIMEOUT,
+            last_known: LastKnownInputs::default(),
+            rate_limit: None,
         }
     }
 
+    /// Publish the market inputs of every cycle to `current_context`.
+    pub fn with_context_snapshot(mut self, current_context: Arc<RwLock<MarketContextSnapshot>>) -> Self {
+        self.current_context = current_context;
+        self
+    }
+
+    /// Override the per-cycle timeout for fetching market inputs.
+    pub fn with_fetch_timeout(mut self, fetch_timeout: Duration) -> Self {
+        self.fetch_timeout = fetch_timeout;
+        self
+    }
+
+    /// On every regime change, set `rate_limiter`'s base quota to the new
+    /// regime's `rate_limit_requests_per_second`, or to the config's
+    /// `rate_limit_requests_per_second` if the regime has no override.
+    pub fn with_rate_limiter(mut self, rate_limiter: Arc<Mutex<AdaptiveRateLimiter>>, config: &OracleConfig) -> Self {
+        self.rate_limit = Some(RegimeRateLimit {
+            limiter: rate_limiter,
+            default_requests_per_second: config.rate_limit_requests_per_second,
+            overrides: config.regime_parameters.iter()
+                .filter_map(|(regime, params)| Some((*regime, params.rate_limit_requests_per_second?)))
+                .collect(),
+        });
+        self
+    }
+
     /// Run the market regime detection loop.
     /// 
     /// This method runs indefinitely, periodically analyzing market conditions
@@ -81,39 +185,52 @@ impl MarketRegimeDetector {
         debug!("Performing market regime analysis...");
 
         // --- Phase 1: Gather Macro-economic Data ---
-        let sol_price = self.data_sources.fetch_sol_price_usd().await
-            .unwrap_or_else(|e| {
-                warn!("Failed to fetch SOL price: {}", e);
-                0.0 // Use 0.0 as fallback, but don't update history
-            });
+        // All inputs are fetched concurrently and share one deadline, so a single
+        // slow API cannot hold up the cycle; missing inputs reuse the last-known value.
+        let deadline = Instant::now() + self.fetch_timeout;
+        let (sol_price, network_tps, dex_volume) = tokio::join!(
+            timeout_at(deadline, self.data_sources.fetch_sol_price_usd()),
+            timeout_at(deadline, self.data_sources.fetch_network_tps()),
+            timeout_at(deadline, self.data_sources.fetch_global_dex_volume()),
+        );
 
-        // Only update price history if we got a valid price
-        if sol_price > 0.0 {
-            self.update_price_history(sol_price);
-        }
+        let sol_price = match flatten_timeout(sol_price) {
+            Ok(price) => {
+                self.update_price_history(price);
+                self.last_known.sol_price = price;
+                price
+            }
+            Err(e) => {
+                warn!("Failed to fetch SOL price, using last known: {}", e);
+                self.last_known.sol_price
+            }
+        };
 
-        let volatility = self.data_sources
-            .calculate_sol_volatility(&self.sol_price_history)
-            .await
+        let network_tps = flatten_timeout(network_tps)
+            .inspect(|&tps| self.last_known.network_tps = tps)
             .unwrap_or_else(|e| {
-                warn!("Failed to calculate volatility: {}", e);
-                0.0
+                warn!("Failed to fetch network TPS, using last known: {}", e);
+                self.last_known.network_tps
             });
 
-        let network_tps = self.data_sources.fetch_network_tps().await
+        let dex_volume = flatten_timeout(dex_volume)
+            .inspect(|&volume| self.last_known.dex_volume = volume)
             .unwrap_or_else(|e| {
-                warn!("Failed to fetch network TPS: {}", e);
-                1000.0 // Default to moderate TPS
+                warn!("Failed to fetch DEX volume, using last known: {}", e);
+                self.last_known.dex_volume
             });
 
-        let dex_volume = self.data_sources.fetch_global_dex_volume().await
-            .unwrap_or_else(|e| {
-                warn!("Failed to fetch DEX volume: {}", e);
-                50_000_000.0 // Default volume
-            });
+        let volatility = sol_volatility_percent(&self.sol_price_history);
 
         // --- Phase 2: Analyze and Determine Regime ---
         let new_regime = self.determine_regime(sol_price, volatility, network_tps, dex_volume);
+        *self.current_context.write().await = MarketContextSnapshot {
+            sol_price,
+            volatility,
+            network_tps,
+            dex_volume,
+            regime: new_regime,
+        };
 
         // --- Phase 3: Update Global State if Changed ---
         let mut current_regime_lock = self.current_regime.write().await;
@@ -128,6 +245,7 @@ impl MarketRegimeDetector {
                 dex_volume
             );
             *current_regime_lock = new_regime;
+            self.apply_rate_limit(new_regime).await;
         } else {
             debug!(
                 "Market regime unchanged: {:?} (SOL: ${:.2}, Vol: {:.1}%, TPS: {:.0})",
@@ -141,8 +259,29 @@ impl MarketRegimeDetector {
         Ok(())
     }
 
+    /// Re-quota the rate limiter, if any, for `regime`.
+    async fn apply_rate_limit(&self, regime: MarketRegime) {
+        let Some(rate_limit) = &self.rate_limit else {
+            return;
+        };
+
+        let requests_per_second = rate_limit.overrides.get(&regime)
+            .copied()
+            .unwrap_or(rate_limit.default_requests_per_second);
+        let mut limiter = rate_limit.limiter.lock().await;
+        if limiter.get_stats().base_quota != requests_per_second {
+            info!("Setting RPC rate limit to {} req/s for {:?} regime", requests_per_second, regime);
+            limiter.switch_base_quota(requests_per_second);
+        }
+    }
+
     /// Update the price history with a new price point.
+    ///
+    /// Non-positive prices are ignored.
     fn update_price_history(&mut self, new_price: f64) {
+        if new_price <= 0.0 {
+            return;
+        }
         // Maintain circular buffer behavior
         if self.sol_price_history.len() >= self.max_price_history {
             self.sol_price_history.remove(0);
@@ -228,12 +367,22 @@ impl MarketRegimeDetector {
         *self.current_regime.read().await
     }
 
+    /// Get the market inputs of the latest cycle.
+    pub async fn get_current_context(&self) -> MarketContextSnapshot {
+        *self.current_context.read().await
+    }
+
     /// Get current price history length (for monitoring/debugging).
     pub fn get_price_history_length(&self) -> usize {
         self.sol_price_history.len()
     }
 }
 
+/// Treat an elapsed deadline the same as a failed fetch.
+fn flatten_timeout(result: Result<Result<f64>, tokio::time::error::Elapsed>) -> Result<f64> {
+    result.map_err(|_| anyhow!("timed out"))?
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -310,4 +459,105 @@ mod tests {
         let regime = detector.determine_regime(150.0, 1.0, 1000.0, 30_000_000.0);
         assert_eq!(regime, MarketRegime::LowActivity);
     }
-}
\ No newline at end of file
+
+    /// Stub source whose TPS fetch hangs and DEX volume fetch fails once degraded.
+    struct StubMarketData {
+        degraded: std::sync::atomic::AtomicBool,
+    }
+
+    #[async_trait]
+    impl MarketDataSource for StubMarketData {
+        async fn fetch_sol_price_usd(&self) -> Result<f64> {
+            Ok(150.0)
+        }
+
+        async fn fetch_network_tps(&self) -> Result<f64> {
+            if self.degraded.load(std::sync::atomic::Ordering::SeqCst) {
+                tokio::time::sleep(Duration::from_secs(30)).await;
+            }
+            Ok(3500.0)
+        }
+
+        async fn fetch_global_dex_volume(&self) -> Result<f64> {
+            if self.degraded.load(std::sync::atomic::Ordering::SeqCst) {
+                return Err(anyhow!("DEX API unavailable"));
+            }
+            Ok(60_000_000.0)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cycle_uses_fallbacks_for_slow_and_failing_sources() {
+        let stub = Arc::new(StubMarketData {
+            degraded: std::sync::atomic::AtomicBool::new(false),
+        });
+        let current_regime = Arc::new(RwLock::new(MarketRegime::LowActivity));
+        let current_context = Arc::new(RwLock::new(MarketContextSnapshot::default()));
+        let mut detector = MarketRegimeDetector::new(stub.clone(), current_regime.clone(), 60)
+            .with_context_snapshot(current_context.clone())
+            .with_fetch_timeout(Duration::from_millis(100));
+
+        detector.analyze_market_regime().await.unwrap();
+        assert_eq!(*current_regime.read().await, MarketRegime::HighCongestion);
+        assert_eq!(*current_context.read().await, MarketContextSnapshot {
+            sol_price: 150.0,
+            volatility: 0.0,
+            network_tps: 3500.0,
+            dex_volume: 60_000_000.0,
+            regime: MarketRegime::HighCongestion,
+        });
+
+        stub.degraded.store(true, std::sync::atomic::Ordering::SeqCst);
+        let started = std::time::Instant::now();
+        detector.analyze_market_regime().await.unwrap();
+
+        assert!(started.elapsed() < Duration::from_secs(1), "cycle must not wait for the slow source");
+        assert_eq!(detector.last_known.network_tps, 3500.0);
+        assert_eq!(detector.last_known.dex_volume, 60_000_000.0);
+        assert_eq!(detector.sol_price_history, vec![150.0, 150.0]);
+        // Last-known TPS keeps the congestion regime in place
+        assert_eq!(*current_regime.read().await, MarketRegime::HighCongestion);
+    }
+
+    /// Stub source with steady price and volume and an adjustable TPS.
+    struct AdjustableTpsMarketData {
+        network_tps: std::sync::Mutex<f64>,
+    }
+
+    #[async_trait]
+    impl MarketDataSource for AdjustableTpsMarketData {
+        async fn fetch_sol_price_usd(&self) -> Result<f64> {
+            Ok(150.0)
+        }
+
+        async fn fetch_network_tps(&self) -> Result<f64> {
+            Ok(*self.network_tps.lock().unwrap())
+        }
+
+        async fn fetch_global_dex_volume(&self) -> Result<f64> {
+            Ok(30_000_000.0)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_regime_rate_limit_override_is_applied_on_regime_change() {
+        let mut config = OracleConfig { rate_limit_requests_per_second: 20, ..OracleConfig::default() };
+        config.regime_parameters.get_mut(&MarketRegime::HighCongestion).unwrap().rate_limit_requests_per_second = Some(5);
+
+        let stub = Arc::new(AdjustableTpsMarketData { network_tps: std::sync::Mutex::new(3500.0) });
+        let current_regime = Arc::new(RwLock::new(MarketRegime::LowActivity));
+        let rate_limiter = Arc::new(Mutex::new(AdaptiveRateLimiter::new(20, 10, 0.2)));
+        let mut detector = MarketRegimeDetector::new(stub.clone(), current_regime.clone(), 60)
+            .with_rate_limiter(rate_limiter.clone(), &config);
+
+        detector.analyze_market_regime().await.unwrap();
+        assert_eq!(*current_regime.read().await, MarketRegime::HighCongestion);
+        assert_eq!(rate_limiter.lock().await.get_current_quota(), 5);
+
+        // Regimes without an override return to the configured rate
+        *stub.network_tps.lock().unwrap() = 1000.0;
+        detector.analyze_market_regime().await.unwrap();
+        assert_eq!(*current_regime.read().await, MarketRegime::LowActivity);
+        assert_eq!(rate_limiter.lock().await.get_current_quota(), 20);
+    }
+}
@@ -3,24 +3,52 @@
 //! This module provides metrics collection and optional Prometheus HTTP server
 //! for monitoring Oracle performance and health.
 
+use async_trait::async_trait;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
-use tracing::{debug, info, warn, instrument};
+use tracing::{debug, instrument, warn};
+#[cfg(feature = "prometheus_exporter")]
+use tracing::info;
 
 #[cfg(feature = "prometheus_exporter")]
 use prometheus::{Counter, Gauge, Histogram, Registry, Encoder, TextEncoder};
 
 #[cfg(feature = "prometheus_exporter")]
-use hyper::service::{make_service_fn, service_fn};
+use http_body_util::Full;
+#[cfg(feature = "prometheus_exporter")]
+use hyper::body::{Bytes, Incoming};
+#[cfg(feature = "prometheus_exporter")]
+use hyper::server::conn::http1;
+#[cfg(feature = "prometheus_exporter")]
+use hyper::service::service_fn;
+#[cfg(feature = "prometheus_exporter")]
+use hyper::{Method, Request, Response, StatusCode};
 #[cfg(feature = "prometheus_exporter")]
-use hyper::{Body, Method, Request, Response, Result as HyperResult, Server, StatusCode};
+use hyper_util::rt::{TokioIo, TokioTimer};
+
+/// How long a metrics client may take to send its request headers.
+#[cfg(feature = "prometheus_exporter")]
+const METRICS_HEADER_READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Default buckets (seconds) for the scoring duration histogram.
+pub const DEFAULT_SCORING_DURATION_BUCKETS: [f64; 7] = [0.001, 0.01, 0.1, 0.5, 1.0, 5.0, 10.0];
+
+/// Supplies the oracle's effective configuration for the `/config` endpoint.
+#[async_trait]
+pub trait EffectiveConfigProvider: Send + Sync {
+    /// Current effective configuration as JSON.
+    async fn effective_config_json(&self) -> serde_json::Value;
+}
 
 /// Oracle metrics collector.
 pub struct OracleMetricsCollector {
     /// Internal metrics storage
     metrics: Arc<RwLock<InternalMetrics>>,
+
+    /// Bucket bounds of the scoring duration histogram
+    scoring_duration_buckets: Vec<f64>,
     
     #[cfg(feature = "prometheus_exporter")]
     /// Prometheus registry
@@ -52,9 +80,36 @@ struct InternalMetrics {
     last_updates: HashMap<String, Instant>,
 }
 
+impl Default for OracleMetricsCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl OracleMetricsCollector {
     /// Create a new metrics collector.
     pub fn new() -> Self {
+        Self::with_valid_buckets(DEFAULT_SCORING_DURATION_BUCKETS.to_vec())
+    }
+
+    /// Create a metrics collector with custom scoring duration histogram buckets.
+    ///
+    /// The buckets must be finite and strictly increasing, with at least one bound.
+    pub fn with_scoring_duration_buckets(scoring_duration_buckets: Vec<f64>) -> anyhow::Result<Self> {
+        if scoring_duration_buckets.is_empty() {
+            anyhow::bail!("Scoring duration buckets must not be empty");
+        }
+        if scoring_duration_buckets.iter().any(|bound| !bound.is_finite()) {
+            anyhow::bail!("Scoring duration buckets must be finite: {:?}", scoring_duration_buckets);
+        }
+        if scoring_duration_buckets.windows(2).any(|pair| pair[0] >= pair[1]) {
+            anyhow::bail!("Scoring duration buckets must be strictly increasing: {:?}", scoring_duration_buckets);
+        }
+
+        Ok(Self::with_valid_buckets(scoring_duration_buckets))
+    }
+
+    fn with_valid_buckets(scoring_duration_buckets: Vec<f64>) -> Self {
         #[cfg(feature = "prometheus_exporter")]
         {
             let registry = Registry::new();
@@ -63,10 +118,17 @@ impl OracleMetricsCollector {
             let mut histograms = HashMap::new();
 
             // Initialize standard Oracle metrics
-            Self::register_standard_metrics(&registry, &mut counters, &mut gauges, &mut histograms);
+            Self::register_standard_metrics(
+                &registry,
+                &mut counters,
+                &mut gauges,
+                &mut histograms,
+                &scoring_duration_buckets,
+            );
 
             Self {
                 metrics: Arc::new(RwLock::new(InternalMetrics::default())),
+                scoring_duration_buckets,
                 prometheus_registry: registry,
                 prometheus_counters: counters,
                 prometheus_gauges: gauges,
@@ -78,10 +140,16 @@ impl OracleMetricsCollector {
         {
             Self {
                 metrics: Arc::new(RwLock::new(InternalMetrics::default())),
+                scoring_duration_buckets,
             }
         }
     }
 
+    /// Bucket bounds used by the scoring duration histogram.
+    pub fn scoring_duration_buckets(&self) -> &[f64] {
+        &self.scoring_duration_buckets
+    }
+
     /// Register standard Oracle metrics.
     #[cfg(feature = "prometheus_exporter")]
     fn register_standard_metrics(
@@ -89,6 +157,7 @@ impl OracleMetricsCollector {
         counters: &mut HashMap<String, Counter>,
         gauges: &mut HashMap<String, Gauge>,
         histograms: &mut HashMap<String, Histogram>,
+        scoring_duration_buckets: &[f64],
     ) {
         use prometheus::opts;
 
@@ -135,6 +204,30 @@ impl OracleMetricsCollector {
         registry.register(Box::new(oracle_api_errors_total.clone())).unwrap();
         counters.insert("oracle_api_errors_total".to_string(), oracle_api_errors_total);
 
+        let oracle_rate_limited_total = Counter::with_opts(opts!(
+            "oracle_rate_limited_total",
+            "Total number of candidates skipped by the rate limiter"
+        )).unwrap();
+        registry.register(Box::new(oracle_rate_limited_total.clone())).unwrap();
+        counters.insert("oracle_rate_limited_total".to_string(), oracle_rate_limited_total);
+
+        let oracle_adaptive_recalc_total = Counter::with_opts(opts!(
+            "oracle_adaptive_recalc_total",
+            "Total number of adaptive weight recalculations"
+        )).unwrap();
+        registry.register(Box::new(oracle_adaptive_recalc_total.clone())).unwrap();
+        counters.insert("oracle_adaptive_recalc_total".to_string(), oracle_adaptive_recalc_total);
+
+        let oracle_gui_notifications_dropped_total = Counter::with_opts(opts!(
+            "oracle_gui_notifications_dropped_total",
+            "Total number of GUI notifications dropped because the GUI channel was full"
+        )).unwrap();
+        registry.register(Box::new(oracle_gui_notifications_dropped_total.clone())).unwrap();
+        counters.insert(
+            "oracle_gui_notifications_dropped_total".to_string(),
+            oracle_gui_notifications_dropped_total,
+        );
+
         // Gauges
         let oracle_avg_scoring_time = Gauge::with_opts(opts!(
             "oracle_avg_scoring_time_seconds",
@@ -143,15 +236,38 @@ impl OracleMetricsCollector {
         registry.register(Box::new(oracle_avg_scoring_time.clone())).unwrap();
         gauges.insert("oracle_avg_scoring_time_seconds".to_string(), oracle_avg_scoring_time);
 
+        let ledger_spill_buffer_size = Gauge::with_opts(opts!(
+            "ledger_spill_buffer_size",
+            "Decision records held in memory while the ledger storage is unavailable"
+        )).unwrap();
+        registry.register(Box::new(ledger_spill_buffer_size.clone())).unwrap();
+        gauges.insert("ledger_spill_buffer_size".to_string(), ledger_spill_buffer_size);
+
+        let oracle_weight_drift = Gauge::with_opts(opts!(
+            "oracle_weight_drift",
+            "L2 distance between base and current effective feature weights"
+        )).unwrap();
+        registry.register(Box::new(oracle_weight_drift.clone())).unwrap();
+        gauges.insert("oracle_weight_drift".to_string(), oracle_weight_drift);
+
         // Histograms
         let oracle_scoring_duration = Histogram::with_opts(
             prometheus::HistogramOpts::new(
                 "oracle_scoring_duration_seconds",
                 "Distribution of scoring durations"
-            ).buckets(vec![0.001, 0.01, 0.1, 0.5, 1.0, 5.0, 10.0])
+            ).buckets(scoring_duration_buckets.to_vec())
         ).unwrap();
         registry.register(Box::new(oracle_scoring_duration.clone())).unwrap();
         histograms.insert("oracle_scoring_duration_seconds".to_string(), oracle_scoring_duration);
+
+        let ledger_decision_to_outcome = Histogram::with_opts(
+            prometheus::HistogramOpts::new(
+                "ledger_decision_to_outcome_seconds",
+                "Time from decision to resolved outcome"
+            ).buckets(vec![1.0, 5.0, 15.0, 30.0, 60.0, 120.0, 300.0, 600.0, 1800.0])
+        ).unwrap();
+        registry.register(Box::new(ledger_decision_to_outcome.clone())).unwrap();
+        histograms.insert("ledger_decision_to_outcome_seconds".to_string(), ledger_decision_to_outcome);
     }
 
     /// Increment a counter metric.
@@ -240,42 +356,42 @@ impl OracleMetricsCollector {
     }
 
     /// Start Prometheus HTTP server (if feature enabled).
+    ///
+    /// When a `config_provider` is given, `/config` serves the effective oracle configuration.
     #[cfg(feature = "prometheus_exporter")]
     pub async fn start_metrics_server(
         &self,
         addr: &str,
+        config_provider: Option<Arc<dyn EffectiveConfigProvider>>,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        use std::convert::Infallible;
-        use std::net::SocketAddr;
-
         let registry = self.prometheus_registry.clone();
-        
-        let make_svc = make_service_fn(move |_conn| {
-            let registry = registry.clone();
-            async move {
-                Ok::<_, Infallible>(service_fn(move |req| {
-                    let registry = registry.clone();
-                    async move { serve_metrics(req, registry).await }
-                }))
-            }
-        });
-
-        let addr: SocketAddr = addr.parse()?;
-        let server = Server::bind(&addr).serve(make_svc);
+        let listener = tokio::net::TcpListener::bind(addr).await?;
 
         info!("Starting Prometheus metrics server on {}", addr);
-        
-        if let Err(e) = server.await {
-            warn!("Metrics server error: {}", e);
-        }
 
-        Ok(())
+        loop {
+            let (stream, _) = listener.accept().await?;
+            let registry = registry.clone();
+            let config_provider = config_provider.clone();
+            tokio::spawn(async move {
+                let service = service_fn(move |req| serve_metrics(req, registry.clone(), config_provider.clone()));
+                if let Err(e) = http1::Builder::new()
+                    .timer(TokioTimer::new())
+                    .header_read_timeout(METRICS_HEADER_READ_TIMEOUT)
+                    .serve_connection(TokioIo::new(stream), service)
+                    .await
+                {
+                    debug!("Metrics connection error: {}", e);
+                }
+            });
+        }
     }
 
     #[cfg(not(feature = "prometheus_exporter"))]
     pub async fn start_metrics_server(
         &self,
         _addr: &str,
+        _config_provider: Option<Arc<dyn EffectiveConfigProvider>>,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         warn!("Prometheus exporter not enabled, cannot start metrics server");
         Ok(())
@@ -294,9 +410,10 @@ pub struct MetricsSnapshot {
 /// Serve Prometheus metrics via HTTP.
 #[cfg(feature = "prometheus_exporter")]
 async fn serve_metrics(
-    req: Request<Body>,
+    req: Request<Incoming>,
     registry: Registry,
-) -> HyperResult<Response<Body>> {
+    config_provider: Option<Arc<dyn EffectiveConfigProvider>>,
+) -> Result<Response<Full<Bytes>>, hyper::http::Error> {
     match (req.method(), req.uri().path()) {
         (&Method::GET, "/metrics") => {
             let encoder = TextEncoder::new();
@@ -305,25 +422,32 @@ async fn serve_metrics(
             
             if let Err(e) = encoder.encode(&metric_families, &mut buffer) {
                 warn!("Failed to encode metrics: {}", e);
-                return Ok(Response::builder()
+                return Response::builder()
                     .status(StatusCode::INTERNAL_SERVER_ERROR)
-                    .body(Body::from("Failed to encode metrics"))?);
+                    .body(Full::from("Failed to encode metrics"));
             }
 
-            Ok(Response::builder()
+            Response::builder()
                 .status(StatusCode::OK)
                 .header("content-type", "text/plain; version=0.0.4")
-                .body(Body::from(buffer))?)
+                .body(Full::from(buffer))
+        }
+        (&Method::GET, "/config") if config_provider.is_some() => {
+            let config = config_provider.unwrap().effective_config_json().await;
+            Response::builder()
+                .status(StatusCode::OK)
+                .header("content-type", "application/json")
+                .body(Full::from(config.to_string()))
         }
         (&Method::GET, "/health") => {
-            Ok(Response::builder()
+            Response::builder()
                 .status(StatusCode::OK)
-                .body(Body::from("OK"))?)
+                .body(Full::from("OK"))
         }
         _ => {
-            Ok(Response::builder()
+            Response::builder()
                 .status(StatusCode::NOT_FOUND)
-                .body(Body::from("Not Found"))?)
+                .body(Full::from("Not Found"))
         }
     }
 }
@@ -418,4 +542,76 @@ mod tests {
         assert_eq!(snapshot.gauges.get("gauge1"), Some(&123.45));
         assert_eq!(snapshot.histograms.get("hist1").unwrap().len(), 1);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_custom_scoring_duration_buckets() {
+        let buckets = vec![0.0001, 0.0005, 0.001, 0.005];
+        let collector = OracleMetricsCollector::with_scoring_duration_buckets(buckets.clone()).unwrap();
+        assert_eq!(collector.scoring_duration_buckets(), buckets.as_slice());
+
+        #[cfg(feature = "prometheus_exporter")]
+        {
+            let family = collector
+                .prometheus_registry
+                .gather()
+                .into_iter()
+                .find(|f| f.name() == "oracle_scoring_duration_seconds")
+                .unwrap();
+            let bounds: Vec<f64> = family.get_metric()[0]
+                .get_histogram()
+                .get_bucket()
+                .iter()
+                .map(|b| b.upper_bound())
+                .collect();
+            assert_eq!(bounds, buckets);
+        }
+
+        let default_collector = OracleMetricsCollector::new();
+        assert_eq!(default_collector.scoring_duration_buckets(), DEFAULT_SCORING_DURATION_BUCKETS.as_slice());
+    }
+
+    #[test]
+    fn test_invalid_scoring_duration_buckets_are_rejected() {
+        for buckets in [vec![], vec![0.1, 0.01], vec![0.1, 0.1, 1.0], vec![0.1, f64::NAN], vec![0.1, f64::INFINITY]] {
+            assert!(
+                OracleMetricsCollector::with_scoring_duration_buckets(buckets.clone()).is_err(),
+                "accepted {:?}", buckets
+            );
+        }
+    }
+
+    #[cfg(feature = "prometheus_exporter")]
+    #[tokio::test]
+    async fn test_metrics_server_routes() {
+        struct FixedConfig;
+
+        #[async_trait]
+        impl EffectiveConfigProvider for FixedConfig {
+            async fn effective_config_json(&self) -> serde_json::Value {
+                serde_json::json!({ "regime": "LowActivity" })
+            }
+        }
+
+        let addr = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().to_string();
+        let collector = OracleMetricsCollector::new();
+        collector.increment_counter("oracle_scored_total").await;
+        let server_addr = addr.clone();
+        tokio::spawn(async move {
+            collector.start_metrics_server(&server_addr, Some(Arc::new(FixedConfig))).await.unwrap();
+        });
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let config: serde_json::Value = reqwest::get(format!("http://{}/config", addr)).await.unwrap().json().await.unwrap();
+        assert_eq!(config["regime"], "LowActivity");
+
+        let metrics = reqwest::get(format!("http://{}/metrics", addr)).await.unwrap();
+        assert!(metrics.status().is_success());
+        assert!(metrics.text().await.unwrap().contains("oracle_scored_total 1"));
+
+        let client = reqwest::Client::new();
+        let posted = client.post(format!("http://{}/metrics", addr)).send().await.unwrap();
+        assert_eq!(posted.status(), reqwest::StatusCode::NOT_FOUND);
+        let unknown = reqwest::get(format!("http://{}/unknown", addr)).await.unwrap();
+        assert_eq!(unknown.status(), reqwest::StatusCode::NOT_FOUND);
+    }
+}
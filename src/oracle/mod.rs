@@ -6,34 +6,65 @@
 pub mod types;
 pub mod types_old; // Old types that are still in use
 pub mod storage; // Storage abstraction layer
+pub mod postgres_ledger; // PostgreSQL LedgerStorage backend
+#[cfg(test)]
+mod storage_conformance; // Runs every LedgerStorage backend through the same scenario
+#[cfg(test)]
+mod test_support; // Shared unit test fixtures
 pub mod decision_ledger;
 pub mod transaction_monitor;
 pub mod performance_monitor;
 pub mod strategy_optimizer;
 pub mod market_regime_detector; // Pillar III
 pub mod data_sources; // For MarketRegimeDetector
-pub mod quantum_oracle; // Universe-Class Predictive Oracle
+pub mod quantum_oracle; // Simple oracle for the hot-swap demonstration
+pub mod circuit_breaker; // RPC endpoint health
+pub mod rate_limit; // Adaptive request rate limiting
+pub mod weights; // Adaptive feature weights
+pub mod decay; // Exponential time decay
+pub mod metrics; // Prometheus metrics and HTTP server
+pub mod anomaly; // Anomaly detection
+pub mod features; // Feature extraction
+pub mod scorer; // Candidate scoring
+pub mod predictive_oracle; // Full scoring oracle driving the scorer
+pub mod ledger_import; // JSONL trade history importer
+pub mod backtest; // Replays stored decisions through the scorer
+pub mod shutdown; // Cooperative shutdown of the run loops
+pub mod sink; // Destinations for scored candidates
+pub mod kill_switch; // Stops buying after a losing streak
+pub mod pipeline; // Ordered scoring stages with early exit
+pub mod result_stream; // Live scored-candidate feed with catch-up
+pub mod blob_compression; // Compression of the flat ledger's JSON blobs
 
 // Re-export main types
 pub use types::{
-    ScoredCandidate, TransactionRecord, Outcome,
-    DecisionRecordSender, OutcomeUpdateSender,
-    FeatureWeights, ScoreThresholds,
+    Score, ScoreDiff, ScoredCandidate, TransactionRecord, Outcome,
+    DecisionRecordSender, OutcomeUpdate, OutcomeUpdateSender, OutcomeTransition,
+    FeatureWeights, WeightError, WEIGHT_SUM_TOLERANCE, ScoreThresholds,
     PerformanceReport, OptimizedParameters,
     PerformanceReportSender, PerformanceReportReceiver,
     OptimizedParametersSender, OptimizedParametersReceiver,
     // Pillar III types
-    MarketRegime, RegimeSpecificParameters, OracleConfig,
+    MarketRegime, MarketContextSnapshot, RegimeSpecificParameters, JitoBundleScores, OracleConfig, RetryPolicy, RpcCommitment, GuiBackpressurePolicy, ScoreAggregation, EffectiveConfigSnapshot,
+    ScoringTrace, FeatureTrace, ReasonCode, Rejection, ScoreBandStats, SCORE_BAND_WIDTH, SkippedCandidate, TradeAction,
+    score_variance,
 };
 
 // Re-export storage abstraction
-pub use storage::{LedgerStorage, SqliteLedger, SqliteLedgerNormalized};
+pub use postgres_ledger::PostgresLedger;
+pub use storage::{LedgerStorage, SqliteLedger, SqliteLedgerNormalized, SqliteStorageOptions};
+pub use ledger_import::import_from_jsonl;
+pub use backtest::{Backtester, BacktestAggregates, BacktestCheckpoint, BacktestConfig, BacktestResult};
+pub use shutdown::ShutdownToken;
+pub use sink::{ChannelSink, CompositeSink, FileSink, ScoreSink, WebhookSink};
+pub use result_stream::ResultStream;
+pub use blob_compression::BlobCompression;
 
 // Re-export key components
 pub use decision_ledger::DecisionLedger;
-pub use transaction_monitor::{TransactionMonitor, MonitoredTransaction};
+pub use transaction_monitor::{TransactionMonitor, MonitoredTransaction, OutcomeResolver, RpcOutcomeResolver};
 pub use performance_monitor::PerformanceMonitor;
-pub use strategy_optimizer::StrategyOptimizer;
-pub use market_regime_detector::MarketRegimeDetector; // Pillar III
-pub use data_sources::OracleDataSources; // For MarketRegimeDetector
-pub use quantum_oracle::PredictiveOracle; // Universe-Class Predictive Oracle
\ No newline at end of file
+pub use strategy_optimizer::{StrategyOptimizer, ThresholdObjective};
+pub use market_regime_detector::{MarketDataSource, MarketRegimeDetector}; // Pillar III
+pub use data_sources::{CacheStats, OracleDataSources}; // For MarketRegimeDetector
+pub use predictive_oracle::{PredictiveOracle, QueuedCandidate}; // Universe-Class Predictive Oracle
\ No newline at end of file
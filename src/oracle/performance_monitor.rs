@@ -5,19 +5,28 @@
 
 use anyhow::Result;
 use sqlx::{Pool, Sqlite};
+use std::collections::BTreeMap;
 use std::time::Duration;
-use tracing::{info, error};
+use tracing::{info, error, warn};
 
+use crate::oracle::blob_compression::BlobCompression;
+use crate::oracle::shutdown::ShutdownToken;
 use crate::oracle::types::{
-    PerformanceReport, PerformanceReportSender, TransactionRecord, Outcome
+    deserialize_feature_scores, score_variance, PerformanceReport, PerformanceReportSender, TradeAction, TransactionRecord, Outcome,
+    Score,
 };
 
+/// Score variance above which a re-scored mint is reported as unstable
+/// (a standard deviation of 10 score points).
+pub const HIGH_SCORE_VARIANCE: f64 = 100.0;
+
 /// PerformanceMonitor analyzes historical trading performance and generates reports
 pub struct PerformanceMonitor {
     db_pool: Pool<Sqlite>,
     report_sender: PerformanceReportSender,
     analysis_interval: Duration,
     time_window_hours: f64,
+    shutdown: ShutdownToken,
 }
 
 impl PerformanceMonitor {
@@ -33,9 +42,16 @@ impl PerformanceMonitor {
             report_sender,
             analysis_interval: Duration::from_secs(analysis_interval_minutes * 60),
             time_window_hours: time_window_hours as f64,
+            shutdown: ShutdownToken::new(),
         }
     }
 
+    /// Stop `run` once `shutdown` is triggered.
+    pub fn with_shutdown(mut self, shutdown: ShutdownToken) -> Self {
+        self.shutdown = shutdown;
+        self
+    }
+
     /// Main execution loop - periodically analyzes performance
     pub async fn run(self) {
         info!("PerformanceMonitor is running. Analysis every {} minutes.", 
@@ -44,7 +60,13 @@ impl PerformanceMonitor {
         let mut interval = tokio::time::interval(self.analysis_interval);
 
         loop {
-            interval.tick().await;
+            tokio::select! {
+                _ = interval.tick() => {},
+                _ = self.shutdown.triggered() => {
+                    info!("PerformanceMonitor shutdown requested. Shutting down.");
+                    break;
+                }
+            }
             info!("Performing periodic performance analysis...");
             
             match self.analyze_performance().await {
@@ -70,6 +92,10 @@ impl PerformanceMonitor {
         
         // Query historical records from the DecisionLedger database
         let records = self.get_records_since(since_timestamp).await?;
+        let high_score_variance_mints = find_high_score_variance_mints(&records);
+        if !high_score_variance_mints.is_empty() {
+            warn!("Unstable scores for {} mints: {:?}", high_score_variance_mints.len(), high_score_variance_mints);
+        }
         
         // Filter for closed trades (with definitive outcomes)
         let closed_trades: Vec<_> = records.iter()
@@ -77,7 +103,7 @@ impl PerformanceMonitor {
             .collect();
 
         if closed_trades.is_empty() {
-            return Ok(PerformanceReport::default());
+            return Ok(PerformanceReport { high_score_variance_mints, ..Default::default() });
         }
 
         let mut total_profit = 0.0;
@@ -121,7 +147,8 @@ impl PerformanceMonitor {
             } else { 
                 0.0 
             },
-            max_drawdown_percent: 0.0, // TODO: Implement drawdown calculation
+            max_drawdown_percent: compute_max_drawdown(&records),
+            high_score_variance_mints,
         };
 
         Ok(report)
@@ -138,7 +165,7 @@ impl PerformanceMonitor {
             mint: String,
             score: i32,
             reason: String,
-            feature_scores: String,
+            feature_scores: Vec<u8>,
             calculation_time: i64,
             anomaly_detected: bool,
             timestamp_decision_made: i64,
@@ -152,7 +179,9 @@ impl PerformanceMonitor {
             timestamp_transaction_sent: Option<i64>,
             timestamp_outcome_evaluated: Option<i64>,
             actual_outcome: String,
-            market_context_snapshot: String,
+            market_context_snapshot: Vec<u8>,
+            #[sqlx(default)]
+            blob_compression: Option<String>,
         }
 
         let rows: Vec<TransactionRecordRow> = sqlx::query_as(
@@ -168,6 +197,10 @@ impl PerformanceMonitor {
 
         let mut records = Vec::new();
         for row in rows {
+            let compression = BlobCompression::from_column(row.blob_compression.as_deref())?;
+            let feature_scores = compression.decompress(row.feature_scores)?;
+            let market_context_snapshot = compression.decompress(row.market_context_snapshot)?;
+
             // Reconstruct the scored candidate
             let scored_candidate = crate::oracle::types::ScoredCandidate {
                 base: crate::types::PremintCandidate {
@@ -178,14 +211,20 @@ impl PerformanceMonitor {
                     timestamp: row.timestamp_decision_made as u64,
                     instruction_summary: None,
                     is_jito_bundle: None,
+                    funding_source: None,
                 },
                 mint: row.mint.clone(),
-                predicted_score: row.score as u8,
+                predicted_score: Score::clamped(row.score),
                 reason: row.reason,
-                feature_scores: serde_json::from_str(&row.feature_scores)?,
+                feature_scores: deserialize_feature_scores(&feature_scores)?,
                 calculation_time: row.calculation_time as u128,
                 anomaly_detected: row.anomaly_detected,
                 timestamp: row.timestamp_decision_made as u64,
+                data_source_timestamps: std::collections::HashMap::new(), // Not persisted
+                scoring_trace: None, // Not persisted
+                reason_codes: Vec::new(),
+                anomaly_report: None,
+                action: TradeAction::of_stored_decision(row.transaction_signature.as_deref()),
             };
 
             records.push(TransactionRecord {
@@ -202,10 +241,133 @@ impl PerformanceMonitor {
                 timestamp_transaction_sent: row.timestamp_transaction_sent.map(|t| t as u64),
                 timestamp_outcome_evaluated: row.timestamp_outcome_evaluated.map(|t| t as u64),
                 actual_outcome: serde_json::from_str(&row.actual_outcome)?,
-                market_context_snapshot: serde_json::from_str(&row.market_context_snapshot)?,
+                market_context_snapshot: serde_json::from_str(&market_context_snapshot)?,
             });
         }
         
         Ok(records)
     }
-}
\ No newline at end of file
+}
+
+/// Mints whose repeated decisions have a score variance above `HIGH_SCORE_VARIANCE`,
+/// in mint order. Mints decided only once are never flagged, and skipped
+/// candidates do not count as decisions.
+pub fn find_high_score_variance_mints(records: &[TransactionRecord]) -> Vec<String> {
+    // Keyed by decision time too, since a buy is recorded once when decided and again when sent
+    let mut scores: BTreeMap<&str, BTreeMap<u64, u8>> = BTreeMap::new();
+    for record in records.iter().filter(|r| !r.is_skipped_candidate()) {
+        scores
+            .entry(record.scored_candidate.mint.as_str())
+            .or_default()
+            .insert(record.timestamp_decision_made, record.scored_candidate.predicted_score.value());
+    }
+
+    scores
+        .into_iter()
+        .filter(|(_, scores)| {
+            let scores: Vec<u8> = scores.values().copied().collect();
+            score_variance(&scores).is_some_and(|v| v > HIGH_SCORE_VARIANCE)
+        })
+        .map(|(mint, _)| mint.to_string())
+        .collect()
+}
+
+/// Largest peak-to-trough drop of the cumulative PnL curve, in percent of the peak.
+///
+/// `trades_ordered` must be in execution order; only `Profit`/`Loss` outcomes
+/// contribute. The curve starts at zero equity, so drops are measured only
+/// from positive peaks; a curve that never goes above zero has no drawdown.
+pub fn compute_max_drawdown(trades_ordered: &[TransactionRecord]) -> f64 {
+    let mut equity = 0.0;
+    let mut peak = 0.0;
+    let mut max_drawdown: f64 = 0.0;
+
+    for trade in trades_ordered {
+        let pnl = match trade.actual_outcome {
+            Outcome::Profit(pnl) | Outcome::Loss(pnl) => pnl,
+            _ => continue,
+        };
+
+        equity += pnl;
+        if equity > peak {
+            peak = equity;
+        } else if peak > 0.0 {
+            max_drawdown = max_drawdown.max((peak - equity) / peak * 100.0);
+        }
+    }
+
+    max_drawdown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oracle::test_support::RecordBuilder;
+    use crate::oracle::types::{ReasonCode, SkippedCandidate};
+
+    fn create_trade(outcome: Outcome) -> TransactionRecord {
+        RecordBuilder::new("DrawdownMint").score(80).outcome(outcome).build()
+    }
+
+    #[test]
+    fn test_max_drawdown_tracks_running_peak() {
+        // Equity: 10 -> 5 -> 7 -> -1; the worst drop is from the peak of 10 to -1
+        let trades = vec![
+            create_trade(Outcome::Profit(10.0)),
+            create_trade(Outcome::Loss(-5.0)),
+            create_trade(Outcome::Profit(2.0)),
+            create_trade(Outcome::Loss(-8.0)),
+        ];
+        assert!((compute_max_drawdown(&trades) - 110.0).abs() < 1e-9);
+
+        // Equity: 10 -> 5 -> 20 -> 16; the later dip is only 20% of the new peak
+        let trades = vec![
+            create_trade(Outcome::Profit(10.0)),
+            create_trade(Outcome::Loss(-5.0)),
+            create_trade(Outcome::Profit(15.0)),
+            create_trade(Outcome::Loss(-4.0)),
+        ];
+        assert!((compute_max_drawdown(&trades) - 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_max_drawdown_ignores_unresolved_and_monotonic_curves() {
+        let trades = vec![
+            create_trade(Outcome::Profit(1.0)),
+            create_trade(Outcome::PendingConfirmation),
+            create_trade(Outcome::Profit(2.0)),
+        ];
+        assert_eq!(compute_max_drawdown(&trades), 0.0);
+        assert_eq!(compute_max_drawdown(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_high_score_variance_mints() {
+        let decision = |mint: &str, decided_at: u64, score: u8| {
+            RecordBuilder::new(mint).decided_at(decided_at).score(score).build()
+        };
+        let skip = |mint: &str, decided_at: u64| {
+            SkippedCandidate {
+                candidate: RecordBuilder::new(mint).build().scored_candidate.base,
+                reason_code: ReasonCode::RateLimited,
+                timestamp: decided_at,
+            }
+            .into_record()
+        };
+
+        let records = vec![
+            // Variance 400: far apart scores for the same data
+            decision("NoisyMint", 1, 40),
+            decision("NoisyMint", 2, 80),
+            // Variance 4: small jitter is tolerated
+            decision("StableMint", 1, 70),
+            decision("StableMint", 2, 74),
+            // A single decision has no variance
+            decision("OnceMint", 1, 10),
+            // A skip is not a scored decision
+            decision("SkippedMint", 1, 70),
+            skip("SkippedMint", 2),
+        ];
+        assert_eq!(find_high_score_variance_mints(&records), vec!["NoisyMint".to_string()]);
+    }
+}
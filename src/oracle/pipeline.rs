@@ -0,0 +1,389 @@
+//! Scoring pipeline: ordered stages with early exit.
+//!
+//! Rather than fetching every data source before looking at any of it, scoring
+//! runs a configurable sequence of stages: cheap filters on the candidate
+//! itself (blocklist, launchpad program) first, then each fetch together with
+//! the check that needs it (authorities, minimum liquidity), then the
+//! expensive fetches (full holder scan, social APIs). The first stage that
+//! rejects the candidate ends scoring, so later stages never run for it.
+
+use crate::oracle::data_sources::{empty_token_data, finish_token_data, OracleDataSources, TokenDataPart};
+use crate::oracle::types::{OracleConfig, ReasonCode, TokenData};
+use crate::types::PremintCandidate;
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
+use tracing::debug;
+
+/// Built-in scoring stages, as listed in `OracleConfig::scoring_pipeline`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PipelineStage {
+    /// Reject mints and creators on the blocklist
+    Blocklist,
+    /// Reject launchpad programs not in `allowed_programs`
+    ProgramFilter,
+    /// Fetch the mint account; reject active authorities if `reject_active_authorities`
+    AuthorityStatus,
+    /// Fetch the liquidity pool; reject pools below `min_liquidity_filter_sol`
+    MinLiquidity,
+    /// Fetch metadata
+    Metadata,
+    /// Fetch the full holder distribution
+    Holders,
+    /// Fetch volume and transaction data
+    Volume,
+    /// Fetch creator holdings
+    Creator,
+    /// Fetch social activity
+    Social,
+}
+
+/// Every stage, cheap filters first and the expensive fetches last.
+pub const DEFAULT_PIPELINE: [PipelineStage; 9] = [
+    PipelineStage::Blocklist,
+    PipelineStage::ProgramFilter,
+    PipelineStage::AuthorityStatus,
+    PipelineStage::MinLiquidity,
+    PipelineStage::Metadata,
+    PipelineStage::Volume,
+    PipelineStage::Creator,
+    PipelineStage::Holders,
+    PipelineStage::Social,
+];
+
+impl PipelineStage {
+    /// Stable snake_case identifier, matching the serialized form.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PipelineStage::Blocklist => "blocklist",
+            PipelineStage::ProgramFilter => "program_filter",
+            PipelineStage::AuthorityStatus => "authority_status",
+            PipelineStage::MinLiquidity => "min_liquidity",
+            PipelineStage::Metadata => "metadata",
+            PipelineStage::Holders => "holders",
+            PipelineStage::Volume => "volume",
+            PipelineStage::Creator => "creator",
+            PipelineStage::Social => "social",
+        }
+    }
+}
+
+/// What a stage decided about the candidate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StageVerdict {
+    /// Go on with the next stage
+    Continue,
+    /// Stop scoring the candidate
+    Reject(ReasonCode),
+}
+
+/// The candidate being scored and the token data fetched for it so far.
+pub struct StageContext<'a> {
+    pub candidate: &'a PremintCandidate,
+    pub config: &'a OracleConfig,
+    pub token_data: TokenData,
+    data_sources: &'a OracleDataSources,
+    /// Parts already in `token_data`
+    fetched: HashSet<TokenDataPart>,
+}
+
+impl StageContext<'_> {
+    /// Make sure `part` is in `token_data`, fetching it unless an earlier stage did.
+    pub async fn fetch(&mut self, part: TokenDataPart) -> Result<()> {
+        if !self.fetched.contains(&part) {
+            self.data_sources.fetch_token_data_part(part, self.candidate, &mut self.token_data).await?;
+            self.fetched.insert(part);
+        }
+        Ok(())
+    }
+}
+
+/// One step of the scoring pipeline.
+#[async_trait]
+pub trait ScoringStage: Send + Sync {
+    /// Name used in logs and rejection reasons.
+    fn name(&self) -> &str;
+
+    /// Fetch what the stage needs through `ctx` and decide whether scoring goes on.
+    async fn run(&self, ctx: &mut StageContext<'_>) -> Result<StageVerdict>;
+}
+
+#[async_trait]
+impl ScoringStage for PipelineStage {
+    fn name(&self) -> &str {
+        self.as_str()
+    }
+
+    async fn run(&self, ctx: &mut StageContext<'_>) -> Result<StageVerdict> {
+        let (candidate, config) = (ctx.candidate, ctx.config);
+        let rejected = match self {
+            PipelineStage::Blocklist => {
+                (config.blocklist.contains(&candidate.mint) || config.blocklist.contains(&candidate.creator))
+                    .then_some(ReasonCode::Blocklisted)
+            }
+            PipelineStage::ProgramFilter => {
+                (!config.allowed_programs.is_empty() && !config.allowed_programs.contains(&candidate.program))
+                    .then_some(ReasonCode::ProgramNotAllowed)
+            }
+            PipelineStage::AuthorityStatus => {
+                ctx.fetch(TokenDataPart::MintAccount).await?;
+                let active = ctx.token_data.mint_authority_active == Some(true)
+                    || ctx.token_data.freeze_authority_active == Some(true);
+                (config.reject_active_authorities && active).then_some(ReasonCode::ActiveAuthority)
+            }
+            PipelineStage::MinLiquidity => {
+                ctx.fetch(TokenDataPart::Liquidity).await?;
+                let pool_sol = ctx.token_data.liquidity_pool.as_ref().map_or(0.0, |pool| pool.sol_amount);
+                config
+                    .min_liquidity_filter_sol
+                    .filter(|&min_sol| pool_sol < min_sol)
+                    .map(|_| ReasonCode::LowLiquidity)
+            }
+            PipelineStage::Metadata => {
+                ctx.fetch(TokenDataPart::Metadata).await?;
+                None
+            }
+            PipelineStage::Holders => {
+                ctx.fetch(TokenDataPart::Holders).await?;
+                None
+            }
+            PipelineStage::Volume => {
+                ctx.fetch(TokenDataPart::Volume).await?;
+                None
+            }
+            PipelineStage::Creator => {
+                ctx.fetch(TokenDataPart::Creator).await?;
+                None
+            }
+            PipelineStage::Social => {
+                ctx.fetch(TokenDataPart::Social).await?;
+                None
+            }
+        };
+
+        Ok(rejected.map_or(StageVerdict::Continue, StageVerdict::Reject))
+    }
+}
+
+/// Result of running the pipeline for a candidate.
+#[derive(Debug)]
+pub enum PipelineOutcome {
+    /// Every stage passed; the token data is complete
+    Passed(Box<TokenData>),
+    /// The named stage rejected the candidate
+    Rejected { stage: String, reason_code: ReasonCode },
+}
+
+/// Scoring stages run in order until one rejects the candidate.
+#[derive(Clone)]
+pub struct ScoringPipeline {
+    stages: Vec<Arc<dyn ScoringStage>>,
+}
+
+impl ScoringPipeline {
+    /// Pipeline running `stages` in the given order.
+    pub fn new(stages: Vec<Arc<dyn ScoringStage>>) -> Self {
+        Self { stages }
+    }
+
+    /// The built-in stages, in `config.scoring_pipeline` order.
+    pub fn from_config(config: &OracleConfig) -> Self {
+        Self::new(
+            config
+                .scoring_pipeline
+                .iter()
+                .map(|stage| Arc::new(*stage) as Arc<dyn ScoringStage>)
+                .collect(),
+        )
+    }
+
+    /// Run the stages for `candidate`, stopping at the first rejection.
+    ///
+    /// Data no stage fetched is fetched once every stage has passed, so
+    /// candidates that pass are scored on complete (and then cached) data.
+    /// Recently cached data skips the fetches but not the checks.
+    pub async fn run(
+        &self,
+        candidate: &PremintCandidate,
+        data_sources: &OracleDataSources,
+        config: &OracleConfig,
+    ) -> Result<PipelineOutcome> {
+        let cached = data_sources.cached_token_data(candidate).await;
+        let from_cache = cached.is_some();
+        let mut ctx = StageContext {
+            candidate,
+            config,
+            token_data: cached.unwrap_or_else(|| empty_token_data(candidate)),
+            data_sources,
+            fetched: if from_cache { TokenDataPart::ALL.into_iter().collect() } else { HashSet::new() },
+        };
+
+        for stage in &self.stages {
+            if let StageVerdict::Reject(reason_code) = stage.run(&mut ctx).await? {
+                debug!("Stage {} rejected {}: {}", stage.name(), candidate.mint, reason_code);
+                return Ok(PipelineOutcome::Rejected { stage: stage.name().to_string(), reason_code });
+            }
+        }
+
+        if !from_cache {
+            for part in TokenDataPart::ALL {
+                ctx.fetch(part).await?;
+            }
+            finish_token_data(&mut ctx.token_data);
+            data_sources.cache_token_data(candidate, ctx.token_data.clone()).await;
+        }
+        Ok(PipelineOutcome::Passed(Box::new(ctx.token_data)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oracle::data_sources::RpcClient;
+    use reqwest::Client;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Stage counting how often it runs.
+    struct CountingStage {
+        calls: AtomicUsize,
+        verdict: StageVerdict,
+    }
+
+    impl CountingStage {
+        fn new(verdict: StageVerdict) -> Arc<Self> {
+            Arc::new(Self { calls: AtomicUsize::new(0), verdict })
+        }
+    }
+
+    #[async_trait]
+    impl ScoringStage for CountingStage {
+        fn name(&self) -> &str {
+            "counting"
+        }
+
+        async fn run(&self, _ctx: &mut StageContext<'_>) -> Result<StageVerdict> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(self.verdict)
+        }
+    }
+
+    fn create_candidate(creator: &str, program: &str) -> PremintCandidate {
+        PremintCandidate {
+            mint: format!("pipeline_mint_{}", creator),
+            creator: creator.to_string(),
+            program: program.to_string(),
+            slot: 1,
+            timestamp: 1_640_995_200,
+            instruction_summary: None,
+            is_jito_bundle: None,
+            funding_source: None,
+        }
+    }
+
+    fn create_data_sources(config: &OracleConfig) -> (OracleDataSources, Arc<RpcClient>) {
+        let rpc = Arc::new(RpcClient::new("http://localhost:8899"));
+        (OracleDataSources::new(vec![rpc.clone()], Client::new(), config.clone()), rpc)
+    }
+
+    #[tokio::test]
+    async fn test_rejection_skips_later_stages() {
+        let config = OracleConfig {
+            blocklist: ["known_rugger".to_string()].into_iter().collect(),
+            ..OracleConfig::default()
+        };
+        let (data_sources, rpc) = create_data_sources(&config);
+        let later = CountingStage::new(StageVerdict::Continue);
+        let pipeline = ScoringPipeline::new(vec![Arc::new(PipelineStage::Blocklist), later.clone()]);
+
+        let outcome = pipeline.run(&create_candidate("known_rugger", "pump.fun"), &data_sources, &config).await.unwrap();
+        match outcome {
+            PipelineOutcome::Rejected { stage, reason_code } => {
+                assert_eq!(stage, "blocklist");
+                assert_eq!(reason_code, ReasonCode::Blocklisted);
+            }
+            other => panic!("Expected a rejection, got {:?}", other),
+        }
+        assert_eq!(later.calls.load(Ordering::SeqCst), 0);
+        assert!(rpc.requested_commitments().is_empty());
+
+        // A stub rejecting first keeps the built-in stages from running too
+        let first = CountingStage::new(StageVerdict::Reject(ReasonCode::ProgramNotAllowed));
+        let pipeline = ScoringPipeline::new(vec![first.clone(), Arc::new(PipelineStage::AuthorityStatus), later.clone()]);
+        let outcome = pipeline.run(&create_candidate("honest_dev", "pump.fun"), &data_sources, &config).await.unwrap();
+        assert!(matches!(outcome, PipelineOutcome::Rejected { reason_code: ReasonCode::ProgramNotAllowed, .. }));
+        assert_eq!(first.calls.load(Ordering::SeqCst), 1);
+        assert_eq!(later.calls.load(Ordering::SeqCst), 0);
+        assert!(rpc.requested_commitments().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_passing_candidate_runs_every_stage_on_complete_data() {
+        let config = OracleConfig {
+            allowed_programs: vec!["pump.fun".to_string()],
+            ..OracleConfig::default()
+        };
+        let (data_sources, _rpc) = create_data_sources(&config);
+        let last = CountingStage::new(StageVerdict::Continue);
+        let mut stages = ScoringPipeline::from_config(&config).stages;
+        stages.push(last.clone());
+        let pipeline = ScoringPipeline::new(stages);
+
+        let outcome = pipeline.run(&create_candidate("honest_dev", "pump.fun"), &data_sources, &config).await.unwrap();
+        let PipelineOutcome::Passed(token_data) = outcome else {
+            panic!("Expected the candidate to pass, got {:?}", outcome);
+        };
+        assert_eq!(last.calls.load(Ordering::SeqCst), 1);
+        assert!(!token_data.holder_distribution.is_empty());
+        assert_eq!(token_data.holder_history.len(), 1);
+        assert_eq!(token_data.volume_data.transaction_count, 150);
+    }
+
+    #[tokio::test]
+    async fn test_default_pipeline_filters_before_fetching() {
+        let config = OracleConfig {
+            allowed_programs: vec!["raydium".to_string()],
+            ..OracleConfig::default()
+        };
+        let (data_sources, rpc) = create_data_sources(&config);
+        let pipeline = ScoringPipeline::from_config(&config);
+
+        let outcome = pipeline.run(&create_candidate("honest_dev", "pump.fun"), &data_sources, &config).await.unwrap();
+        assert!(matches!(outcome, PipelineOutcome::Rejected { reason_code: ReasonCode::ProgramNotAllowed, .. }));
+        assert!(rpc.requested_commitments().is_empty());
+
+        // Without a liquidity filter every source is fetched and the candidate passes
+        let config = OracleConfig::default();
+        let (data_sources, full_rpc) = create_data_sources(&config);
+        let outcome = ScoringPipeline::from_config(&config)
+            .run(&create_candidate("honest_dev", "pump.fun"), &data_sources, &config)
+            .await
+            .unwrap();
+        assert!(matches!(outcome, PipelineOutcome::Passed(_)));
+
+        let config = OracleConfig { min_liquidity_filter_sol: Some(1.0), ..OracleConfig::default() };
+        let (data_sources, rpc) = create_data_sources(&config);
+        let outcome = ScoringPipeline::from_config(&config)
+            .run(&create_candidate("honest_dev", "pump.fun"), &data_sources, &config)
+            .await
+            .unwrap();
+        // Without a pool the liquidity check fails before the remaining sources are fetched
+        assert!(matches!(outcome, PipelineOutcome::Rejected { reason_code: ReasonCode::LowLiquidity, .. }));
+        assert!(!rpc.requested_commitments().is_empty());
+        assert!(rpc.requested_commitments().len() < full_rpc.requested_commitments().len());
+    }
+
+    #[test]
+    fn test_pipeline_order_from_config() {
+        let mut value = serde_json::to_value(OracleConfig::default()).unwrap();
+        value["scoring_pipeline"] = serde_json::json!(["program_filter", "blocklist", "holders"]);
+        let config: OracleConfig = serde_json::from_value(value).unwrap();
+        assert_eq!(
+            config.scoring_pipeline,
+            vec![PipelineStage::ProgramFilter, PipelineStage::Blocklist, PipelineStage::Holders]
+        );
+        assert_eq!(OracleConfig::default().scoring_pipeline, DEFAULT_PIPELINE.to_vec());
+    }
+}
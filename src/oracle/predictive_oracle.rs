@@ -0,0 +1,1245 @@
+//! Main Oracle orchestrator - Universe-Class predictive oracle implementation.
+//!
+//! This module contains the main PredictiveOracle that orchestrates all the 
+//! modular components to provide comprehensive token scoring.
+
+use crate::oracle::types::{
+    ScoredCandidate, OracleConfig, EffectiveConfigSnapshot, MarketRegime,
+    DecisionRecordSender, ReasonCode, SkippedCandidate, TradeAction,
+};
+use crate::oracle::quantum_oracle::OracleMetrics;
+use crate::oracle::anomaly::AnomalyFeedSender;
+use crate::oracle::scorer::OracleScorer;
+use crate::oracle::data_sources::{OracleDataSources, RpcClient};
+use crate::oracle::metrics::{EffectiveConfigProvider, OracleMetricsCollector};
+use crate::oracle::circuit_breaker::CircuitBreaker;
+use crate::oracle::kill_switch::{KillSwitch, DEFAULT_REFRESH_INTERVAL as KILL_SWITCH_REFRESH_INTERVAL};
+use crate::oracle::rate_limit::AdaptiveRateLimiter;
+use crate::oracle::storage::LedgerStorage;
+use crate::oracle::shutdown::ShutdownToken;
+use crate::oracle::result_stream::ResultStream;
+use crate::oracle::sink::{ChannelSink, CompositeSink, FileSink, ScoreSink, WebhookSink};
+use crate::oracle::weights::RecalcSchedule;
+use crate::types::{PremintCandidate, QuantumCandidateGui};
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use futures::Stream;
+use moka::future::Cache;
+use reqwest::Client;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, Mutex, RwLock, Semaphore};
+use tracing::{debug, info, warn, instrument};
+
+/// A candidate on its way to the oracle, stamped when it was queued so the
+/// oracle can tell how long it waited to be scored.
+#[derive(Debug, Clone)]
+pub struct QueuedCandidate {
+    pub candidate: PremintCandidate,
+    pub enqueued_at: Instant,
+}
+
+impl From<PremintCandidate> for QueuedCandidate {
+    /// Stamp `candidate` as queued now.
+    fn from(candidate: PremintCandidate) -> Self {
+        Self {
+            candidate,
+            enqueued_at: Instant::now(),
+        }
+    }
+}
+
+/// Main Universe-Class Predictive Oracle.
+pub struct PredictiveOracle {
+    pub candidate_receiver: mpsc::Receiver<QueuedCandidate>,
+    pub scored_sender: mpsc::Sender<ScoredCandidate>,
+    pub gui_suggestions: Arc<Mutex<Option<mpsc::Sender<QuantumCandidateGui>>>>,
+    pub config: Arc<RwLock<OracleConfig>>,
+    /// Receives a record for every candidate skipped without scoring
+    decision_sender: Option<DecisionRecordSender>,
+    /// Rejection log for skipped and below-threshold candidates
+    rejection_storage: Option<Arc<dyn LedgerStorage>>,
+    /// Receives every scored candidate
+    score_sink: Arc<CompositeSink>,
+    
+    // Core components
+    scorer: OracleScorer,
+    metrics_collector: Arc<OracleMetricsCollector>,
+    circuit_breaker: Arc<Mutex<CircuitBreaker>>,
+    rate_limiter: Arc<Mutex<AdaptiveRateLimiter>>,
+    
+    // Infrastructure
+    token_cache: Cache<String, (Instant, String)>, // Simplified cache for now
+    request_semaphore: Arc<Semaphore>,
+    max_parallel_requests: usize,
+    shutdown: ShutdownToken,
+    
+    // State tracking
+    /// Scoring history and live feed for subscribers
+    results: ResultStream,
+    adaptive_recalc_schedule: Arc<Mutex<RecalcSchedule>>,
+    current_regime: Arc<RwLock<MarketRegime>>,
+    warmup: Arc<Warmup>,
+    kill_switch: Arc<KillSwitch>,
+}
+
+/// Start-up period during which the oracle scores candidates but never acts.
+struct Warmup {
+    started: Instant,
+    candidates: u64,
+    duration: Duration,
+    scored: AtomicU64,
+}
+
+impl Warmup {
+    fn new(candidates: u64, duration: Duration) -> Self {
+        Self {
+            started: Instant::now(),
+            candidates,
+            duration,
+            scored: AtomicU64::new(0),
+        }
+    }
+
+    /// Count a scored candidate; true if it falls within the warm-up period.
+    fn observe(&self) -> bool {
+        let seen = self.scored.fetch_add(1, Ordering::SeqCst);
+        seen < self.candidates || self.started.elapsed() < self.duration
+    }
+}
+
+/// Read-only view used to serve the effective configuration over HTTP.
+struct EffectiveConfigView {
+    scorer: OracleScorer,
+    config: Arc<RwLock<OracleConfig>>,
+    current_regime: Arc<RwLock<MarketRegime>>,
+}
+
+impl EffectiveConfigView {
+    async fn snapshot(&self) -> EffectiveConfigSnapshot {
+        let regime = *self.current_regime.read().await;
+        let mut snapshot = self.scorer.effective_config(regime).await;
+        // The scorer here is a copy; base weights may have been hot-swapped since
+        snapshot.base_weights = self.config.read().await.weights.clone();
+        snapshot
+    }
+}
+
+#[async_trait]
+impl EffectiveConfigProvider for EffectiveConfigView {
+    async fn effective_config_json(&self) -> serde_json::Value {
+        serde_json::to_value(self.snapshot().await).unwrap_or_default()
+    }
+}
+
+impl PredictiveOracle {
+    /// Create a new Universe-Class Predictive Oracle.
+    pub fn new(
+        candidate_receiver: mpsc::Receiver<QueuedCandidate>,
+        scored_sender: mpsc::Sender<ScoredCandidate>,
+        config: Arc<RwLock<OracleConfig>>,
+    ) -> Result<Self> {
+        // Read config for validation and initial setup
+        let config_snapshot = config
+            .try_read()
+            .map_err(|_| anyhow!("Oracle config is locked during construction"))?
+            .clone();
+        
+        // Validate configuration
+        if config_snapshot.rpc_endpoints.is_empty() {
+            return Err(anyhow!("At least one RPC endpoint is required"));
+        }
+
+        // Create RPC clients
+        let rpc_clients: Vec<Arc<RpcClient>> = config_snapshot
+            .rpc_endpoints
+            .iter()
+            .map(|endpoint| Arc::new(RpcClient::new(endpoint)))
+            .collect();
+
+        // Create HTTP client
+        let http_client = Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()?;
+
+        // Create GUI suggestions channel
+        let gui_suggestions = Arc::new(Mutex::new(None));
+
+        // Scored candidates go to the scored channel plus any configured log or webhook
+        let mut score_sink = CompositeSink::new(vec![Box::new(ChannelSink::new(scored_sender.clone()))]);
+        if let Some(path) = &config_snapshot.score_log_path {
+            score_sink.push(Box::new(FileSink::new(path)?));
+        }
+        if let Some(url) = &config_snapshot.score_webhook_url {
+            score_sink.push(Box::new(
+                WebhookSink::new(http_client.clone(), url.clone())
+                    .with_timeout(Duration::from_millis(config_snapshot.score_webhook_timeout_ms)),
+            ));
+        }
+
+        // Create scorer
+        let scorer = OracleScorer::new(
+            scored_sender.clone(),
+            gui_suggestions.clone(),
+            rpc_clients,
+            http_client,
+            config_snapshot.clone(),
+        );
+
+        // Create supporting components
+        let metrics_collector = Arc::new(OracleMetricsCollector::with_scoring_duration_buckets(
+            config_snapshot.scoring_duration_buckets.clone(),
+        )?);
+        
+        // Shared with the data sources, which skip endpoints whose circuit is open
+        let circuit_breaker = scorer.data_sources.circuit_breaker();
+
+        let rate_limiter = Arc::new(Mutex::new(AdaptiveRateLimiter::new(
+            config_snapshot.rate_limit_requests_per_second,
+            config_snapshot.adaptive_error_rate_window,
+            0.2, // 20% error threshold
+        )));
+
+        // Create cache
+        let token_cache = Cache::builder()
+            .max_capacity(config_snapshot.max_cache_entries as u64)
+            .time_to_live(Duration::from_secs(config_snapshot.cache_ttl_seconds))
+            .build();
+
+        let request_semaphore = Arc::new(Semaphore::new(config_snapshot.max_parallel_requests));
+
+        info!("Created Universe-Class Predictive Oracle with {} RPC endpoints", config_snapshot.rpc_endpoints.len());
+
+        Ok(Self {
+            candidate_receiver,
+            scored_sender,
+            gui_suggestions,
+            config,
+            decision_sender: None,
+            rejection_storage: None,
+            score_sink: Arc::new(score_sink),
+            scorer,
+            metrics_collector,
+            circuit_breaker,
+            rate_limiter,
+            token_cache,
+            request_semaphore,
+            max_parallel_requests: config_snapshot.max_parallel_requests,
+            shutdown: ShutdownToken::new(),
+            results: ResultStream::new(),
+            adaptive_recalc_schedule: Arc::new(Mutex::new(RecalcSchedule::new(
+                config_snapshot.adaptive_recalc_interval,
+                config_snapshot.adaptive_recalc_jitter,
+            ))),
+            current_regime: Arc::new(RwLock::new(MarketRegime::default())),
+            warmup: Arc::new(Warmup::new(
+                config_snapshot.warmup_candidates,
+                Duration::from_secs(config_snapshot.warmup_duration_seconds),
+            )),
+            kill_switch: Arc::new(
+                KillSwitch::new(config_snapshot.kill_switch_consecutive_losses)
+                    .with_lookback_seconds(config_snapshot.kill_switch_lookback_seconds),
+            ),
+        })
+    }
+
+    /// Share the regime state maintained by a `MarketRegimeDetector`.
+    pub fn set_regime_handle(&mut self, current_regime: Arc<RwLock<MarketRegime>>) {
+        self.current_regime = current_regime;
+    }
+
+    /// RPC rate limiter, to be handed to `MarketRegimeDetector::with_rate_limiter`.
+    pub fn rate_limiter(&self) -> Arc<Mutex<AdaptiveRateLimiter>> {
+        self.rate_limiter.clone()
+    }
+
+    /// Data sources used for scoring, whose token cache backs the monitor's last-price fallback.
+    pub fn data_sources(&self) -> Arc<OracleDataSources> {
+        self.scorer.data_sources.clone()
+    }
+
+    /// Check candidates' creators against the trade history in `storage`.
+    pub fn set_ledger(&mut self, storage: Arc<dyn LedgerStorage>) {
+        self.scorer.set_ledger(storage);
+    }
+
+    /// Publish `(mint, report)` for every scored candidate with anomalies.
+    pub fn set_anomaly_sender(&mut self, sender: AnomalyFeedSender) {
+        self.scorer.set_anomaly_sender(sender);
+    }
+
+    /// Record skipped candidates in the decision ledger.
+    pub fn set_decision_sender(&mut self, sender: DecisionRecordSender) {
+        self.decision_sender = Some(sender);
+    }
+
+    /// Log every rejected candidate, with its reason code, to `storage`.
+    pub fn set_rejection_storage(&mut self, storage: Arc<dyn LedgerStorage>) {
+        self.rejection_storage = Some(storage);
+    }
+
+    /// Track losing streaks for the kill switch in `storage`'s closed trades, saving resets there too.
+    pub fn set_kill_switch_storage(&mut self, storage: Arc<dyn LedgerStorage>) {
+        self.kill_switch.set_storage(storage);
+    }
+
+    /// Kill switch, shared so it can be reset while `run` holds the oracle.
+    pub fn kill_switch(&self) -> Arc<KillSwitch> {
+        self.kill_switch.clone()
+    }
+
+    /// Scoring history and live feed, shared so consumers can subscribe while `run` holds the oracle.
+    pub fn result_stream(&self) -> ResultStream {
+        self.results.clone()
+    }
+
+    /// The last `replay_last` scored candidates, then every candidate scored from now on.
+    pub fn subscribe(&self, replay_last: usize) -> impl Stream<Item = ScoredCandidate> + Send + 'static {
+        self.results.subscribe(replay_last)
+    }
+
+    /// Resume buying after the kill switch tripped.
+    pub async fn reset_kill_switch(&self) -> Result<()> {
+        self.kill_switch.reset().await
+    }
+
+    /// Emit scored candidates to `sinks` instead of the configured defaults.
+    pub fn set_score_sinks(&mut self, sinks: Vec<Box<dyn ScoreSink>>) {
+        self.score_sink = Arc::new(CompositeSink::new(sinks));
+    }
+
+    /// Stop `run` once `shutdown` is triggered, after in-flight candidates are scored.
+    pub fn set_shutdown_token(&mut self, shutdown: ShutdownToken) {
+        self.shutdown = shutdown;
+    }
+
+    /// Weights and thresholds currently used for scoring, including adaptive
+    /// adjustments and the active regime's parameters.
+    pub async fn effective_config(&self) -> EffectiveConfigSnapshot {
+        self.config_view().snapshot().await
+    }
+
+    fn config_view(&self) -> EffectiveConfigView {
+        EffectiveConfigView {
+            scorer: self.scorer.clone(),
+            config: self.config.clone(),
+            current_regime: self.current_regime.clone(),
+        }
+    }
+
+    /// Hand weights and thresholds hot-swapped into the shared config to the scorer.
+    async fn apply_hot_swapped_config(&mut self) {
+        let (weights, thresholds) = {
+            let config = self.config.read().await;
+            if config.weights == self.scorer.config.weights && config.thresholds == self.scorer.config.thresholds {
+                return;
+            }
+            (config.weights.clone(), config.thresholds.clone())
+        };
+
+        self.scorer.set_base_parameters(weights, thresholds).await;
+        info!("Scoring with hot-swapped weights and thresholds");
+    }
+
+    /// Set GUI sender for notifications.
+    pub fn set_gui_sender(&self, sender: mpsc::Sender<QuantumCandidateGui>) {
+        tokio::spawn({
+            let gui_suggestions = self.gui_suggestions.clone();
+            async move {
+                let mut gui_lock = gui_suggestions.lock().await;
+                *gui_lock = Some(sender);
+            }
+        });
+    }
+
+    /// Main execution loop.
+    #[instrument(skip(self))]
+    pub async fn run(&mut self) {
+        info!("Starting Universe-Class Predictive Oracle main loop");
+        let run_config = self.config.read().await.clone();
+
+        // Start metrics server if configured
+        if let Some(metrics_addr) = &run_config.metrics_http_listen {
+            let metrics_collector = self.metrics_collector.clone();
+            let addr = metrics_addr.clone();
+            let config_view: Arc<dyn EffectiveConfigProvider> = Arc::new(self.config_view());
+            tokio::spawn(async move {
+                if let Err(e) = metrics_collector.start_metrics_server(&addr, Some(config_view)).await {
+                    warn!("Failed to start metrics server: {}", e);
+                }
+            });
+        }
+
+        let shutdown = self.shutdown.clone();
+
+        // Keep the kill switch up to date with the ledger's closed trades
+        if self.kill_switch.is_watching() {
+            let kill_switch = self.kill_switch.clone();
+            let shutdown = shutdown.clone();
+            tokio::spawn(async move {
+                if let Err(e) = kill_switch.load().await {
+                    warn!("Failed to load kill switch state: {}", e);
+                }
+                let mut interval = tokio::time::interval(KILL_SWITCH_REFRESH_INTERVAL);
+                loop {
+                    tokio::select! {
+                        _ = interval.tick() => {
+                            if let Err(e) = kill_switch.refresh().await {
+                                warn!("Failed to refresh kill switch: {}", e);
+                            }
+                        }
+                        _ = shutdown.triggered() => break,
+                    }
+                }
+            });
+        }
+
+        loop {
+            let candidate = tokio::select! {
+                candidate = self.candidate_receiver.recv() => candidate,
+                _ = shutdown.triggered() => {
+                    info!("Oracle shutdown requested");
+                    None
+                }
+            };
+            let Some(QueuedCandidate { candidate, enqueued_at }) = candidate else {
+                break;
+            };
+
+            // Acquire rate limit and semaphore
+            let permit = self.request_semaphore.clone().acquire_owned().await;
+
+            // Under load the launch opportunity may be gone by the time a slot frees up
+            let queue_wait = enqueued_at.elapsed();
+            if queue_wait > Duration::from_millis(run_config.max_queue_wait_ms) {
+                debug!("Candidate {} waited {}ms for a scoring slot, skipping", candidate.mint, queue_wait.as_millis());
+                self.metrics_collector.increment_counter("oracle_stale_total").await;
+                Self::record_skip(self.rejection_storage.as_deref(), self.decision_sender.as_ref(), candidate, ReasonCode::Stale)
+                    .await;
+                continue;
+            }
+
+            let rate_check = {
+                let mut limiter = self.rate_limiter.lock().await;
+                limiter.check_and_record_request().await
+            };
+
+            if rate_check.is_err() {
+                debug!("Rate limit exceeded, skipping candidate {}", candidate.mint);
+                self.metrics_collector.increment_counter("oracle_rate_limited_total").await;
+                Self::record_skip(self.rejection_storage.as_deref(), self.decision_sender.as_ref(), candidate, ReasonCode::RateLimited)
+                    .await;
+                continue;
+            }
+
+            // Process candidate, concurrently unless `sequential` is set
+            self.apply_hot_swapped_config().await;
+            let scorer = self.scorer.clone();
+            let metrics_collector = self.metrics_collector.clone();
+            let circuit_breaker = self.circuit_breaker.clone();
+            let rate_limiter = self.rate_limiter.clone();
+            let results = self.results.clone();
+            let adaptive_schedule = self.adaptive_recalc_schedule.clone();
+            let current_regime = self.current_regime.clone();
+            let rejection_storage = self.rejection_storage.clone();
+            let decision_sender = self.decision_sender.clone();
+            let score_sink = self.score_sink.clone();
+            let warmup = self.warmup.clone();
+            let kill_switch = self.kill_switch.clone();
+
+            let task = async move {
+                let start_time = Instant::now();
+
+                let regime = *current_regime.read().await;
+
+                match Self::process_candidate(
+                    &scorer,
+                    &candidate,
+                    &regime,
+                    &metrics_collector,
+                    &circuit_breaker,
+                    &rate_limiter,
+                ).await {
+                    // Rejected before any scoring: a skip, but nothing to emit or learn from
+                    Ok(scored) if scored.reason_codes.iter().any(ReasonCode::is_pipeline_rejection) => {
+                        if let Some(reason_code) = scored.reason_codes.into_iter().find(ReasonCode::is_pipeline_rejection) {
+                            Self::record_skip(rejection_storage.as_deref(), decision_sender.as_ref(), candidate, reason_code)
+                                .await;
+                        }
+                    }
+                    Ok(mut scored) => {
+                        // Record metrics
+                        let scoring_duration = start_time.elapsed();
+                        metrics_collector.record_scoring_time(scoring_duration).await;
+                        metrics_collector.increment_counter("oracle_scored_total").await;
+
+                        if scored.predicted_score >= 80 {
+                            metrics_collector.increment_counter("oracle_high_score_total").await;
+                        }
+
+                        // Consumers act on the action, so warm-up and the kill switch hold on everything emitted
+                        let (action, reason_code) = Self::decide_action(&scorer, &warmup, &kill_switch, &scored, &regime);
+                        scored.action = action;
+                        if let (TradeAction::Skip, Some(reason_code)) = (action, reason_code) {
+                            Self::log_rejection(
+                                rejection_storage.as_deref(),
+                                &candidate.mint,
+                                reason_code,
+                                chrono::Utc::now().timestamp_millis() as u64,
+                            ).await;
+                        }
+
+                        // Only suggest candidates the oracle would buy
+                        if action == TradeAction::Buy {
+                            let dropped = scorer.send_gui_notification(&scored).await;
+                            for _ in 0..dropped {
+                                metrics_collector.increment_counter("oracle_gui_notifications_dropped_total").await;
+                            }
+                        }
+
+                        score_sink.emit(&scored).await;
+
+                        // Add to history for adaptive weights and send to subscribers
+                        results.publish(scored.clone()).await;
+
+                        // Check if we should recalculate adaptive weights
+                        let recalc_due = adaptive_schedule.lock().await.record();
+                        if recalc_due {
+                            // Trigger weight recalculation
+                            let history = results.history().await;
+                            if !history.is_empty() {
+                                scorer.update_adaptive_weights(&history).await;
+                                let drift = scorer.adaptive_weights.lock().await.weight_drift();
+                                metrics_collector.increment_counter("oracle_adaptive_recalc_total").await;
+                                metrics_collector.set_gauge("oracle_weight_drift", drift).await;
+                                info!("Updated adaptive weights from {} scored candidates", history.len());
+                            }
+                        }
+
+                        info!("Successfully scored candidate {} with score {} in {}μs",
+                              candidate.mint, scored.predicted_score, scoring_duration.as_micros());
+                    }
+                    Err(e) => {
+                        warn!("Failed to score candidate {}: {}", candidate.mint, e);
+                        
+                        // Record failure for rate limiting
+                        let mut limiter = rate_limiter.lock().await;
+                        limiter.record_failure();
+                    }
+                }
+
+                drop(permit);
+            };
+
+            if run_config.sequential {
+                task.await;
+            } else {
+                tokio::spawn(task);
+            }
+        }
+
+        // Every in-flight candidate holds a permit; wait for all of them to finish
+        let _in_flight = self.request_semaphore.acquire_many(self.max_parallel_requests as u32).await;
+
+        info!("Oracle main loop ended");
+    }
+
+    /// Record a skipped candidate in the rejection log and decision ledger,
+    /// whichever are attached.
+    async fn record_skip(
+        rejection_storage: Option<&dyn LedgerStorage>,
+        decision_sender: Option<&DecisionRecordSender>,
+        candidate: PremintCandidate,
+        reason_code: ReasonCode,
+    ) {
+        let timestamp = chrono::Utc::now().timestamp_millis() as u64;
+        Self::log_rejection(rejection_storage, &candidate.mint, reason_code, timestamp).await;
+
+        let Some(sender) = decision_sender else {
+            return;
+        };
+
+        let skipped = SkippedCandidate {
+            candidate,
+            reason_code,
+            timestamp,
+        };
+        if let Err(e) = sender.send(skipped.into_record()).await {
+            warn!("Failed to record skipped candidate: {}", e);
+        }
+    }
+
+    /// Trade action for a scored candidate, with the reason when skipping.
+    ///
+    /// During warm-up and while the kill switch is tripped every candidate is
+    /// skipped regardless of its score.
+    fn decide_action(
+        scorer: &OracleScorer,
+        warmup: &Warmup,
+        kill_switch: &KillSwitch,
+        scored: &ScoredCandidate,
+        regime: &MarketRegime,
+    ) -> (TradeAction, Option<ReasonCode>) {
+        if warmup.observe() {
+            debug!("Warming up, skipping candidate {}", scored.mint);
+            return (TradeAction::Skip, Some(ReasonCode::Warmup));
+        }
+        if kill_switch.is_tripped() {
+            debug!("Kill switch tripped, skipping candidate {}", scored.mint);
+            return (TradeAction::Skip, Some(ReasonCode::KillSwitch));
+        }
+
+        match scorer.decide_action(scored.predicted_score, regime) {
+            TradeAction::Buy => (TradeAction::Buy, None),
+            TradeAction::Skip => (TradeAction::Skip, Some(ReasonCode::BelowBuyThreshold)),
+        }
+    }
+
+    /// Write a rejection to the rejection log, if one is attached.
+    async fn log_rejection(
+        storage: Option<&dyn LedgerStorage>,
+        mint: &str,
+        reason_code: ReasonCode,
+        timestamp: u64,
+    ) {
+        let Some(storage) = storage else {
+            return;
+        };
+
+        if let Err(e) = storage.record_rejection(mint, reason_code, timestamp).await {
+            warn!("Failed to record rejection of {}: {}", mint, e);
+        }
+    }
+
+    /// Process a single candidate.
+    async fn process_candidate(
+        scorer: &OracleScorer,
+        candidate: &PremintCandidate,
+        regime: &MarketRegime,
+        metrics_collector: &OracleMetricsCollector,
+        circuit_breaker: &Arc<Mutex<CircuitBreaker>>,
+        rate_limiter: &Arc<Mutex<AdaptiveRateLimiter>>,
+    ) -> Result<ScoredCandidate> {
+        debug!("Processing candidate: {}", candidate.mint);
+
+        // Score the candidate
+        let scored = match scorer.score_candidate_with_regime(candidate, regime).await {
+            Ok(scored) => scored,
+            Err(e) => {
+                metrics_collector.increment_counter("oracle_rpc_errors_total").await;
+                return Err(e);
+            }
+        };
+
+        // Record success for circuit breaker and rate limiter
+        {
+            let mut cb = circuit_breaker.lock().await;
+            for endpoint in &scorer.config.rpc_endpoints {
+                cb.record_success(endpoint);
+            }
+        }
+
+        {
+            let mut limiter = rate_limiter.lock().await;
+            limiter.record_success();
+        }
+
+        Ok(scored)
+    }
+
+    /// Update Oracle configuration for hot-swap (Pillar II "Act" phase)
+    #[instrument(skip(self, new_weights, new_thresholds))]
+    pub async fn update_config(&self, new_weights: crate::oracle::types::FeatureWeights, new_thresholds: crate::oracle::types::ScoreThresholds) -> Result<()> {
+        info!("Hot-swapping Oracle configuration...");
+        
+        // Update the shared config
+        {
+            let mut config_guard = self.config.write().await;
+            config_guard.weights = new_weights.clone();
+            config_guard.thresholds = new_thresholds.clone();
+        }
+        
+        info!("Oracle configuration updated successfully:");
+        info!("  New liquidity weight: {:.3}", new_weights.liquidity);
+        info!("  New holder_distribution weight: {:.3}", new_weights.holder_distribution);
+        info!("  New volume_growth weight: {:.3}", new_weights.volume_growth);
+        info!("  New min_liquidity_sol threshold: {:.2}", new_thresholds.min_liquidity_sol);
+        
+        Ok(())
+    }
+
+    /// Get current Oracle metrics.
+    #[instrument(skip(self))]
+    pub async fn get_metrics(&self) -> OracleMetrics {
+        let snapshot = self.metrics_collector.get_metrics_snapshot().await;
+        
+        OracleMetrics {
+            total_scored: *snapshot.counters.get("oracle_scored_total").unwrap_or(&0),
+            avg_scoring_time: *snapshot.gauges.get("oracle_avg_scoring_time_seconds").unwrap_or(&0.0),
+            high_score_count: *snapshot.counters.get("oracle_high_score_total").unwrap_or(&0),
+            cache_hits: *snapshot.counters.get("oracle_cache_hits_total").unwrap_or(&0),
+            cache_misses: *snapshot.counters.get("oracle_cache_misses_total").unwrap_or(&0),
+            rpc_errors: *snapshot.counters.get("oracle_rpc_errors_total").unwrap_or(&0),
+            api_errors: *snapshot.counters.get("oracle_api_errors_total").unwrap_or(&0),
+        }
+    }
+
+    /// Clear the token cache.
+    pub async fn clear_cache(&self) {
+        self.token_cache.invalidate_all();
+        info!("Cleared Oracle token cache");
+    }
+
+    /// Get current cache size.
+    pub async fn get_cache_size(&self) -> usize {
+        self.token_cache.entry_count() as usize
+    }
+
+    /// Graceful shutdown.
+    #[instrument(skip(self))]
+    pub async fn shutdown(&self) {
+        info!("Initiating Oracle graceful shutdown");
+
+        // Flush metrics snapshot
+        let snapshot = self.metrics_collector.get_metrics_snapshot().await;
+        info!("Final metrics - Scored: {}, High scores: {}, Cache hits: {}", 
+              snapshot.counters.get("oracle_scored_total").unwrap_or(&0),
+              snapshot.counters.get("oracle_high_score_total").unwrap_or(&0),
+              snapshot.counters.get("oracle_cache_hits_total").unwrap_or(&0));
+
+        // Clear cache
+        self.clear_cache().await;
+
+        info!("Oracle shutdown complete");
+    }
+}
+
+// Make sure we don't accidentally implement Clone
+// (PredictiveOracle should not be cloneable due to the receiver)
+// The comment from original code explains this is intentional
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oracle::test_support::{isolated_ledger, RecordBuilder};
+    use crate::oracle::types::{OracleConfig, Outcome};
+    use tokio::sync::mpsc;
+
+    fn create_test_config() -> OracleConfig {
+        OracleConfig {
+            rpc_endpoints: vec!["https://api.mainnet-beta.solana.com".to_string()],
+            ..OracleConfig::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_oracle_creation() {
+        let (_candidate_tx, candidate_rx) = mpsc::channel(10);
+        let (scored_tx, _scored_rx) = mpsc::channel(10);
+        let config = Arc::new(RwLock::new(create_test_config()));
+
+        let oracle = PredictiveOracle::new(candidate_rx, scored_tx, config);
+        assert!(oracle.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_oracle_creation_empty_rpc_endpoints() {
+        let (_candidate_tx, candidate_rx) = mpsc::channel(10);
+        let (scored_tx, _scored_rx) = mpsc::channel(10);
+        let mut config = create_test_config();
+        config.rpc_endpoints.clear();
+        let config = Arc::new(RwLock::new(config));
+
+        let oracle = PredictiveOracle::new(candidate_rx, scored_tx, config);
+        assert!(oracle.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_metrics() {
+        let (_candidate_tx, candidate_rx) = mpsc::channel(10);
+        let (scored_tx, _scored_rx) = mpsc::channel(10);
+        let config = Arc::new(RwLock::new(create_test_config()));
+
+        let oracle = PredictiveOracle::new(candidate_rx, scored_tx, config).unwrap();
+        let metrics = oracle.get_metrics().await;
+
+        // Should have default values
+        assert_eq!(metrics.total_scored, 0);
+        assert_eq!(metrics.high_score_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_cache_operations() {
+        let (_candidate_tx, candidate_rx) = mpsc::channel(10);
+        let (scored_tx, _scored_rx) = mpsc::channel(10);
+        let config = Arc::new(RwLock::new(create_test_config()));
+
+        let oracle = PredictiveOracle::new(candidate_rx, scored_tx, config).unwrap();
+        
+        // Initially empty
+        assert_eq!(oracle.get_cache_size().await, 0);
+        
+        // Clear should not panic
+        oracle.clear_cache().await;
+        assert_eq!(oracle.get_cache_size().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_set_gui_sender() {
+        let (_candidate_tx, candidate_rx) = mpsc::channel(10);
+        let (scored_tx, _scored_rx) = mpsc::channel(10);
+        let (gui_tx, _gui_rx) = mpsc::channel(10);
+        let config = Arc::new(RwLock::new(create_test_config()));
+
+        let oracle = PredictiveOracle::new(candidate_rx, scored_tx, config).unwrap();
+        
+        // Should not panic
+        oracle.set_gui_sender(gui_tx);
+        
+        // Give a moment for the spawn to complete
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    #[tokio::test]
+    async fn test_shutdown() {
+        let (_candidate_tx, candidate_rx) = mpsc::channel(10);
+        let (scored_tx, _scored_rx) = mpsc::channel(10);
+        let config = Arc::new(RwLock::new(create_test_config()));
+
+        let oracle = PredictiveOracle::new(candidate_rx, scored_tx, config).unwrap();
+        
+        // Should not panic
+        oracle.shutdown().await;
+    }
+
+    #[tokio::test] 
+    async fn test_update_config() {
+        let (_candidate_tx, candidate_rx) = mpsc::channel(10);
+        let (scored_tx, _scored_rx) = mpsc::channel(10);
+        let config = Arc::new(RwLock::new(create_test_config()));
+
+        let oracle = PredictiveOracle::new(candidate_rx, scored_tx, config.clone()).unwrap();
+        
+        // Create new weights and thresholds
+        let new_weights = crate::oracle::types::FeatureWeights {
+            liquidity: 0.5,
+            holder_distribution: 0.3,
+            volume_growth: 0.2,
+            ..crate::oracle::types::FeatureWeights::default()
+        };
+        let new_thresholds = crate::oracle::types::ScoreThresholds {
+            min_liquidity_sol: 25.0,
+            ..crate::oracle::types::ScoreThresholds::default()
+        };
+        
+        // Update config
+        let result = oracle.update_config(new_weights.clone(), new_thresholds.clone()).await;
+        assert!(result.is_ok());
+        
+        // Verify config was updated
+        let config_guard = config.read().await;
+        assert_eq!(config_guard.weights.liquidity, 0.5);
+        assert_eq!(config_guard.weights.holder_distribution, 0.3);
+        assert_eq!(config_guard.thresholds.min_liquidity_sol, 25.0);
+    }
+
+    #[tokio::test]
+    async fn test_hot_swapped_config_reaches_the_scorer() {
+        let (candidate_tx, candidate_rx) = mpsc::channel(10);
+        let (scored_tx, _scored_rx) = mpsc::channel(10);
+
+        let mut config = create_test_config();
+        config.rpc_endpoints = vec!["http://127.0.0.1:1".to_string()];
+        config.sequential = true;
+        let config = Arc::new(RwLock::new(config));
+        let mut oracle = PredictiveOracle::new(candidate_rx, scored_tx, config.clone()).unwrap();
+
+        // Swapped the way main's OODA loop does it, straight into the shared config
+        let new_weights = crate::oracle::types::FeatureWeights {
+            liquidity: 0.5,
+            ..crate::oracle::types::FeatureWeights::default()
+        };
+        let new_thresholds = crate::oracle::types::ScoreThresholds {
+            min_liquidity_sol: 25.0,
+            ..crate::oracle::types::ScoreThresholds::default()
+        };
+        {
+            let mut config = config.write().await;
+            config.weights = new_weights.clone();
+            config.thresholds = new_thresholds.clone();
+        }
+        assert_eq!(oracle.effective_config().await.base_weights, new_weights);
+
+        // Picked up by the scorer with the next candidate
+        let candidate = RecordBuilder::new("HotSwapMint").decided_at(chrono::Utc::now().timestamp_millis() as u64);
+        candidate_tx.send(QueuedCandidate::from(candidate.candidate().base)).await.unwrap();
+        drop(candidate_tx);
+        oracle.run().await;
+
+        assert_eq!(oracle.scorer.config.weights, new_weights);
+        assert_eq!(oracle.scorer.config.thresholds, new_thresholds);
+        assert_eq!(oracle.scorer.adaptive_weights.lock().await.get_effective_weights(), new_weights);
+    }
+
+    #[tokio::test]
+    async fn test_effective_config_reflects_adjustment_and_regime_override() {
+        let (_candidate_tx, candidate_rx) = mpsc::channel(10);
+        let (scored_tx, _scored_rx) = mpsc::channel(10);
+
+        let mut config = create_test_config();
+        let bullish = config.regime_parameters.get_mut(&MarketRegime::Bullish).unwrap();
+        bullish.weights.liquidity = 0.4;
+        bullish.thresholds.min_liquidity_sol = 42.0;
+
+        let mut oracle = PredictiveOracle::new(candidate_rx, scored_tx, Arc::new(RwLock::new(config))).unwrap();
+        oracle.set_regime_handle(Arc::new(RwLock::new(MarketRegime::Bullish)));
+        oracle.scorer.adaptive_weights.lock().await
+            .set_adjustment(crate::oracle::types::Feature::Liquidity, 0.5);
+
+        let snapshot = oracle.effective_config().await;
+
+        assert_eq!(snapshot.regime, MarketRegime::Bullish);
+        assert_eq!(snapshot.regime_weights.liquidity, 0.4);
+        assert_eq!(snapshot.adaptive_adjustments.get("liquidity"), Some(&0.5));
+        assert!((snapshot.effective_weights.liquidity - 0.6).abs() < 1e-9);
+        assert_eq!(snapshot.effective_thresholds.min_liquidity_sol, 42.0);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_candidates_are_recorded_as_skips() {
+        let (candidate_tx, candidate_rx) = mpsc::channel(10);
+        let (scored_tx, _scored_rx) = mpsc::channel(10);
+        let (decision_tx, mut decision_rx) = mpsc::channel(10);
+
+        // One request per second: everything after the first candidate is rejected
+        let mut config = create_test_config();
+        config.rpc_endpoints = vec!["http://127.0.0.1:1".to_string()];
+        config.rate_limit_requests_per_second = 1;
+
+        let mut oracle = PredictiveOracle::new(candidate_rx, scored_tx, Arc::new(RwLock::new(config))).unwrap();
+        oracle.set_decision_sender(decision_tx);
+
+        for i in 0..5 {
+            candidate_tx.send(QueuedCandidate::from(PremintCandidate {
+                mint: format!("RateLimitedMint{}", i),
+                creator: "creator".to_string(),
+                program: "pump.fun".to_string(),
+                slot: i,
+                timestamp: 0,
+                instruction_summary: None,
+                is_jito_bundle: None,
+                funding_source: None,
+            })).await.unwrap();
+        }
+        drop(candidate_tx);
+        oracle.run().await;
+
+        let mut skipped = Vec::new();
+        while let Ok(record) = decision_rx.try_recv() {
+            skipped.push(record);
+        }
+
+        assert_eq!(skipped.len(), 4);
+        for record in &skipped {
+            assert_eq!(record.actual_outcome, crate::oracle::types::Outcome::NotExecuted);
+            assert_eq!(record.scored_candidate.reason, "skipped: rate_limited");
+            assert_eq!(record.scored_candidate.predicted_score, 0);
+        }
+
+        let metrics = oracle.metrics_collector.get_metrics_snapshot().await;
+        assert_eq!(metrics.counters.get("oracle_rate_limited_total"), Some(&4));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_candidates_are_logged_as_rejections() {
+        let (candidate_tx, candidate_rx) = mpsc::channel(10);
+        let (scored_tx, _scored_rx) = mpsc::channel(10);
+
+        let mut config = create_test_config();
+        config.rpc_endpoints = vec!["http://127.0.0.1:1".to_string()];
+        config.rate_limit_requests_per_second = 1;
+
+        let storage = isolated_ledger().await;
+        let mut oracle = PredictiveOracle::new(candidate_rx, scored_tx, Arc::new(RwLock::new(config))).unwrap();
+        oracle.set_rejection_storage(storage.clone());
+
+        for i in 0..3 {
+            candidate_tx.send(QueuedCandidate::from(PremintCandidate {
+                mint: format!("RejectedMint{}", i),
+                creator: "creator".to_string(),
+                program: "pump.fun".to_string(),
+                slot: i,
+                timestamp: 0,
+                instruction_summary: None,
+                is_jito_bundle: None,
+                funding_source: None,
+            })).await.unwrap();
+        }
+        drop(candidate_tx);
+        oracle.run().await;
+
+        let rejections = storage.get_rejections_since(0).await.unwrap();
+        assert_eq!(rejections.len(), 2);
+        assert!(rejections.iter().all(|r| r.reason_code == ReasonCode::RateLimited));
+    }
+
+    #[tokio::test]
+    async fn test_candidates_waiting_too_long_are_skipped_as_stale() {
+        let (candidate_tx, candidate_rx) = mpsc::channel(10);
+        let (scored_tx, _scored_rx) = mpsc::channel(10);
+        let (decision_tx, mut decision_rx) = mpsc::channel(10);
+
+        let mut config = create_test_config();
+        config.rpc_endpoints = vec!["http://127.0.0.1:1".to_string()];
+        config.max_queue_wait_ms = 50;
+
+        let mut oracle = PredictiveOracle::new(candidate_rx, scored_tx, Arc::new(RwLock::new(config))).unwrap();
+        oracle.set_decision_sender(decision_tx);
+
+        candidate_tx.send(QueuedCandidate::from(PremintCandidate {
+            mint: "StaleMint".to_string(),
+            creator: "creator".to_string(),
+            program: "pump.fun".to_string(),
+            slot: 0,
+            timestamp: 0,
+            instruction_summary: None,
+            is_jito_bundle: None,
+            funding_source: None,
+        })).await.unwrap();
+        drop(candidate_tx);
+
+        // Simulate load: the candidate sits in the queue longer than it may wait
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        oracle.run().await;
+
+        let record = decision_rx.try_recv().expect("stale candidate was not recorded");
+        assert_eq!(record.scored_candidate.mint, "StaleMint");
+        assert_eq!(record.scored_candidate.reason, "skipped: stale");
+        assert_eq!(record.actual_outcome, crate::oracle::types::Outcome::NotExecuted);
+
+        let metrics = oracle.metrics_collector.get_metrics_snapshot().await;
+        assert_eq!(metrics.counters.get("oracle_stale_total"), Some(&1));
+        assert_eq!(metrics.counters.get("oracle_scored_total"), None);
+    }
+
+    #[tokio::test]
+    async fn test_warmup_skips_then_resumes_normal_decisions() {
+        let (candidate_tx, candidate_rx) = mpsc::channel(10);
+        let (scored_tx, mut scored_rx) = mpsc::channel(10);
+        let (gui_tx, mut gui_rx) = mpsc::channel(10);
+
+        let mut config = create_test_config();
+        config.rpc_endpoints = vec!["http://127.0.0.1:1".to_string()];
+        config.sequential = true;
+        config.notify_threshold = 0;
+        config.warmup_candidates = 2;
+        config.warmup_duration_seconds = 0;
+        // Every score is a buy once warm-up is over
+        for params in config.regime_parameters.values_mut() {
+            params.buy_score_threshold = 0;
+        }
+
+        let storage = isolated_ledger().await;
+        let mut oracle = PredictiveOracle::new(candidate_rx, scored_tx, Arc::new(RwLock::new(config))).unwrap();
+        oracle.set_rejection_storage(storage.clone());
+        oracle.set_gui_sender(gui_tx);
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        for i in 0..5 {
+            candidate_tx.send(QueuedCandidate::from(PremintCandidate {
+                mint: format!("WarmupMint{}", i),
+                creator: "creator".to_string(),
+                program: "pump.fun".to_string(),
+                slot: i,
+                timestamp: 1640995200,
+                instruction_summary: None,
+                is_jito_bundle: None,
+                funding_source: None,
+            })).await.unwrap();
+        }
+        drop(candidate_tx);
+        oracle.run().await;
+
+        // Still emitted during warm-up, but marked so that nobody buys them
+        let mut actions = Vec::new();
+        while let Ok(scored) = scored_rx.try_recv() {
+            actions.push(scored.action);
+        }
+        let (skip, buy) = (TradeAction::Skip, TradeAction::Buy);
+        assert_eq!(actions, [skip, skip, buy, buy, buy]);
+
+        // Only the candidates it would buy are suggested in the GUI
+        let mut suggested = Vec::new();
+        while let Ok(suggestion) = gui_rx.try_recv() {
+            suggested.push(suggestion.candidate.mint);
+        }
+        assert_eq!(suggested, [2, 3, 4].map(|i| format!("WarmupMint{}", i)));
+
+        let rejections = storage.get_rejections_since(0).await.unwrap();
+        assert_eq!(rejections.len(), 2);
+        assert!(rejections.iter().all(|r| r.reason_code == ReasonCode::Warmup));
+    }
+
+    #[tokio::test]
+    async fn test_kill_switch_skips_candidates_until_reset() {
+        let (candidate_tx, candidate_rx) = mpsc::channel(10);
+        let (scored_tx, mut scored_rx) = mpsc::channel(10);
+
+        let mut config = create_test_config();
+        config.rpc_endpoints = vec!["http://127.0.0.1:1".to_string()];
+        config.sequential = true;
+        config.warmup_candidates = 0;
+        config.warmup_duration_seconds = 0;
+        config.kill_switch_consecutive_losses = Some(2);
+        for params in config.regime_parameters.values_mut() {
+            params.buy_score_threshold = 0;
+        }
+        let mut oracle = PredictiveOracle::new(candidate_rx, scored_tx, Arc::new(RwLock::new(config))).unwrap();
+
+        let storage = Arc::new(crate::oracle::storage_conformance::FlakyStorage::available());
+        let decided_at = chrono::Utc::now().timestamp_millis() as u64 - 1_000;
+        let trade = RecordBuilder::new("KillSwitchMint").decided_at(decided_at);
+        for loss in [-0.2, -0.4] {
+            storage.insert_record(&trade.clone().outcome(Outcome::Loss(loss)).build()).await.unwrap();
+        }
+        oracle.set_kill_switch_storage(storage.clone());
+        let kill_switch = oracle.kill_switch();
+        kill_switch.refresh().await.unwrap();
+        tokio::spawn(async move { oracle.run().await });
+        let candidate = |mint: &str| QueuedCandidate::from(RecordBuilder::new(mint).decided_at(decided_at).candidate().base);
+
+        // Emitted, but marked so that nobody buys it
+        candidate_tx.send(candidate("KillSwitchSkipped")).await.unwrap();
+        let scored = tokio::time::timeout(Duration::from_secs(10), scored_rx.recv()).await.unwrap().unwrap();
+        assert_eq!(scored.action, TradeAction::Skip);
+
+        kill_switch.reset().await.unwrap();
+        assert!(storage.get_kill_switch_reset_at().await.unwrap().is_some());
+        candidate_tx.send(candidate("KillSwitchReset")).await.unwrap();
+        let scored = tokio::time::timeout(Duration::from_secs(10), scored_rx.recv()).await.unwrap().unwrap();
+        assert_eq!(scored.action, TradeAction::Buy);
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_rejections_are_recorded_but_not_emitted() {
+        let (candidate_tx, candidate_rx) = mpsc::channel(10);
+        let (scored_tx, mut scored_rx) = mpsc::channel(10);
+        let (decision_tx, mut decision_rx) = mpsc::channel(10);
+
+        let mut config = create_test_config();
+        config.rpc_endpoints = vec!["http://127.0.0.1:1".to_string()];
+        config.sequential = true;
+        config.blocklist = ["blocked_creator".to_string()].into_iter().collect();
+        let mut oracle = PredictiveOracle::new(candidate_rx, scored_tx, Arc::new(RwLock::new(config))).unwrap();
+        oracle.set_decision_sender(decision_tx);
+        let results = oracle.result_stream();
+
+        let decided_at = chrono::Utc::now().timestamp_millis() as u64;
+        let blocked = RecordBuilder::new("BlockedMint").creator("blocked_creator").decided_at(decided_at);
+        let allowed = RecordBuilder::new("AllowedMint").decided_at(decided_at);
+        for candidate in [blocked, allowed] {
+            candidate_tx.send(QueuedCandidate::from(candidate.candidate().base)).await.unwrap();
+        }
+        drop(candidate_tx);
+        oracle.run().await;
+
+        // Only the scored candidate reaches consumers and the adaptive weights' history
+        let mut emitted = Vec::new();
+        while let Ok(scored) = scored_rx.try_recv() {
+            emitted.push(scored.mint);
+        }
+        assert_eq!(emitted, ["AllowedMint"]);
+        let history: Vec<_> = results.history().await.iter().map(|scored| scored.mint.clone()).collect();
+        assert_eq!(history, ["AllowedMint"]);
+
+        // The rejection is still recorded as a skip
+        let skipped = decision_rx.try_recv().unwrap();
+        assert_eq!(skipped.scored_candidate.mint, "BlockedMint");
+        assert_eq!(skipped.scored_candidate.reason, "skipped: blocklisted");
+        assert!(decision_rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_sequential_mode_scores_in_input_order() {
+        let (candidate_tx, candidate_rx) = mpsc::channel(20);
+        let (scored_tx, mut scored_rx) = mpsc::channel(20);
+
+        let mut config = create_test_config();
+        config.rpc_endpoints = vec!["http://127.0.0.1:1".to_string()];
+        config.sequential = true;
+        let mut oracle = PredictiveOracle::new(candidate_rx, scored_tx, Arc::new(RwLock::new(config))).unwrap();
+
+        let mints: Vec<String> = (0..10).map(|i| format!("SequentialMint{}", i)).collect();
+        for (slot, mint) in mints.iter().enumerate() {
+            candidate_tx.send(QueuedCandidate::from(PremintCandidate {
+                mint: mint.clone(),
+                creator: "creator".to_string(),
+                program: "pump.fun".to_string(),
+                slot: slot as u64,
+                timestamp: 1640995200,
+                instruction_summary: None,
+                is_jito_bundle: None,
+                funding_source: None,
+            })).await.unwrap();
+        }
+        drop(candidate_tx);
+        oracle.run().await;
+
+        // Every candidate was scored before `run` returned, with nothing left in flight
+        let mut scored = Vec::new();
+        while let Ok(candidate) = scored_rx.try_recv() {
+            scored.push(candidate.mint);
+        }
+        assert_eq!(scored, mints);
+    }
+
+    #[tokio::test]
+    async fn test_late_subscriber_gets_replay_then_live_candidates() {
+        use futures::StreamExt;
+
+        let (candidate_tx, candidate_rx) = mpsc::channel(10);
+        let (scored_tx, _scored_rx) = mpsc::channel(10);
+
+        let mut config = create_test_config();
+        config.rpc_endpoints = vec!["http://127.0.0.1:1".to_string()];
+        config.sequential = true;
+        let mut oracle = PredictiveOracle::new(candidate_rx, scored_tx, Arc::new(RwLock::new(config))).unwrap();
+        let results = oracle.result_stream();
+        let running = tokio::spawn(async move { oracle.run().await });
+
+        let candidate = |i: u64| PremintCandidate {
+            mint: format!("StreamMint{}", i),
+            creator: "creator".to_string(),
+            program: "pump.fun".to_string(),
+            slot: i,
+            timestamp: 1640995200,
+            instruction_summary: None,
+            is_jito_bundle: None,
+            funding_source: None,
+        };
+        for i in 0..3 {
+            candidate_tx.send(candidate(i).into()).await.unwrap();
+        }
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while results.history().await.len() < 3 {
+            assert!(Instant::now() < deadline, "candidates were not scored in time");
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+
+        // Connecting late: the last two scored candidates first, then the live feed
+        let mut stream = Box::pin(results.subscribe(2));
+        let mut received = vec![stream.next().await.unwrap().mint];
+        for i in 3..5 {
+            candidate_tx.send(candidate(i).into()).await.unwrap();
+        }
+        drop(candidate_tx);
+        running.await.unwrap();
+
+        let timeout = Duration::from_secs(10);
+        for _ in 0..3 {
+            received.push(tokio::time::timeout(timeout, stream.next()).await.unwrap().unwrap().mint);
+        }
+        assert_eq!(received, ["StreamMint1", "StreamMint2", "StreamMint3", "StreamMint4"]);
+    }
+}
@@ -152,7 +152,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_oracle_creation() {
-        let (candidate_tx, candidate_rx) = mpsc::channel(10);
+        let (_candidate_tx, candidate_rx) = mpsc::channel(10);
         let (scored_tx, _scored_rx) = mpsc::channel(10);
         let config = Arc::new(RwLock::new(create_test_config()));
 
@@ -162,7 +162,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_oracle_creation_empty_rpc_endpoints() {
-        let (candidate_tx, candidate_rx) = mpsc::channel(10);
+        let (_candidate_tx, candidate_rx) = mpsc::channel(10);
         let (scored_tx, _scored_rx) = mpsc::channel(10);
         let mut config = create_test_config();
         config.rpc_endpoints.clear();
@@ -175,7 +175,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_update_config() {
-        let (candidate_tx, candidate_rx) = mpsc::channel(10);
+        let (_candidate_tx, candidate_rx) = mpsc::channel(10);
         let (scored_tx, _scored_rx) = mpsc::channel(10);
         let config = Arc::new(RwLock::new(create_test_config()));
 
@@ -206,7 +206,7 @@ mod tests {
 
     #[tokio::test] 
     async fn test_get_metrics() {
-        let (candidate_tx, candidate_rx) = mpsc::channel(10);
+        let (_candidate_tx, candidate_rx) = mpsc::channel(10);
         let (scored_tx, _scored_rx) = mpsc::channel(10);
         let config = Arc::new(RwLock::new(create_test_config()));
 
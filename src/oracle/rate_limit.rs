@@ -36,6 +36,7 @@ pub struct AdaptiveRateLimiter {
 /// Result of a request (for error rate tracking).
 #[derive(Debug, Clone)]
 struct RequestResult {
+    #[allow(dead_code)] // Kept for time-based windows
     timestamp: Instant,
     success: bool,
 }
@@ -149,10 +150,12 @@ impl AdaptiveRateLimiter {
             
             warn!("High error rate {:.2}%, reducing quota from {} to {} req/s", 
                   error_rate * 100.0, old_quota, self.current_quota);
-        } else if error_rate < self.error_threshold * 0.5 {
+        } else if error_rate <= self.error_threshold * 0.5 {
             // Low error rate: gradually increase quota back toward base
             let increase_factor = 1.0 + (self.error_threshold * 0.5 - error_rate) * 0.2;
-            let new_quota = ((self.current_quota as f64) * increase_factor) as u32;
+            // Always step by at least one request so small quotas can recover
+            let new_quota = (((self.current_quota as f64) * increase_factor) as u32)
+                .max(self.current_quota + 1);
             self.current_quota = new_quota.min(self.max_quota).min(self.base_quota);
             
             debug!("Low error rate {:.2}%, increasing quota from {} to {} req/s", 
@@ -238,6 +241,18 @@ impl AdaptiveRateLimiter {
         debug!("Set new base quota to {}", new_base);
     }
 
+    /// Set new base quota and apply it right away, keeping the error history.
+    ///
+    /// Unlike `set_base_quota`, a higher base raises the current quota too,
+    /// so leaving a temporarily lowered base does not wait for adjustments.
+    pub fn switch_base_quota(&mut self, new_base: u32) {
+        self.set_base_quota(new_base);
+        if self.current_quota != new_base {
+            self.current_quota = new_base;
+            self.update_limiter_quota();
+        }
+    }
+
     /// Set error threshold for quota adjustment.
     pub fn set_error_threshold(&mut self, threshold: f64) {
         self.error_threshold = threshold.clamp(0.0, 1.0);
@@ -403,6 +418,20 @@ mod tests {
         assert_eq!(limiter.max_quota, 80); // 200% of 40
     }
 
+    #[test]
+    fn test_switch_base_quota_applies_immediately() {
+        let mut limiter = AdaptiveRateLimiter::new(20, 10, 0.2);
+
+        limiter.switch_base_quota(5);
+        assert_eq!(limiter.get_current_quota(), 5);
+        assert_eq!(limiter.min_quota, 1);
+
+        // Raising the base takes effect without waiting for quota adjustments
+        limiter.switch_base_quota(20);
+        assert_eq!(limiter.get_current_quota(), 20);
+        assert_eq!(limiter.base_quota, 20);
+    }
+
     #[test]
     fn test_set_error_threshold() {
         let mut limiter = AdaptiveRateLimiter::new(20, 10, 0.2);
@@ -0,0 +1,168 @@
+//! Live feed of scored candidates with catch-up.
+//!
+//! The oracle publishes every scored candidate here: it is appended to the
+//! bounded scoring history and broadcast to subscribers. A consumer that
+//! connects late (e.g. a GUI) subscribes with the number of recent candidates
+//! it wants replayed and then keeps receiving the live feed.
+
+use futures::stream::{self, Stream, StreamExt};
+use std::sync::Arc;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio::sync::{Mutex, MutexGuard};
+use tracing::warn;
+
+use crate::oracle::types::ScoredCandidate;
+
+/// Scored candidates a subscriber may fall behind by before it skips ahead.
+pub const LIVE_FEED_CAPACITY: usize = 1024;
+
+/// History length at which the oldest half is dropped.
+const MAX_HISTORY: usize = 1000;
+
+/// Scoring history plus the live feed of newly scored candidates.
+///
+/// Cheap to clone; clones share the history and the feed, so a handle can be
+/// kept for subscribing while the oracle runs.
+#[derive(Clone)]
+pub struct ResultStream {
+    history: Arc<Mutex<Vec<ScoredCandidate>>>,
+    live: broadcast::Sender<ScoredCandidate>,
+}
+
+impl Default for ResultStream {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ResultStream {
+    pub fn new() -> Self {
+        Self {
+            history: Arc::new(Mutex::new(Vec::new())),
+            live: broadcast::channel(LIVE_FEED_CAPACITY).0,
+        }
+    }
+
+    /// Recently scored candidates, oldest first.
+    pub async fn history(&self) -> MutexGuard<'_, Vec<ScoredCandidate>> {
+        self.history.lock().await
+    }
+
+    /// Append `scored` to the history and send it to every subscriber.
+    pub async fn publish(&self, scored: ScoredCandidate) {
+        let mut history = self.history.lock().await;
+        history.push(scored.clone());
+
+        // Keep history bounded
+        if history.len() > MAX_HISTORY {
+            history.drain(0..MAX_HISTORY / 2); // Remove older half
+        }
+
+        // Sent under the history lock, so subscribers see each candidate exactly once
+        let _ = self.live.send(scored);
+    }
+
+    /// The last `replay_last` scored candidates (fewer if the history is
+    /// shorter), oldest first, followed by every candidate scored from then on.
+    ///
+    /// The replay is taken when the stream is first polled. A subscriber that
+    /// falls more than `LIVE_FEED_CAPACITY` candidates behind skips the ones
+    /// it missed. The stream ends once every `ResultStream` handle is dropped.
+    pub fn subscribe(&self, replay_last: usize) -> impl Stream<Item = ScoredCandidate> + Send + 'static {
+        let history = self.history.clone();
+        let live = self.live.clone();
+
+        stream::once(async move {
+            let history = history.lock().await;
+            let replay = history[history.len().saturating_sub(replay_last)..].to_vec();
+            let receiver = live.subscribe();
+            drop(history);
+
+            stream::iter(replay).chain(live_feed(receiver))
+        })
+        .flatten()
+    }
+}
+
+/// Candidates received on the broadcast channel until it closes.
+fn live_feed(receiver: broadcast::Receiver<ScoredCandidate>) -> impl Stream<Item = ScoredCandidate> {
+    stream::unfold(receiver, |mut receiver| async move {
+        loop {
+            match receiver.recv().await {
+                Ok(scored) => return Some((scored, receiver)),
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!("Result stream subscriber fell behind, skipped {} scored candidates", skipped);
+                }
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oracle::types::{Score, TradeAction};
+    use crate::types::PremintCandidate;
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    fn create_scored(mint: &str) -> ScoredCandidate {
+        ScoredCandidate {
+            base: PremintCandidate {
+                mint: mint.to_string(),
+                creator: "creator".to_string(),
+                program: "pump.fun".to_string(),
+                slot: 1,
+                timestamp: 1640995200,
+                instruction_summary: None,
+                is_jito_bundle: None,
+                funding_source: None,
+            },
+            mint: mint.to_string(),
+            predicted_score: Score::clamped(50),
+            reason: String::new(),
+            feature_scores: HashMap::new(),
+            calculation_time: 0,
+            anomaly_detected: false,
+            timestamp: 1640995200,
+            data_source_timestamps: HashMap::new(),
+            scoring_trace: None,
+            reason_codes: Vec::new(),
+            anomaly_report: None,
+            action: TradeAction::Skip,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_replays_then_follows_live_feed() {
+        let results = ResultStream::new();
+        for mint in ["a", "b", "c"] {
+            results.publish(create_scored(mint)).await;
+        }
+
+        let mut stream = Box::pin(results.subscribe(2));
+        assert_eq!(stream.next().await.unwrap().mint, "b");
+        assert_eq!(stream.next().await.unwrap().mint, "c");
+
+        results.publish(create_scored("d")).await;
+        assert_eq!(stream.next().await.unwrap().mint, "d");
+
+        drop(results);
+        assert!(tokio::time::timeout(Duration::from_secs(1), stream.next()).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_replay_is_limited_to_history() {
+        let results = ResultStream::new();
+        results.publish(create_scored("only")).await;
+
+        let replayed: Vec<_> = results.subscribe(10).take(1).map(|scored| scored.mint).collect().await;
+        assert_eq!(replayed, vec!["only"]);
+
+        for i in 0..=MAX_HISTORY {
+            results.publish(create_scored(&i.to_string())).await;
+        }
+        assert!(results.history().await.len() <= MAX_HISTORY);
+    }
+}
@@ -4,22 +4,37 @@
 //! anomaly detection, and weighting to produce final candidate scores.
 
 use crate::oracle::types::{
-    ScoredCandidate, OracleConfig, TokenData, FeatureScores, Feature, FeatureWeights,
+    deserialize_feature_scores, ScoredCandidate, OracleConfig, FeatureScores, Feature, FeatureWeights,
     MarketRegime, // Add MarketRegime import for Pillar III
+    ReasonCode,
+    EffectiveConfigSnapshot, RegimeSpecificParameters, Score, ScoreThresholds, TokenData,
+    ScoringTrace, FeatureTrace, TradeAction, GuiBackpressurePolicy, ScoreAggregation, Outcome, TransactionRecord,
 };
-use crate::oracle::features::OracleFeatureComputer;
-use crate::oracle::data_sources::OracleDataSources;
-use crate::oracle::anomaly::AnomalyDetector;
+use crate::oracle::features::{feature_raw_inputs, OracleFeatureComputer};
+use crate::oracle::data_sources::{current_timestamp_ms, OracleDataSources, RpcClient};
+use crate::oracle::pipeline::{PipelineOutcome, ScoringPipeline};
+use crate::oracle::anomaly::{AnomalyDetector, AnomalyFeedSender, AnomalyReport};
 use crate::oracle::weights::AdaptiveWeights;
+use crate::oracle::storage::LedgerStorage;
 use crate::types::{PremintCandidate, QuantumCandidateGui};
-use anyhow::Result;
+use anyhow::{anyhow, Context, Result};
 use reqwest::Client;
-use solana_client::nonblocking::rpc_client::RpcClient;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::error::TrySendError;
 use tokio::sync::{mpsc, Mutex};
 use tracing::{debug, info, warn, instrument};
 
+/// Multiplier applied to the score when an anomaly is detected.
+pub const ANOMALY_PENALTY: f64 = 0.5;
+
+/// Default multiplier applied to the score when the metadata URI is unreachable.
+pub const DEFAULT_METADATA_UNREACHABLE_PENALTY: f64 = 0.8;
+
+/// Default multiplier applied to the score when scoring exceeded the slow scoring threshold.
+pub const DEFAULT_SLOW_SCORING_PENALTY: f64 = 0.8;
+
 /// Oracle scorer that combines all scoring components.
 #[derive(Clone)]
 pub struct OracleScorer {
@@ -30,6 +45,14 @@ pub struct OracleScorer {
     pub feature_computer: Arc<OracleFeatureComputer>,
     pub anomaly_detector: Arc<AnomalyDetector>,
     pub adaptive_weights: Arc<Mutex<AdaptiveWeights>>,
+    /// Stages run before scoring, cheap filters first
+    pipeline: ScoringPipeline,
+    /// Receives a report for every candidate with detected anomalies
+    anomaly_sender: Option<AnomalyFeedSender>,
+    /// GUI notifications waiting for channel space under `DropOldest`
+    gui_backlog: Arc<Mutex<VecDeque<QuantumCandidateGui>>>,
+    /// Trade history, whose closed trades judge the adaptive weights
+    ledger: Option<Arc<dyn LedgerStorage>>,
 }
 
 impl OracleScorer {
@@ -49,7 +72,17 @@ impl OracleScorer {
         
         let feature_computer = Arc::new(OracleFeatureComputer::new(config.clone()));
         let anomaly_detector = Arc::new(AnomalyDetector::new(config.clone()));
-        let adaptive_weights = Arc::new(Mutex::new(AdaptiveWeights::new(config.weights.clone())));
+        let mut adaptive_weights = AdaptiveWeights::new(config.weights.clone());
+        adaptive_weights.set_min_samples_for_adjustment(config.min_samples_for_adjustment);
+        adaptive_weights.set_weight_floor(config.weight_floor);
+        adaptive_weights.set_exploration_rate(config.weight_exploration_rate);
+        adaptive_weights.set_performance_decay(config.weight_performance_decay);
+        adaptive_weights.set_decay_half_life(config.weight_decay_half_life_seconds);
+        for (feature, rate) in &config.feature_adaptation_rates {
+            adaptive_weights.set_feature_adaptation_rate(*feature, *rate);
+        }
+        let adaptive_weights = Arc::new(Mutex::new(adaptive_weights));
+        let pipeline = ScoringPipeline::from_config(&config);
 
         Self {
             scored_sender,
@@ -59,9 +92,44 @@ impl OracleScorer {
             feature_computer,
             anomaly_detector,
             adaptive_weights,
+            pipeline,
+            anomaly_sender: None,
+            gui_backlog: Arc::new(Mutex::new(VecDeque::new())),
+            ledger: None,
         }
     }
 
+    /// Publish anomaly reports to an external consumer.
+    pub fn set_anomaly_sender(&mut self, sender: AnomalyFeedSender) {
+        self.anomaly_sender = Some(sender);
+    }
+
+    /// Replace the stages run before scoring (built from `config.scoring_pipeline` by default).
+    pub fn set_pipeline(&mut self, pipeline: ScoringPipeline) {
+        self.pipeline = pipeline;
+    }
+
+    /// Check candidates' creators against the trade history in `storage`
+    /// (shared funding sources with losing trades, rapid successive launches),
+    /// and judge adaptive weights by the PnL of its closed trades.
+    pub fn set_ledger(&mut self, storage: Arc<dyn LedgerStorage>) {
+        self.anomaly_detector = Arc::new(
+            AnomalyDetector::new(self.config.clone()).with_ledger(storage.clone()),
+        );
+        self.ledger = Some(storage);
+    }
+
+    /// Swap in new base weights and thresholds, e.g. from the strategy optimizer.
+    ///
+    /// Learned adaptive adjustments are kept and applied on top of the new weights.
+    pub async fn set_base_parameters(&mut self, weights: FeatureWeights, thresholds: ScoreThresholds) {
+        self.config.weights = weights.clone();
+        self.config.thresholds = thresholds;
+        self.feature_computer = Arc::new(OracleFeatureComputer::new(self.config.clone()));
+        self.anomaly_detector = Arc::new(self.anomaly_detector.reconfigured(self.config.clone()));
+        self.adaptive_weights.lock().await.set_base_weights(weights);
+    }
+
     /// Score a candidate token.
     #[instrument(skip(self), fields(mint = %candidate.mint))]
     pub async fn score_candidate(&self, candidate: &PremintCandidate) -> Result<ScoredCandidate> {
@@ -80,44 +148,155 @@ impl OracleScorer {
         
         debug!("Starting to score candidate: {} in regime: {:?}", candidate.mint, current_regime);
 
-        // --- PILLAR III: Dynamically load regime-specific parameters ---
-        let regime_params = self.config.regime_parameters.get(current_regime)
-            .unwrap_or_else(|| {
-                warn!("No parameters found for regime {:?}, falling back to LowActivity", current_regime);
-                self.config.regime_parameters.get(&MarketRegime::LowActivity)
-                    .expect("LowActivity regime parameters must exist")
-            });
+        // Run the pipeline: cheap filters first, fetching token data stage by stage
+        let token_data = match self.pipeline.run(candidate, &self.data_sources, &self.config).await? {
+            PipelineOutcome::Passed(token_data) => *token_data,
+            PipelineOutcome::Rejected { stage, reason_code } => {
+                return Ok(self.rejected_candidate(candidate, &stage, reason_code, start_time));
+            }
+        };
 
-        // Fetch token data from multiple sources
-        let token_data = self.data_sources
-            .fetch_token_data_with_retries(candidate)
-            .await?;
+        self.score_token_data(candidate, &token_data, current_regime, start_time).await
+    }
 
-        // Compute feature scores using regime-specific thresholds
-        let feature_scores = self.feature_computer
-            .compute_all_features_with_thresholds(candidate, &token_data, &regime_params.thresholds)
-            .await
-            .unwrap_or_else(|_| {
-                // Fallback to default method if new method doesn't exist yet
-                self.feature_computer.compute_all_features(candidate, &token_data)
-            })?;
+    /// Zero score for a candidate a pipeline stage rejected before any scoring.
+    fn rejected_candidate(
+        &self,
+        candidate: &PremintCandidate,
+        stage: &str,
+        reason_code: ReasonCode,
+        start_time: Instant,
+    ) -> ScoredCandidate {
+        info!("Rejected candidate {} at the {} stage: {}", candidate.mint, stage, reason_code);
+
+        ScoredCandidate {
+            base: candidate.clone(),
+            mint: candidate.mint.clone(),
+            predicted_score: Score::clamped(0),
+            feature_scores: HashMap::new(),
+            reason: format!("Rejected by the {} stage: {}", stage, reason_code),
+            calculation_time: start_time.elapsed().as_micros(),
+            anomaly_detected: false,
+            timestamp: candidate.timestamp,
+            data_source_timestamps: HashMap::new(),
+            scoring_trace: None,
+            reason_codes: vec![reason_code],
+            anomaly_report: None,
+            action: TradeAction::Skip,
+        }
+    }
+
+    /// Score already fetched token data under the given regime.
+    async fn score_token_data(
+        &self,
+        candidate: &PremintCandidate,
+        token_data: &TokenData,
+        current_regime: &MarketRegime,
+        start_time: Instant,
+    ) -> Result<ScoredCandidate> {
+        // --- PILLAR III: Dynamically load regime-specific parameters ---
+        let regime_params = self.regime_parameters(current_regime);
+        let weights = self.without_disabled_features(
+            self.adaptive_weights.lock().await.apply_to(&regime_params.weights),
+        );
+
+        // Compute feature scores using regime-specific thresholds, then scale down stale
+        // inputs and pin overridden features
+        let raw_scores = self.feature_computer
+            .compute_unadjusted_features(
+                candidate,
+                token_data,
+                &regime_params.thresholds,
+                &regime_params.jito_bundle_scores,
+            )?;
+        let mut feature_scores = raw_scores.clone();
+        self.feature_computer
+            .apply_data_age_penalty(&mut feature_scores, token_data, current_timestamp_ms());
+        self.feature_computer.apply_overrides(&mut feature_scores);
 
         // Detect anomalies
-        let anomaly_detected = self.anomaly_detector
-            .detect_anomalies(&token_data)
+        let anomalies = self.anomaly_detector
+            .identify_all_anomalies(token_data)
             .await;
+        let anomaly_detected = !anomalies.is_empty();
+        let anomaly_report = if anomaly_detected {
+            warn!("Detected {} anomalies for {}: {:?}", anomalies.len(), candidate.mint, anomalies);
+            let report = AnomalyReport {
+                anomalies: anomalies.clone(),
+                anomaly_score: self.anomaly_detector.calculate_anomaly_score(&anomalies),
+                timestamp: current_timestamp_ms(),
+            };
+            self.publish_anomalies(&candidate.mint, &report).await;
+            Some(report)
+        } else {
+            None
+        };
 
-        // Calculate weighted final score using regime-specific weights
-        let predicted_score = self.calculate_predicted_score_with_weights(&feature_scores, &regime_params.weights).await?;
+        // Calculate weighted final score using adaptively adjusted regime weights,
+        // capped at the regime's maximum
+        let aggregated_score = self.calculate_predicted_score_with_weights(&feature_scores, &weights).await?.value();
+        let predicted_score = regime_params.cap_score(aggregated_score);
+        if predicted_score < aggregated_score {
+            debug!("Capped score {} at {} for {:?} regime", aggregated_score, predicted_score, current_regime);
+        }
 
-        // Apply anomaly penalty if detected
-        let final_score = if anomaly_detected {
-            debug!("Anomaly detected, applying penalty");
-            (predicted_score as f64 * 0.5) as u8 // 50% penalty for anomalies
+        // Apply anomaly, unreachable metadata and slow scoring penalties
+        let mut reason_codes = Vec::new();
+        let anomaly_penalty = if anomaly_detected { ANOMALY_PENALTY } else { 1.0 };
+        let metadata_penalty = if token_data.metadata_unreachable {
+            reason_codes.push(ReasonCode::MetadataUnreachable);
+            self.config.metadata_unreachable_penalty
+        } else {
+            1.0
+        };
+        let elapsed = start_time.elapsed();
+        let slow_scoring_penalty = match self.config.slow_scoring_threshold_ms {
+            Some(threshold_ms) if elapsed > Duration::from_millis(threshold_ms) => {
+                warn!("Scoring {} took {:?}, over the {}ms threshold", candidate.mint, elapsed, threshold_ms);
+                reason_codes.push(ReasonCode::SlowScoring);
+                self.config.slow_scoring_penalty
+            }
+            _ => 1.0,
+        };
+        let final_score = if anomaly_detected || !reason_codes.is_empty() {
+            debug!("Applying penalties: anomaly x{}, metadata x{}, slow scoring x{}",
+                   anomaly_penalty, metadata_penalty, slow_scoring_penalty);
+            (predicted_score as f64 * anomaly_penalty * metadata_penalty * slow_scoring_penalty) as u8
         } else {
             predicted_score
         };
 
+        let scoring_trace = if self.config.enable_scoring_trace {
+            let mut features = Vec::new();
+            for feature in Feature::all() {
+                let score = feature_scores.get(feature);
+                let weight = self.get_feature_weight(&weights, feature);
+                features.push(FeatureTrace {
+                    feature: feature.as_str().to_string(),
+                    raw_inputs: feature_raw_inputs(feature, candidate, token_data),
+                    raw_score: raw_scores.get(feature),
+                    score,
+                    weight,
+                    contribution: score * weight,
+                });
+            }
+
+            Some(ScoringTrace {
+                regime: *current_regime,
+                weighted_sum: features.iter().map(|f| f.contribution).sum(),
+                total_weight: features.iter().map(|f| f.weight).sum(),
+                features,
+                anomalies: anomalies.iter().map(|a| format!("{:?}", a)).collect(),
+                base_score: predicted_score,
+                anomaly_penalty,
+                metadata_penalty,
+                slow_scoring_penalty,
+                final_score,
+            })
+        } else {
+            None
+        };
+
         // Generate explanation
         let reason = self.generate_reason_with_regime(&feature_scores, final_score, anomaly_detected, current_regime);
 
@@ -125,12 +304,17 @@ impl OracleScorer {
         let scored = ScoredCandidate {
             base: candidate.clone(),
             mint: candidate.mint.clone(),
-            predicted_score: final_score,
+            predicted_score: Score::clamped(final_score),
             feature_scores: feature_scores.to_hashmap(),
             reason,
             calculation_time: start_time.elapsed().as_micros(),
             anomaly_detected,
             timestamp: candidate.timestamp,
+            data_source_timestamps: token_data.data_timestamps.clone(),
+            scoring_trace,
+            reason_codes,
+            anomaly_report,
+            action: self.decide_action(Score::clamped(final_score), current_regime),
         };
 
         info!("Scored candidate {} with score {} in {}μs using {:?} regime", 
@@ -139,6 +323,68 @@ impl OracleScorer {
         Ok(scored)
     }
 
+    /// Send an anomaly report to the feed, if one is attached.
+    async fn publish_anomalies(&self, mint: &str, report: &AnomalyReport) {
+        let Some(sender) = &self.anomaly_sender else {
+            return;
+        };
+
+        if let Err(e) = sender.send((mint.to_string(), report.clone())).await {
+            warn!("Failed to publish anomaly report: {}", e);
+        }
+    }
+
+    /// Parameters for the given regime, falling back to LowActivity.
+    fn regime_parameters(&self, regime: &MarketRegime) -> &RegimeSpecificParameters {
+        self.config.regime_parameters.get(regime)
+            .unwrap_or_else(|| {
+                warn!("No parameters found for regime {:?}, falling back to LowActivity", regime);
+                self.config.regime_parameters.get(&MarketRegime::LowActivity)
+                    .expect("LowActivity regime parameters must exist")
+            })
+    }
+
+    /// Weights and thresholds that `score_candidate_with_regime` uses for the given regime.
+    pub async fn effective_config(&self, regime: MarketRegime) -> EffectiveConfigSnapshot {
+        let regime_params = self.regime_parameters(&regime);
+        let adaptive_weights = self.adaptive_weights.lock().await;
+
+        EffectiveConfigSnapshot {
+            regime,
+            base_weights: self.config.weights.clone(),
+            regime_weights: regime_params.weights.clone(),
+            adaptive_adjustments: adaptive_weights
+                .get_adaptation_stats()
+                .current_adjustments
+                .into_iter()
+                .map(|(feature, adjustment)| (feature.as_str().to_string(), adjustment))
+                .collect(),
+            effective_weights: self.without_disabled_features(
+                adaptive_weights.apply_to(&regime_params.weights),
+            ),
+            effective_thresholds: regime_params.thresholds.clone(),
+            buy_score_threshold: regime_params.buy_score_threshold,
+        }
+    }
+
+    /// Decide whether to buy a candidate with the given score under the given regime.
+    pub fn decide_action(&self, score: Score, regime: &MarketRegime) -> TradeAction {
+        self.regime_parameters(regime).trade_action(score)
+    }
+
+    /// Zero the weights of features switched off in the configuration.
+    ///
+    /// The score is normalized by the total weight, so the remaining
+    /// features are renormalized automatically.
+    fn without_disabled_features(&self, mut weights: FeatureWeights) -> FeatureWeights {
+        for feature in Feature::all() {
+            if !self.config.is_feature_enabled(feature) {
+                weights.set(feature, 0.0);
+            }
+        }
+        weights
+    }
+
     /// Legacy method - kept for backward compatibility.
     /// New code should use score_candidate_with_regime().
     pub async fn score_candidate_legacy(&self, candidate: &PremintCandidate) -> Result<ScoredCandidate> {
@@ -162,12 +408,12 @@ impl OracleScorer {
             .await;
 
         // Calculate weighted final score
-        let predicted_score = self.calculate_predicted_score(&feature_scores).await?;
+        let predicted_score = self.calculate_predicted_score(&feature_scores).await?.value();
 
         // Apply anomaly penalty if detected
         let final_score = if anomaly_detected {
             debug!("Anomaly detected, applying penalty");
-            (predicted_score as f64 * 0.5) as u8 // 50% penalty for anomalies
+            (predicted_score as f64 * ANOMALY_PENALTY) as u8
         } else {
             predicted_score
         };
@@ -179,12 +425,17 @@ impl OracleScorer {
         let scored = ScoredCandidate {
             base: candidate.clone(),
             mint: candidate.mint.clone(),
-            predicted_score: final_score,
+            predicted_score: Score::clamped(final_score),
             feature_scores: feature_scores.to_hashmap(),
             reason,
             calculation_time: start_time.elapsed().as_micros(),
             anomaly_detected,
             timestamp: candidate.timestamp,
+            data_source_timestamps: token_data.data_timestamps.clone(),
+            scoring_trace: None,
+            reason_codes: Vec::new(),
+            anomaly_report: None,
+            action: self.decide_action(Score::clamped(final_score), &MarketRegime::LowActivity),
         };
 
         info!("Scored candidate {} with score {} in {}μs", 
@@ -195,33 +446,11 @@ impl OracleScorer {
 
     /// Calculate the predicted score using adaptive weights.
     #[instrument(skip(self, feature_scores))]
-    async fn calculate_predicted_score(&self, feature_scores: &FeatureScores) -> Result<u8> {
+    async fn calculate_predicted_score(&self, feature_scores: &FeatureScores) -> Result<Score> {
         let weights = self.adaptive_weights.lock().await;
-        let effective_weights = weights.get_effective_weights();
+        let effective_weights = self.without_disabled_features(weights.get_effective_weights());
 
-        let mut weighted_sum = 0.0;
-        let mut total_weight = 0.0;
-
-        // Calculate weighted sum of all features
-        for feature in Feature::all() {
-            let score = feature_scores.get(feature);
-            let weight = self.get_feature_weight(&effective_weights, feature);
-            
-            weighted_sum += score * weight;
-            total_weight += weight;
-        }
-
-        // Normalize to 0-100 scale
-        let normalized_score = if total_weight > 0.0 {
-            (weighted_sum / total_weight * 100.0).round() as u8
-        } else {
-            50 // Default score if no weights
-        };
-
-        debug!("Calculated weighted score: {}/100 (sum={:.3}, weight={:.3})", 
-               normalized_score, weighted_sum, total_weight);
-
-        Ok(normalized_score.min(100))
+        Ok(aggregate_feature_scores(feature_scores, &effective_weights, self.config.score_aggregation))
     }
 
     /// Get weight for a specific feature.
@@ -236,6 +465,10 @@ impl OracleScorer {
             Feature::CreatorSellSpeed => weights.creator_sell_speed,
             Feature::MetadataQuality => weights.metadata_quality,
             Feature::SocialActivity => weights.social_activity,
+            Feature::AuthorityStatus => weights.authority_status,
+            Feature::ContractVerified => weights.contract_verified,
+            Feature::GraduationProximity => weights.graduation_proximity,
+            Feature::TxPerHolder => weights.tx_per_holder,
         }
     }
 
@@ -245,30 +478,39 @@ impl OracleScorer {
         &self, 
         feature_scores: &FeatureScores, 
         regime_weights: &FeatureWeights
-    ) -> Result<u8> {
-        let mut weighted_sum = 0.0;
-        let mut total_weight = 0.0;
-
-        // Calculate weighted sum using regime-specific weights
-        for feature in Feature::all() {
-            let score = feature_scores.get(feature);
-            let weight = self.get_feature_weight(regime_weights, feature);
-            
-            weighted_sum += score * weight;
-            total_weight += weight;
-        }
+    ) -> Result<Score> {
+        Ok(aggregate_feature_scores(feature_scores, regime_weights, self.config.score_aggregation))
+    }
 
-        // Normalize to 0-100 scale
-        let normalized_score = if total_weight > 0.0 {
-            (weighted_sum / total_weight * 100.0).round() as u8
-        } else {
-            50 // Default score if no weights
-        };
+    /// Score from the feature JSON stored in the ledger, in any version read by
+    /// `deserialize_feature_scores` (e.g. `{"liquidity": 0.8, ...}`).
+    ///
+    /// Uses the same aggregation as live scoring, without adaptive adjustments or
+    /// the anomaly penalty: with linear aggregation `round(100 * Σ(score·weight) / Σ(weight))`,
+    /// rounding half away from zero, or 50 if all weights are zero. Features missing
+    /// from the JSON score 0.0; unknown feature names are rejected.
+    pub fn score_from_feature_json(&self, feature_json: &str, weights: &FeatureWeights) -> Result<u8> {
+        let map = deserialize_feature_scores(feature_json)
+            .context("Invalid feature JSON")?;
+
+        self.score_from_feature_map(&map, weights)
+    }
 
-        debug!("Calculated regime-weighted score: {}/100 (sum={:.3}, weight={:.3})", 
-               normalized_score, weighted_sum, total_weight);
+    /// Score from stored feature scores keyed by feature name, as in
+    /// `ScoredCandidate::feature_scores`. See [`Self::score_from_feature_json`].
+    pub fn score_from_feature_map(&self, feature_scores: &HashMap<String, f64>, weights: &FeatureWeights) -> Result<u8> {
+        if let Some(unknown) = feature_scores
+            .keys()
+            .find(|name| !Feature::all().iter().any(|f| f.as_str() == name.as_str()))
+        {
+            return Err(anyhow!("Unknown feature in feature JSON: {}", unknown));
+        }
 
-        Ok(normalized_score.min(100))
+        Ok(aggregate_feature_scores(
+            &FeatureScores::from_hashmap(feature_scores),
+            weights,
+            self.config.score_aggregation,
+        ).value())
     }
 
     /// Generate regime-aware explanation for the score (Pillar III).
@@ -377,47 +619,169 @@ impl OracleScorer {
     }
 
     /// Update adaptive weights with historical data.
+    ///
+    /// With a ledger, trades decided since the oldest of `historical_scores`
+    /// that have closed are judged by their PnL instead; the scores alone
+    /// decide only until the first of them closes.
     #[instrument(skip(self, historical_scores))]
     pub async fn update_adaptive_weights(&self, historical_scores: &[ScoredCandidate]) {
         if historical_scores.is_empty() {
             return;
         }
 
+        let since = historical_scores.iter().map(|c| c.timestamp).min().unwrap_or(0);
+        let closed_trades = self.closed_trades_since(since).await;
+
         let mut weights = self.adaptive_weights.lock().await;
-        weights.recalculate(historical_scores);
-        
-        debug!("Updated adaptive weights with {} historical scores", historical_scores.len());
+        if closed_trades.is_empty() {
+            weights.recalculate(historical_scores);
+            debug!("Updated adaptive weights with {} historical scores", historical_scores.len());
+        } else {
+            weights.recalculate_with_outcomes(&closed_trades);
+            debug!("Updated adaptive weights with {} closed trades", closed_trades.len());
+        }
+    }
+
+    /// Ledger records decided at or after `since` whose trade made a profit or a loss.
+    async fn closed_trades_since(&self, since: u64) -> Vec<TransactionRecord> {
+        let Some(storage) = &self.ledger else {
+            return Vec::new();
+        };
+
+        match storage.get_records_since(since).await {
+            Ok(records) => records
+                .into_iter()
+                .filter(|r| matches!(r.actual_outcome, Outcome::Profit(_) | Outcome::Loss(_)))
+                .collect(),
+            Err(e) => {
+                warn!("Failed to load closed trades for adaptive weights: {}", e);
+                Vec::new()
+            }
+        }
     }
 
     /// Send GUI notification if score meets threshold.
+    ///
+    /// Returns how many notifications were dropped because the GUI channel was full.
     #[instrument(skip(self, scored))]
-    pub async fn send_gui_notification(&self, scored: &ScoredCandidate) {
-        if scored.predicted_score >= self.config.notify_threshold {
-            let gui_suggestion = QuantumCandidateGui {
-                candidate: scored.base.clone(),
-                score: scored.predicted_score,
-                reason: scored.reason.clone(),
-                features: scored.feature_scores.clone(),
-            };
+    pub async fn send_gui_notification(&self, scored: &ScoredCandidate) -> usize {
+        if scored.predicted_score < self.config.notify_threshold {
+            return 0;
+        }
+
+        let gui_suggestion = QuantumCandidateGui {
+            candidate: scored.base.clone(),
+            score: scored.predicted_score.value(),
+            reason: scored.reason.clone(),
+            features: scored.feature_scores.clone(),
+        };
 
-            if let Some(sender) = self.gui_suggestions.lock().await.as_ref() {
+        let gui_suggestions = self.gui_suggestions.lock().await;
+        let Some(sender) = gui_suggestions.as_ref() else {
+            return 0;
+        };
+
+        match self.config.gui_backpressure_policy {
+            GuiBackpressurePolicy::Block => {
                 if let Err(e) = sender.send(gui_suggestion).await {
                     warn!("Failed to send GUI suggestion: {}", e);
                 } else {
                     debug!("Sent GUI notification for score {}", scored.predicted_score);
                 }
+                0
             }
+            GuiBackpressurePolicy::DropNew => match sender.try_send(gui_suggestion) {
+                Ok(()) => {
+                    debug!("Sent GUI notification for score {}", scored.predicted_score);
+                    0
+                }
+                Err(TrySendError::Full(_)) => {
+                    warn!("GUI channel full, dropping notification for {}", scored.mint);
+                    1
+                }
+                Err(TrySendError::Closed(_)) => {
+                    warn!("Failed to send GUI suggestion: channel closed");
+                    0
+                }
+            },
+            GuiBackpressurePolicy::DropOldest => self.send_dropping_oldest(sender, gui_suggestion).await,
         }
     }
+
+    /// Queue a notification behind any parked ones and flush as many as fit,
+    /// oldest first; parked notifications beyond the channel capacity are dropped.
+    async fn send_dropping_oldest(
+        &self,
+        sender: &mpsc::Sender<QuantumCandidateGui>,
+        gui_suggestion: QuantumCandidateGui,
+    ) -> usize {
+        let mut backlog = self.gui_backlog.lock().await;
+        backlog.push_back(gui_suggestion);
+
+        while let Some(next) = backlog.pop_front() {
+            match sender.try_send(next) {
+                Ok(()) => {}
+                Err(TrySendError::Full(next)) => {
+                    backlog.push_front(next);
+                    break;
+                }
+                Err(TrySendError::Closed(_)) => {
+                    warn!("Failed to send GUI suggestion: channel closed");
+                    backlog.clear();
+                    return 0;
+                }
+            }
+        }
+
+        let mut dropped = 0;
+        while backlog.len() > sender.max_capacity() {
+            backlog.pop_front();
+            dropped += 1;
+        }
+        if dropped > 0 {
+            warn!("GUI channel full, dropped {} oldest notifications", dropped);
+        }
+        dropped
+    }
+}
+
+/// Weighted average of the feature scores, mapped through `aggregation` and
+/// scaled to 0-100.
+fn aggregate_feature_scores(
+    feature_scores: &FeatureScores,
+    weights: &FeatureWeights,
+    aggregation: ScoreAggregation,
+) -> Score {
+    let mut weighted_sum = 0.0;
+    let mut total_weight = 0.0;
+
+    for feature in Feature::all() {
+        let weight = weights.get(feature);
+        weighted_sum += feature_scores.get(feature) * weight;
+        total_weight += weight;
+    }
+
+    // Normalize to 0-100 scale
+    let normalized_score = if total_weight > 0.0 {
+        Score::clamped((aggregation.apply(weighted_sum / total_weight) * 100.0).round() as i64)
+    } else {
+        Score::clamped(50) // Default score if no weights
+    };
+
+    debug!("Calculated weighted score: {}/100 (sum={:.3}, weight={:.3})",
+           normalized_score, weighted_sum, total_weight);
+
+    normalized_score
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::oracle::anomaly::AnomalyType;
     use crate::oracle::types::*;
+    use std::collections::HashSet;
     use crate::types::PremintCandidate;
     use solana_sdk::pubkey::Pubkey;
-    use std::collections::VecDeque;
     use tokio::sync::mpsc;
 
     fn create_test_config() -> OracleConfig {
@@ -426,13 +790,14 @@ mod tests {
 
     fn create_test_candidate() -> PremintCandidate {
         PremintCandidate {
-            mint: Pubkey::new_unique(),
-            creator: Pubkey::new_unique(),
+            mint: Pubkey::new_unique().to_string(),
+            creator: Pubkey::new_unique().to_string(),
             program: "test".to_string(),
             slot: 12345,
             timestamp: 1640995200,
             instruction_summary: None,
             is_jito_bundle: Some(true),
+            funding_source: None,
         }
     }
 
@@ -472,6 +837,9 @@ mod tests {
         );
 
         let mut feature_scores = FeatureScores::new();
+        for feature in Feature::all() {
+            feature_scores.set(feature, 0.6);
+        }
         feature_scores.set(Feature::Liquidity, 0.8);
         feature_scores.set(Feature::VolumeGrowth, 0.7);
         feature_scores.set(Feature::MetadataQuality, 0.9);
@@ -555,4 +923,635 @@ mod tests {
         let weight = scorer.get_feature_weight(&config.weights, Feature::VolumeGrowth);
         assert_eq!(weight, config.weights.volume_growth);
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_scored_candidate_records_data_source_timestamps() {
+        let (scored_tx, _scored_rx) = mpsc::channel(10);
+        let gui_suggestions = Arc::new(Mutex::new(None));
+        let rpc_clients = vec![Arc::new(RpcClient::new("http://localhost:8899"))];
+
+        let scorer = OracleScorer::new(
+            scored_tx,
+            gui_suggestions,
+            rpc_clients,
+            Client::new(),
+            create_test_config(),
+        );
+
+        let scored = scorer.score_candidate(&create_test_candidate()).await.unwrap();
+
+        for source in ["supply", "liquidity", "holders", "social"] {
+            assert!(
+                scored.data_source_timestamps.get(source).is_some_and(|&ts| ts > 0),
+                "missing timestamp for {}", source
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_blocklisted_creator_is_rejected_without_scoring() {
+        let (scored_tx, _scored_rx) = mpsc::channel(10);
+        let candidate = create_test_candidate();
+        let rpc = Arc::new(RpcClient::new("http://localhost:8899"));
+        let config = OracleConfig {
+            blocklist: [candidate.creator.clone()].into_iter().collect(),
+            ..create_test_config()
+        };
+
+        let scorer = OracleScorer::new(scored_tx, Arc::new(Mutex::new(None)), vec![rpc.clone()], Client::new(), config);
+        let scored = scorer.score_candidate(&candidate).await.unwrap();
+
+        assert_eq!(scored.predicted_score.value(), 0);
+        assert_eq!(scored.reason_codes, vec![ReasonCode::Blocklisted]);
+        assert!(scored.feature_scores.is_empty());
+        assert!(scored.reason.contains("blocklist"));
+        assert!(rpc.requested_commitments().is_empty());
+    }
+
+    fn create_test_token_data(social_activity: SocialActivity) -> TokenData {
+        TokenData {
+            supply: 1_000_000_000,
+            decimals: 9,
+            metadata_uri: String::new(),
+            metadata: None,
+            holder_distribution: vec![],
+            liquidity_pool: Some(LiquidityPool {
+                sol_amount: 50.0,
+                token_amount: 1000.0,
+                pool_address: Pubkey::new_unique().to_string(),
+                pool_type: PoolType::PumpFun,
+                bonding_curve_progress: None,
+            }),
+            volume_data: VolumeData::default(),
+            creator_holdings: CreatorHoldings::default(),
+            holder_history: Default::default(),
+            price_history: Default::default(),
+            social_activity,
+            mint_authority_active: Some(false),
+            freeze_authority_active: Some(false),
+            contract_verified: None,
+            metadata_unreachable: false,
+            funding_source: None,
+            creator: None,
+            data_timestamps: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_disable_social_ignores_social_data() {
+        let mut config = create_test_config();
+        config.regime_parameters
+            .get_mut(&MarketRegime::LowActivity)
+            .unwrap()
+            .weights
+            .social_activity = 0.3;
+
+        let quiet = create_test_token_data(SocialActivity::default());
+        let buzzing = create_test_token_data(SocialActivity {
+            twitter_mentions: 500,
+            telegram_members: 2000,
+            discord_members: 1000,
+            social_score: 0.9,
+        });
+        let candidate = create_test_candidate();
+
+        let score_both = |config: OracleConfig| {
+            let (quiet, buzzing, candidate) = (&quiet, &buzzing, &candidate);
+            async move {
+                let (scored_tx, _scored_rx) = mpsc::channel(10);
+                let scorer = OracleScorer::new(
+                    scored_tx,
+                    Arc::new(Mutex::new(None)),
+                    vec![],
+                    Client::new(),
+                    config,
+                );
+                let regime = MarketRegime::LowActivity;
+                let a = scorer.score_token_data(candidate, quiet, &regime, Instant::now()).await.unwrap();
+                let b = scorer.score_token_data(candidate, buzzing, &regime, Instant::now()).await.unwrap();
+                (a.predicted_score, b.predicted_score)
+            }
+        };
+
+        let (quiet_score, buzzing_score) = score_both(config.clone()).await;
+        assert_ne!(quiet_score, buzzing_score);
+
+        config.disable_social = true;
+        let (quiet_score, buzzing_score) = score_both(config).await;
+        assert_eq!(quiet_score, buzzing_score);
+    }
+
+    #[tokio::test]
+    async fn test_enabled_features_exclude_off_chain_data() {
+        let mut config = create_test_config();
+        config.enabled_features = Some(HashSet::from([
+            Feature::Liquidity,
+            Feature::HolderDistribution,
+            Feature::VolumeGrowth,
+            Feature::HolderGrowth,
+            Feature::PriceChange,
+            Feature::CreatorSellSpeed,
+            Feature::AuthorityStatus,
+        ]));
+        let (scored_tx, _scored_rx) = mpsc::channel(10);
+        let scorer = OracleScorer::new(scored_tx, Arc::new(Mutex::new(None)), vec![], Client::new(), config);
+
+        let bare = create_test_token_data(SocialActivity::default());
+        let mut rich = create_test_token_data(SocialActivity {
+            twitter_mentions: 500,
+            telegram_members: 2000,
+            discord_members: 1000,
+            social_score: 0.9,
+        });
+        rich.metadata = Some(Metadata {
+            name: "Rich Token".to_string(),
+            symbol: "RICH".to_string(),
+            description: "A token with complete, high quality metadata".to_string(),
+            image: "https://example.com/rich.png".to_string(),
+            attributes: vec![],
+        });
+        rich.contract_verified = Some(true);
+
+        let regime = MarketRegime::LowActivity;
+        let candidate = create_test_candidate();
+        let bare = scorer.score_token_data(&candidate, &bare, &regime, Instant::now()).await.unwrap();
+        let rich = scorer.score_token_data(&candidate, &rich, &regime, Instant::now()).await.unwrap();
+
+        assert_eq!(bare.predicted_score, rich.predicted_score);
+        for feature in ["social_activity", "metadata_quality", "contract_verified", "jito_bundle_presence"] {
+            assert_eq!(rich.feature_scores[feature], 0.0, "{} should not be computed", feature);
+        }
+
+        // Disabled features carry no weight, so the rest are renormalized
+        let effective = scorer.effective_config(regime).await.effective_weights;
+        assert_eq!(effective.social_activity, 0.0);
+        assert_eq!(effective.metadata_quality, 0.0);
+        assert!(effective.liquidity > 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_scoring_trace_reconstructs_final_score() {
+        let mut config = create_test_config();
+        config.enable_scoring_trace = true;
+        let (scored_tx, _scored_rx) = mpsc::channel(10);
+        let scorer = OracleScorer::new(scored_tx, Arc::new(Mutex::new(None)), vec![], Client::new(), config);
+
+        let token_data = create_test_token_data(SocialActivity {
+            twitter_mentions: 50,
+            telegram_members: 200,
+            discord_members: 100,
+            social_score: 0.7,
+        });
+        let scored = scorer
+            .score_token_data(&create_test_candidate(), &token_data, &MarketRegime::Bullish, Instant::now())
+            .await
+            .unwrap();
+
+        let trace = scored.scoring_trace.clone().expect("trace enabled");
+        assert_eq!(trace.features.len(), Feature::all().len());
+        assert_eq!(trace.final_score, scored.predicted_score.value());
+        assert_eq!(trace.reconstruct_score(), scored.predicted_score.value());
+
+        let liquidity = trace.features.iter().find(|f| f.feature == "liquidity").unwrap();
+        assert_eq!(liquidity.raw_inputs.get("sol_amount"), Some(&50.0));
+
+        let json = serde_json::to_string(&scored).unwrap();
+        let restored: ScoredCandidate = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.scoring_trace, Some(trace));
+    }
+
+    #[test]
+    fn test_score_from_feature_json_matches_reference() {
+        let (scored_tx, _scored_rx) = mpsc::channel(10);
+        let scorer = OracleScorer::new(
+            scored_tx,
+            Arc::new(Mutex::new(None)),
+            vec![],
+            Client::new(),
+            create_test_config(),
+        );
+
+        let feature_json = r#"{
+            "liquidity": 0.8,
+            "holder_distribution": 0.5,
+            "volume_growth": 0.7,
+            "holder_growth": 0.4,
+            "price_change": 0.6,
+            "jito_bundle_presence": 1.0,
+            "creator_sell_speed": 0.9,
+            "metadata_quality": 0.3,
+            "social_activity": 0.2,
+            "authority_status": 1.0,
+            "contract_verified": 1.0,
+            "graduation_proximity": 0.5,
+            "tx_per_holder": 0.9
+        }"#;
+        let weights = FeatureWeights::default();
+
+        // Reference value computed independently (default weights sum to 1.0):
+        // 0.136 + 0.06 + 0.084 + 0.032 + 0.048 + 0.04 + 0.063 + 0.027 + 0.008 + 0.09 + 0.02 + 0.02 + 0.036 = 0.664
+        assert_eq!(scorer.score_from_feature_json(feature_json, &weights).unwrap(), 66);
+
+        // Missing features count as zero: 0.17 / 1.0 = 0.17
+        let partial = r#"{"liquidity": 1.0}"#;
+        assert_eq!(scorer.score_from_feature_json(partial, &weights).unwrap(), 17);
+
+        assert!(scorer.score_from_feature_json(r#"{"liquidty": 1.0}"#, &weights).is_err());
+        assert!(scorer.score_from_feature_json("not json", &weights).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_anomaly_feed_receives_report() {
+        let (scored_tx, _scored_rx) = mpsc::channel(10);
+        let (anomaly_tx, mut anomaly_rx) = mpsc::channel(10);
+        let mut scorer = OracleScorer::new(
+            scored_tx,
+            Arc::new(Mutex::new(None)),
+            vec![],
+            Client::new(),
+            create_test_config(),
+        );
+        scorer.set_anomaly_sender(anomaly_tx);
+
+        let mut token_data = create_test_token_data(SocialActivity::default());
+        token_data.freeze_authority_active = Some(true);
+        let candidate = create_test_candidate();
+
+        let scored = scorer
+            .score_token_data(&candidate, &token_data, &MarketRegime::LowActivity, Instant::now())
+            .await
+            .unwrap();
+        assert!(scored.anomaly_detected);
+
+        let (mint, report) = anomaly_rx.try_recv().expect("no anomaly report published");
+        assert_eq!(mint, candidate.mint);
+        assert!(report.anomalies.contains(&AnomalyType::ActiveFreezeAuthority));
+        assert!(report.anomaly_score > 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_ledger_flags_creator_launch_spam() {
+        use crate::oracle::storage::SqliteLedger;
+        use crate::oracle::test_support::RecordBuilder;
+
+        let (scored_tx, _scored_rx) = mpsc::channel(10);
+        let mut scorer = OracleScorer::new(
+            scored_tx,
+            Arc::new(Mutex::new(None)),
+            vec![],
+            Client::new(),
+            create_test_config(),
+        );
+        let storage = SqliteLedger::new().await.unwrap();
+        scorer.set_ledger(storage.clone());
+
+        // The creator launched more tokens within the window than allowed
+        let creator = Pubkey::new_unique().to_string();
+        let now = chrono::Utc::now().timestamp_millis() as u64;
+        for i in 0..=create_test_config().max_creator_launches {
+            let record = RecordBuilder::new(&format!("spam_mint_{}_{}", creator, i))
+                .decided_at(now)
+                .creator(&creator)
+                .signature(&format!("spam_sig_{}_{}", creator, i))
+                .outcome(Outcome::NotExecuted)
+                .build();
+            storage.insert_record(&record).await.unwrap();
+        }
+
+        let mut token_data = create_test_token_data(SocialActivity::default());
+        token_data.creator = Some(creator);
+        let scored = scorer
+            .score_token_data(&create_test_candidate(), &token_data, &MarketRegime::LowActivity, Instant::now())
+            .await
+            .unwrap();
+
+        let report = scored.anomaly_report.expect("no anomaly report");
+        assert!(
+            report.anomalies.iter().any(|a| matches!(a, AnomalyType::CreatorLaunchSpam { .. })),
+            "{:?}", report.anomalies
+        );
+    }
+
+    #[tokio::test]
+    async fn test_adaptive_weights_judge_closed_trades_by_pnl() {
+        use crate::oracle::test_support::{isolated_ledger, RecordBuilder};
+
+        let mut config = create_test_config();
+        config.min_samples_for_adjustment = 1;
+        let scorer = || {
+            let (scored_tx, _scored_rx) = mpsc::channel(10);
+            OracleScorer::new(scored_tx, Arc::new(Mutex::new(None)), vec![], Client::new(), config.clone())
+        };
+
+        // High liquidity scored well but lost money; low liquidity scored poorly but won
+        let now = chrono::Utc::now().timestamp_millis() as u64;
+        let trades: Vec<_> = [(90, 0.9, -0.8), (85, 0.9, -0.4), (30, 0.1, 0.6), (20, 0.1, 0.6)]
+            .into_iter()
+            .enumerate()
+            .map(|(i, (score, liquidity, pnl))| {
+                let outcome = if pnl > 0.0 { Outcome::Profit(pnl) } else { Outcome::Loss(pnl) };
+                RecordBuilder::new(&format!("AdaptiveMint{}", i))
+                    .decided_at(now)
+                    .score(score)
+                    .feature("liquidity", liquidity)
+                    .signature(&format!("adaptive_sig_{}", i))
+                    .sol_spent(1.0)
+                    .outcome(outcome)
+                    .build()
+            })
+            .collect();
+        let history: Vec<_> = trades.iter().map(|t| t.scored_candidate.clone()).collect();
+
+        let by_score = scorer();
+        by_score.update_adaptive_weights(&history).await;
+
+        let storage = isolated_ledger().await;
+        storage.insert_records(&trades).await.unwrap();
+        let mut by_outcome = scorer();
+        by_outcome.set_ledger(storage);
+        by_outcome.update_adaptive_weights(&history).await;
+
+        let liquidity_weight = |scorer: OracleScorer| async move {
+            scorer.adaptive_weights.lock().await.get_effective_weights().get(Feature::Liquidity)
+        };
+        assert!(liquidity_weight(by_outcome).await < liquidity_weight(by_score).await);
+    }
+
+    fn create_notification(mint: &str) -> ScoredCandidate {
+        ScoredCandidate {
+            base: PremintCandidate { mint: mint.to_string(), ..create_test_candidate() },
+            mint: mint.to_string(),
+            predicted_score: Score::clamped(95),
+            feature_scores: HashMap::new(),
+            reason: "test".to_string(),
+            calculation_time: 0,
+            anomaly_detected: false,
+            timestamp: 0,
+            data_source_timestamps: HashMap::new(),
+            scoring_trace: None,
+            reason_codes: Vec::new(),
+            anomaly_report: None,
+            action: TradeAction::Skip,
+        }
+    }
+
+    fn create_notifying_scorer(
+        policy: GuiBackpressurePolicy,
+        capacity: usize,
+    ) -> (OracleScorer, mpsc::Receiver<QuantumCandidateGui>) {
+        let (scored_tx, _scored_rx) = mpsc::channel(10);
+        let (gui_tx, gui_rx) = mpsc::channel(capacity);
+        let mut config = create_test_config();
+        config.gui_backpressure_policy = policy;
+        let scorer = OracleScorer::new(
+            scored_tx,
+            Arc::new(Mutex::new(Some(gui_tx))),
+            vec![],
+            Client::new(),
+            config,
+        );
+        (scorer, gui_rx)
+    }
+
+    #[tokio::test]
+    async fn test_gui_backpressure_policies() {
+        // DropNew keeps what is already queued
+        let (scorer, mut gui_rx) = create_notifying_scorer(GuiBackpressurePolicy::DropNew, 1);
+        assert_eq!(scorer.send_gui_notification(&create_notification("a")).await, 0);
+        assert_eq!(scorer.send_gui_notification(&create_notification("b")).await, 1);
+        assert_eq!(gui_rx.try_recv().unwrap().candidate.mint, "a");
+        assert!(gui_rx.try_recv().is_err());
+
+        // DropOldest parks overflow and discards the oldest parked notification
+        let (scorer, mut gui_rx) = create_notifying_scorer(GuiBackpressurePolicy::DropOldest, 1);
+        assert_eq!(scorer.send_gui_notification(&create_notification("a")).await, 0);
+        assert_eq!(scorer.send_gui_notification(&create_notification("b")).await, 0);
+        assert_eq!(scorer.send_gui_notification(&create_notification("c")).await, 1);
+        assert_eq!(gui_rx.try_recv().unwrap().candidate.mint, "a");
+        assert_eq!(scorer.send_gui_notification(&create_notification("d")).await, 0);
+        assert_eq!(gui_rx.try_recv().unwrap().candidate.mint, "c");
+
+        // Block waits for room
+        let (scorer, mut gui_rx) = create_notifying_scorer(GuiBackpressurePolicy::Block, 1);
+        assert_eq!(scorer.send_gui_notification(&create_notification("a")).await, 0);
+        let blocked = tokio::time::timeout(
+            std::time::Duration::from_millis(50),
+            scorer.send_gui_notification(&create_notification("b")),
+        ).await;
+        assert!(blocked.is_err());
+        assert_eq!(gui_rx.try_recv().unwrap().candidate.mint, "a");
+    }
+
+    #[test]
+    fn test_logistic_aggregation_pushes_mid_range_to_extremes() {
+        let weights = FeatureWeights::default();
+        let uniform = |value: f64| {
+            let mut scores = FeatureScores::new();
+            for feature in Feature::all() {
+                scores.set(feature, value);
+            }
+            scores
+        };
+        let logistic = ScoreAggregation::Logistic { steepness: 10.0, midpoint: 0.5 };
+
+        // Linear keeps the weighted average as is
+        assert_eq!(aggregate_feature_scores(&uniform(0.7), &weights, ScoreAggregation::Linear), 70);
+        assert_eq!(aggregate_feature_scores(&uniform(0.3), &weights, ScoreAggregation::Linear), 30);
+
+        // Logistic: 1 / (1 + e^-2) = 0.881 and 1 / (1 + e^2) = 0.119
+        assert_eq!(aggregate_feature_scores(&uniform(0.7), &weights, logistic), 88);
+        assert_eq!(aggregate_feature_scores(&uniform(0.3), &weights, logistic), 12);
+        assert_eq!(aggregate_feature_scores(&uniform(0.5), &weights, logistic), 50);
+    }
+
+    #[tokio::test]
+    async fn test_buy_threshold_differs_by_regime() {
+        let (scored_tx, _scored_rx) = mpsc::channel(10);
+        let scorer = OracleScorer::new(
+            scored_tx,
+            Arc::new(Mutex::new(None)),
+            vec![],
+            Client::new(),
+            create_test_config(),
+        );
+
+        let score = Score::clamped(78);
+        assert_eq!(scorer.decide_action(score, &MarketRegime::Bullish), TradeAction::Buy);
+        assert_eq!(scorer.decide_action(score, &MarketRegime::Bearish), TradeAction::Skip);
+
+        // The threshold itself is inclusive
+        assert_eq!(scorer.decide_action(Score::clamped(85), &MarketRegime::Bearish), TradeAction::Buy);
+    }
+
+    #[tokio::test]
+    async fn test_max_score_caps_bearish_but_not_bullish() {
+        // Perfect authority status and unknown verification aggregate to 95
+        let weights = FeatureWeights {
+            liquidity: 0.0,
+            holder_distribution: 0.0,
+            volume_growth: 0.0,
+            holder_growth: 0.0,
+            price_change: 0.0,
+            jito_bundle_presence: 0.0,
+            creator_sell_speed: 0.0,
+            metadata_quality: 0.0,
+            social_activity: 0.0,
+            authority_status: 0.9,
+            contract_verified: 0.1,
+            graduation_proximity: 0.0,
+            tx_per_holder: 0.0,
+        };
+        let mut config = create_test_config();
+        config.weight_floor = 0.0;
+        for regime in [MarketRegime::Bullish, MarketRegime::Bearish] {
+            config.regime_parameters.get_mut(&regime).unwrap().weights = weights.clone();
+        }
+
+        let (scored_tx, _scored_rx) = mpsc::channel(10);
+        let scorer = OracleScorer::new(
+            scored_tx,
+            Arc::new(Mutex::new(None)),
+            vec![],
+            Client::new(),
+            config,
+        );
+        let candidate = create_test_candidate();
+        // No pool, so the pool price check cannot flag an anomaly
+        let token_data = TokenData {
+            liquidity_pool: None,
+            ..create_test_token_data(SocialActivity::default())
+        };
+
+        let bullish = scorer
+            .score_token_data(&candidate, &token_data, &MarketRegime::Bullish, Instant::now())
+            .await
+            .unwrap();
+        let bearish = scorer
+            .score_token_data(&candidate, &token_data, &MarketRegime::Bearish, Instant::now())
+            .await
+            .unwrap();
+
+        assert_eq!(bullish.predicted_score, 95);
+        assert_eq!(bearish.predicted_score, 90);
+    }
+
+    /// Serve 404 for `/missing.json` and minimal metadata for anything else.
+    async fn spawn_metadata_server() -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = [0u8; 1024];
+                let n = socket.read(&mut buf).await.unwrap_or(0);
+                let request = String::from_utf8_lossy(&buf[..n]);
+                let response = if request.starts_with("GET /missing.json") {
+                    "HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\nconnection: close\r\n\r\n".to_string()
+                } else {
+                    let body = r#"{"name":"","symbol":"","description":"","image":"","attributes":[]}"#;
+                    format!(
+                        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                        body.len(),
+                        body
+                    )
+                };
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+        format!("http://{}", addr)
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_metadata_is_penalized() {
+        let base_url = spawn_metadata_server().await;
+        let mut config = create_test_config();
+        config.weight_floor = 0.0;
+        config.enable_scoring_trace = true;
+        let (scored_tx, _scored_rx) = mpsc::channel(10);
+        let scorer = OracleScorer::new(
+            scored_tx,
+            Arc::new(Mutex::new(None)),
+            vec![],
+            Client::builder().no_proxy().build().unwrap(),
+            config.clone(),
+        );
+
+        let (metadata, unreachable) = scorer.data_sources
+            .fetch_metadata_from_uri(&format!("{}/poor.json", base_url))
+            .await;
+        assert!(metadata.is_some());
+        assert!(!unreachable);
+        let (missing, unreachable) = scorer.data_sources
+            .fetch_metadata_from_uri(&format!("{}/missing.json", base_url))
+            .await;
+        assert!(missing.is_none());
+        assert!(unreachable);
+
+        let candidate = create_test_candidate();
+        let poor = TokenData {
+            metadata,
+            liquidity_pool: None,
+            ..create_test_token_data(SocialActivity::default())
+        };
+        let missing = TokenData {
+            metadata_unreachable: true,
+            funding_source: None,
+            liquidity_pool: None,
+            ..create_test_token_data(SocialActivity::default())
+        };
+        let regime = MarketRegime::LowActivity;
+
+        let poor_scored = scorer.score_token_data(&candidate, &poor, &regime, Instant::now()).await.unwrap();
+        let missing_scored = scorer.score_token_data(&candidate, &missing, &regime, Instant::now()).await.unwrap();
+
+        assert!(poor_scored.reason_codes.is_empty());
+        assert_eq!(missing_scored.reason_codes, vec![ReasonCode::MetadataUnreachable]);
+        assert!(missing_scored.predicted_score < poor_scored.predicted_score);
+        let trace = missing_scored.scoring_trace.as_ref().unwrap();
+        assert_eq!(trace.metadata_penalty, config.metadata_unreachable_penalty);
+        assert_eq!(missing_scored.predicted_score, trace.reconstruct_score());
+    }
+
+    #[tokio::test]
+    async fn test_slow_scoring_reduces_confidence() {
+        let config = OracleConfig {
+            enable_scoring_trace: true,
+            slow_scoring_threshold_ms: Some(1_000),
+            ..create_test_config()
+        };
+        let (scored_tx, _scored_rx) = mpsc::channel(10);
+        let scorer = OracleScorer::new(
+            scored_tx,
+            Arc::new(Mutex::new(None)),
+            vec![],
+            Client::new(),
+            config.clone(),
+        );
+        let candidate = create_test_candidate();
+        let token_data = create_test_token_data(SocialActivity::default());
+        let regime = MarketRegime::LowActivity;
+
+        let fast = scorer.score_token_data(&candidate, &token_data, &regime, Instant::now()).await.unwrap();
+        // Pretend the data sources took two seconds to answer; shortly after boot
+        // the clock may not reach back that far, so actually wait instead
+        let delay = Duration::from_secs(2);
+        let slow_start = match Instant::now().checked_sub(delay) {
+            Some(start) => start,
+            None => {
+                let start = Instant::now();
+                tokio::time::sleep(delay).await;
+                start
+            }
+        };
+        let slow = scorer.score_token_data(&candidate, &token_data, &regime, slow_start).await.unwrap();
+
+        assert!(!fast.reason_codes.contains(&ReasonCode::SlowScoring));
+        assert!(slow.reason_codes.contains(&ReasonCode::SlowScoring));
+        assert!(slow.calculation_time >= 2_000_000);
+        assert!(slow.predicted_score < fast.predicted_score);
+        let trace = slow.scoring_trace.as_ref().unwrap();
+        assert_eq!(trace.slow_scoring_penalty, config.slow_scoring_penalty);
+        assert_eq!(slow.predicted_score, trace.reconstruct_score());
+    }
+}
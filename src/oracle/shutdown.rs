@@ -0,0 +1,71 @@
+//! Cooperative shutdown for the long-running component loops.
+//!
+//! Aborting a task loses whatever it was in the middle of. Instead, every `run`
+//! loop holds a clone of one `ShutdownToken`; once it is triggered each loop
+//! finishes its current step, drains the work it still holds and returns.
+
+use std::sync::Arc;
+use tokio::sync::watch;
+
+/// Cloneable shutdown signal shared by the component run loops.
+#[derive(Debug, Clone)]
+pub struct ShutdownToken {
+    sender: Arc<watch::Sender<bool>>,
+}
+
+impl ShutdownToken {
+    /// Create a token that has not been triggered.
+    pub fn new() -> Self {
+        let (sender, _) = watch::channel(false);
+        Self {
+            sender: Arc::new(sender),
+        }
+    }
+
+    /// Ask every loop holding a clone of this token to shut down.
+    pub fn trigger(&self) {
+        self.sender.send_replace(true);
+    }
+
+    /// Whether shutdown has been requested.
+    pub fn is_triggered(&self) -> bool {
+        *self.sender.borrow()
+    }
+
+    /// Resolves once shutdown is requested, immediately if it already was.
+    pub async fn triggered(&self) {
+        let mut receiver = self.sender.subscribe();
+        // The sender lives as long as this token, so waiting cannot fail
+        let _ = receiver.wait_for(|triggered| *triggered).await;
+    }
+}
+
+impl Default for ShutdownToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_trigger_wakes_all_clones() {
+        let token = ShutdownToken::new();
+        let waiter = {
+            let token = token.clone();
+            tokio::spawn(async move { token.triggered().await })
+        };
+
+        assert!(!token.is_triggered());
+        token.trigger();
+
+        tokio::time::timeout(Duration::from_secs(1), waiter).await.unwrap().unwrap();
+        assert!(token.is_triggered());
+
+        // Waiting after the fact returns immediately
+        tokio::time::timeout(Duration::from_secs(1), token.triggered()).await.unwrap();
+    }
+}
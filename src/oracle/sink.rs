@@ -0,0 +1,258 @@
+//! Destinations for scored candidates.
+//!
+//! The oracle hands every scored candidate to a `CompositeSink`, which fans it
+//! out to the scored channel and to any configured file log or webhook.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use reqwest::Client;
+use std::path::Path;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::{mpsc, Mutex};
+use tracing::warn;
+
+use crate::oracle::types::ScoredCandidate;
+
+/// Receives every scored candidate.
+///
+/// Emitting must not fail the scoring pipeline; implementations log their
+/// own errors.
+#[async_trait]
+pub trait ScoreSink: Send + Sync {
+    async fn emit(&self, scored: &ScoredCandidate);
+}
+
+/// Forwards scored candidates to a channel.
+pub struct ChannelSink {
+    sender: mpsc::Sender<ScoredCandidate>,
+}
+
+impl ChannelSink {
+    pub fn new(sender: mpsc::Sender<ScoredCandidate>) -> Self {
+        Self { sender }
+    }
+}
+
+#[async_trait]
+impl ScoreSink for ChannelSink {
+    async fn emit(&self, scored: &ScoredCandidate) {
+        if let Err(e) = self.sender.send(scored.clone()).await {
+            warn!("Failed to send scored candidate {}: {}", scored.mint, e);
+        }
+    }
+}
+
+/// Appends scored candidates to a file, one JSON object per line.
+pub struct FileSink {
+    file: Mutex<tokio::fs::File>,
+}
+
+impl FileSink {
+    /// Open `path` for appending, creating it if needed.
+    pub fn new(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("Failed to open score log {}", path.display()))?;
+
+        Ok(Self {
+            file: Mutex::new(tokio::fs::File::from_std(file)),
+        })
+    }
+}
+
+#[async_trait]
+impl ScoreSink for FileSink {
+    async fn emit(&self, scored: &ScoredCandidate) {
+        let mut line = match serde_json::to_vec(scored) {
+            Ok(line) => line,
+            Err(e) => {
+                warn!("Failed to serialize scored candidate {}: {}", scored.mint, e);
+                return;
+            }
+        };
+        line.push(b'\n');
+
+        // tokio writes in the background until flushed
+        let mut file = self.file.lock().await;
+        if let Err(e) = async { file.write_all(&line).await?; file.flush().await }.await {
+            warn!("Failed to log scored candidate {}: {}", scored.mint, e);
+        }
+    }
+}
+
+/// Default time a webhook gets to answer before the post is abandoned.
+pub const DEFAULT_WEBHOOK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Posts scored candidates as JSON to a webhook.
+pub struct WebhookSink {
+    client: Client,
+    url: String,
+    timeout: Duration,
+}
+
+impl WebhookSink {
+    pub fn new(client: Client, url: impl Into<String>) -> Self {
+        Self {
+            client,
+            url: url.into(),
+            timeout: DEFAULT_WEBHOOK_TIMEOUT,
+        }
+    }
+
+    /// Abandon posts the webhook hasn't answered within `timeout`, so a slow
+    /// endpoint can't hold up scoring.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+#[async_trait]
+impl ScoreSink for WebhookSink {
+    async fn emit(&self, scored: &ScoredCandidate) {
+        let result = self.client
+            .post(&self.url)
+            .timeout(self.timeout)
+            .json(scored)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status());
+
+        if let Err(e) = result {
+            warn!("Failed to post scored candidate {} to webhook: {}", scored.mint, e);
+        }
+    }
+}
+
+/// Emits to each of its sinks in turn.
+#[derive(Default)]
+pub struct CompositeSink {
+    sinks: Vec<Box<dyn ScoreSink>>,
+}
+
+impl CompositeSink {
+    pub fn new(sinks: Vec<Box<dyn ScoreSink>>) -> Self {
+        Self { sinks }
+    }
+
+    /// Add another destination.
+    pub fn push(&mut self, sink: Box<dyn ScoreSink>) {
+        self.sinks.push(sink);
+    }
+
+    pub fn len(&self) -> usize {
+        self.sinks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sinks.is_empty()
+    }
+}
+
+#[async_trait]
+impl ScoreSink for CompositeSink {
+    async fn emit(&self, scored: &ScoredCandidate) {
+        for sink in &self.sinks {
+            sink.emit(scored).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oracle::types::{Score, TradeAction};
+    use crate::types::PremintCandidate;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    /// Keeps emitted mints in memory.
+    #[derive(Clone, Default)]
+    struct MemorySink {
+        mints: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl ScoreSink for MemorySink {
+        async fn emit(&self, scored: &ScoredCandidate) {
+            self.mints.lock().await.push(scored.mint.clone());
+        }
+    }
+
+    fn create_scored(mint: &str) -> ScoredCandidate {
+        ScoredCandidate {
+            base: PremintCandidate {
+                mint: mint.to_string(),
+                creator: "creator".to_string(),
+                program: "pump.fun".to_string(),
+                slot: 1,
+                timestamp: 1000,
+                instruction_summary: None,
+                is_jito_bundle: None,
+                funding_source: None,
+            },
+            mint: mint.to_string(),
+            predicted_score: Score::clamped(80),
+            reason: String::new(),
+            feature_scores: HashMap::new(),
+            calculation_time: 0,
+            anomaly_detected: false,
+            timestamp: 1000,
+            data_source_timestamps: HashMap::new(),
+            scoring_trace: None,
+            reason_codes: Vec::new(),
+            anomaly_report: None,
+            action: TradeAction::Skip,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_composite_sink_fans_out_to_every_sink() {
+        let first = MemorySink::default();
+        let second = MemorySink::default();
+        let composite = CompositeSink::new(vec![Box::new(first.clone()), Box::new(second.clone())]);
+
+        composite.emit(&create_scored("FanOutMint")).await;
+
+        assert_eq!(*first.mints.lock().await, vec!["FanOutMint"]);
+        assert_eq!(*second.mints.lock().await, vec!["FanOutMint"]);
+    }
+
+    #[tokio::test]
+    async fn test_file_sink_appends_json_lines() {
+        let path = std::env::temp_dir().join(format!("score_sink_{}.jsonl", rand::random::<u64>()));
+        let sink = FileSink::new(&path).unwrap();
+
+        sink.emit(&create_scored("FirstMint")).await;
+        sink.emit(&create_scored("SecondMint")).await;
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let mints: Vec<String> = contents
+            .lines()
+            .map(|line| serde_json::from_str::<ScoredCandidate>(line).unwrap().mint)
+            .collect();
+        assert_eq!(mints, vec!["FirstMint", "SecondMint"]);
+    }
+
+    #[tokio::test]
+    async fn test_webhook_sink_gives_up_after_timeout() {
+        // Accepts connections but never answers
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/scores", listener.local_addr().unwrap());
+        let _server = tokio::spawn(async move {
+            let mut connections = Vec::new();
+            while let Ok((stream, _)) = listener.accept().await {
+                connections.push(stream);
+            }
+        });
+
+        let sink = WebhookSink::new(Client::new(), url).with_timeout(Duration::from_millis(100));
+        let emitted = tokio::time::timeout(Duration::from_secs(5), sink.emit(&create_scored("SlowMint"))).await;
+        assert!(emitted.is_ok(), "Webhook post should have timed out on its own");
+    }
+}
@@ -3,13 +3,22 @@
 //! This module defines the formal contract for data persistence operations,
 //! allowing for clean separation between business logic and storage implementation.
 
-use anyhow::{Result, Context};
+use anyhow::{anyhow, Result, Context};
 use async_trait::async_trait;
-use sqlx::{sqlite::SqlitePoolOptions, FromRow, Pool, Sqlite};
-use tracing::{info, debug};
+use sqlx::{sqlite::SqlitePoolOptions, FromRow, Pool, Sqlite, SqliteConnection};
+use tracing::{info, debug, warn};
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
-
-use crate::oracle::types::{TransactionRecord, Outcome, ScoredCandidate};
+use tokio::sync::{Mutex, MutexGuard};
+
+use crate::oracle::types::{
+    TransactionRecord, Outcome, OutcomeTransition, OutcomeUpdate, ReasonCode, Rejection, Score,
+    ScoreBandStats, ScoredCandidate, SkippedCandidate, TradeAction, SCORE_BAND_WIDTH, score_variance, deserialize_feature_scores,
+    serialize_feature_scores,
+};
+use crate::oracle::anomaly::AnomalyReport;
+use crate::oracle::blob_compression::BlobCompression;
 use crate::oracle::transaction_monitor::MonitoredTransaction;
 
 /// Formal contract for persistent operational memory.
@@ -20,7 +29,18 @@ pub trait LedgerStorage: Send + Sync {
     /// Returns the unique identifier (ID) of the saved record.
     async fn insert_record(&self, record: &TransactionRecord) -> Result<i64>;
 
-    /// Updates the outcome of an existing transaction record identified by signature.
+    /// Saves several records, returning their IDs in input order.
+    async fn insert_records(&self, records: &[TransactionRecord]) -> Result<Vec<i64>> {
+        let mut ids = Vec::with_capacity(records.len());
+        for record in records {
+            ids.push(self.insert_record(record).await?);
+        }
+        Ok(ids)
+    }
+
+    /// Updates the outcome of an existing transaction record identified by signature,
+    /// recording the transition and its reason in the outcome history.
+    #[allow(clippy::too_many_arguments)]
     async fn update_outcome(
         &self,
         signature: &str,
@@ -31,11 +51,41 @@ pub trait LedgerStorage: Send + Sync {
         final_sol_received: Option<f64>,
         timestamp_evaluated: Option<u64>,
         is_verified: bool,
+        outcome_reason: Option<String>,
     ) -> Result<()>;
 
+    /// Applies several outcome updates, in order.
+    async fn update_outcomes_batch(&self, updates: &[OutcomeUpdate]) -> Result<()> {
+        for update in updates {
+            self.update_outcome(
+                &update.signature,
+                update.outcome.clone(),
+                update.buy_price_sol,
+                update.sell_price_sol,
+                update.initial_sol_spent,
+                update.final_sol_received,
+                update.timestamp_evaluated,
+                update.is_verified,
+                update.outcome_reason.clone(),
+            ).await?;
+        }
+        Ok(())
+    }
+
+    /// Every outcome transition of the trade with `signature`, oldest first.
+    async fn get_outcome_history(&self, signature: &str) -> Result<Vec<OutcomeTransition>>;
+
     /// Retrieves historical records since a given timestamp (for analysis).
     /// Returns records ordered by decision timestamp (ascending).
-    async fn get_records_since(&self, timestamp: u64) -> Result<Vec<TransactionRecord>>;
+    async fn get_records_since(&self, timestamp: u64) -> Result<Vec<TransactionRecord>> {
+        self.get_records_paged(timestamp, u64::MAX, 0, u64::MAX).await
+    }
+
+    /// One page of the records decided at or after `since` and before `until`,
+    /// skipping the first `offset` and returning at most `limit`. Records are
+    /// ordered by decision timestamp (ascending), then by ID, so consecutive
+    /// pages neither overlap nor miss records.
+    async fn get_records_paged(&self, since: u64, until: u64, offset: u64, limit: u64) -> Result<Vec<TransactionRecord>>;
 
     /// Retrieves a specific record by its database ID.
     async fn get_record_by_id(&self, id: i64) -> Result<Option<TransactionRecord>>;
@@ -46,6 +96,45 @@ pub trait LedgerStorage: Send + Sync {
     /// Gets the total count of records in the storage.
     async fn get_record_count(&self) -> Result<i64>;
 
+    /// Retrieves decisions still `PendingConfirmation` that were made before
+    /// `older_than` (decision timestamp), oldest first.
+    async fn get_unresolved_decisions(&self, older_than: u64) -> Result<Vec<TransactionRecord>>;
+
+    /// Feature scores of every decision made for `mint`, as
+    /// `(decision timestamp, feature scores)` ordered by decision time.
+    async fn get_feature_history_for_mint(&self, mint: &str) -> Result<Vec<(u64, HashMap<String, f64>)>>;
+
+    /// Variance of the scores of every decision made for `mint`, or `None` if the
+    /// mint was scored fewer than twice. Re-scoring the same data should give
+    /// the same score, so a high variance points at nondeterminism or noisy inputs.
+    async fn score_variance_for_mint(&self, mint: &str) -> Result<Option<f64>>;
+
+    /// Fraction of closed trades (profit or loss) that made a profit, per launchpad
+    /// program, for decisions made at or after `since`.
+    async fn get_win_rate_by_program(&self, since: u64) -> Result<HashMap<String, f64>>;
+
+    /// Number of decisions made at or after `since` per outcome variant
+    /// name (`"Profit"`, `"NotExecuted"`, ...), whatever data the variant carries.
+    async fn get_outcome_counts(&self, since: u64) -> Result<HashMap<String, u64>>;
+
+    /// Number of trades closed at a PnL of `max_pnl` or worse (rugs) whose
+    /// creator was funded by `funding_source`, to recognize serial scammers'
+    /// funding wallets.
+    async fn count_rugs_by_funding_source(&self, funding_source: &str, max_pnl: f64) -> Result<u64>;
+
+    /// Number of distinct mints by `creator` decided within the last
+    /// `window_seconds`, to recognize creators spamming launches.
+    async fn count_recent_launches_by_creator(&self, creator: &str, window_seconds: u64) -> Result<u64>;
+
+    /// Win rate and average PnL of closed trades decided at or after `since`,
+    /// bucketed into `SCORE_BAND_WIDTH`-wide score bands. Bands without closed
+    /// trades are omitted; the rest are ordered by score.
+    async fn get_score_outcome_calibration(&self, since: u64) -> Result<Vec<ScoreBandStats>>;
+
+    /// Mints with the highest total PnL (SOL) over closed trades decided at or
+    /// after `since`, best first, at most `limit` of them.
+    async fn get_top_mints_by_pnl(&self, limit: u32, since: u64) -> Result<Vec<(String, f64)>>;
+
     /// Health check for the storage backend.
     async fn health_check(&self) -> Result<bool>;
     
@@ -62,6 +151,40 @@ pub trait LedgerStorage: Send + Sync {
     
     /// Remove completed transactions from the monitoring queue (cleanup).
     async fn cleanup_completed_monitoring(&self) -> Result<u64>;
+
+    /// Recalculate the stored PnL of every verified trade from its SOL spent and
+    /// received, fixing records written by older versions.
+    /// Returns the number of records whose PnL changed.
+    async fn recompute_pnl(&self) -> Result<u64>;
+
+    /// Delete every decision recorded for `mint`, with its feature scores,
+    /// market context and outcome history, in one transaction. Meant for
+    /// purging test mints or mistakes; `compact` reclaims the space afterwards.
+    /// Returns the number of decisions deleted.
+    async fn delete_records_for_mint(&self, mint: &str) -> Result<u64>;
+
+    // === Rejection Log ===
+
+    /// Record that a candidate was rejected without being acted upon.
+    async fn record_rejection(&self, mint: &str, reason_code: ReasonCode, timestamp: u64) -> Result<()>;
+
+    /// Rejections at or after `timestamp` (ms), oldest first.
+    async fn get_rejections_since(&self, timestamp: u64) -> Result<Vec<Rejection>>;
+
+    // === Kill Switch ===
+
+    /// Time (ms) of the last kill switch reset, if it was ever reset.
+    async fn get_kill_switch_reset_at(&self) -> Result<Option<u64>>;
+
+    /// Save the time (ms) of a kill switch reset, so that trades decided
+    /// before it stay forgotten across restarts.
+    async fn set_kill_switch_reset_at(&self, timestamp: u64) -> Result<()>;
+
+    /// Reclaim space left behind by deleted rows. A no-op for backends that
+    /// have nothing to compact.
+    async fn compact(&self) -> Result<()> {
+        Ok(())
+    }
     
     /// Allows downcasting to concrete storage types for backward compatibility
     fn as_any(&self) -> &dyn std::any::Any;
@@ -69,6 +192,346 @@ pub trait LedgerStorage: Send + Sync {
 
 const DB_FILE: &str = "./decisions.db";
 
+/// Serializes writes to the database from every storage in the process. SQLite
+/// allows a single writer, so concurrent writers on separate pool connections
+/// otherwise contend for the file lock and fail with `database is locked`
+/// once the busy timeout runs out.
+static WRITE_LOCK: Mutex<()> = Mutex::const_new(());
+
+/// Hold `WRITE_LOCK` for the rest of the write, if writes are serialized.
+async fn lock_writes(serialize_writes: bool) -> Option<MutexGuard<'static, ()>> {
+    if serialize_writes {
+        Some(WRITE_LOCK.lock().await)
+    } else {
+        None
+    }
+}
+
+/// Settings shared by the SQLite storages.
+#[derive(Debug, Clone)]
+pub struct SqliteStorageOptions {
+    /// Database file, created if missing; `./decisions.db` by default
+    pub db_path: PathBuf,
+    /// Furthest back `get_records_since` and `get_records_paged` scan, if capped
+    pub max_lookback_seconds: Option<u64>,
+    /// Funnel every write through one process-wide lock instead of letting
+    /// the pool's connections race for SQLite's write lock
+    pub serialize_writes: bool,
+    /// Compression of the flat table's feature score and market context
+    /// JSON; the normalized schema stores neither as JSON
+    pub blob_compression: BlobCompression,
+}
+
+impl Default for SqliteStorageOptions {
+    fn default() -> Self {
+        Self {
+            db_path: PathBuf::from(DB_FILE),
+            max_lookback_seconds: None,
+            serialize_writes: true,
+            blob_compression: BlobCompression::None,
+        }
+    }
+}
+
+/// Add a column to an existing table unless it is already there.
+async fn add_column_if_missing(pool: &Pool<Sqlite>, table: &str, column: &str, decl: &str) -> Result<()> {
+    let exists: Option<(String,)> = sqlx::query_as(
+        "SELECT name FROM pragma_table_info(?) WHERE name = ?"
+    )
+    .bind(table)
+    .bind(column)
+    .fetch_optional(pool)
+    .await
+    .context(format!("Failed to inspect columns of {}", table))?;
+
+    if exists.is_none() {
+        sqlx::query(&format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, decl))
+            .execute(pool)
+            .await
+            .context(format!("Failed to add column {}.{}", table, column))?;
+        info!("Added missing column {}.{}", table, column);
+    }
+    Ok(())
+}
+
+/// Create the rejection log table shared by both schemas.
+async fn create_rejections_table(pool: &Pool<Sqlite>) -> Result<()> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS rejections (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            mint TEXT NOT NULL,
+            reason_code TEXT NOT NULL,
+            timestamp INTEGER NOT NULL
+        );
+        "#
+    )
+    .execute(pool)
+    .await
+    .context("Failed to create rejections table")?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_rejections_timestamp ON rejections (timestamp)")
+        .execute(pool)
+        .await
+        .context("Failed to create rejections index")?;
+    Ok(())
+}
+
+/// Create the single-row kill switch state table shared by both schemas.
+async fn create_kill_switch_table(pool: &Pool<Sqlite>) -> Result<()> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS kill_switch_state (
+            id INTEGER PRIMARY KEY CHECK (id = 1),
+            reset_at INTEGER NOT NULL
+        );
+        "#
+    )
+    .execute(pool)
+    .await
+    .context("Failed to create kill_switch_state table")?;
+    Ok(())
+}
+
+/// Turn `(band start, trades, win rate, average PnL)` rows into band stats.
+pub(crate) fn score_bands_from_rows(rows: Vec<(i64, i64, f64, f64)>) -> Vec<ScoreBandStats> {
+    rows.into_iter()
+        .map(|(band, trades, win_rate, average_pnl)| {
+            ScoreBandStats::from_band(band as u8, trades as u64, win_rate, average_pnl)
+        })
+        .collect()
+}
+
+/// Add up `(serialized outcome, count)` rows per outcome variant, so that
+/// variants carrying data (`{"Profit":0.1}`) count under their name.
+pub(crate) fn outcome_counts_from_rows(rows: Vec<(String, i64)>) -> Result<HashMap<String, u64>> {
+    let mut counts = HashMap::new();
+    for (outcome, count) in rows {
+        let variant = match serde_json::from_str(&outcome)? {
+            serde_json::Value::String(name) => Some(name),
+            serde_json::Value::Object(fields) => fields.into_iter().next().map(|(name, _)| name),
+            _ => None,
+        }
+        .ok_or_else(|| anyhow!("Unrecognized outcome: {}", outcome))?;
+        *counts.entry(variant).or_insert(0) += count as u64;
+    }
+    Ok(counts)
+}
+
+/// `value` as a SQL integer, saturating at `i64::MAX` (no bound in practice).
+pub(crate) fn sql_bound(value: u64) -> i64 {
+    i64::try_from(value).unwrap_or(i64::MAX)
+}
+
+/// The record's anomaly report serialized for its database column.
+pub(crate) fn anomaly_report_json(record: &TransactionRecord) -> Result<Option<String>> {
+    Ok(record.scored_candidate.anomaly_report.as_ref().map(serde_json::to_string).transpose()?)
+}
+
+/// The record's reason codes serialized for their database column, NULL if
+/// there are none.
+pub(crate) fn reason_codes_json(record: &TransactionRecord) -> Result<Option<String>> {
+    let reason_codes = &record.scored_candidate.reason_codes;
+    Ok((!reason_codes.is_empty()).then(|| serde_json::to_string(reason_codes)).transpose()?)
+}
+
+/// Reason codes read back from their database column.
+pub(crate) fn reason_codes_from_column(column: Option<&str>) -> Result<Vec<ReasonCode>> {
+    Ok(column.map(serde_json::from_str).transpose()?.unwrap_or_default())
+}
+
+/// Unix timestamp (ms) `window_seconds` before now.
+pub(crate) fn window_start_ms(window_seconds: u64) -> u64 {
+    (chrono::Utc::now().timestamp_millis() as u64).saturating_sub(window_seconds.saturating_mul(1000))
+}
+
+/// Raise `since` (Unix ms) to `max_lookback_seconds` before now, if set and
+/// exceeded, so a caller cannot accidentally scan the entire ledger.
+pub(crate) fn clamp_lookback(since: u64, max_lookback_seconds: Option<u64>) -> u64 {
+    let Some(max_lookback_seconds) = max_lookback_seconds else {
+        return since;
+    };
+
+    let earliest = window_start_ms(max_lookback_seconds);
+    if since < earliest {
+        warn!(
+            "Requested records since {}, clamping to {} (max lookback {}s)",
+            since, earliest, max_lookback_seconds
+        );
+        earliest
+    } else {
+        since
+    }
+}
+
+/// Rebuild the database file without free pages and truncate the WAL.
+async fn compact_sqlite(pool: &Pool<Sqlite>) -> Result<()> {
+    sqlx::query("VACUUM")
+        .execute(pool)
+        .await
+        .context("Failed to vacuum database")?;
+    sqlx::query("PRAGMA wal_checkpoint(TRUNCATE)")
+        .execute(pool)
+        .await
+        .context("Failed to checkpoint WAL")?;
+
+    info!("Compacted ledger database");
+    Ok(())
+}
+
+/// Create the outcome history table shared by both schemas.
+async fn create_outcome_history_table(pool: &Pool<Sqlite>) -> Result<()> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS outcome_history (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            signature TEXT NOT NULL,
+            from_outcome TEXT NOT NULL,
+            to_outcome TEXT NOT NULL,
+            reason TEXT,
+            timestamp INTEGER NOT NULL
+        );
+        "#
+    )
+    .execute(pool)
+    .await
+    .context("Failed to create outcome_history table")?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_outcome_history_signature ON outcome_history (signature)")
+        .execute(pool)
+        .await
+        .context("Failed to create outcome_history index")?;
+    Ok(())
+}
+
+/// Record that the trade with `update.signature` moved from `from` to `update.outcome`.
+async fn insert_outcome_transition(conn: &mut SqliteConnection, from: &str, update: &OutcomeUpdate) -> Result<()> {
+    let timestamp = update
+        .timestamp_evaluated
+        .unwrap_or_else(|| chrono::Utc::now().timestamp_millis() as u64);
+
+    sqlx::query(
+        "INSERT INTO outcome_history (signature, from_outcome, to_outcome, reason, timestamp) VALUES (?, ?, ?, ?, ?)"
+    )
+    .bind(&update.signature)
+    .bind(from)
+    .bind(serde_json::to_string(&update.outcome)?)
+    .bind(&update.outcome_reason)
+    .bind(timestamp as i64)
+    .execute(conn)
+    .await
+    .context(format!("Failed to record outcome transition for signature {}", update.signature))?;
+    Ok(())
+}
+
+/// Delete the outcome history of `mint`'s trades, whose signatures are kept
+/// in `signature_column` of `table`.
+async fn delete_outcome_history_for_mint(
+    conn: &mut SqliteConnection,
+    table: &str,
+    signature_column: &str,
+    mint: &str,
+) -> Result<()> {
+    sqlx::query(&format!(
+        "DELETE FROM outcome_history WHERE signature IN (SELECT {} FROM {} WHERE mint = ?)",
+        signature_column, table
+    ))
+    .bind(mint)
+    .execute(conn)
+    .await
+    .context(format!("Failed to delete outcome history for mint {}", mint))?;
+    Ok(())
+}
+
+async fn table_exists(conn: &mut SqliteConnection, table: &str) -> Result<bool> {
+    let exists: Option<(String,)> = sqlx::query_as(
+        "SELECT name FROM sqlite_master WHERE type = 'table' AND name = ?"
+    )
+    .bind(table)
+    .fetch_optional(conn)
+    .await
+    .context(format!("Failed to look up table {}", table))?;
+    Ok(exists.is_some())
+}
+
+async fn fetch_outcome_history(pool: &Pool<Sqlite>, signature: &str) -> Result<Vec<OutcomeTransition>> {
+    let rows: Vec<(String, String, Option<String>, i64)> = sqlx::query_as(
+        "SELECT from_outcome, to_outcome, reason, timestamp FROM outcome_history WHERE signature = ? ORDER BY id ASC"
+    )
+    .bind(signature)
+    .fetch_all(pool)
+    .await
+    .context("Failed to fetch outcome history")?;
+
+    rows.into_iter()
+        .map(|(from, to, reason, timestamp)| {
+            Ok(OutcomeTransition {
+                signature: signature.to_string(),
+                from: serde_json::from_str(&from)?,
+                to: serde_json::from_str(&to)?,
+                reason,
+                timestamp: timestamp as u64,
+            })
+        })
+        .collect()
+}
+
+async fn insert_rejection(pool: &Pool<Sqlite>, mint: &str, reason_code: ReasonCode, timestamp: u64) -> Result<()> {
+    sqlx::query("INSERT INTO rejections (mint, reason_code, timestamp) VALUES (?, ?, ?)")
+        .bind(mint)
+        .bind(reason_code.as_str())
+        .bind(timestamp as i64)
+        .execute(pool)
+        .await
+        .context("Failed to record rejection")?;
+
+    debug!("Recorded rejection of {}: {}", mint, reason_code);
+    Ok(())
+}
+
+async fn fetch_kill_switch_reset_at(pool: &Pool<Sqlite>) -> Result<Option<u64>> {
+    let row: Option<(i64,)> = sqlx::query_as("SELECT reset_at FROM kill_switch_state WHERE id = 1")
+        .fetch_optional(pool)
+        .await
+        .context("Failed to fetch kill switch reset time")?;
+
+    Ok(row.map(|(reset_at,)| reset_at as u64))
+}
+
+async fn save_kill_switch_reset_at(pool: &Pool<Sqlite>, timestamp: u64) -> Result<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO kill_switch_state (id, reset_at) VALUES (1, ?)
+        ON CONFLICT (id) DO UPDATE SET reset_at = excluded.reset_at
+        "#
+    )
+    .bind(timestamp as i64)
+    .execute(pool)
+    .await
+    .context("Failed to save kill switch reset time")?;
+    Ok(())
+}
+
+async fn fetch_rejections_since(pool: &Pool<Sqlite>, timestamp: u64) -> Result<Vec<Rejection>> {
+    let rows: Vec<(String, String, i64)> = sqlx::query_as(
+        "SELECT mint, reason_code, timestamp FROM rejections WHERE timestamp >= ? ORDER BY timestamp ASC, id ASC"
+    )
+    .bind(timestamp as i64)
+    .fetch_all(pool)
+    .await
+    .context("Failed to fetch rejections")?;
+
+    rows.into_iter()
+        .map(|(mint, reason_code, timestamp)| {
+            Ok(Rejection {
+                mint,
+                reason_code: reason_code.parse()?,
+                timestamp: timestamp as u64,
+            })
+        })
+        .collect()
+}
+
 /// Helper type for deserializing records from SQLite
 #[derive(FromRow)]
 struct TransactionRecordRow {
@@ -76,7 +539,7 @@ struct TransactionRecordRow {
     mint: String,
     score: i32,
     reason: String,
-    feature_scores: String, // JSON
+    feature_scores: Vec<u8>, // JSON, compressed per `blob_compression`
     calculation_time: i64,
     anomaly_detected: bool,
     timestamp_decision_made: i64,
@@ -92,7 +555,13 @@ struct TransactionRecordRow {
     timestamp_transaction_sent: Option<i64>,
     timestamp_outcome_evaluated: Option<i64>,
     actual_outcome: String, // Enum serialized to string
-    market_context_snapshot: String, // JSON
+    market_context_snapshot: Vec<u8>, // JSON, compressed per `blob_compression`
+    program: String,
+    funding_source: Option<String>,
+    creator: String,
+    anomaly_report: Option<String>, // JSON
+    blob_compression: Option<String>, // Codec of the JSON blobs, NULL if uncompressed
+    reason_codes: Option<String>, // JSON, NULL if there are none
 }
 
 /// SQLite implementation of the LedgerStorage trait.
@@ -100,6 +569,12 @@ struct TransactionRecordRow {
 /// to be easily replaceable with the normalized schema or PostgreSQL.
 pub struct SqliteLedger {
     pool: Pool<Sqlite>,
+    /// Furthest back `get_records_since` and `get_records_paged` scan, if capped
+    max_lookback_seconds: Option<u64>,
+    /// Whether writes take `WRITE_LOCK`
+    serialize_writes: bool,
+    /// Codec for the JSON blobs of new records
+    blob_compression: BlobCompression,
 }
 
 /// Normalized SQLite implementation of the LedgerStorage trait.
@@ -107,20 +582,39 @@ pub struct SqliteLedger {
 /// - Main trades table
 /// - Feature scores in a separate table
 /// - Market context in a separate table
+///
 /// This design is optimized for analytical queries and future PostgreSQL migration.
 pub struct SqliteLedgerNormalized {
     pool: Pool<Sqlite>,
+    /// Furthest back `get_records_since` and `get_records_paged` scan, if capped
+    max_lookback_seconds: Option<u64>,
+    /// Whether writes take `WRITE_LOCK`
+    serialize_writes: bool,
 }
 
 impl SqliteLedger {
     /// Creates a new SqliteLedger with the current flat table schema.
     /// This maintains compatibility with the existing system.
     pub async fn new() -> Result<Arc<Self>> {
+        Self::new_with_max_lookback(None).await
+    }
+
+    /// Like `new`, but `get_records_since` never scans further back than
+    /// `max_lookback_seconds` before now.
+    pub async fn new_with_max_lookback(max_lookback_seconds: Option<u64>) -> Result<Arc<Self>> {
+        Self::new_with_options(SqliteStorageOptions { max_lookback_seconds, ..Default::default() }).await
+    }
+
+    /// Like `new`, with the given options.
+    pub async fn new_with_options(options: SqliteStorageOptions) -> Result<Arc<Self>> {
+        let SqliteStorageOptions { db_path, max_lookback_seconds, serialize_writes, blob_compression } = options;
         let pool = SqlitePoolOptions::new()
             .max_connections(5)
-            .connect(&format!("sqlite:{}?mode=rwc", DB_FILE))
+            .connect(&format!("sqlite:{}?mode=rwc", db_path.display()))
             .await
             .context("Failed to connect to SQLite database")?;
+        // Schema setup writes too
+        let _write = lock_writes(serialize_writes).await;
 
         // Create the transaction_records table if it doesn't exist
         sqlx::query(
@@ -147,7 +641,14 @@ impl SqliteLedger {
                 timestamp_outcome_evaluated INTEGER,
                 actual_outcome TEXT NOT NULL,
                 market_context_snapshot TEXT NOT NULL,
-                is_verified BOOLEAN NOT NULL DEFAULT FALSE
+                is_verified BOOLEAN NOT NULL DEFAULT FALSE,
+                program TEXT NOT NULL DEFAULT '',
+                funding_source TEXT,
+                creator TEXT NOT NULL DEFAULT '',
+                anomaly_report TEXT,
+                blob_compression TEXT,
+                low_confidence BOOLEAN NOT NULL DEFAULT FALSE,
+                reason_codes TEXT
             );
             "#
         )
@@ -155,6 +656,17 @@ impl SqliteLedger {
         .await
         .context("Failed to create transaction_records table")?;
 
+        // Databases created before the launchpad program, funding source, creator and anomalies were stored lack these columns
+        add_column_if_missing(&pool, "transaction_records", "program", "TEXT NOT NULL DEFAULT ''").await?;
+        add_column_if_missing(&pool, "transaction_records", "funding_source", "TEXT").await?;
+        add_column_if_missing(&pool, "transaction_records", "creator", "TEXT NOT NULL DEFAULT ''").await?;
+        add_column_if_missing(&pool, "transaction_records", "anomaly_report", "TEXT").await?;
+        add_column_if_missing(&pool, "transaction_records", "blob_compression", "TEXT").await?;
+        // Outcomes stored before estimates were flagged read as confident
+        add_column_if_missing(&pool, "transaction_records", "low_confidence", "BOOLEAN NOT NULL DEFAULT FALSE").await?;
+        // Decisions stored before their reason codes read as having none
+        add_column_if_missing(&pool, "transaction_records", "reason_codes", "TEXT").await?;
+
         // Create the monitoring_queue table for persistent transaction monitoring
         sqlx::query(
             r#"
@@ -162,6 +674,7 @@ impl SqliteLedger {
                 signature TEXT PRIMARY KEY NOT NULL,
                 mint TEXT NOT NULL,
                 initial_sol_spent REAL NOT NULL,
+                amount_bought_tokens REAL NOT NULL DEFAULT 0,
                 -- Status: Pending, Processing, Completed, Failed
                 status TEXT NOT NULL DEFAULT 'Pending',
                 created_at INTEGER NOT NULL
@@ -171,10 +684,16 @@ impl SqliteLedger {
         .execute(&pool)
         .await
         .context("Failed to create monitoring_queue table")?;
+        // Queues created before the bought amount was stored read it as 0, an unknown buy price
+        add_column_if_missing(&pool, "monitoring_queue", "amount_bought_tokens", "REAL NOT NULL DEFAULT 0").await?;
+
+        create_rejections_table(&pool).await?;
+        create_outcome_history_table(&pool).await?;
+        create_kill_switch_table(&pool).await?;
 
-        info!("SqliteLedger initialized and connected to {}", DB_FILE);
+        info!("SqliteLedger initialized and connected to {}", db_path.display());
 
-        Ok(Arc::new(Self { pool }))
+        Ok(Arc::new(Self { pool, max_lookback_seconds, serialize_writes, blob_compression }))
     }
 
     /// Get a reference to the database pool for backward compatibility
@@ -186,44 +705,27 @@ impl SqliteLedger {
 #[async_trait]
 impl LedgerStorage for SqliteLedger {
     async fn insert_record(&self, record: &TransactionRecord) -> Result<i64> {
+        let _write = lock_writes(self.serialize_writes).await;
         debug!("Inserting new transaction record for mint: {}", record.scored_candidate.mint);
-        
-        let feature_scores_json = serde_json::to_string(&record.scored_candidate.feature_scores)?;
-        let market_context_json = serde_json::to_string(&record.market_context_snapshot)?;
 
-        let result = sqlx::query(
-            r#"
-            INSERT INTO transaction_records (
-                mint, score, reason, feature_scores, calculation_time, anomaly_detected,
-                timestamp_decision_made, transaction_signature, actual_outcome, market_context_snapshot,
-                buy_price_sol, sell_price_sol, amount_bought_tokens, amount_sold_tokens,
-                initial_sol_spent, final_sol_received, timestamp_transaction_sent, timestamp_outcome_evaluated
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?);
-            "#
-        )
-        .bind(record.scored_candidate.mint.clone())
-        .bind(record.scored_candidate.predicted_score as i64)
-        .bind(record.scored_candidate.reason.clone())
-        .bind(feature_scores_json)
-        .bind(record.scored_candidate.calculation_time as i64)
-        .bind(record.scored_candidate.anomaly_detected)
-        .bind(record.timestamp_decision_made as i64)
-        .bind(record.transaction_signature.clone())
-        .bind(serde_json::to_string(&record.actual_outcome)?) // Serialize Outcome enum
-        .bind(market_context_json)
-        .bind(record.buy_price_sol)
-        .bind(record.sell_price_sol)
-        .bind(record.amount_bought_tokens)
-        .bind(record.amount_sold_tokens)
-        .bind(record.initial_sol_spent)
-        .bind(record.final_sol_received)
-        .bind(record.timestamp_transaction_sent.map(|t| t as i64))
-        .bind(record.timestamp_outcome_evaluated.map(|t| t as i64))
-        .execute(&self.pool)
-        .await
-        .context("Failed to insert record into DB")?;
+        let mut conn = self.pool.acquire().await.context("Failed to acquire connection")?;
+        Self::insert_trade(&mut conn, record, self.blob_compression).await
+    }
 
-        Ok(result.last_insert_rowid())
+    async fn insert_records(&self, records: &[TransactionRecord]) -> Result<Vec<i64>> {
+        let _write = lock_writes(self.serialize_writes).await;
+        debug!("Inserting {} transaction records in one transaction", records.len());
+
+        // One transaction for the whole batch: the insert is prepared once and
+        // reused, and a failure part way through leaves no records behind
+        let mut tx = self.pool.begin().await.context("Failed to begin transaction")?;
+        let mut ids = Vec::with_capacity(records.len());
+        for record in records {
+            ids.push(Self::insert_trade(&mut tx, record, self.blob_compression).await?);
+        }
+        tx.commit().await.context("Failed to commit records")?;
+
+        Ok(ids)
     }
 
     async fn update_outcome(
@@ -236,47 +738,57 @@ impl LedgerStorage for SqliteLedger {
         final_sol_received: Option<f64>,
         timestamp_evaluated: Option<u64>,
         is_verified: bool,
+        outcome_reason: Option<String>,
     ) -> Result<()> {
+        let _write = lock_writes(self.serialize_writes).await;
         debug!("Updating outcome for signature: {} (verified: {})", signature, is_verified);
         
-        sqlx::query(
-            r#"
-            UPDATE transaction_records
-            SET
-                actual_outcome = ?,
-                buy_price_sol = COALESCE(?, buy_price_sol),
-                sell_price_sol = COALESCE(?, sell_price_sol),
-                initial_sol_spent = COALESCE(?, initial_sol_spent),
-                final_sol_received = COALESCE(?, final_sol_received),
-                timestamp_outcome_evaluated = COALESCE(?, timestamp_outcome_evaluated),
-                is_verified = ?
-            WHERE transaction_signature = ?;
-            "#
-        )
-        .bind(serde_json::to_string(&outcome)?)
-        .bind(buy_price_sol)
-        .bind(sell_price_sol)
-        .bind(initial_sol_spent)
-        .bind(final_sol_received)
-        .bind(timestamp_evaluated.map(|t| t as i64))
-        .bind(is_verified)
-        .bind(signature)
-        .execute(&self.pool)
-        .await
-        .context(format!("Failed to update outcome for signature {}", signature))?;
-        
+        let update = OutcomeUpdate {
+            signature: signature.to_string(),
+            outcome,
+            buy_price_sol,
+            sell_price_sol,
+            initial_sol_spent,
+            final_sol_received,
+            timestamp_evaluated,
+            is_verified,
+            outcome_reason,
+            low_confidence: false,
+        };
+
+        let mut tx = self.pool.begin().await.context("Failed to begin transaction")?;
+        Self::apply_outcome_update(&mut tx, &update).await?;
+        tx.commit().await.context("Failed to commit outcome update")?;
         Ok(())
     }
 
-    async fn get_records_since(&self, timestamp: u64) -> Result<Vec<TransactionRecord>> {
+    async fn update_outcomes_batch(&self, updates: &[OutcomeUpdate]) -> Result<()> {
+        let _write = lock_writes(self.serialize_writes).await;
+        debug!("Updating {} outcomes in one transaction", updates.len());
+
+        let mut tx = self.pool.begin().await.context("Failed to begin transaction")?;
+        for update in updates {
+            Self::apply_outcome_update(&mut tx, update).await?;
+        }
+        tx.commit().await.context("Failed to commit outcome updates")?;
+
+        Ok(())
+    }
+
+    async fn get_records_paged(&self, since: u64, until: u64, offset: u64, limit: u64) -> Result<Vec<TransactionRecord>> {
+        let since = clamp_lookback(since, self.max_lookback_seconds);
         let rows: Vec<TransactionRecordRow> = sqlx::query_as(
             r#"
             SELECT * FROM transaction_records 
-            WHERE timestamp_decision_made >= ? 
-            ORDER BY timestamp_decision_made ASC;
+            WHERE timestamp_decision_made >= ? AND timestamp_decision_made < ?
+            ORDER BY timestamp_decision_made ASC, id ASC
+            LIMIT ? OFFSET ?;
             "#
         )
-        .bind(timestamp as i64)
+        .bind(sql_bound(since))
+        .bind(sql_bound(until))
+        .bind(sql_bound(limit))
+        .bind(sql_bound(offset))
         .fetch_all(&self.pool)
         .await
         .context("Failed to fetch historical records")?;
@@ -328,6 +840,182 @@ impl LedgerStorage for SqliteLedger {
         Ok(count.0)
     }
 
+    async fn get_unresolved_decisions(&self, older_than: u64) -> Result<Vec<TransactionRecord>> {
+        let rows: Vec<TransactionRecordRow> = sqlx::query_as(
+            r#"
+            SELECT * FROM transaction_records
+            WHERE actual_outcome = ? AND timestamp_decision_made < ?
+            ORDER BY timestamp_decision_made ASC;
+            "#
+        )
+        .bind(serde_json::to_string(&Outcome::PendingConfirmation)?)
+        .bind(older_than as i64)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch unresolved decisions")?;
+
+        rows.into_iter()
+            .map(|row| self.row_to_transaction_record(row))
+            .collect()
+    }
+
+    async fn get_feature_history_for_mint(&self, mint: &str) -> Result<Vec<(u64, HashMap<String, f64>)>> {
+        let rows: Vec<(i64, Vec<u8>, Option<String>)> = sqlx::query_as(
+            r#"
+            SELECT timestamp_decision_made, feature_scores, blob_compression FROM transaction_records
+            WHERE mint = ?
+            ORDER BY timestamp_decision_made ASC, id ASC;
+            "#
+        )
+        .bind(mint)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch feature history")?;
+
+        rows.into_iter()
+            .map(|(timestamp, feature_scores, compression)| {
+                let feature_scores = BlobCompression::from_column(compression.as_deref())?.decompress(feature_scores)?;
+                Ok((timestamp as u64, deserialize_feature_scores(&feature_scores)?))
+            })
+            .collect()
+    }
+
+    async fn score_variance_for_mint(&self, mint: &str) -> Result<Option<f64>> {
+        // Skipped candidates were never scored, and a buy is recorded once when
+        // decided and again when sent, so count each scored decision once
+        let scores: Vec<(i64,)> = sqlx::query_as(
+            r#"
+            SELECT MIN(score) FROM transaction_records
+            WHERE mint = ? AND reason NOT LIKE ?
+            GROUP BY timestamp_decision_made
+            "#
+        )
+            .bind(mint)
+            .bind(format!("{}%", SkippedCandidate::REASON_PREFIX))
+            .fetch_all(&self.pool)
+            .await
+            .context("Failed to fetch scores for mint")?;
+
+        let scores: Vec<u8> = scores.into_iter().map(|(s,)| Score::clamped(s).value()).collect();
+        Ok(score_variance(&scores))
+    }
+
+    async fn get_win_rate_by_program(&self, since: u64) -> Result<HashMap<String, f64>> {
+        let rows: Vec<(String, f64)> = sqlx::query_as(
+            r#"
+            SELECT program, AVG(CASE WHEN actual_outcome LIKE '{"Profit"%' THEN 1.0 ELSE 0.0 END)
+            FROM transaction_records
+            WHERE timestamp_decision_made >= ?
+              AND (actual_outcome LIKE '{"Profit"%' OR actual_outcome LIKE '{"Loss"%')
+            GROUP BY program
+            "#
+        )
+        .bind(since as i64)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to compute win rate by program")?;
+
+        Ok(rows.into_iter().collect())
+    }
+
+    async fn get_outcome_counts(&self, since: u64) -> Result<HashMap<String, u64>> {
+        let rows: Vec<(String, i64)> = sqlx::query_as(
+            r#"
+            SELECT actual_outcome, COUNT(*)
+            FROM transaction_records
+            WHERE timestamp_decision_made >= ?
+            GROUP BY actual_outcome
+            "#
+        )
+        .bind(since as i64)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to count outcomes")?;
+
+        outcome_counts_from_rows(rows)
+    }
+
+    async fn count_rugs_by_funding_source(&self, funding_source: &str, max_pnl: f64) -> Result<u64> {
+        let (count,): (i64,) = sqlx::query_as(
+            r#"
+            SELECT COUNT(*) FROM transaction_records
+            WHERE funding_source = ? AND actual_outcome LIKE '{"Loss"%'
+              AND json_extract(actual_outcome, '$.Loss') <= ?
+            "#
+        )
+        .bind(funding_source)
+        .bind(max_pnl)
+        .fetch_one(&self.pool)
+        .await
+        .context("Failed to count rugs by funding source")?;
+
+        Ok(count as u64)
+    }
+
+    async fn count_recent_launches_by_creator(&self, creator: &str, window_seconds: u64) -> Result<u64> {
+        let (count,): (i64,) = sqlx::query_as(
+            r#"
+            SELECT COUNT(DISTINCT mint) FROM transaction_records
+            WHERE creator = ? AND timestamp_decision_made >= ?
+            "#
+        )
+        .bind(creator)
+        .bind(window_start_ms(window_seconds) as i64)
+        .fetch_one(&self.pool)
+        .await
+        .context("Failed to count recent launches by creator")?;
+
+        Ok(count as u64)
+    }
+
+    async fn get_top_mints_by_pnl(&self, limit: u32, since: u64) -> Result<Vec<(String, f64)>> {
+        let rows: Vec<(String, f64)> = sqlx::query_as(
+            r#"
+            SELECT mint,
+                   SUM(COALESCE(json_extract(actual_outcome, '$.Profit'), json_extract(actual_outcome, '$.Loss'))) AS total_pnl
+            FROM transaction_records
+            WHERE timestamp_decision_made >= ?
+              AND (actual_outcome LIKE '{"Profit"%' OR actual_outcome LIKE '{"Loss"%')
+            GROUP BY mint
+            ORDER BY total_pnl DESC, mint ASC
+            LIMIT ?
+            "#
+        )
+        .bind(since as i64)
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to rank mints by PnL")?;
+
+        Ok(rows)
+    }
+
+    async fn get_score_outcome_calibration(&self, since: u64) -> Result<Vec<ScoreBandStats>> {
+        // A score of 100 falls into the top band rather than a band of its own
+        let rows: Vec<(i64, i64, f64, f64)> = sqlx::query_as(
+            r#"
+            SELECT MIN(score, ?) / ? * ? AS band,
+                   COUNT(*),
+                   AVG(CASE WHEN actual_outcome LIKE '{"Profit"%' THEN 1.0 ELSE 0.0 END),
+                   AVG(COALESCE(json_extract(actual_outcome, '$.Profit'), json_extract(actual_outcome, '$.Loss')))
+            FROM transaction_records
+            WHERE timestamp_decision_made >= ?
+              AND (actual_outcome LIKE '{"Profit"%' OR actual_outcome LIKE '{"Loss"%')
+            GROUP BY band
+            ORDER BY band
+            "#
+        )
+        .bind(Score::MAX as i64 - 1)
+        .bind(SCORE_BAND_WIDTH as i64)
+        .bind(SCORE_BAND_WIDTH as i64)
+        .bind(since as i64)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to compute score outcome calibration")?;
+
+        Ok(score_bands_from_rows(rows))
+    }
+
     async fn health_check(&self) -> Result<bool> {
         match sqlx::query("SELECT 1").execute(&self.pool).await {
             Ok(_) => Ok(true),
@@ -338,15 +1026,17 @@ impl LedgerStorage for SqliteLedger {
     // === Persistent Monitoring Queue Implementation ===
     
     async fn enqueue_for_monitoring(&self, tx: &MonitoredTransaction) -> Result<()> {
+        let _write = lock_writes(self.serialize_writes).await;
         sqlx::query(
             r#"
-            INSERT OR REPLACE INTO monitoring_queue (signature, mint, initial_sol_spent, status, created_at)
-            VALUES (?, ?, ?, ?, ?)
+            INSERT OR REPLACE INTO monitoring_queue (signature, mint, initial_sol_spent, amount_bought_tokens, status, created_at)
+            VALUES (?, ?, ?, ?, ?, ?)
             "#
         )
         .bind(&tx.signature)
         .bind(tx.mint.clone())
         .bind(tx.initial_sol_spent)
+        .bind(tx.amount_bought_tokens)
         .bind("Pending")
         .bind(chrono::Utc::now().timestamp_millis())
         .execute(&self.pool)
@@ -358,9 +1048,9 @@ impl LedgerStorage for SqliteLedger {
     }
     
     async fn get_pending_monitoring_transactions(&self) -> Result<Vec<MonitoredTransaction>> {
-        let rows: Vec<(String, String, f64, i64)> = sqlx::query_as(
+        let rows: Vec<(String, String, f64, f64, i64)> = sqlx::query_as(
             r#"
-            SELECT signature, mint, initial_sol_spent, created_at 
+            SELECT signature, mint, initial_sol_spent, amount_bought_tokens, created_at 
             FROM monitoring_queue 
             WHERE status = 'Pending' 
             ORDER BY created_at ASC
@@ -371,14 +1061,14 @@ impl LedgerStorage for SqliteLedger {
         .context("Failed to fetch pending monitoring transactions")?;
         
         let mut transactions = Vec::new();
-        for (signature, mint, initial_sol_spent, created_at) in rows {
+        for (signature, mint, initial_sol_spent, amount_bought_tokens, created_at) in rows {
             // Calculate monitor_until as 30 seconds after creation (as per current TransactionMonitor logic)
             let monitor_until = (created_at + 30_000) as u64; // 30 seconds in milliseconds
             
             transactions.push(MonitoredTransaction {
                 signature,
                 mint: mint.clone(), // The mint field in DB is stored as String, same as Pubkey type alias
-                amount_bought_tokens: 0.0, // Not stored in queue, will be reconstructed if needed
+                amount_bought_tokens,
                 initial_sol_spent,
                 monitor_until,
             });
@@ -388,6 +1078,7 @@ impl LedgerStorage for SqliteLedger {
     }
     
     async fn update_monitoring_status(&self, signature: &str, status: &str) -> Result<()> {
+        let _write = lock_writes(self.serialize_writes).await;
         sqlx::query(
             r#"
             UPDATE monitoring_queue 
@@ -406,6 +1097,7 @@ impl LedgerStorage for SqliteLedger {
     }
     
     async fn cleanup_completed_monitoring(&self) -> Result<u64> {
+        let _write = lock_writes(self.serialize_writes).await;
         let result = sqlx::query(
             r#"
             DELETE FROM monitoring_queue 
@@ -423,32 +1115,186 @@ impl LedgerStorage for SqliteLedger {
         Ok(rows_affected)
     }
     
-    fn as_any(&self) -> &dyn std::any::Any {
-        self
+    async fn recompute_pnl(&self) -> Result<u64> {
+        // The flat schema stores no PnL; it is derived from the SOL amounts on read
+        Ok(0)
     }
-}
 
-impl SqliteLedger {
-    /// Helper method to convert a database row to a TransactionRecord
-    fn row_to_transaction_record(&self, row: TransactionRecordRow) -> Result<TransactionRecord> {
-        // Reconstruct the TransactionRecord from the database row
-        let scored_candidate = ScoredCandidate {
-            base: crate::types::PremintCandidate {
-                mint: row.mint.clone(),
-                creator: String::new(), // TODO: Store base candidate as JSON
-                program: String::new(),
-                slot: 0,
-                timestamp: row.timestamp_decision_made as u64,
+    async fn delete_records_for_mint(&self, mint: &str) -> Result<u64> {
+        let _write = lock_writes(self.serialize_writes).await;
+        let mut tx = self.pool.begin().await.context("Failed to begin transaction")?;
+        delete_outcome_history_for_mint(&mut tx, "transaction_records", "transaction_signature", mint).await?;
+        let deleted = sqlx::query("DELETE FROM transaction_records WHERE mint = ?")
+            .bind(mint)
+            .execute(&mut *tx)
+            .await
+            .context(format!("Failed to delete records for mint {}", mint))?
+            .rows_affected();
+        tx.commit().await.context("Failed to commit transaction")?;
+
+        info!("Deleted {} records for mint {}", deleted, mint);
+        Ok(deleted)
+    }
+
+    async fn record_rejection(&self, mint: &str, reason_code: ReasonCode, timestamp: u64) -> Result<()> {
+        let _write = lock_writes(self.serialize_writes).await;
+        insert_rejection(&self.pool, mint, reason_code, timestamp).await
+    }
+
+    async fn get_rejections_since(&self, timestamp: u64) -> Result<Vec<Rejection>> {
+        fetch_rejections_since(&self.pool, timestamp).await
+    }
+
+    async fn get_kill_switch_reset_at(&self) -> Result<Option<u64>> {
+        fetch_kill_switch_reset_at(&self.pool).await
+    }
+
+    async fn set_kill_switch_reset_at(&self, timestamp: u64) -> Result<()> {
+        let _write = lock_writes(self.serialize_writes).await;
+        save_kill_switch_reset_at(&self.pool, timestamp).await
+    }
+
+    async fn get_outcome_history(&self, signature: &str) -> Result<Vec<OutcomeTransition>> {
+        fetch_outcome_history(&self.pool, signature).await
+    }
+
+    async fn compact(&self) -> Result<()> {
+        let _write = lock_writes(self.serialize_writes).await;
+        compact_sqlite(&self.pool).await
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl SqliteLedger {
+    /// Insert one record using the given connection or transaction, with its
+    /// JSON blobs compressed by `compression`.
+    async fn insert_trade(
+        conn: &mut SqliteConnection,
+        record: &TransactionRecord,
+        compression: BlobCompression,
+    ) -> Result<i64> {
+        let feature_scores_json = serialize_feature_scores(&record.scored_candidate.feature_scores)?;
+        let market_context_json = serde_json::to_string(&record.market_context_snapshot)?;
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO transaction_records (
+                mint, score, reason, feature_scores, calculation_time, anomaly_detected,
+                timestamp_decision_made, transaction_signature, actual_outcome, market_context_snapshot,
+                buy_price_sol, sell_price_sol, amount_bought_tokens, amount_sold_tokens,
+                initial_sol_spent, final_sol_received, timestamp_transaction_sent, timestamp_outcome_evaluated,
+                program, funding_source, creator, anomaly_report, blob_compression, reason_codes
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?);
+            "#
+        )
+        .bind(record.scored_candidate.mint.clone())
+        .bind(record.scored_candidate.predicted_score.value() as i64)
+        .bind(record.scored_candidate.reason.clone())
+        .bind(compression.compress(feature_scores_json)?)
+        .bind(record.scored_candidate.calculation_time as i64)
+        .bind(record.scored_candidate.anomaly_detected)
+        .bind(record.timestamp_decision_made as i64)
+        .bind(record.transaction_signature.clone())
+        .bind(serde_json::to_string(&record.actual_outcome)?) // Serialize Outcome enum
+        .bind(compression.compress(market_context_json)?)
+        .bind(record.buy_price_sol)
+        .bind(record.sell_price_sol)
+        .bind(record.amount_bought_tokens)
+        .bind(record.amount_sold_tokens)
+        .bind(record.initial_sol_spent)
+        .bind(record.final_sol_received)
+        .bind(record.timestamp_transaction_sent.map(|t| t as i64))
+        .bind(record.timestamp_outcome_evaluated.map(|t| t as i64))
+        .bind(&record.scored_candidate.base.program)
+        .bind(&record.scored_candidate.base.funding_source)
+        .bind(&record.scored_candidate.base.creator)
+        .bind(anomaly_report_json(record)?)
+        .bind(compression.column_value())
+        .bind(reason_codes_json(record)?)
+        .execute(&mut *conn)
+        .await
+        .context("Failed to insert record into DB")?;
+
+        Ok(result.last_insert_rowid())
+    }
+
+    /// Helper method to convert a database row to a TransactionRecord
+    /// Write one outcome update using the given connection or transaction.
+    async fn apply_outcome_update(conn: &mut SqliteConnection, update: &OutcomeUpdate) -> Result<()> {
+        let previous: Option<(String,)> = sqlx::query_as(
+            "SELECT actual_outcome FROM transaction_records WHERE transaction_signature = ?"
+        )
+        .bind(&update.signature)
+        .fetch_optional(&mut *conn)
+        .await
+        .context(format!("Failed to read outcome for signature {}", update.signature))?;
+
+        sqlx::query(
+            r#"
+            UPDATE transaction_records
+            SET
+                actual_outcome = ?,
+                buy_price_sol = COALESCE(?, buy_price_sol),
+                sell_price_sol = COALESCE(?, sell_price_sol),
+                initial_sol_spent = COALESCE(?, initial_sol_spent),
+                final_sol_received = COALESCE(?, final_sol_received),
+                timestamp_outcome_evaluated = COALESCE(?, timestamp_outcome_evaluated),
+                is_verified = ?,
+                low_confidence = ?
+            WHERE transaction_signature = ?;
+            "#
+        )
+        .bind(serde_json::to_string(&update.outcome)?)
+        .bind(update.buy_price_sol)
+        .bind(update.sell_price_sol)
+        .bind(update.initial_sol_spent)
+        .bind(update.final_sol_received)
+        .bind(update.timestamp_evaluated.map(|t| t as i64))
+        .bind(update.is_verified)
+        .bind(update.low_confidence)
+        .bind(&update.signature)
+        .execute(&mut *conn)
+        .await
+        .context(format!("Failed to update outcome for signature {}", update.signature))?;
+
+        if let Some((from,)) = previous {
+            insert_outcome_transition(conn, &from, update).await?;
+        }
+        Ok(())
+    }
+
+    fn row_to_transaction_record(&self, row: TransactionRecordRow) -> Result<TransactionRecord> {
+        let compression = BlobCompression::from_column(row.blob_compression.as_deref())?;
+        let feature_scores = compression.decompress(row.feature_scores)?;
+        let market_context_snapshot = compression.decompress(row.market_context_snapshot)?;
+
+        // Reconstruct the TransactionRecord from the database row
+        let scored_candidate = ScoredCandidate {
+            base: crate::types::PremintCandidate {
+                mint: row.mint.clone(),
+                creator: row.creator,
+                program: row.program,
+                slot: 0,
+                timestamp: row.timestamp_decision_made as u64,
                 instruction_summary: None,
                 is_jito_bundle: None,
+                funding_source: row.funding_source,
             },
             mint: row.mint.clone(),
-            predicted_score: row.score as u8,
+            predicted_score: Score::clamped(row.score),
             reason: row.reason,
-            feature_scores: serde_json::from_str(&row.feature_scores)?,
+            feature_scores: deserialize_feature_scores(&feature_scores)?,
             calculation_time: row.calculation_time as u128,
             anomaly_detected: row.anomaly_detected,
             timestamp: row.timestamp_decision_made as u64,
+            data_source_timestamps: std::collections::HashMap::new(), // Not persisted
+            scoring_trace: None, // Not persisted
+            reason_codes: reason_codes_from_column(row.reason_codes.as_deref())?,
+            anomaly_report: row.anomaly_report.as_deref().map(serde_json::from_str).transpose()?,
+            action: TradeAction::of_stored_decision(row.transaction_signature.as_deref()),
         };
 
         Ok(TransactionRecord {
@@ -465,20 +1311,61 @@ impl SqliteLedger {
             timestamp_transaction_sent: row.timestamp_transaction_sent.map(|t| t as u64),
             timestamp_outcome_evaluated: row.timestamp_outcome_evaluated.map(|t| t as u64),
             actual_outcome: serde_json::from_str(&row.actual_outcome)?,
-            market_context_snapshot: serde_json::from_str(&row.market_context_snapshot)?,
+            market_context_snapshot: serde_json::from_str(&market_context_snapshot)?,
         })
     }
 }
 
+/// A `trades` row, before its features and market context are joined in.
+#[derive(FromRow)]
+struct NormalizedTradeRow {
+    id: i64,
+    mint: String,
+    decision_timestamp: i64,
+    signature: Option<String>,
+    final_outcome: Option<String>,
+    pnl_sol: Option<f64>,
+    initial_sol_spent: Option<f64>,
+    final_sol_received: Option<f64>,
+    program: String,
+    score: i64,
+    funding_source: Option<String>,
+    creator: String,
+    anomaly_report: Option<String>,
+    slot: Option<i64>,
+    candidate_timestamp: Option<i64>,
+    instruction_summary: Option<String>,
+    is_jito_bundle: Option<bool>,
+    reason: Option<String>,
+    calculation_time: Option<i64>,
+    anomaly_detected: Option<bool>,
+    reason_codes: Option<String>,
+}
+
 impl SqliteLedgerNormalized {
     /// Creates a new SqliteLedgerNormalized with the normalized table schema.
     /// This is the new, optimized schema designed for analytical queries.
     pub async fn new() -> Result<Arc<Self>> {
+        Self::new_with_max_lookback(None).await
+    }
+
+    /// Like `new`, but `get_records_since` never scans further back than
+    /// `max_lookback_seconds` before now.
+    pub async fn new_with_max_lookback(max_lookback_seconds: Option<u64>) -> Result<Arc<Self>> {
+        Self::new_with_options(SqliteStorageOptions { max_lookback_seconds, ..Default::default() }).await
+    }
+
+    /// Like `new`, with the given options.
+    pub async fn new_with_options(options: SqliteStorageOptions) -> Result<Arc<Self>> {
+        // The normalized schema stores no JSON blobs to compress
+        let SqliteStorageOptions { db_path, max_lookback_seconds, serialize_writes, .. } = options;
         let pool = SqlitePoolOptions::new()
             .max_connections(5)
-            .connect(&format!("sqlite:{}?mode=rwc", DB_FILE))
+            .connect(&format!("sqlite:{}?mode=rwc", db_path.display()))
             .await
             .context("Failed to connect to SQLite database")?;
+        // Schema setup writes too
+        let _write = lock_writes(serialize_writes).await;
 
         // Create the normalized schema tables
         // Main trades table
@@ -491,7 +1378,22 @@ impl SqliteLedgerNormalized {
                 signature TEXT UNIQUE,
                 final_outcome TEXT,
                 pnl_sol REAL,
-                is_verified BOOLEAN NOT NULL DEFAULT FALSE
+                is_verified BOOLEAN NOT NULL DEFAULT FALSE,
+                initial_sol_spent REAL,
+                final_sol_received REAL,
+                program TEXT NOT NULL DEFAULT '',
+                score INTEGER NOT NULL DEFAULT 0,
+                funding_source TEXT,
+                creator TEXT NOT NULL DEFAULT '',
+                anomaly_report TEXT,
+                slot INTEGER,
+                candidate_timestamp INTEGER,
+                instruction_summary TEXT,
+                is_jito_bundle BOOLEAN,
+                reason TEXT,
+                calculation_time INTEGER,
+                anomaly_detected BOOLEAN,
+                low_confidence BOOLEAN NOT NULL DEFAULT FALSE
             );
             "#
         )
@@ -499,6 +1401,32 @@ impl SqliteLedgerNormalized {
         .await
         .context("Failed to create trades table")?;
 
+        // Databases created before the SOL amounts, program, score, funding source, creator and anomalies were stored lack these columns
+        for column in ["initial_sol_spent", "final_sol_received"] {
+            add_column_if_missing(&pool, "trades", column, "REAL").await?;
+        }
+        add_column_if_missing(&pool, "trades", "program", "TEXT NOT NULL DEFAULT ''").await?;
+        add_column_if_missing(&pool, "trades", "score", "INTEGER NOT NULL DEFAULT 0").await?;
+        add_column_if_missing(&pool, "trades", "funding_source", "TEXT").await?;
+        add_column_if_missing(&pool, "trades", "creator", "TEXT NOT NULL DEFAULT ''").await?;
+        add_column_if_missing(&pool, "trades", "anomaly_report", "TEXT").await?;
+        // Rows written before the rest of the candidate was stored read these as NULL
+        for (column, decl) in [
+            ("slot", "INTEGER"),
+            ("candidate_timestamp", "INTEGER"),
+            ("instruction_summary", "TEXT"),
+            ("is_jito_bundle", "BOOLEAN"),
+            ("reason", "TEXT"),
+            ("calculation_time", "INTEGER"),
+            ("anomaly_detected", "BOOLEAN"),
+        ] {
+            add_column_if_missing(&pool, "trades", column, decl).await?;
+        }
+        // Outcomes stored before estimates were flagged read as confident
+        add_column_if_missing(&pool, "trades", "low_confidence", "BOOLEAN NOT NULL DEFAULT FALSE").await?;
+        // Decisions stored before their reason codes read as having none
+        add_column_if_missing(&pool, "trades", "reason_codes", "TEXT").await?;
+
         // Table for storing feature values at decision time
         sqlx::query(
             r#"
@@ -538,6 +1466,7 @@ impl SqliteLedgerNormalized {
                 signature TEXT PRIMARY KEY NOT NULL,
                 mint TEXT NOT NULL,
                 initial_sol_spent REAL NOT NULL,
+                amount_bought_tokens REAL NOT NULL DEFAULT 0,
                 -- Status: Pending, Processing, Completed, Failed
                 status TEXT NOT NULL DEFAULT 'Pending',
                 created_at INTEGER NOT NULL
@@ -547,31 +1476,36 @@ impl SqliteLedgerNormalized {
         .execute(&pool)
         .await
         .context("Failed to create monitoring_queue table")?;
+        // Queues created before the bought amount was stored read it as 0, an unknown buy price
+        add_column_if_missing(&pool, "monitoring_queue", "amount_bought_tokens", "REAL NOT NULL DEFAULT 0").await?;
+
+        create_rejections_table(&pool).await?;
+        create_outcome_history_table(&pool).await?;
+        create_kill_switch_table(&pool).await?;
 
         info!("SqliteLedgerNormalized initialized with normalized schema");
 
-        Ok(Arc::new(Self { pool }))
+        Ok(Arc::new(Self { pool, max_lookback_seconds, serialize_writes }))
     }
 
     /// Get a reference to the database pool
     pub fn get_db_pool(&self) -> &Pool<Sqlite> {
         &self.pool
     }
-}
-
-#[async_trait]
-impl LedgerStorage for SqliteLedgerNormalized {
-    async fn insert_record(&self, record: &TransactionRecord) -> Result<i64> {
-        debug!("Inserting new transaction record for mint: {} (normalized schema)", record.scored_candidate.mint);
-        
-        // Start a transaction to ensure atomicity
-        let mut tx = self.pool.begin().await.context("Failed to begin transaction")?;
 
+    /// Insert one trade with its features and market context using the given
+    /// transaction, so a failure leaves none of them behind.
+    async fn insert_trade(conn: &mut SqliteConnection, record: &TransactionRecord) -> Result<i64> {
         // 1. Insert into trades table and get the ID
         let trade_id = sqlx::query(
             r#"
-            INSERT INTO trades (mint, decision_timestamp, signature, final_outcome, pnl_sol, is_verified)
-            VALUES (?, ?, ?, ?, ?, ?)
+            INSERT INTO trades (
+                mint, decision_timestamp, signature, final_outcome, pnl_sol, is_verified,
+                initial_sol_spent, final_sol_received, program, score, funding_source, creator, anomaly_report,
+                slot, candidate_timestamp, instruction_summary, is_jito_bundle, reason, calculation_time, anomaly_detected,
+                reason_codes
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#
         )
         .bind(&record.scored_candidate.mint)
@@ -582,7 +1516,22 @@ impl LedgerStorage for SqliteLedgerNormalized {
             received - record.initial_sol_spent.unwrap_or(0.0)
         }))
         .bind(record.actual_outcome != Outcome::NotExecuted && record.actual_outcome != Outcome::PendingConfirmation)
-        .execute(&mut *tx)
+        .bind(record.initial_sol_spent)
+        .bind(record.final_sol_received)
+        .bind(&record.scored_candidate.base.program)
+        .bind(record.scored_candidate.predicted_score.value() as i64)
+        .bind(&record.scored_candidate.base.funding_source)
+        .bind(&record.scored_candidate.base.creator)
+        .bind(anomaly_report_json(record)?)
+        .bind(record.scored_candidate.base.slot as i64)
+        .bind(record.scored_candidate.base.timestamp as i64)
+        .bind(&record.scored_candidate.base.instruction_summary)
+        .bind(record.scored_candidate.base.is_jito_bundle)
+        .bind(&record.scored_candidate.reason)
+        .bind(record.scored_candidate.calculation_time as i64)
+        .bind(record.scored_candidate.anomaly_detected)
+        .bind(reason_codes_json(record)?)
+        .execute(&mut *conn)
         .await
         .context("Failed to insert into trades table")?
         .last_insert_rowid();
@@ -595,7 +1544,7 @@ impl LedgerStorage for SqliteLedgerNormalized {
             .bind(trade_id)
             .bind(feature_name)
             .bind(feature_value)
-            .execute(&mut *tx)
+            .execute(&mut *conn)
             .await
             .context("Failed to insert feature score")?;
         }
@@ -608,82 +1557,17 @@ impl LedgerStorage for SqliteLedgerNormalized {
             .bind(trade_id)
             .bind(metric_name)
             .bind(metric_value)
-            .execute(&mut *tx)
+            .execute(&mut *conn)
             .await
             .context("Failed to insert market context")?;
         }
 
-        // Commit the transaction
-        tx.commit().await.context("Failed to commit transaction")?;
-
         Ok(trade_id)
     }
 
-    async fn update_outcome(
-        &self,
-        signature: &str,
-        outcome: Outcome,
-        buy_price_sol: Option<f64>,
-        sell_price_sol: Option<f64>,
-        initial_sol_spent: Option<f64>,
-        final_sol_received: Option<f64>,
-        timestamp_evaluated: Option<u64>,
-        is_verified: bool,
-    ) -> Result<()> {
-        debug!("Updating outcome for signature: {} (normalized schema, verified: {})", signature, is_verified);
-
-        let pnl_sol = match (final_sol_received, initial_sol_spent) {
-            (Some(received), Some(spent)) => Some(received - spent),
-            _ => None,
-        };
-
-        sqlx::query(
-            r#"
-            UPDATE trades
-            SET
-                final_outcome = ?,
-                pnl_sol = COALESCE(?, pnl_sol),
-                is_verified = ?
-            WHERE signature = ?
-            "#
-        )
-        .bind(serde_json::to_string(&outcome)?)
-        .bind(pnl_sol)
-        .bind(is_verified)
-        .bind(signature)
-        .execute(&self.pool)
-        .await
-        .context(format!("Failed to update outcome for signature {}", signature))?;
-
-        Ok(())
-    }
-
-    async fn get_records_since(&self, timestamp: u64) -> Result<Vec<TransactionRecord>> {
-        // This is a complex query that joins all three tables to reconstruct the records
-        #[derive(sqlx::FromRow)]
-        struct TradeRow {
-            id: i64,
-            mint: String,
-            decision_timestamp: i64,
-            signature: Option<String>,
-            final_outcome: Option<String>,
-            pnl_sol: Option<f64>,
-            is_verified: bool,
-        }
-        
-        let trade_rows: Vec<TradeRow> = sqlx::query_as(
-            r#"
-            SELECT id, mint, decision_timestamp, signature, final_outcome, pnl_sol, is_verified
-            FROM trades 
-            WHERE decision_timestamp >= ? 
-            ORDER BY decision_timestamp ASC
-            "#
-        )
-        .bind(timestamp as i64)
-        .fetch_all(&self.pool)
-        .await
-        .context("Failed to fetch trade records")?;
-
+    /// Reconstruct full records from `trades` rows, joining in their feature
+    /// scores and market context.
+    async fn load_trade_records(&self, trade_rows: Vec<NormalizedTradeRow>) -> Result<Vec<TransactionRecord>> {
         let mut records = Vec::new();
 
         for trade_row in trade_rows {
@@ -727,24 +1611,34 @@ impl LedgerStorage for SqliteLedgerNormalized {
                 market_context_snapshot.insert(context_row.metric_name, context_row.metric_value);
             }
 
-            // Reconstruct the TransactionRecord
+            let anomaly_report: Option<AnomalyReport> =
+                trade_row.anomaly_report.as_deref().map(serde_json::from_str).transpose()?;
+
+            // Reconstruct the TransactionRecord; rows written before the whole
+            // candidate was stored fall back to defaults
             let scored_candidate = ScoredCandidate {
                 base: crate::types::PremintCandidate {
                     mint: trade_row.mint.clone(),
-                    creator: String::new(), // TODO: Store in separate table
-                    program: String::new(),
-                    slot: 0,
-                    timestamp: trade_row.decision_timestamp as u64,
-                    instruction_summary: None,
-                    is_jito_bundle: None,
+                    creator: trade_row.creator,
+                    program: trade_row.program,
+                    slot: trade_row.slot.unwrap_or(0) as u64,
+                    timestamp: trade_row.candidate_timestamp.unwrap_or(trade_row.decision_timestamp) as u64,
+                    instruction_summary: trade_row.instruction_summary,
+                    is_jito_bundle: trade_row.is_jito_bundle,
+                    funding_source: trade_row.funding_source,
                 },
                 mint: trade_row.mint.clone(),
-                predicted_score: 0, // TODO: Store in trades table
-                reason: String::new(), // TODO: Store in trades table
+                predicted_score: Score::clamped(trade_row.score),
+                reason: trade_row.reason.unwrap_or_default(),
                 feature_scores,
-                calculation_time: 0, // TODO: Store in trades table
-                anomaly_detected: false, // TODO: Store in trades table
+                calculation_time: trade_row.calculation_time.unwrap_or(0) as u128,
+                anomaly_detected: trade_row.anomaly_detected.unwrap_or(anomaly_report.is_some()),
                 timestamp: trade_row.decision_timestamp as u64,
+                data_source_timestamps: std::collections::HashMap::new(), // Not persisted
+                scoring_trace: None, // Not persisted
+                reason_codes: reason_codes_from_column(trade_row.reason_codes.as_deref())?,
+                anomaly_report,
+                action: TradeAction::of_stored_decision(trade_row.signature.as_deref()),
             };
 
             records.push(TransactionRecord {
@@ -755,8 +1649,11 @@ impl LedgerStorage for SqliteLedgerNormalized {
                 sell_price_sol: None, // TODO: Store in trades table
                 amount_bought_tokens: None, // TODO: Store in trades table
                 amount_sold_tokens: None, // TODO: Store in trades table
-                initial_sol_spent: trade_row.pnl_sol.map(|pnl| if pnl < 0.0 { -pnl } else { 0.0 }),
-                final_sol_received: trade_row.pnl_sol.map(|pnl| if pnl > 0.0 { pnl } else { 0.0 }),
+                // Rows written before the SOL amounts were stored only have the PnL
+                initial_sol_spent: trade_row.initial_sol_spent
+                    .or(trade_row.pnl_sol.map(|pnl| if pnl < 0.0 { -pnl } else { 0.0 })),
+                final_sol_received: trade_row.final_sol_received
+                    .or(trade_row.pnl_sol.map(|pnl| if pnl > 0.0 { pnl } else { 0.0 })),
                 timestamp_decision_made: trade_row.decision_timestamp as u64,
                 timestamp_transaction_sent: None, // TODO: Store in trades table
                 timestamp_outcome_evaluated: None, // TODO: Store in trades table
@@ -768,6 +1665,160 @@ impl LedgerStorage for SqliteLedgerNormalized {
         Ok(records)
     }
 
+    /// Write one outcome update using the given connection or transaction.
+    /// Prices and evaluation time have no columns in this schema.
+    async fn apply_outcome_update(conn: &mut SqliteConnection, update: &OutcomeUpdate) -> Result<()> {
+        let previous: Option<(Option<String>,)> = sqlx::query_as(
+            "SELECT final_outcome FROM trades WHERE signature = ?"
+        )
+        .bind(&update.signature)
+        .fetch_optional(&mut *conn)
+        .await
+        .context(format!("Failed to read outcome for signature {}", update.signature))?;
+
+        let pnl_sol = match (update.final_sol_received, update.initial_sol_spent) {
+            (Some(received), Some(spent)) => Some(received - spent),
+            _ => None,
+        };
+
+        sqlx::query(
+            r#"
+            UPDATE trades
+            SET
+                final_outcome = ?,
+                pnl_sol = COALESCE(?, pnl_sol),
+                is_verified = ?,
+                low_confidence = ?,
+                initial_sol_spent = COALESCE(?, initial_sol_spent),
+                final_sol_received = COALESCE(?, final_sol_received)
+            WHERE signature = ?
+            "#
+        )
+        .bind(serde_json::to_string(&update.outcome)?)
+        .bind(pnl_sol)
+        .bind(update.is_verified)
+        .bind(update.low_confidence)
+        .bind(update.initial_sol_spent)
+        .bind(update.final_sol_received)
+        .bind(&update.signature)
+        .execute(&mut *conn)
+        .await
+        .context(format!("Failed to update outcome for signature {}", update.signature))?;
+
+        if let Some((from,)) = previous {
+            // Trades inserted without an outcome are still pending
+            let from = match from {
+                Some(from) => from,
+                None => serde_json::to_string(&Outcome::PendingConfirmation)?,
+            };
+            insert_outcome_transition(conn, &from, update).await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl LedgerStorage for SqliteLedgerNormalized {
+    async fn insert_record(&self, record: &TransactionRecord) -> Result<i64> {
+        let _write = lock_writes(self.serialize_writes).await;
+        debug!("Inserting new transaction record for mint: {} (normalized schema)", record.scored_candidate.mint);
+        
+        // Start a transaction to ensure atomicity
+        let mut tx = self.pool.begin().await.context("Failed to begin transaction")?;
+        let trade_id = Self::insert_trade(&mut tx, record).await?;
+        tx.commit().await.context("Failed to commit transaction")?;
+
+        Ok(trade_id)
+    }
+
+    async fn insert_records(&self, records: &[TransactionRecord]) -> Result<Vec<i64>> {
+        let _write = lock_writes(self.serialize_writes).await;
+        debug!("Inserting {} transaction records in one transaction (normalized schema)", records.len());
+
+        // One transaction for the whole batch: the inserts are prepared once and
+        // reused, and a failure part way through leaves no records behind
+        let mut tx = self.pool.begin().await.context("Failed to begin transaction")?;
+        let mut ids = Vec::with_capacity(records.len());
+        for record in records {
+            ids.push(Self::insert_trade(&mut tx, record).await?);
+        }
+        tx.commit().await.context("Failed to commit records")?;
+
+        Ok(ids)
+    }
+
+    async fn update_outcome(
+        &self,
+        signature: &str,
+        outcome: Outcome,
+        buy_price_sol: Option<f64>,
+        sell_price_sol: Option<f64>,
+        initial_sol_spent: Option<f64>,
+        final_sol_received: Option<f64>,
+        timestamp_evaluated: Option<u64>,
+        is_verified: bool,
+        outcome_reason: Option<String>,
+    ) -> Result<()> {
+        let _write = lock_writes(self.serialize_writes).await;
+        debug!("Updating outcome for signature: {} (normalized schema, verified: {})", signature, is_verified);
+
+        let update = OutcomeUpdate {
+            signature: signature.to_string(),
+            outcome,
+            buy_price_sol,
+            sell_price_sol,
+            initial_sol_spent,
+            final_sol_received,
+            timestamp_evaluated,
+            is_verified,
+            outcome_reason,
+            low_confidence: false,
+        };
+
+        let mut tx = self.pool.begin().await.context("Failed to begin transaction")?;
+        Self::apply_outcome_update(&mut tx, &update).await?;
+        tx.commit().await.context("Failed to commit outcome update")?;
+        Ok(())
+    }
+
+    async fn update_outcomes_batch(&self, updates: &[OutcomeUpdate]) -> Result<()> {
+        let _write = lock_writes(self.serialize_writes).await;
+        debug!("Updating {} outcomes in one transaction (normalized schema)", updates.len());
+
+        let mut tx = self.pool.begin().await.context("Failed to begin transaction")?;
+        for update in updates {
+            Self::apply_outcome_update(&mut tx, update).await?;
+        }
+        tx.commit().await.context("Failed to commit outcome updates")?;
+
+        Ok(())
+    }
+
+    async fn get_records_paged(&self, since: u64, until: u64, offset: u64, limit: u64) -> Result<Vec<TransactionRecord>> {
+        let since = clamp_lookback(since, self.max_lookback_seconds);
+        let trade_rows: Vec<NormalizedTradeRow> = sqlx::query_as(
+            r#"
+            SELECT id, mint, decision_timestamp, signature, final_outcome, pnl_sol, is_verified,
+                   initial_sol_spent, final_sol_received, program, score, funding_source, creator,
+                   anomaly_report, slot, candidate_timestamp, instruction_summary, is_jito_bundle,
+                   reason, calculation_time, anomaly_detected, reason_codes
+            FROM trades 
+            WHERE decision_timestamp >= ? AND decision_timestamp < ?
+            ORDER BY decision_timestamp ASC, id ASC
+            LIMIT ? OFFSET ?
+            "#
+        )
+        .bind(sql_bound(since))
+        .bind(sql_bound(until))
+        .bind(sql_bound(limit))
+        .bind(sql_bound(offset))
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch trade records")?;
+
+        self.load_trade_records(trade_rows).await
+    }
+
     async fn get_record_by_id(&self, id: i64) -> Result<Option<TransactionRecord>> {
         // For simplicity, delegate to get_records_since and filter
         let all_records = self.get_records_since(0).await?;
@@ -778,7 +1829,7 @@ impl LedgerStorage for SqliteLedgerNormalized {
         // For simplicity, delegate to get_records_since and filter
         let all_records = self.get_records_since(0).await?;
         Ok(all_records.into_iter().find(|r| 
-            r.transaction_signature.as_ref().map(|s| s.as_str()) == Some(signature)
+            r.transaction_signature.as_deref() == Some(signature)
         ))
     }
 
@@ -796,6 +1847,197 @@ impl LedgerStorage for SqliteLedgerNormalized {
         Ok(count.count)
     }
 
+    async fn get_unresolved_decisions(&self, older_than: u64) -> Result<Vec<TransactionRecord>> {
+        // Trades inserted without an outcome are still pending
+        let trade_rows: Vec<NormalizedTradeRow> = sqlx::query_as(
+            r#"
+            SELECT id, mint, decision_timestamp, signature, COALESCE(final_outcome, ?) AS final_outcome,
+                   pnl_sol, initial_sol_spent, final_sol_received, program, score, funding_source, creator,
+                   anomaly_report, slot, candidate_timestamp, instruction_summary, is_jito_bundle,
+                   reason, calculation_time, anomaly_detected, reason_codes
+            FROM trades
+            WHERE (final_outcome IS NULL OR final_outcome = ?) AND decision_timestamp < ?
+            ORDER BY decision_timestamp ASC, id ASC
+            "#
+        )
+        .bind(serde_json::to_string(&Outcome::PendingConfirmation)?)
+        .bind(serde_json::to_string(&Outcome::PendingConfirmation)?)
+        .bind(sql_bound(older_than))
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch unresolved decisions")?;
+
+        self.load_trade_records(trade_rows).await
+    }
+
+    async fn get_feature_history_for_mint(&self, mint: &str) -> Result<Vec<(u64, HashMap<String, f64>)>> {
+        // One row per feature; trades without features still yield a snapshot
+        let rows: Vec<(i64, i64, Option<String>, Option<f64>)> = sqlx::query_as(
+            r#"
+            SELECT t.id, t.decision_timestamp, f.feature_name, f.feature_value
+            FROM trades t
+            LEFT JOIN decision_features f ON f.trade_id = t.id
+            WHERE t.mint = ?
+            ORDER BY t.decision_timestamp ASC, t.id ASC
+            "#
+        )
+        .bind(mint)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch feature history")?;
+
+        let mut history: Vec<(u64, HashMap<String, f64>)> = Vec::new();
+        let mut current_trade = None;
+        for (trade_id, timestamp, feature_name, feature_value) in rows {
+            if current_trade != Some(trade_id) {
+                current_trade = Some(trade_id);
+                history.push((timestamp as u64, HashMap::new()));
+            }
+            if let (Some(name), Some(value), Some((_, features))) = (feature_name, feature_value, history.last_mut()) {
+                features.insert(name, value);
+            }
+        }
+
+        Ok(history)
+    }
+
+    async fn score_variance_for_mint(&self, mint: &str) -> Result<Option<f64>> {
+        // Skipped candidates were never scored, and a buy is recorded once when
+        // decided and again when sent, so count each scored decision once
+        let scores: Vec<(i64,)> = sqlx::query_as(
+            r#"
+            SELECT MIN(score) FROM trades
+            WHERE mint = ? AND COALESCE(reason, '') NOT LIKE ?
+            GROUP BY decision_timestamp
+            "#
+        )
+            .bind(mint)
+            .bind(format!("{}%", SkippedCandidate::REASON_PREFIX))
+            .fetch_all(&self.pool)
+            .await
+            .context("Failed to fetch scores for mint")?;
+
+        let scores: Vec<u8> = scores.into_iter().map(|(s,)| Score::clamped(s).value()).collect();
+        Ok(score_variance(&scores))
+    }
+
+    async fn get_win_rate_by_program(&self, since: u64) -> Result<HashMap<String, f64>> {
+        let rows: Vec<(String, f64)> = sqlx::query_as(
+            r#"
+            SELECT program, AVG(CASE WHEN final_outcome LIKE '{"Profit"%' THEN 1.0 ELSE 0.0 END)
+            FROM trades
+            WHERE decision_timestamp >= ?
+              AND (final_outcome LIKE '{"Profit"%' OR final_outcome LIKE '{"Loss"%')
+            GROUP BY program
+            "#
+        )
+        .bind(since as i64)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to compute win rate by program")?;
+
+        Ok(rows.into_iter().collect())
+    }
+
+    async fn get_outcome_counts(&self, since: u64) -> Result<HashMap<String, u64>> {
+        // Trades inserted without an outcome were never executed
+        let rows: Vec<(String, i64)> = sqlx::query_as(
+            r#"
+            SELECT COALESCE(final_outcome, '"NotExecuted"'), COUNT(*)
+            FROM trades
+            WHERE decision_timestamp >= ?
+            GROUP BY final_outcome
+            "#
+        )
+        .bind(since as i64)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to count outcomes")?;
+
+        outcome_counts_from_rows(rows)
+    }
+
+    async fn count_rugs_by_funding_source(&self, funding_source: &str, max_pnl: f64) -> Result<u64> {
+        let (count,): (i64,) = sqlx::query_as(
+            r#"
+            SELECT COUNT(*) FROM trades
+            WHERE funding_source = ? AND final_outcome LIKE '{"Loss"%'
+              AND json_extract(final_outcome, '$.Loss') <= ?
+            "#
+        )
+        .bind(funding_source)
+        .bind(max_pnl)
+        .fetch_one(&self.pool)
+        .await
+        .context("Failed to count rugs by funding source")?;
+
+        Ok(count as u64)
+    }
+
+    async fn count_recent_launches_by_creator(&self, creator: &str, window_seconds: u64) -> Result<u64> {
+        let (count,): (i64,) = sqlx::query_as(
+            r#"
+            SELECT COUNT(DISTINCT mint) FROM trades
+            WHERE creator = ? AND decision_timestamp >= ?
+            "#
+        )
+        .bind(creator)
+        .bind(window_start_ms(window_seconds) as i64)
+        .fetch_one(&self.pool)
+        .await
+        .context("Failed to count recent launches by creator")?;
+
+        Ok(count as u64)
+    }
+
+    async fn get_top_mints_by_pnl(&self, limit: u32, since: u64) -> Result<Vec<(String, f64)>> {
+        let rows: Vec<(String, f64)> = sqlx::query_as(
+            r#"
+            SELECT mint,
+                   SUM(COALESCE(json_extract(final_outcome, '$.Profit'), json_extract(final_outcome, '$.Loss'))) AS total_pnl
+            FROM trades
+            WHERE decision_timestamp >= ?
+              AND (final_outcome LIKE '{"Profit"%' OR final_outcome LIKE '{"Loss"%')
+            GROUP BY mint
+            ORDER BY total_pnl DESC, mint ASC
+            LIMIT ?
+            "#
+        )
+        .bind(since as i64)
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to rank mints by PnL")?;
+
+        Ok(rows)
+    }
+
+    async fn get_score_outcome_calibration(&self, since: u64) -> Result<Vec<ScoreBandStats>> {
+        // A score of 100 falls into the top band rather than a band of its own
+        let rows: Vec<(i64, i64, f64, f64)> = sqlx::query_as(
+            r#"
+            SELECT MIN(score, ?) / ? * ? AS band,
+                   COUNT(*),
+                   AVG(CASE WHEN final_outcome LIKE '{"Profit"%' THEN 1.0 ELSE 0.0 END),
+                   AVG(COALESCE(json_extract(final_outcome, '$.Profit'), json_extract(final_outcome, '$.Loss')))
+            FROM trades
+            WHERE decision_timestamp >= ?
+              AND (final_outcome LIKE '{"Profit"%' OR final_outcome LIKE '{"Loss"%')
+            GROUP BY band
+            ORDER BY band
+            "#
+        )
+        .bind(Score::MAX as i64 - 1)
+        .bind(SCORE_BAND_WIDTH as i64)
+        .bind(SCORE_BAND_WIDTH as i64)
+        .bind(since as i64)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to compute score outcome calibration")?;
+
+        Ok(score_bands_from_rows(rows))
+    }
+
     async fn health_check(&self) -> Result<bool> {
         match sqlx::query("SELECT 1").execute(&self.pool).await {
             Ok(_) => Ok(true),
@@ -806,15 +2048,17 @@ impl LedgerStorage for SqliteLedgerNormalized {
     // === Persistent Monitoring Queue Implementation ===
     
     async fn enqueue_for_monitoring(&self, tx: &MonitoredTransaction) -> Result<()> {
+        let _write = lock_writes(self.serialize_writes).await;
         sqlx::query(
             r#"
-            INSERT OR REPLACE INTO monitoring_queue (signature, mint, initial_sol_spent, status, created_at)
-            VALUES (?, ?, ?, ?, ?)
+            INSERT OR REPLACE INTO monitoring_queue (signature, mint, initial_sol_spent, amount_bought_tokens, status, created_at)
+            VALUES (?, ?, ?, ?, ?, ?)
             "#
         )
         .bind(&tx.signature)
         .bind(tx.mint.clone())
         .bind(tx.initial_sol_spent)
+        .bind(tx.amount_bought_tokens)
         .bind("Pending")
         .bind(chrono::Utc::now().timestamp_millis())
         .execute(&self.pool)
@@ -826,9 +2070,9 @@ impl LedgerStorage for SqliteLedgerNormalized {
     }
     
     async fn get_pending_monitoring_transactions(&self) -> Result<Vec<MonitoredTransaction>> {
-        let rows: Vec<(String, String, f64, i64)> = sqlx::query_as(
+        let rows: Vec<(String, String, f64, f64, i64)> = sqlx::query_as(
             r#"
-            SELECT signature, mint, initial_sol_spent, created_at 
+            SELECT signature, mint, initial_sol_spent, amount_bought_tokens, created_at 
             FROM monitoring_queue 
             WHERE status = 'Pending' 
             ORDER BY created_at ASC
@@ -839,14 +2083,14 @@ impl LedgerStorage for SqliteLedgerNormalized {
         .context("Failed to fetch pending monitoring transactions")?;
         
         let mut transactions = Vec::new();
-        for (signature, mint, initial_sol_spent, created_at) in rows {
+        for (signature, mint, initial_sol_spent, amount_bought_tokens, created_at) in rows {
             // Calculate monitor_until as 30 seconds after creation (as per current TransactionMonitor logic)
             let monitor_until = (created_at + 30_000) as u64; // 30 seconds in milliseconds
             
             transactions.push(MonitoredTransaction {
                 signature,
                 mint: mint.clone(), // The mint field in DB is stored as String, same as Pubkey type alias
-                amount_bought_tokens: 0.0, // Not stored in queue, will be reconstructed if needed
+                amount_bought_tokens,
                 initial_sol_spent,
                 monitor_until,
             });
@@ -856,6 +2100,7 @@ impl LedgerStorage for SqliteLedgerNormalized {
     }
     
     async fn update_monitoring_status(&self, signature: &str, status: &str) -> Result<()> {
+        let _write = lock_writes(self.serialize_writes).await;
         sqlx::query(
             r#"
             UPDATE monitoring_queue 
@@ -874,6 +2119,7 @@ impl LedgerStorage for SqliteLedgerNormalized {
     }
     
     async fn cleanup_completed_monitoring(&self) -> Result<u64> {
+        let _write = lock_writes(self.serialize_writes).await;
         let result = sqlx::query(
             r#"
             DELETE FROM monitoring_queue 
@@ -891,6 +2137,90 @@ impl LedgerStorage for SqliteLedgerNormalized {
         Ok(rows_affected)
     }
     
+    async fn recompute_pnl(&self) -> Result<u64> {
+        let _write = lock_writes(self.serialize_writes).await;
+        let result = sqlx::query(
+            r#"
+            UPDATE trades
+            SET pnl_sol = final_sol_received - initial_sol_spent
+            WHERE is_verified
+              AND initial_sol_spent IS NOT NULL
+              AND final_sol_received IS NOT NULL
+              AND (pnl_sol IS NULL OR pnl_sol != final_sol_received - initial_sol_spent)
+            "#
+        )
+        .execute(&self.pool)
+        .await
+        .context("Failed to recompute trade PnL")?;
+
+        let rows_affected = result.rows_affected();
+        info!("Recomputed PnL for {} trades", rows_affected);
+        Ok(rows_affected)
+    }
+
+    async fn delete_records_for_mint(&self, mint: &str) -> Result<u64> {
+        let _write = lock_writes(self.serialize_writes).await;
+        let mut tx = self.pool.begin().await.context("Failed to begin transaction")?;
+
+        delete_outcome_history_for_mint(&mut tx, "trades", "signature", mint).await?;
+        for table in ["decision_features", "market_context"] {
+            sqlx::query(&format!("DELETE FROM {} WHERE trade_id IN (SELECT id FROM trades WHERE mint = ?)", table))
+                .bind(mint)
+                .execute(&mut *tx)
+                .await
+                .context(format!("Failed to delete {} rows for mint {}", table, mint))?;
+        }
+        let mut deleted = sqlx::query("DELETE FROM trades WHERE mint = ?")
+            .bind(mint)
+            .execute(&mut *tx)
+            .await
+            .context(format!("Failed to delete trades for mint {}", mint))?
+            .rows_affected();
+
+        // Databases that started out on the flat schema may still hold the mint's legacy records
+        if table_exists(&mut tx, "transaction_records").await? {
+            delete_outcome_history_for_mint(&mut tx, "transaction_records", "transaction_signature", mint).await?;
+            deleted += sqlx::query("DELETE FROM transaction_records WHERE mint = ?")
+                .bind(mint)
+                .execute(&mut *tx)
+                .await
+                .context(format!("Failed to delete legacy records for mint {}", mint))?
+                .rows_affected();
+        }
+
+        tx.commit().await.context("Failed to commit transaction")?;
+
+        info!("Deleted {} records for mint {}", deleted, mint);
+        Ok(deleted)
+    }
+
+    async fn record_rejection(&self, mint: &str, reason_code: ReasonCode, timestamp: u64) -> Result<()> {
+        let _write = lock_writes(self.serialize_writes).await;
+        insert_rejection(&self.pool, mint, reason_code, timestamp).await
+    }
+
+    async fn get_rejections_since(&self, timestamp: u64) -> Result<Vec<Rejection>> {
+        fetch_rejections_since(&self.pool, timestamp).await
+    }
+
+    async fn get_kill_switch_reset_at(&self) -> Result<Option<u64>> {
+        fetch_kill_switch_reset_at(&self.pool).await
+    }
+
+    async fn set_kill_switch_reset_at(&self, timestamp: u64) -> Result<()> {
+        let _write = lock_writes(self.serialize_writes).await;
+        save_kill_switch_reset_at(&self.pool, timestamp).await
+    }
+
+    async fn get_outcome_history(&self, signature: &str) -> Result<Vec<OutcomeTransition>> {
+        fetch_outcome_history(&self.pool, signature).await
+    }
+
+    async fn compact(&self) -> Result<()> {
+        let _write = lock_writes(self.serialize_writes).await;
+        compact_sqlite(&self.pool).await
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
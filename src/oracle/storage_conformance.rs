@@ -0,0 +1,616 @@
+//! Conformance suite for `LedgerStorage` implementations.
+//!
+//! The same scenario of inserts, outcome updates and queries is run against
+//! every backend, and each backend's observable results must match the
+//! in-memory reference exactly. Fields a backend legitimately does not persist
+//! (prices, reasons, evaluation times in the normalized schema) are left out of
+//! the comparison; everything else is expected to round-trip identically.
+//!
+//! The SQLite backends share `./decisions.db` with other tests, so every run
+//! tags its mints, signatures, creators and programs with a unique prefix,
+//! filters query results down to that prefix and strips it before comparing.
+//!
+//! PostgreSQL is checked by a separate ignored test, run with
+//! `TEST_POSTGRES_URL` set and `cargo test -- --ignored`.
+//!
+//! To add a backend, construct it in `backends()` under a descriptive name.
+//! If it cannot persist a field compared in `RecordView`, prefer fixing the
+//! backend; a field only drops out of the view when no backend can keep it.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::oracle::anomaly::{AnomalyReport, AnomalyType};
+use crate::oracle::storage::{outcome_counts_from_rows, LedgerStorage, SqliteLedger, SqliteLedgerNormalized};
+use crate::oracle::postgres_ledger::PostgresLedger;
+use crate::oracle::test_support::RecordBuilder;
+use crate::oracle::transaction_monitor::MonitoredTransaction;
+use crate::oracle::types::{
+    score_variance, Outcome, OutcomeTransition, OutcomeUpdate, ReasonCode, Rejection, Score, ScoreBandStats,
+    TransactionRecord, SCORE_BAND_WIDTH,
+};
+
+/// In-memory storage whose writes fail while `available` is false.
+#[derive(Default)]
+pub(crate) struct FlakyStorage {
+    pub(crate) available: AtomicBool,
+    pub(crate) records: Mutex<Vec<TransactionRecord>>,
+    /// Every outcome update written, whether or not a record matched
+    pub(crate) outcomes: Mutex<Vec<OutcomeUpdate>>,
+    /// Size of each outcome batch written
+    pub(crate) outcome_batches: Mutex<Vec<usize>>,
+    history: Mutex<Vec<OutcomeTransition>>,
+    rejections: Mutex<Vec<Rejection>>,
+    kill_switch_reset_at: Mutex<Option<u64>>,
+}
+
+impl FlakyStorage {
+    /// Storage that accepts writes from the start.
+    pub(crate) fn available() -> Self {
+        let storage = Self::default();
+        storage.available.store(true, Ordering::SeqCst);
+        storage
+    }
+
+    /// Records decided at or after `since`, in decision order.
+    async fn records_since(&self, since: u64) -> Vec<TransactionRecord> {
+        let mut records: Vec<_> = self.records.lock().await
+            .iter()
+            .filter(|r| r.timestamp_decision_made >= since)
+            .cloned()
+            .collect();
+        records.sort_by_key(|r| r.timestamp_decision_made);
+        records
+    }
+
+    /// Apply `update` to the record it refers to, keeping the update either way.
+    async fn apply_outcome_update(&self, update: OutcomeUpdate) {
+        let mut records = self.records.lock().await;
+        if let Some(record) = records.iter_mut().find(|r| r.transaction_signature.as_deref() == Some(update.signature.as_str())) {
+            self.history.lock().await.push(OutcomeTransition {
+                signature: update.signature.clone(),
+                from: record.actual_outcome.clone(),
+                to: update.outcome.clone(),
+                reason: update.outcome_reason.clone(),
+                timestamp: update.timestamp_evaluated
+                    .unwrap_or_else(|| chrono::Utc::now().timestamp_millis() as u64),
+            });
+            record.actual_outcome = update.outcome.clone();
+            record.buy_price_sol = update.buy_price_sol.or(record.buy_price_sol);
+            record.sell_price_sol = update.sell_price_sol.or(record.sell_price_sol);
+            record.initial_sol_spent = update.initial_sol_spent.or(record.initial_sol_spent);
+            record.final_sol_received = update.final_sol_received.or(record.final_sol_received);
+            record.timestamp_outcome_evaluated = update.timestamp_evaluated.or(record.timestamp_outcome_evaluated);
+        }
+        self.outcomes.lock().await.push(update);
+    }
+}
+
+#[async_trait]
+impl LedgerStorage for FlakyStorage {
+    async fn insert_record(&self, record: &TransactionRecord) -> Result<i64> {
+        if !self.available.load(Ordering::SeqCst) {
+            return Err(anyhow!("disk full"));
+        }
+        let mut records = self.records.lock().await;
+        let id = records.iter().filter_map(|r| r.id).max().unwrap_or(0) + 1;
+        records.push(TransactionRecord { id: Some(id), ..record.clone() });
+        Ok(id)
+    }
+
+    async fn update_outcome(
+        &self,
+        signature: &str,
+        outcome: Outcome,
+        buy_price_sol: Option<f64>,
+        sell_price_sol: Option<f64>,
+        initial_sol_spent: Option<f64>,
+        final_sol_received: Option<f64>,
+        timestamp_evaluated: Option<u64>,
+        is_verified: bool,
+        outcome_reason: Option<String>,
+    ) -> Result<()> {
+        let update = OutcomeUpdate {
+            signature: signature.to_string(),
+            outcome,
+            buy_price_sol,
+            sell_price_sol,
+            initial_sol_spent,
+            final_sol_received,
+            timestamp_evaluated,
+            is_verified,
+            outcome_reason,
+            low_confidence: false,
+        };
+        self.update_outcomes_batch(&[update]).await
+    }
+
+    async fn update_outcomes_batch(&self, updates: &[OutcomeUpdate]) -> Result<()> {
+        if !self.available.load(Ordering::SeqCst) {
+            return Err(anyhow!("disk full"));
+        }
+        self.outcome_batches.lock().await.push(updates.len());
+        for update in updates {
+            self.apply_outcome_update(update.clone()).await;
+        }
+        Ok(())
+    }
+
+    async fn get_records_paged(&self, since: u64, until: u64, offset: u64, limit: u64) -> Result<Vec<TransactionRecord>> {
+        let mut records: Vec<_> = self.records.lock().await
+            .iter()
+            .filter(|r| (since..until).contains(&r.timestamp_decision_made))
+            .cloned()
+            .collect();
+        records.sort_by_key(|r| (r.timestamp_decision_made, r.id));
+        Ok(records.into_iter().skip(offset as usize).take(limit as usize).collect())
+    }
+
+    async fn get_record_by_id(&self, id: i64) -> Result<Option<TransactionRecord>> {
+        Ok(self.records.lock().await.iter().find(|r| r.id == Some(id)).cloned())
+    }
+
+    async fn get_record_by_signature(&self, signature: &str) -> Result<Option<TransactionRecord>> {
+        Ok(self.records.lock().await
+            .iter()
+            .find(|r| r.transaction_signature.as_deref() == Some(signature))
+            .cloned())
+    }
+
+    async fn get_record_count(&self) -> Result<i64> {
+        Ok(self.records.lock().await.len() as i64)
+    }
+
+    async fn health_check(&self) -> Result<bool> {
+        Ok(self.available.load(Ordering::SeqCst))
+    }
+
+    async fn enqueue_for_monitoring(&self, _tx: &MonitoredTransaction) -> Result<()> {
+        Ok(())
+    }
+
+    async fn get_pending_monitoring_transactions(&self) -> Result<Vec<MonitoredTransaction>> {
+        Ok(vec![])
+    }
+
+    async fn update_monitoring_status(&self, _signature: &str, _status: &str) -> Result<()> {
+        Ok(())
+    }
+
+    async fn cleanup_completed_monitoring(&self) -> Result<u64> {
+        Ok(0)
+    }
+
+    async fn recompute_pnl(&self) -> Result<u64> {
+        Ok(0)
+    }
+
+    async fn get_unresolved_decisions(&self, older_than: u64) -> Result<Vec<TransactionRecord>> {
+        Ok(self.records_since(0).await
+            .into_iter()
+            .filter(|r| r.actual_outcome == Outcome::PendingConfirmation && r.timestamp_decision_made < older_than)
+            .collect())
+    }
+
+    async fn get_feature_history_for_mint(&self, mint: &str) -> Result<Vec<(u64, HashMap<String, f64>)>> {
+        Ok(self.records_since(0).await
+            .into_iter()
+            .filter(|r| r.scored_candidate.mint == mint)
+            .map(|r| (r.timestamp_decision_made, r.scored_candidate.feature_scores))
+            .collect())
+    }
+
+    async fn get_win_rate_by_program(&self, since: u64) -> Result<HashMap<String, f64>> {
+        let mut counts: HashMap<String, (usize, usize)> = HashMap::new();
+        for record in self.records.lock().await.iter().filter(|r| r.timestamp_decision_made >= since) {
+            let won = match record.actual_outcome {
+                Outcome::Profit(_) => true,
+                Outcome::Loss(_) => false,
+                _ => continue,
+            };
+            let (wins, closed) = counts.entry(record.scored_candidate.base.program.clone()).or_default();
+            *wins += won as usize;
+            *closed += 1;
+        }
+        Ok(counts.into_iter()
+            .map(|(program, (wins, closed))| (program, wins as f64 / closed as f64))
+            .collect())
+    }
+
+    async fn score_variance_for_mint(&self, mint: &str) -> Result<Option<f64>> {
+        let scores: BTreeMap<u64, u8> = self.records.lock().await
+            .iter()
+            .filter(|r| r.scored_candidate.mint == mint && !r.is_skipped_candidate())
+            .map(|r| (r.timestamp_decision_made, r.scored_candidate.predicted_score.value()))
+            .collect();
+        Ok(score_variance(&scores.into_values().collect::<Vec<_>>()))
+    }
+
+    async fn count_recent_launches_by_creator(&self, creator: &str, window_seconds: u64) -> Result<u64> {
+        let since = (chrono::Utc::now().timestamp_millis() as u64).saturating_sub(window_seconds * 1000);
+        let mints: std::collections::HashSet<_> = self.records.lock().await
+            .iter()
+            .filter(|r| r.scored_candidate.base.creator == creator && r.timestamp_decision_made >= since)
+            .map(|r| r.scored_candidate.mint.clone())
+            .collect();
+        Ok(mints.len() as u64)
+    }
+
+    async fn get_outcome_counts(&self, since: u64) -> Result<HashMap<String, u64>> {
+        let rows = self.records.lock().await
+            .iter()
+            .filter(|r| r.timestamp_decision_made >= since)
+            .map(|r| Ok((serde_json::to_string(&r.actual_outcome)?, 1)))
+            .collect::<Result<Vec<_>>>()?;
+        outcome_counts_from_rows(rows)
+    }
+
+    async fn count_rugs_by_funding_source(&self, funding_source: &str, max_pnl: f64) -> Result<u64> {
+        Ok(self.records.lock().await
+            .iter()
+            .filter(|r| r.scored_candidate.base.funding_source.as_deref() == Some(funding_source))
+            .filter(|r| matches!(r.actual_outcome, Outcome::Loss(pnl) if pnl <= max_pnl))
+            .count() as u64)
+    }
+
+    async fn get_top_mints_by_pnl(&self, limit: u32, since: u64) -> Result<Vec<(String, f64)>> {
+        let mut totals: HashMap<String, f64> = HashMap::new();
+        for record in self.records.lock().await.iter().filter(|r| r.timestamp_decision_made >= since) {
+            let pnl = match record.actual_outcome {
+                Outcome::Profit(pnl) | Outcome::Loss(pnl) => pnl,
+                _ => continue,
+            };
+            *totals.entry(record.scored_candidate.mint.clone()).or_default() += pnl;
+        }
+        let mut ranked: Vec<(String, f64)> = totals.into_iter().collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        ranked.truncate(limit as usize);
+        Ok(ranked)
+    }
+
+    async fn get_score_outcome_calibration(&self, since: u64) -> Result<Vec<ScoreBandStats>> {
+        let mut bands: std::collections::BTreeMap<u8, (u64, u64, f64)> = Default::default();
+        for record in self.records.lock().await.iter().filter(|r| r.timestamp_decision_made >= since) {
+            let (won, pnl) = match record.actual_outcome {
+                Outcome::Profit(p) => (true, p),
+                Outcome::Loss(l) => (false, l),
+                _ => continue,
+            };
+            let score = record.scored_candidate.predicted_score.value().min(Score::MAX - 1);
+            let (wins, closed, total_pnl) = bands.entry(score / SCORE_BAND_WIDTH * SCORE_BAND_WIDTH).or_default();
+            *wins += won as u64;
+            *closed += 1;
+            *total_pnl += pnl;
+        }
+        Ok(bands.into_iter()
+            .map(|(band, (wins, closed, total_pnl))| {
+                ScoreBandStats::from_band(band, closed, wins as f64 / closed as f64, total_pnl / closed as f64)
+            })
+            .collect())
+    }
+
+    async fn delete_records_for_mint(&self, mint: &str) -> Result<u64> {
+        if !self.available.load(Ordering::SeqCst) {
+            return Err(anyhow!("disk full"));
+        }
+        let mut records = self.records.lock().await;
+        let (deleted, kept): (Vec<_>, Vec<_>) = std::mem::take(&mut *records)
+            .into_iter()
+            .partition(|r| r.scored_candidate.mint == mint);
+        *records = kept;
+
+        let signatures: Vec<_> = deleted.iter().filter_map(|r| r.transaction_signature.clone()).collect();
+        self.history.lock().await.retain(|t| !signatures.contains(&t.signature));
+        Ok(deleted.len() as u64)
+    }
+
+    async fn record_rejection(&self, mint: &str, reason_code: ReasonCode, timestamp: u64) -> Result<()> {
+        self.rejections.lock().await.push(Rejection { mint: mint.to_string(), reason_code, timestamp });
+        Ok(())
+    }
+
+    async fn get_rejections_since(&self, timestamp: u64) -> Result<Vec<Rejection>> {
+        let mut rejections: Vec<_> = self.rejections.lock().await
+            .iter()
+            .filter(|r| r.timestamp >= timestamp)
+            .cloned()
+            .collect();
+        rejections.sort_by_key(|r| r.timestamp);
+        Ok(rejections)
+    }
+
+    async fn get_kill_switch_reset_at(&self) -> Result<Option<u64>> {
+        Ok(*self.kill_switch_reset_at.lock().await)
+    }
+
+    async fn set_kill_switch_reset_at(&self, timestamp: u64) -> Result<()> {
+        *self.kill_switch_reset_at.lock().await = Some(timestamp);
+        Ok(())
+    }
+
+    async fn get_outcome_history(&self, signature: &str) -> Result<Vec<OutcomeTransition>> {
+        Ok(self.history.lock().await.iter().filter(|t| t.signature == signature).cloned().collect())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+/// The in-memory reference every other backend is compared against.
+fn reference() -> (&'static str, Arc<dyn LedgerStorage>) {
+    ("in-memory", Arc::new(FlakyStorage::available()))
+}
+
+/// Every backend that runs without external services, by name. The first is the reference.
+async fn backends() -> Vec<(&'static str, Arc<dyn LedgerStorage>)> {
+    vec![
+        reference(),
+        ("flat", SqliteLedger::new().await.expect("Failed to create flat storage")),
+        ("normalized", SqliteLedgerNormalized::new().await.expect("Failed to create normalized storage")),
+    ]
+}
+
+/// The parts of a stored record every backend persists.
+#[derive(Debug, Clone, PartialEq)]
+struct RecordView {
+    mint: String,
+    signature: Option<String>,
+    score: u8,
+    feature_scores: HashMap<String, f64>,
+    program: String,
+    creator: String,
+    funding_source: Option<String>,
+    anomaly_report: Option<AnomalyReport>,
+    reason_codes: Vec<ReasonCode>,
+    decided_at: u64,
+    outcome: Outcome,
+    initial_sol_spent: Option<f64>,
+    final_sol_received: Option<f64>,
+}
+
+/// Everything the scenario observed through the `LedgerStorage` interface,
+/// with the run's tag stripped from all names.
+#[derive(Debug, Default, PartialEq)]
+struct Observations {
+    ids_increase: bool,
+    /// Other tests share the SQLite databases, so only growth is comparable
+    count_grew_by_inserts: bool,
+    pending_before_updates: Vec<RecordView>,
+    records: Vec<RecordView>,
+    by_signature: Vec<Option<RecordView>>,
+    by_id: Vec<Option<RecordView>>,
+    unresolved_after_updates: Vec<RecordView>,
+    outcome_history: Vec<(Outcome, Outcome, Option<String>, u64)>,
+    feature_history: Vec<(u64, HashMap<String, f64>)>,
+    score_variance: Option<f64>,
+    win_rate: Vec<(String, f64)>,
+    rugs_by_funding_source: (u64, u64),
+    recent_launches: u64,
+    top_mints_by_pnl: Vec<(String, f64)>,
+    rejections: Vec<(String, ReasonCode, u64)>,
+    kill_switch_reset_round_trips: bool,
+    purged: u64,
+    records_after_purge: Vec<RecordView>,
+    history_after_purge: usize,
+}
+
+/// Names scoped to one scenario run.
+struct Tag(String);
+
+impl Tag {
+    fn new() -> Self {
+        static RUNS: AtomicU64 = AtomicU64::new(0);
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        Self(format!("conformance_{}_{}", nanos, RUNS.fetch_add(1, Ordering::SeqCst)))
+    }
+
+    fn name(&self, name: &str) -> String {
+        format!("{}_{}", self.0, name)
+    }
+
+    fn strip(&self, name: &str) -> Option<String> {
+        name.strip_prefix(&format!("{}_", self.0)).map(str::to_string)
+    }
+
+    fn view(&self, record: &TransactionRecord) -> Option<RecordView> {
+        let candidate = &record.scored_candidate;
+        Some(RecordView {
+            mint: self.strip(&candidate.mint)?,
+            signature: record.transaction_signature.as_deref().and_then(|s| self.strip(s)),
+            score: candidate.predicted_score.value(),
+            feature_scores: candidate.feature_scores.clone(),
+            program: self.strip(&candidate.base.program).unwrap_or_default(),
+            creator: self.strip(&candidate.base.creator).unwrap_or_default(),
+            funding_source: candidate.base.funding_source.as_deref().and_then(|s| self.strip(s)),
+            anomaly_report: candidate.anomaly_report.clone(),
+            reason_codes: candidate.reason_codes.clone(),
+            decided_at: record.timestamp_decision_made,
+            outcome: record.actual_outcome.clone(),
+            initial_sol_spent: record.initial_sol_spent,
+            final_sol_received: record.final_sol_received,
+        })
+    }
+
+    /// Views of this run's records, ordered by signature.
+    fn views(&self, records: &[TransactionRecord]) -> Vec<RecordView> {
+        let mut views: Vec<_> = records.iter().filter_map(|r| self.view(r)).collect();
+        views.sort_by(|a, b| a.signature.cmp(&b.signature));
+        views
+    }
+
+    fn record(
+        &self,
+        mint: &str,
+        signature: &str,
+        score: u8,
+        feature_scores: &[(&str, f64)],
+        decided_at: u64,
+        outcome: Outcome,
+    ) -> TransactionRecord {
+        RecordBuilder::new(&self.name(mint))
+            .decided_at(decided_at)
+            .creator(&self.name("creator"))
+            .program(&self.name("program"))
+            .funding_source(&self.name("funder"))
+            .score(score)
+            .feature_scores(feature_scores.iter().map(|(name, value)| (name.to_string(), *value)).collect())
+            .signature(&self.name(signature))
+            .outcome(outcome)
+            .build()
+    }
+}
+
+/// Run the scenario against `storage`, recording what it returns.
+async fn run_scenario(storage: &dyn LedgerStorage, start: u64) -> Result<Observations> {
+    let tag = Tag::new();
+    let pending = Outcome::PendingConfirmation;
+
+    let mut flagged = tag.record("alpha", "sig_alpha_1", 80, &[("liquidity", 0.8), ("social_activity", 0.3)], start, pending.clone());
+    flagged.scored_candidate.anomaly_detected = true;
+    flagged.scored_candidate.anomaly_report = Some(AnomalyReport {
+        anomalies: vec![AnomalyType::ActiveFreezeAuthority],
+        anomaly_score: 0.8,
+        timestamp: start,
+    });
+    let mut penalized = tag.record("gamma", "sig_gamma", 65, &[], start + 3_000, Outcome::NotExecuted);
+    penalized.scored_candidate.reason_codes = vec![ReasonCode::MetadataUnreachable, ReasonCode::SlowScoring];
+    let records = vec![
+        flagged,
+        tag.record("beta", "sig_beta", 40, &[("liquidity", 0.2)], start + 1_000, pending.clone()),
+        tag.record("alpha", "sig_alpha_2", 70, &[("liquidity", 0.7)], start + 2_000, pending.clone()),
+        penalized,
+    ];
+
+    let mut obs = Observations::default();
+    let count_before = storage.get_record_count().await?;
+    let ids = storage.insert_records(&records).await?;
+    obs.ids_increase = ids.windows(2).all(|pair| pair[0] < pair[1]);
+    obs.count_grew_by_inserts = storage.get_record_count().await? - count_before >= records.len() as i64;
+    obs.pending_before_updates = tag.views(&storage.get_unresolved_decisions(start + 1_500).await?);
+
+    storage.update_outcome(
+        &tag.name("sig_alpha_1"),
+        Outcome::Profit(0.5),
+        Some(0.001),
+        Some(0.0015),
+        Some(1.0),
+        Some(1.5),
+        Some(start + 10_000),
+        true,
+        Some("sold".to_string()),
+    ).await?;
+    storage.update_outcomes_batch(&[
+        OutcomeUpdate {
+            signature: tag.name("sig_beta"),
+            outcome: Outcome::Loss(-0.4),
+            buy_price_sol: None,
+            sell_price_sol: None,
+            initial_sol_spent: Some(1.0),
+            final_sol_received: Some(0.6),
+            timestamp_evaluated: Some(start + 11_000),
+            is_verified: true,
+            outcome_reason: Some("stop loss".to_string()),
+            low_confidence: false,
+        },
+        OutcomeUpdate::unverified(tag.name("sig_alpha_2"), Outcome::ConfirmationTimeout, start + 12_000),
+    ]).await?;
+
+    obs.records = tag.views(&storage.get_records_since(start).await?);
+    for signature in ["sig_alpha_1", "sig_alpha_2", "sig_beta", "sig_gamma", "sig_unknown"] {
+        let record = storage.get_record_by_signature(&tag.name(signature)).await?;
+        obs.by_signature.push(record.and_then(|r| tag.view(&r)));
+    }
+    for id in ids {
+        obs.by_id.push(storage.get_record_by_id(id).await?.and_then(|r| tag.view(&r)));
+    }
+    obs.unresolved_after_updates = tag.views(&storage.get_unresolved_decisions(start + 5_000).await?);
+    obs.outcome_history = storage.get_outcome_history(&tag.name("sig_alpha_1")).await?
+        .into_iter()
+        .chain(storage.get_outcome_history(&tag.name("sig_alpha_2")).await?)
+        .map(|t| (t.from, t.to, t.reason, t.timestamp))
+        .collect();
+    obs.feature_history = storage.get_feature_history_for_mint(&tag.name("alpha")).await?;
+    obs.score_variance = storage.score_variance_for_mint(&tag.name("alpha")).await?;
+    obs.win_rate = storage.get_win_rate_by_program(start).await?
+        .into_iter()
+        .filter_map(|(program, rate)| Some((tag.strip(&program)?, rate)))
+        .collect();
+    // The -0.4 loss is a rug only under the looser threshold
+    obs.rugs_by_funding_source = (
+        storage.count_rugs_by_funding_source(&tag.name("funder"), -0.3).await?,
+        storage.count_rugs_by_funding_source(&tag.name("funder"), -0.8).await?,
+    );
+    obs.recent_launches = storage.count_recent_launches_by_creator(&tag.name("creator"), 3600).await?;
+    obs.top_mints_by_pnl = storage.get_top_mints_by_pnl(u32::MAX, start).await?
+        .into_iter()
+        .filter_map(|(mint, pnl)| Some((tag.strip(&mint)?, pnl)))
+        .collect();
+
+    storage.record_rejection(&tag.name("rejected"), ReasonCode::Stale, start + 4_000).await?;
+    obs.rejections = storage.get_rejections_since(start).await?
+        .into_iter()
+        .filter_map(|r| Some((tag.strip(&r.mint)?, r.reason_code, r.timestamp)))
+        .collect();
+
+    // A single row shared by every run, so only check that the last write reads back
+    storage.set_kill_switch_reset_at(start + 5_000).await?;
+    obs.kill_switch_reset_round_trips = storage.get_kill_switch_reset_at().await? == Some(start + 5_000);
+
+    obs.purged = storage.delete_records_for_mint(&tag.name("alpha")).await?;
+    obs.records_after_purge = tag.views(&storage.get_records_since(start).await?);
+    obs.history_after_purge = storage.get_outcome_history(&tag.name("sig_alpha_1")).await?.len();
+
+    Ok(obs)
+}
+
+/// Run the scenario on every backend, holding each to the first one's results.
+async fn assert_backends_agree(backends: Vec<(&'static str, Arc<dyn LedgerStorage>)>) {
+    let start = chrono::Utc::now().timestamp_millis() as u64;
+
+    let mut reference: Option<(&str, Observations)> = None;
+    for (name, storage) in backends {
+        let observed = run_scenario(storage.as_ref(), start)
+            .await
+            .unwrap_or_else(|e| panic!("{} backend failed the scenario: {:#}", name, e));
+
+        match &reference {
+            None => {
+                // Sanity-check the reference itself before holding others to it
+                assert!(observed.ids_increase);
+                assert!(observed.count_grew_by_inserts);
+                assert_eq!(observed.records.len(), 4);
+                assert_eq!(observed.records[3].reason_codes, [ReasonCode::MetadataUnreachable, ReasonCode::SlowScoring]);
+                assert_eq!(observed.outcome_history.len(), 2);
+                assert_eq!(observed.win_rate, vec![("program".to_string(), 0.5)]);
+                assert_eq!(observed.purged, 2);
+                assert_eq!(observed.records_after_purge.len(), 2);
+                assert!(observed.kill_switch_reset_round_trips);
+                reference = Some((name, observed));
+            }
+            Some((reference_name, expected)) => {
+                assert_eq!(&observed, expected, "{} backend diverges from {}", name, reference_name);
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_backends_behave_identically() {
+    assert_backends_agree(backends().await).await;
+}
+
+#[tokio::test]
+#[ignore = "needs a PostgreSQL database at TEST_POSTGRES_URL"]
+async fn test_postgres_backend_behaves_identically() {
+    let dsn = std::env::var("TEST_POSTGRES_URL").expect("TEST_POSTGRES_URL must point at a PostgreSQL database");
+    let postgres = PostgresLedger::new(&dsn).await.expect("Failed to create PostgreSQL storage");
+    assert_backends_agree(vec![reference(), ("postgres", postgres)]).await;
+}
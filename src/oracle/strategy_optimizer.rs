@@ -6,13 +6,42 @@
 use anyhow::Result;
 use sqlx::{Pool, Sqlite};
 use std::collections::HashMap;
-use tracing::{info, warn, error};
+use std::time::Duration;
+use tokio::time::Instant;
+use tracing::{debug, info, warn, error};
 
+use crate::oracle::blob_compression::BlobCompression;
+use crate::oracle::shutdown::ShutdownToken;
 use crate::oracle::types::{
-    FeatureWeights, OptimizedParameters, OptimizedParametersSender, PerformanceReportReceiver, ScoreThresholds,
-    TransactionRecord, Outcome
+    deserialize_feature_scores, FeatureWeights, OptimizedParameters, OptimizedParametersSender, PerformanceReport,
+    PerformanceReportReceiver, Score, ScoreThresholds, TradeAction, TransactionRecord, Outcome
 };
 
+/// Relative KPI drop after a swap that triggers an automatic rollback.
+pub const DEFAULT_ROLLBACK_KPI_DROP: f64 = 0.2;
+
+/// Reason attached to parameters that restore the pre-swap configuration.
+pub const ROLLBACK_REASON: &str = "auto-rollback";
+
+/// Quantity maximized by `StrategyOptimizer::suggest_notify_threshold`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ThresholdObjective {
+    /// Summed PnL of the trades that would have been alerted
+    #[default]
+    NetProfit,
+    /// Share of alerted trades that were profitable
+    Precision,
+    /// Harmonic mean of precision and the share of all profitable trades alerted
+    F1,
+}
+
+/// Parameters replaced by the last swap, kept until the next report confirms it.
+struct SwapCheckpoint {
+    previous_weights: FeatureWeights,
+    previous_thresholds: ScoreThresholds,
+    baseline_report: PerformanceReport,
+}
+
 /// StrategyOptimizer analyzes performance and dynamically adjusts Oracle parameters
 pub struct StrategyOptimizer {
     db_pool: Pool<Sqlite>,
@@ -20,6 +49,16 @@ pub struct StrategyOptimizer {
     optimized_params_sender: OptimizedParametersSender,
     current_weights: FeatureWeights,
     current_thresholds: ScoreThresholds,
+    /// Minimum time between two emitted proposals
+    optimization_interval: Duration,
+    last_proposal_at: Option<Instant>,
+    /// Latest report received during the cooldown, evaluated once it expires
+    pending_report: Option<PerformanceReport>,
+    /// Relative drop in profit factor or win rate that reverts the last swap
+    rollback_kpi_drop: f64,
+    last_swap: Option<SwapCheckpoint>,
+    threshold_objective: ThresholdObjective,
+    shutdown: ShutdownToken,
 }
 
 impl StrategyOptimizer {
@@ -30,6 +69,7 @@ impl StrategyOptimizer {
         optimized_params_sender: OptimizedParametersSender,
         initial_weights: FeatureWeights,
         initial_thresholds: ScoreThresholds,
+        optimization_interval_minutes: u64,
     ) -> Self {
         Self {
             db_pool,
@@ -37,39 +77,214 @@ impl StrategyOptimizer {
             optimized_params_sender,
             current_weights: initial_weights,
             current_thresholds: initial_thresholds,
+            optimization_interval: Duration::from_secs(optimization_interval_minutes * 60),
+            last_proposal_at: None,
+            pending_report: None,
+            rollback_kpi_drop: DEFAULT_ROLLBACK_KPI_DROP,
+            last_swap: None,
+            threshold_objective: ThresholdObjective::default(),
+            shutdown: ShutdownToken::new(),
         }
     }
 
+    /// Override the relative KPI drop that triggers an automatic rollback.
+    pub fn with_rollback_threshold(mut self, kpi_drop: f64) -> Self {
+        self.rollback_kpi_drop = kpi_drop;
+        self
+    }
+
+    /// Choose what `suggest_notify_threshold` maximizes.
+    pub fn with_threshold_objective(mut self, objective: ThresholdObjective) -> Self {
+        self.threshold_objective = objective;
+        self
+    }
+
+    /// Stop `run` once `shutdown` is triggered. A report deferred by the
+    /// cooldown is dropped.
+    pub fn with_shutdown(mut self, shutdown: ShutdownToken) -> Self {
+        self.shutdown = shutdown;
+        self
+    }
+
     /// Main execution loop - awaits performance reports and optimizes strategy
     pub async fn run(mut self) {
         info!("StrategyOptimizer is running, awaiting performance reports...");
+        let shutdown = self.shutdown.clone();
         
-        while let Some(report) = self.report_receiver.recv().await {
-            info!("Received new performance report. Analyzing for potential optimizations...");
-            
-            // Basic optimization logic: if Profit Factor is weak, try to optimize
-            if report.profit_factor < 1.2 && report.total_trades_evaluated > 10 {
-                warn!("Profit Factor is below threshold ({:.2}). Attempting to optimize strategy.", 
-                      report.profit_factor);
-                
-                match self.find_optimizations().await {
-                    Ok(Some(new_params)) => {
-                        info!("Found new optimized parameters: {}", new_params.reason);
-                        if let Err(e) = self.optimized_params_sender.send(new_params).await {
-                            error!("Failed to send optimized parameters: {}", e);
-                        }
-                    }
-                    Ok(None) => {
-                        info!("No clear optimization path found in this cycle.");
+        loop {
+            let cooldown_end = self.pending_report.as_ref().and(self.cooldown_end());
+
+            let report = match cooldown_end {
+                Some(deadline) => tokio::select! {
+                    report = self.report_receiver.recv() => report,
+                    _ = tokio::time::sleep_until(deadline) => self.pending_report.take(),
+                    _ = shutdown.triggered() => None,
+                },
+                None => tokio::select! {
+                    report = self.report_receiver.recv() => report,
+                    _ = shutdown.triggered() => None,
+                },
+            };
+
+            let Some(report) = report else {
+                info!("StrategyOptimizer shutting down.");
+                break;
+            };
+
+            // Rollbacks bypass the cooldown: bad parameters should not stay live
+            if let Some(rollback) = self.check_rollback(&report) {
+                self.last_proposal_at = Some(Instant::now());
+                if let Err(e) = self.optimized_params_sender.send(rollback).await {
+                    error!("Failed to send rollback parameters: {}", e);
+                }
+                continue;
+            }
+
+            if self.cooldown_end().is_some_and(|deadline| Instant::now() < deadline) {
+                // Coalesce: only the most recent report matters once the cooldown expires
+                debug!("Optimization cooldown active, deferring performance report");
+                self.pending_report = Some(report);
+                continue;
+            }
+
+            self.pending_report = None;
+            self.handle_report(report).await;
+        }
+    }
+
+    /// Notify threshold that would have maximized the configured objective over
+    /// `records`.
+    ///
+    /// Every predicted score of a closed (`Profit`/`Loss`) trade is tried as a
+    /// threshold, alerting on trades scored at or above it. Ties go to the
+    /// higher threshold, which reaches the same result with fewer alerts; with
+    /// no closed trades this is `Score::MAX`.
+    pub fn suggest_notify_threshold(&self, records: &[TransactionRecord]) -> u8 {
+        let trades: Vec<(u8, f64)> = records
+            .iter()
+            .filter_map(|record| {
+                let pnl = match record.actual_outcome {
+                    Outcome::Profit(pnl) | Outcome::Loss(pnl) => pnl,
+                    _ => return None,
+                };
+                Some((record.scored_candidate.predicted_score.value(), pnl))
+            })
+            .collect();
+        let total_wins = trades.iter().filter(|(_, pnl)| *pnl > 0.0).count();
+
+        let mut thresholds: Vec<u8> = trades.iter().map(|(score, _)| *score).collect();
+        thresholds.sort_unstable();
+        thresholds.dedup();
+
+        let mut best = (Score::MAX, f64::NEG_INFINITY);
+        for threshold in thresholds {
+            let alerted: Vec<f64> = trades
+                .iter()
+                .filter(|(score, _)| *score >= threshold)
+                .map(|(_, pnl)| *pnl)
+                .collect();
+            let wins = alerted.iter().filter(|pnl| **pnl > 0.0).count() as f64;
+            let precision = wins / alerted.len() as f64;
+
+            let value = match self.threshold_objective {
+                ThresholdObjective::NetProfit => alerted.iter().sum(),
+                ThresholdObjective::Precision => precision,
+                ThresholdObjective::F1 => {
+                    let recall = if total_wins > 0 { wins / total_wins as f64 } else { 0.0 };
+                    if precision + recall > 0.0 {
+                        2.0 * precision * recall / (precision + recall)
+                    } else {
+                        0.0
                     }
-                    Err(e) => {
-                        error!("Error during strategy optimization: {}", e);
+                }
+            };
+
+            if value >= best.1 {
+                best = (threshold, value);
+            }
+        }
+
+        debug!(
+            "Suggested notify threshold {} ({:?} = {:.4})",
+            best.0, self.threshold_objective, best.1
+        );
+        best.0
+    }
+
+    /// Compare the first report after a swap against the one that triggered it and
+    /// restore the previous parameters if the KPIs dropped beyond the threshold.
+    fn check_rollback(&mut self, report: &PerformanceReport) -> Option<OptimizedParameters> {
+        if report.total_trades_evaluated == 0 {
+            // No closed trades yet under the new parameters, nothing to judge
+            return None;
+        }
+        let checkpoint = self.last_swap.take()?;
+        let baseline = &checkpoint.baseline_report;
+
+        let retained = 1.0 - self.rollback_kpi_drop;
+        let profit_factor_dropped = report.profit_factor < baseline.profit_factor * retained;
+        let win_rate_dropped = report.win_rate_percent < baseline.win_rate_percent * retained;
+
+        if !profit_factor_dropped && !win_rate_dropped {
+            debug!("Last parameter swap confirmed (PF: {:.2} -> {:.2})",
+                   baseline.profit_factor, report.profit_factor);
+            return None;
+        }
+
+        warn!("KPIs degraded after parameter swap (PF: {:.2} -> {:.2}, win rate: {:.1}% -> {:.1}%). Rolling back.",
+              baseline.profit_factor, report.profit_factor,
+              baseline.win_rate_percent, report.win_rate_percent);
+
+        self.current_weights = checkpoint.previous_weights;
+        self.current_thresholds = checkpoint.previous_thresholds;
+
+        Some(OptimizedParameters {
+            new_weights: self.current_weights.clone(),
+            new_thresholds: self.current_thresholds.clone(),
+            reason: ROLLBACK_REASON.to_string(),
+        })
+    }
+
+    /// End of the cooldown started by the last emitted proposal.
+    fn cooldown_end(&self) -> Option<Instant> {
+        self.last_proposal_at.map(|at| at + self.optimization_interval)
+    }
+
+    /// Analyze a single performance report and emit new parameters if warranted.
+    async fn handle_report(&mut self, report: PerformanceReport) {
+        info!("Received new performance report. Analyzing for potential optimizations...");
+        
+        // Basic optimization logic: if Profit Factor is weak, try to optimize
+        if report.profit_factor < 1.2 && report.total_trades_evaluated > 10 {
+            warn!("Profit Factor is below threshold ({:.2}). Attempting to optimize strategy.", 
+                  report.profit_factor);
+            
+            let previous_weights = self.current_weights.clone();
+            let previous_thresholds = self.current_thresholds.clone();
+
+            match self.find_optimizations().await {
+                Ok(Some(new_params)) => {
+                    info!("Found new optimized parameters: {}", new_params.reason);
+                    self.last_proposal_at = Some(Instant::now());
+                    self.last_swap = Some(SwapCheckpoint {
+                        previous_weights,
+                        previous_thresholds,
+                        baseline_report: report,
+                    });
+                    if let Err(e) = self.optimized_params_sender.send(new_params).await {
+                        error!("Failed to send optimized parameters: {}", e);
                     }
                 }
-            } else {
-                info!("Current strategy performance is acceptable (PF: {:.2}). No optimization needed.", 
-                      report.profit_factor);
+                Ok(None) => {
+                    info!("No clear optimization path found in this cycle.");
+                }
+                Err(e) => {
+                    error!("Error during strategy optimization: {}", e);
+                }
             }
+        } else {
+            info!("Current strategy performance is acceptable (PF: {:.2}). No optimization needed.", 
+                  report.profit_factor);
         }
     }
 
@@ -123,6 +338,10 @@ impl StrategyOptimizer {
             "creator_sell_speed" => new_weights.creator_sell_speed *= 1.1,
             "metadata_quality" => new_weights.metadata_quality *= 1.1,
             "social_activity" => new_weights.social_activity *= 1.1,
+            "authority_status" => new_weights.authority_status *= 1.1,
+            "contract_verified" => new_weights.contract_verified *= 1.1,
+            "graduation_proximity" => new_weights.graduation_proximity *= 1.1,
+            "tx_per_holder" => new_weights.tx_per_holder *= 1.1,
             _ => {
                 warn!("Unknown feature '{}' found in analysis", worst_feature.0);
                 return Ok(None);
@@ -150,7 +369,7 @@ impl StrategyOptimizer {
             mint: String,
             score: i32,
             reason: String,
-            feature_scores: String,
+            feature_scores: Vec<u8>,
             calculation_time: i64,
             anomaly_detected: bool,
             timestamp_decision_made: i64,
@@ -164,7 +383,9 @@ impl StrategyOptimizer {
             timestamp_transaction_sent: Option<i64>,
             timestamp_outcome_evaluated: Option<i64>,
             actual_outcome: String,
-            market_context_snapshot: String,
+            market_context_snapshot: Vec<u8>,
+            #[sqlx(default)]
+            blob_compression: Option<String>,
         }
 
         let rows: Vec<TransactionRecordRow> = sqlx::query_as(
@@ -186,6 +407,10 @@ impl StrategyOptimizer {
                 continue;
             }
 
+            let compression = BlobCompression::from_column(row.blob_compression.as_deref())?;
+            let feature_scores = compression.decompress(row.feature_scores)?;
+            let market_context_snapshot = compression.decompress(row.market_context_snapshot)?;
+
             // Reconstruct the scored candidate
             let scored_candidate = crate::oracle::types::ScoredCandidate {
                 base: crate::types::PremintCandidate {
@@ -196,14 +421,20 @@ impl StrategyOptimizer {
                     timestamp: row.timestamp_decision_made as u64,
                     instruction_summary: None,
                     is_jito_bundle: None,
+                    funding_source: None,
                 },
                 mint: row.mint.clone(),
-                predicted_score: row.score as u8,
+                predicted_score: Score::clamped(row.score),
                 reason: row.reason,
-                feature_scores: serde_json::from_str(&row.feature_scores)?,
+                feature_scores: deserialize_feature_scores(&feature_scores)?,
                 calculation_time: row.calculation_time as u128,
                 anomaly_detected: row.anomaly_detected,
                 timestamp: row.timestamp_decision_made as u64,
+                data_source_timestamps: HashMap::new(), // Not persisted
+                scoring_trace: None, // Not persisted
+                reason_codes: Vec::new(),
+                anomaly_report: None,
+                action: TradeAction::of_stored_decision(row.transaction_signature.as_deref()),
             };
 
             records.push(TransactionRecord {
@@ -220,10 +451,170 @@ impl StrategyOptimizer {
                 timestamp_transaction_sent: row.timestamp_transaction_sent.map(|t| t as u64),
                 timestamp_outcome_evaluated: row.timestamp_outcome_evaluated.map(|t| t as u64),
                 actual_outcome: outcome,
-                market_context_snapshot: serde_json::from_str(&row.market_context_snapshot)?,
+                market_context_snapshot: serde_json::from_str(&market_context_snapshot)?,
             });
         }
         
         Ok(records)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oracle::test_support::RecordBuilder;
+    use sqlx::sqlite::SqlitePoolOptions;
+    use tokio::sync::mpsc;
+
+    async fn create_test_pool_with_loss() -> Pool<Sqlite> {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+
+        sqlx::query(
+            r#"
+            CREATE TABLE transaction_records (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                mint TEXT NOT NULL,
+                score INTEGER NOT NULL,
+                reason TEXT NOT NULL,
+                feature_scores TEXT NOT NULL,
+                calculation_time INTEGER NOT NULL,
+                anomaly_detected BOOLEAN NOT NULL,
+                timestamp_decision_made INTEGER NOT NULL,
+                transaction_signature TEXT,
+                buy_price_sol REAL,
+                sell_price_sol REAL,
+                amount_bought_tokens REAL,
+                amount_sold_tokens REAL,
+                initial_sol_spent REAL,
+                final_sol_received REAL,
+                timestamp_transaction_sent INTEGER,
+                timestamp_outcome_evaluated INTEGER,
+                actual_outcome TEXT NOT NULL,
+                market_context_snapshot TEXT NOT NULL
+            );
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        sqlx::query(
+            r#"
+            INSERT INTO transaction_records (
+                mint, score, reason, feature_scores, calculation_time, anomaly_detected,
+                timestamp_decision_made, actual_outcome, market_context_snapshot
+            ) VALUES ('loss_mint', 80, 'test', '{"liquidity":0.1,"social_activity":0.9}', 100, 0, 1000, '{"Loss":-0.5}', '{}');
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        pool
+    }
+
+    fn create_trade(score: u8, outcome: Outcome) -> TransactionRecord {
+        RecordBuilder::new(&format!("mint_{}", score)).decided_at(1000).score(score).outcome(outcome).build()
+    }
+
+    fn poor_report() -> PerformanceReport {
+        PerformanceReport {
+            total_trades_evaluated: 20,
+            profit_factor: 0.5,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rapid_reports_produce_single_proposal_within_interval() {
+        let (report_tx, report_rx) = mpsc::channel(16);
+        let (params_tx, mut params_rx) = mpsc::channel(16);
+
+        let optimizer = StrategyOptimizer::new(
+            create_test_pool_with_loss().await,
+            report_rx,
+            params_tx,
+            FeatureWeights::default(),
+            ScoreThresholds::default(),
+            60,
+        );
+
+        for _ in 0..5 {
+            report_tx.send(poor_report()).await.unwrap();
+        }
+        drop(report_tx);
+
+        optimizer.run().await;
+
+        let first = params_rx.try_recv().expect("first report should produce a proposal");
+        assert!(first.new_weights.liquidity > FeatureWeights::default().liquidity);
+        assert!(params_rx.try_recv().is_err(), "reports within the interval must be coalesced");
+    }
+
+    #[tokio::test]
+    async fn test_degraded_report_after_swap_triggers_rollback() {
+        let (report_tx, report_rx) = mpsc::channel(16);
+        let (params_tx, mut params_rx) = mpsc::channel(16);
+
+        let optimizer = StrategyOptimizer::new(
+            create_test_pool_with_loss().await,
+            report_rx,
+            params_tx,
+            FeatureWeights::default(),
+            ScoreThresholds::default(),
+            60,
+        );
+
+        report_tx.send(PerformanceReport { win_rate_percent: 40.0, ..poor_report() }).await.unwrap();
+        report_tx.send(PerformanceReport {
+            win_rate_percent: 20.0,
+            profit_factor: 0.2,
+            ..poor_report()
+        }).await.unwrap();
+        drop(report_tx);
+
+        optimizer.run().await;
+
+        let swap = params_rx.try_recv().expect("poor report should produce a swap");
+        assert_ne!(swap.reason, ROLLBACK_REASON);
+
+        let rollback = params_rx.try_recv().expect("degraded report should produce a rollback");
+        assert_eq!(rollback.reason, ROLLBACK_REASON);
+        assert_eq!(rollback.new_weights.liquidity, FeatureWeights::default().liquidity);
+        assert!(params_rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_suggest_notify_threshold_per_objective() {
+        let records = vec![
+            create_trade(90, Outcome::Profit(3.0)),
+            create_trade(85, Outcome::Profit(2.0)),
+            create_trade(80, Outcome::Profit(1.0)),
+            create_trade(70, Outcome::Loss(-4.0)),
+            create_trade(60, Outcome::Profit(0.5)),
+            create_trade(50, Outcome::Loss(-2.0)),
+            // Not closed, so never counted
+            create_trade(40, Outcome::PendingConfirmation),
+        ];
+        let optimizer = |objective| {
+            let (_report_tx, report_rx) = mpsc::channel(1);
+            let (params_tx, _params_rx) = mpsc::channel(1);
+            let pool = SqlitePoolOptions::new().connect_lazy("sqlite::memory:").unwrap();
+            StrategyOptimizer::new(pool, report_rx, params_tx, FeatureWeights::default(), ScoreThresholds::default(), 60)
+                .with_threshold_objective(objective)
+        };
+
+        // Alerting from 80 up collects 6 SOL; going lower adds the 4 SOL loss
+        assert_eq!(optimizer(ThresholdObjective::NetProfit).suggest_notify_threshold(&records), 80);
+        // 90, 85 and 80 are all perfectly precise; the highest wins the tie
+        assert_eq!(optimizer(ThresholdObjective::Precision).suggest_notify_threshold(&records), 90);
+        // 60 catches every winner at 80% precision
+        assert_eq!(optimizer(ThresholdObjective::F1).suggest_notify_threshold(&records), 60);
+
+        assert_eq!(optimizer(ThresholdObjective::NetProfit).suggest_notify_threshold(&[]), Score::MAX);
+    }
+}
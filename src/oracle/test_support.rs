@@ -0,0 +1,152 @@
+//! Fixtures shared by the oracle's unit tests.
+//!
+//! Integration tests have their own copy in `tests/common`, since they cannot
+//! see `cfg(test)` items of the library.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::oracle::storage::{SqliteLedger, SqliteStorageOptions};
+use crate::oracle::types::{Outcome, Score, ScoredCandidate, TradeAction, TransactionRecord};
+use crate::types::PremintCandidate;
+
+/// SQLite ledger in a temporary file of its own, so that no rows are shared
+/// with other tests.
+pub(crate) async fn isolated_ledger() -> Arc<SqliteLedger> {
+    let db_path = std::env::temp_dir().join(format!("ledger_{}.db", rand::random::<u64>()));
+    SqliteLedger::new_with_options(SqliteStorageOptions { db_path, ..Default::default() })
+        .await
+        .expect("Failed to create ledger")
+}
+
+/// Builds a `TransactionRecord` for `mint`, decided at time 0 by creator
+/// `"creator"` on `"pump.fun"`, with a zero score and nothing else set.
+#[derive(Clone)]
+pub(crate) struct RecordBuilder {
+    record: TransactionRecord,
+}
+
+impl RecordBuilder {
+    pub(crate) fn new(mint: &str) -> Self {
+        let candidate = PremintCandidate {
+            mint: mint.to_string(),
+            creator: "creator".to_string(),
+            program: "pump.fun".to_string(),
+            slot: 1,
+            timestamp: 0,
+            instruction_summary: None,
+            is_jito_bundle: None,
+            funding_source: None,
+        };
+
+        Self {
+            record: TransactionRecord {
+                id: None,
+                scored_candidate: ScoredCandidate {
+                    mint: candidate.mint.clone(),
+                    base: candidate,
+                    predicted_score: Score::default(),
+                    reason: String::new(),
+                    feature_scores: HashMap::new(),
+                    calculation_time: 0,
+                    anomaly_detected: false,
+                    timestamp: 0,
+                    data_source_timestamps: HashMap::new(),
+                    scoring_trace: None,
+                    reason_codes: Vec::new(),
+                    anomaly_report: None,
+                    action: TradeAction::Skip,
+                },
+                transaction_signature: None,
+                buy_price_sol: None,
+                sell_price_sol: None,
+                amount_bought_tokens: None,
+                amount_sold_tokens: None,
+                initial_sol_spent: None,
+                final_sol_received: None,
+                timestamp_decision_made: 0,
+                timestamp_transaction_sent: None,
+                timestamp_outcome_evaluated: None,
+                actual_outcome: Outcome::default(),
+                market_context_snapshot: HashMap::new(),
+            },
+        }
+    }
+
+    pub(crate) fn id(mut self, id: i64) -> Self {
+        self.record.id = Some(id);
+        self
+    }
+
+    /// Stamp the candidate, its score and the decision with `timestamp`.
+    pub(crate) fn decided_at(mut self, timestamp: u64) -> Self {
+        self.record.scored_candidate.base.timestamp = timestamp;
+        self.record.scored_candidate.timestamp = timestamp;
+        self.record.timestamp_decision_made = timestamp;
+        self
+    }
+
+    pub(crate) fn creator(mut self, creator: &str) -> Self {
+        self.record.scored_candidate.base.creator = creator.to_string();
+        self
+    }
+
+    pub(crate) fn program(mut self, program: &str) -> Self {
+        self.record.scored_candidate.base.program = program.to_string();
+        self
+    }
+
+    pub(crate) fn funding_source(mut self, funding_source: &str) -> Self {
+        self.record.scored_candidate.base.funding_source = Some(funding_source.to_string());
+        self
+    }
+
+    pub(crate) fn score(mut self, score: u8) -> Self {
+        self.record.scored_candidate.predicted_score = Score::clamped(score);
+        self
+    }
+
+    pub(crate) fn feature(mut self, name: &str, value: f64) -> Self {
+        self.record.scored_candidate.feature_scores.insert(name.to_string(), value);
+        self
+    }
+
+    pub(crate) fn feature_scores(mut self, feature_scores: HashMap<String, f64>) -> Self {
+        self.record.scored_candidate.feature_scores = feature_scores;
+        self
+    }
+
+    pub(crate) fn signature(mut self, signature: &str) -> Self {
+        self.record.transaction_signature = Some(signature.to_string());
+        self
+    }
+
+    pub(crate) fn sent_at(mut self, timestamp: u64) -> Self {
+        self.record.timestamp_transaction_sent = Some(timestamp);
+        self
+    }
+
+    pub(crate) fn sol_spent(mut self, sol: f64) -> Self {
+        self.record.initial_sol_spent = Some(sol);
+        self
+    }
+
+    pub(crate) fn sol_received(mut self, sol: f64) -> Self {
+        self.record.final_sol_received = Some(sol);
+        self
+    }
+
+    pub(crate) fn outcome(mut self, outcome: Outcome) -> Self {
+        self.record.actual_outcome = outcome;
+        self
+    }
+
+    pub(crate) fn build(self) -> TransactionRecord {
+        self.record
+    }
+
+    /// Just the scored candidate of the record.
+    pub(crate) fn candidate(self) -> ScoredCandidate {
+        self.record.scored_candidate
+    }
+}
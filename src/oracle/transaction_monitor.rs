@@ -3,16 +3,19 @@
 //! This module tracks transactions sent by the Oracle and evaluates their final outcomes,
 //! updating the DecisionLedger with profit/loss information.
 
+use async_trait::async_trait;
 use std::time::Duration;
 use tokio::{sync::mpsc, time::sleep};
 use tracing::{info, warn, error, debug};
-use crate::oracle::types::{Outcome, OutcomeUpdateSender};
+use crate::oracle::data_sources::OracleDataSources;
+use crate::oracle::types::{Outcome, OutcomeUpdate, OutcomeUpdateSender};
+use crate::oracle::types_old::TokenData;
 use crate::oracle::storage::LedgerStorage;
+use crate::oracle::shutdown::ShutdownToken;
 use crate::types::Pubkey;
 use std::sync::Arc;
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::signature::Signature;
-use solana_transaction_status::{UiTransactionEncoding, option_serializer::OptionSerializer};
 
 /// Represents a transaction being monitored for outcome
 #[derive(Debug, Clone)]
@@ -25,14 +28,61 @@ pub struct MonitoredTransaction {
     // Future: could include pool address, creator address, etc. for more detailed monitoring
 }
 
+/// Determines the final outcome of a monitored transaction.
+///
+/// Implement this to resolve trades against your own execution venue (e.g. a
+/// DEX aggregator fill feed) instead of the default on-chain lookup.
+#[async_trait]
+pub trait OutcomeResolver: Send + Sync {
+    /// Resolve the outcome of `tx`.
+    ///
+    /// Returns `Ok(None)` while the outcome is not known yet, so the transaction
+    /// stays queued for the next check. An error marks the transaction as
+    /// `Outcome::VerificationFailed`.
+    async fn resolve(&self, tx: &MonitoredTransaction) -> anyhow::Result<Option<OutcomeUpdate>>;
+}
+
+/// Last token data seen for a mint, looked up without fetching fresh data.
+///
+/// Lets the monitor estimate an outcome from the last known price when the
+/// resolver cannot get a fresh one.
+#[async_trait]
+pub trait LastKnownTokenData: Send + Sync {
+    async fn last_known_token_data(&self, mint: &Pubkey) -> Option<TokenData>;
+}
+
+#[async_trait]
+impl LastKnownTokenData for OracleDataSources {
+    async fn last_known_token_data(&self, mint: &Pubkey) -> Option<TokenData> {
+        self.peek_cached_token_data(mint).await
+    }
+}
+
+/// Default resolver that checks transaction status over Solana RPC.
+pub struct RpcOutcomeResolver {
+    rpc_client: Arc<RpcClient>,
+}
+
 /// TransactionMonitor tracks the outcomes of trading transactions
 pub struct TransactionMonitor {
     storage: Arc<dyn LedgerStorage>, // Persistent storage for monitoring queue
     update_sender: OutcomeUpdateSender, // Channel to send updates to DecisionLedger
     monitor_interval: Duration,
-    rpc_client: Arc<RpcClient>, // RPC client for on-chain verification
-    wallet_pubkey: Pubkey, // Our wallet's public key for transaction analysis
+    resolver: Arc<dyn OutcomeResolver>, // Outcome resolution, on-chain by default
+    #[allow(dead_code)]
+    wallet_pubkey: Pubkey, // Our wallet's public key, reserved for balance-based PnL parsing
     verification_timeout: Duration, // Timeout for transaction verification (90 seconds)
+    shutdown: ShutdownToken,
+    outcome_batch: Option<OutcomeBatch>, // Buffered outcomes, when batching is enabled
+    price_fallback: Option<Arc<dyn LastKnownTokenData>>, // Last known prices for failed resolutions
+}
+
+/// Resolved outcomes waiting to be written to storage together.
+struct OutcomeBatch {
+    /// Updates with the monitoring status to set once they are written
+    pending: Vec<(OutcomeUpdate, &'static str)>,
+    max_size: usize,
+    flush_interval: Duration,
 }
 
 impl TransactionMonitor {
@@ -48,12 +98,50 @@ impl TransactionMonitor {
             storage,
             update_sender,
             monitor_interval: Duration::from_millis(monitor_interval_ms),
-            rpc_client,
+            resolver: Arc::new(RpcOutcomeResolver::new(rpc_client)),
             wallet_pubkey,
             verification_timeout: Duration::from_secs(90), // 90 second timeout as specified
+            shutdown: ShutdownToken::new(),
+            outcome_batch: None,
+            price_fallback: None,
         }
     }
 
+    /// Replace the default on-chain resolver with a custom one.
+    pub fn with_resolver(mut self, resolver: Arc<dyn OutcomeResolver>) -> Self {
+        self.resolver = resolver;
+        self
+    }
+
+    /// Stop `run` once `shutdown` is triggered. Transactions still being
+    /// monitored stay in the persistent queue for the next start.
+    pub fn with_shutdown(mut self, shutdown: ShutdownToken) -> Self {
+        self.shutdown = shutdown;
+        self
+    }
+
+    /// Hold resolved outcomes and send them to the DecisionLedger in batches of
+    /// up to `max_size`, at least every `flush_interval`, instead of one at a
+    /// time, so the ledger writes each batch in one storage call. A transaction
+    /// stays in the monitoring queue until its outcome is sent, so nothing is
+    /// lost if the monitor stops early.
+    pub fn with_outcome_batching(mut self, max_size: usize, flush_interval: Duration) -> Self {
+        self.outcome_batch = Some(OutcomeBatch {
+            pending: Vec::new(),
+            max_size: max_size.max(1),
+            flush_interval,
+        });
+        self
+    }
+
+    /// When the resolver fails, estimate the outcome from the last price in the
+    /// token's `price_history` instead of marking it `VerificationFailed`.
+    /// Estimated outcomes are unverified and flagged `low_confidence`.
+    pub fn with_last_price_fallback(mut self, source: Arc<dyn LastKnownTokenData>) -> Self {
+        self.price_fallback = Some(source);
+        self
+    }
+
     /// Main execution loop - monitors active transactions and processes new ones
     pub async fn run(mut self, mut new_tx_receiver: mpsc::Receiver<MonitoredTransaction>) {
         info!("TransactionMonitor is running with persistent storage...");
@@ -62,21 +150,38 @@ impl TransactionMonitor {
         if let Err(e) = self.storage.cleanup_completed_monitoring().await {
             warn!("Failed to cleanup completed monitoring transactions on startup: {}", e);
         }
+        let shutdown = self.shutdown.clone();
+        let batching = self.outcome_batch.is_some();
+        let mut flush_ticker = tokio::time::interval(
+            self.outcome_batch.as_ref().map_or(self.monitor_interval, |batch| batch.flush_interval),
+        );
         
         loop {
             tokio::select! {
                 // Receive new transactions to monitor
                 Some(new_tx) = new_tx_receiver.recv() => {
-                    info!("Adding new transaction to monitor: {}", new_tx.signature);
-                    if let Err(e) = self.storage.enqueue_for_monitoring(&new_tx).await {
-                        error!("Failed to enqueue transaction {} for monitoring: {}", new_tx.signature, e);
-                    }
+                    self.enqueue(&new_tx).await;
                 },
                 // Periodically check active transactions
                 _ = sleep(self.monitor_interval) => {
                     self.process_active_transactions().await;
                 },
+                // Periodically write batched outcomes
+                _ = flush_ticker.tick(), if batching => {
+                    self.flush_outcomes().await;
+                },
+                _ = shutdown.triggered() => {
+                    // Persist transactions still in the channel so the next start picks them up
+                    new_tx_receiver.close();
+                    while let Ok(new_tx) = new_tx_receiver.try_recv() {
+                        self.enqueue(&new_tx).await;
+                    }
+                    self.flush_outcomes().await;
+                    info!("TransactionMonitor shutdown requested. Shutting down.");
+                    break;
+                },
                 else => {
+                    self.flush_outcomes().await;
                     info!("TransactionMonitor channels closed. Shutting down.");
                     break;
                 }
@@ -84,6 +189,13 @@ impl TransactionMonitor {
         }
     }
 
+    async fn enqueue(&self, new_tx: &MonitoredTransaction) {
+        info!("Adding new transaction to monitor: {}", new_tx.signature);
+        if let Err(e) = self.storage.enqueue_for_monitoring(new_tx).await {
+            error!("Failed to enqueue transaction {} for monitoring: {}", new_tx.signature, e);
+        }
+    }
+
     /// Process all currently active transactions
     async fn process_active_transactions(&mut self) {
         let now = chrono::Utc::now().timestamp_millis() as u64;
@@ -98,42 +210,28 @@ impl TransactionMonitor {
         };
 
         for tx in active_transactions {
+            if self.is_batched(&tx.signature) {
+                continue;
+            }
+
             if tx.monitor_until < now {
                 warn!("Monitoring for transaction {} expired. Marking as ConfirmationTimeout.", tx.signature);
-                // Send timeout status to DecisionLedger
-                if let Err(e) = self.update_sender.send((
+                let update = OutcomeUpdate::unverified(
                     tx.signature.clone(),
                     Outcome::ConfirmationTimeout,
-                    None, None, None, None, Some(now), false
-                )).await {
-                    error!("Failed to send timeout outcome update: {}", e);
-                }
-                // Mark as Failed in storage
-                if let Err(e) = self.storage.update_monitoring_status(&tx.signature, "Failed").await {
-                    error!("Failed to update monitoring status to Failed for {}: {}", tx.signature, e);
-                }
+                    now,
+                ).with_reason("Monitoring window expired before confirmation");
+                self.finish(update, "Failed").await;
                 continue;
             }
 
-            // --- REAL ON-CHAIN VERIFICATION ---
-            match self.verify_transaction_on_chain(&tx).await {
-                Ok(Some((outcome, buy_price_sol, sell_price_sol, final_sol_received))) => {
-                    info!("Transaction {} outcome verified on-chain: {:?}", tx.signature, outcome);
-                    let is_verified = matches!(outcome, Outcome::Profit(_) | Outcome::Loss(_));
-                    
-                    if let Err(e) = self.update_sender.send((
-                        tx.signature.clone(),
-                        outcome,
-                        Some(buy_price_sol), sell_price_sol, 
-                        Some(tx.initial_sol_spent), final_sol_received, 
-                        Some(now), is_verified
-                    )).await {
-                        error!("Failed to send verified outcome update: {}", e);
-                    }
-                    // Mark as Completed in storage
-                    if let Err(e) = self.storage.update_monitoring_status(&tx.signature, "Completed").await {
-                        error!("Failed to update monitoring status to Completed for {}: {}", tx.signature, e);
-                    }
+            let verified = tokio::time::timeout(self.verification_timeout, self.resolver.resolve(&tx))
+                .await
+                .unwrap_or_else(|_| Err(anyhow::anyhow!("Verification timed out after {:?}", self.verification_timeout)));
+            match verified {
+                Ok(Some(update)) => {
+                    info!("Transaction {} outcome resolved: {:?}", tx.signature, update.outcome);
+                    self.finish(update, "Completed").await;
                 },
                 Ok(None) => {
                     debug!("Transaction {} still pending verification", tx.signature);
@@ -141,17 +239,17 @@ impl TransactionMonitor {
                 },
                 Err(verification_error) => {
                     warn!("Verification failed for transaction {}: {}", tx.signature, verification_error);
-                    if let Err(send_err) = self.update_sender.send((
+                    if let Some(update) = self.estimate_from_last_price(&tx, now).await {
+                        info!("Transaction {} outcome estimated from last known price: {:?}", tx.signature, update.outcome);
+                        self.finish(update, "Completed").await;
+                        continue;
+                    }
+                    let update = OutcomeUpdate::unverified(
                         tx.signature.clone(),
                         Outcome::VerificationFailed(format!("Verification error: {}", verification_error)),
-                        None, None, None, None, Some(now), false
-                    )).await {
-                        error!("Failed to send verification failed update: {}", send_err);
-                    }
-                    // Mark as Failed in storage
-                    if let Err(e) = self.storage.update_monitoring_status(&tx.signature, "Failed").await {
-                        error!("Failed to update monitoring status to Failed for {}: {}", tx.signature, e);
-                    }
+                        now,
+                    ).with_reason("On-chain verification failed");
+                    self.finish(update, "Failed").await;
                 }
             }
         }
@@ -169,6 +267,104 @@ impl TransactionMonitor {
         }
     }
 
+    /// Outcome of `tx` at its token's last known price, if the fallback is
+    /// enabled, a price is known and `tx` records how many tokens it bought.
+    ///
+    /// Without the amount bought there is no buy price to compare against, so
+    /// no estimate is made.
+    async fn estimate_from_last_price(&self, tx: &MonitoredTransaction, now: u64) -> Option<OutcomeUpdate> {
+        if tx.amount_bought_tokens <= 0.0 {
+            return None;
+        }
+        let source = self.price_fallback.as_ref()?;
+        let token_data = source.last_known_token_data(&tx.mint).await?;
+        let last_price = token_data.price_history.back().copied().filter(|price| *price > 0.0)?;
+        let buy_price = tx.initial_sol_spent / tx.amount_bought_tokens;
+
+        let final_sol = tx.initial_sol_spent / buy_price * last_price;
+        let pnl = final_sol - tx.initial_sol_spent;
+        let outcome = if pnl > 0.0 {
+            Outcome::Profit(pnl)
+        } else if pnl < 0.0 {
+            Outcome::Loss(pnl)
+        } else {
+            Outcome::Neutral
+        };
+
+        Some(OutcomeUpdate {
+            signature: tx.signature.clone(),
+            outcome,
+            buy_price_sol: Some(buy_price),
+            sell_price_sol: Some(last_price),
+            initial_sol_spent: Some(tx.initial_sol_spent),
+            final_sol_received: Some(final_sol),
+            timestamp_evaluated: Some(now),
+            is_verified: false,
+            outcome_reason: Some("Estimated from last known price, fresh price unavailable".to_string()),
+            low_confidence: true,
+        })
+    }
+
+    /// Report a final outcome and take the transaction out of the monitoring
+    /// queue with `status`, right away or with the next batch.
+    async fn finish(&mut self, update: OutcomeUpdate, status: &'static str) {
+        if let Some(batch) = &mut self.outcome_batch {
+            batch.pending.push((update, status));
+            if batch.pending.len() >= batch.max_size {
+                self.flush_outcomes().await;
+            }
+            return;
+        }
+
+        let signature = update.signature.clone();
+        if let Err(e) = self.update_sender.send(update).await {
+            error!("Failed to send outcome update for {}: {}", signature, e);
+        }
+        if let Err(e) = self.storage.update_monitoring_status(&signature, status).await {
+            error!("Failed to update monitoring status to {} for {}: {}", status, signature, e);
+        }
+    }
+
+    /// Whether an outcome for `signature` is waiting in the batch.
+    fn is_batched(&self, signature: &str) -> bool {
+        self.outcome_batch.as_ref()
+            .is_some_and(|batch| batch.pending.iter().any(|(update, _)| update.signature == signature))
+    }
+
+    /// Hand all batched outcomes to the DecisionLedger together, which
+    /// writes outcomes queued together in one storage call.
+    async fn flush_outcomes(&mut self) {
+        let Some(batch) = &mut self.outcome_batch else {
+            return;
+        };
+        if batch.pending.is_empty() {
+            return;
+        }
+
+        debug!("Sending {} batched outcome updates", batch.pending.len());
+        for (update, status) in batch.pending.drain(..) {
+            let signature = update.signature.clone();
+            if let Err(e) = self.update_sender.send(update).await {
+                error!("Failed to send outcome update for {}: {}", signature, e);
+            }
+            if let Err(e) = self.storage.update_monitoring_status(&signature, status).await {
+                error!("Failed to update monitoring status to {} for {}: {}", status, signature, e);
+            }
+        }
+    }
+
+    /// Get active transactions from persistent storage (for debugging/metrics)
+    pub async fn get_active_transactions(&self) -> Result<Vec<MonitoredTransaction>, anyhow::Error> {
+        self.storage.get_pending_monitoring_transactions().await
+    }
+}
+
+impl RpcOutcomeResolver {
+    /// Create a resolver backed by the given RPC client.
+    pub fn new(rpc_client: Arc<RpcClient>) -> Self {
+        Self { rpc_client }
+    }
+
     /// Verify transaction outcome using on-chain data
     /// 
     /// This method:
@@ -196,83 +392,253 @@ impl TransactionMonitor {
             .await
             .map_err(|e| anyhow::anyhow!("Failed to get signature status: {}", e))?;
 
-        if let Some(status_option) = status_response.value.get(0) {
-            match status_option {
-                Some(status) => {
-                    // Check if transaction is finalized
-                    if let Some(confirmation_status) = &status.confirmation_status {
-                        if *confirmation_status != solana_transaction_status::TransactionConfirmationStatus::Finalized {
-                            // Not finalized yet, continue monitoring
-                            return Ok(None);
-                        }
-                    }
-
-                    if let Some(err) = &status.err {
-                        // Transaction failed on-chain
-                        return Ok(Some((
-                            Outcome::ExecutionError(format!("Transaction failed: {:?}", err)),
-                            0.0,
-                            None,
-                            Some(tx.initial_sol_spent) // Assume SOL returned on failure
-                        )));
-                    }
+        // No status means the transaction is not found or not confirmed yet
+        let Some(Some(status)) = status_response.value.first() else {
+            return Ok(None);
+        };
 
-                    // Transaction successful and finalized
-                    // For now, use simplified calculation that indicates on-chain verification was performed
-                    let buy_price = tx.initial_sol_spent / tx.amount_bought_tokens;
-                    
-                    // In a real implementation, we would parse transaction logs and balance changes
-                    // For this POC, we simulate a verified profitable trade
-                    let profit = tx.initial_sol_spent * 0.05; // 5% profit for verified transactions
-                    let final_sol = tx.initial_sol_spent + profit;
-                    let sell_price = final_sol / tx.amount_bought_tokens;
-
-                    info!("Transaction {} verified as profitable on-chain", tx.signature);
-                    return Ok(Some((
-                        Outcome::Profit(profit),
-                        buy_price,
-                        Some(sell_price),
-                        Some(final_sol)
-                    )));
-                },
-                None => {
-                    // Transaction not found or not confirmed yet
-                    return Ok(None);
-                }
+        // Check if transaction is finalized
+        if let Some(confirmation_status) = &status.confirmation_status {
+            if *confirmation_status != solana_transaction_status::TransactionConfirmationStatus::Finalized {
+                // Not finalized yet, continue monitoring
+                return Ok(None);
             }
-        } else {
-            // No status information available
-            return Ok(None);
         }
-    }
 
-    /// Calculate PnL from pre/post token balances (placeholder for future implementation)
-    async fn calculate_pnl_from_balances(
-        &self,
-        _pre_balances: &[u8], // Simplified placeholder type
-        _post_balances: &[u8], // Simplified placeholder type  
-        tx: &MonitoredTransaction,
-    ) -> anyhow::Result<(f64, f64, Option<f64>, Option<f64>)> {
-        // This is a placeholder for future complex transaction parsing
-        // In a real implementation, you would:
-        // 1. Identify the specific token mint being traded
-        // 2. Find the wallet's token accounts for SOL/wSOL and the target token
-        // 3. Calculate the exact balance changes
-        // 4. Account for transaction fees
-        // 5. Handle wSOL unwrapping/wrapping
+        if let Some(err) = &status.err {
+            // Transaction failed on-chain
+            return Ok(Some((
+                Outcome::ExecutionError(format!("Transaction failed: {:?}", err)),
+                0.0,
+                None,
+                Some(tx.initial_sol_spent) // Assume SOL returned on failure
+            )));
+        }
 
+        // Transaction successful and finalized
+        // For now, use simplified calculation that indicates on-chain verification was performed
         let buy_price = tx.initial_sol_spent / tx.amount_bought_tokens;
-        
-        // Placeholder calculation - in reality would parse actual balance changes
-        let pnl = tx.initial_sol_spent * 0.05; // Assume 5% profit for verified transactions
-        let final_sol = tx.initial_sol_spent + pnl;
+
+        // In a real implementation, we would parse transaction logs and balance changes
+        // For this POC, we simulate a verified profitable trade
+        let profit = tx.initial_sol_spent * 0.05; // 5% profit for verified transactions
+        let final_sol = tx.initial_sol_spent + profit;
         let sell_price = final_sol / tx.amount_bought_tokens;
 
-        Ok((pnl, buy_price, Some(sell_price), Some(final_sol)))
+        info!("Transaction {} verified as profitable on-chain", tx.signature);
+        Ok(Some((
+            Outcome::Profit(profit),
+            buy_price,
+            Some(sell_price),
+            Some(final_sol)
+        )))
     }
+}
 
-    /// Get active transactions from persistent storage (for debugging/metrics)
-    pub async fn get_active_transactions(&self) -> Result<Vec<MonitoredTransaction>, anyhow::Error> {
-        self.storage.get_pending_monitoring_transactions().await
+#[async_trait]
+impl OutcomeResolver for RpcOutcomeResolver {
+    async fn resolve(&self, tx: &MonitoredTransaction) -> anyhow::Result<Option<OutcomeUpdate>> {
+        let Some((outcome, buy_price_sol, sell_price_sol, final_sol_received)) =
+            self.verify_transaction_on_chain(tx).await?
+        else {
+            return Ok(None);
+        };
+
+        let is_verified = matches!(outcome, Outcome::Profit(_) | Outcome::Loss(_));
+        Ok(Some(OutcomeUpdate {
+            signature: tx.signature.clone(),
+            outcome,
+            buy_price_sol: Some(buy_price_sol),
+            sell_price_sol,
+            initial_sol_spent: Some(tx.initial_sol_spent),
+            final_sol_received,
+            timestamp_evaluated: Some(chrono::Utc::now().timestamp_millis() as u64),
+            is_verified,
+            outcome_reason: Some(if is_verified { "Finalized on-chain" } else { "Failed on-chain" }.to_string()),
+            low_confidence: false,
+        }))
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oracle::test_support::{isolated_ledger, RecordBuilder};
+    use crate::oracle::types::TransactionRecord;
+    use std::collections::HashMap;
+
+    /// Resolver that reports every transaction as a small profit.
+    struct ProfitResolver;
+
+    #[async_trait]
+    impl OutcomeResolver for ProfitResolver {
+        async fn resolve(&self, tx: &MonitoredTransaction) -> anyhow::Result<Option<OutcomeUpdate>> {
+            Ok(Some(OutcomeUpdate {
+                signature: tx.signature.clone(),
+                outcome: Outcome::Profit(0.1),
+                buy_price_sol: None,
+                sell_price_sol: None,
+                initial_sol_spent: Some(tx.initial_sol_spent),
+                final_sol_received: Some(tx.initial_sol_spent + 0.1),
+                timestamp_evaluated: Some(chrono::Utc::now().timestamp_millis() as u64),
+                is_verified: true,
+                outcome_reason: None,
+                low_confidence: false,
+            }))
+        }
+    }
+
+    fn create_sent_decision(mint: &str, signature: &str) -> TransactionRecord {
+        RecordBuilder::new(mint)
+            .decided_at(1000)
+            .signature(signature)
+            .sent_at(1000)
+            .sol_spent(1.0)
+            .outcome(Outcome::PendingConfirmation)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_batched_outcomes_are_sent_together_after_interval() {
+        let storage = isolated_ledger().await;
+        let (update_sender, mut update_receiver) = mpsc::channel(10);
+        let rpc_client = Arc::new(RpcClient::new("http://127.0.0.1:1".to_string()));
+
+        let run_id = rand::random::<u64>();
+        let signatures: Vec<String> = (0..3).map(|i| format!("batched_sig_{}_{}", run_id, i)).collect();
+        for (i, signature) in signatures.iter().enumerate() {
+            let mint = format!("BatchedMint{}_{}", run_id, i);
+            storage.insert_record(&create_sent_decision(&mint, signature)).await.unwrap();
+            storage.enqueue_for_monitoring(&MonitoredTransaction {
+                signature: signature.clone(),
+                mint,
+                amount_bought_tokens: 1000.0,
+                initial_sol_spent: 1.0,
+                monitor_until: chrono::Utc::now().timestamp_millis() as u64 + 60_000,
+            }).await.unwrap();
+        }
+
+        let monitor = TransactionMonitor::new(
+            storage.clone(),
+            update_sender,
+            20,
+            rpc_client,
+            "11111111111111111111111111111112".to_string(),
+        )
+        .with_resolver(Arc::new(ProfitResolver))
+        .with_outcome_batching(100, Duration::from_millis(500));
+        let handle = tokio::spawn(monitor.run(mpsc::channel(1).1));
+
+        // Resolved within the first check, but held back until the interval passes
+        sleep(Duration::from_millis(250)).await;
+        assert!(update_receiver.try_recv().is_err());
+
+        // Then queued for the DecisionLedger all at once
+        let mut sent = Vec::new();
+        while sent.len() < signatures.len() {
+            let update = tokio::time::timeout(Duration::from_secs(5), update_receiver.recv())
+                .await
+                .expect("batched outcomes were not sent")
+                .unwrap();
+            assert_eq!(update.outcome, Outcome::Profit(0.1));
+            sent.push(update.signature);
+        }
+        assert_eq!(sent, signatures);
+
+        // Each send is followed by its monitoring status update
+        sleep(Duration::from_millis(100)).await;
+        handle.abort();
+        let pending = storage.get_pending_monitoring_transactions().await.unwrap();
+        assert!(!pending.iter().any(|tx| signatures.contains(&tx.signature)));
+    }
+
+    /// Resolver whose price feed is down.
+    struct UnavailableResolver;
+
+    #[async_trait]
+    impl OutcomeResolver for UnavailableResolver {
+        async fn resolve(&self, _tx: &MonitoredTransaction) -> anyhow::Result<Option<OutcomeUpdate>> {
+            Err(anyhow::anyhow!("price feed unavailable"))
+        }
+    }
+
+    /// Token data last seen for a single mint.
+    struct LastSeen {
+        mint: Pubkey,
+        price_history: Vec<f64>,
     }
-}
\ No newline at end of file
+
+    #[async_trait]
+    impl LastKnownTokenData for LastSeen {
+        async fn last_known_token_data(&self, mint: &Pubkey) -> Option<TokenData> {
+            if *mint != self.mint {
+                return None;
+            }
+            let record = create_sent_decision(mint, "unused");
+            let mut token_data = crate::oracle::data_sources::empty_token_data(&record.scored_candidate.base);
+            token_data.price_history = self.price_history.iter().copied().collect();
+            Some(token_data)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failed_price_fetch_falls_back_to_last_known_price() {
+        let storage = isolated_ledger().await;
+        let (update_sender, mut update_receiver) = mpsc::channel(10);
+        let rpc_client = Arc::new(RpcClient::new("http://127.0.0.1:1".to_string()));
+
+        let run_id = rand::random::<u64>();
+        let known_mint = format!("FallbackMint_{}", run_id);
+        let unknown_mint = format!("UnpricedMint_{}", run_id);
+        for mint in [&known_mint, &unknown_mint] {
+            let signature = format!("{}_sig", mint);
+            storage.insert_record(&create_sent_decision(mint, &signature)).await.unwrap();
+            storage.enqueue_for_monitoring(&MonitoredTransaction {
+                signature,
+                mint: mint.clone(),
+                amount_bought_tokens: 1000.0,
+                initial_sol_spent: 1.0,
+                monitor_until: chrono::Utc::now().timestamp_millis() as u64 + 60_000,
+            }).await.unwrap();
+        }
+
+        let monitor = TransactionMonitor::new(
+            storage.clone(),
+            update_sender,
+            20,
+            rpc_client,
+            "11111111111111111111111111111112".to_string(),
+        )
+        .with_resolver(Arc::new(UnavailableResolver))
+        .with_last_price_fallback(Arc::new(LastSeen {
+            mint: known_mint.clone(),
+            price_history: vec![0.001, 0.0015],
+        }));
+        let handle = tokio::spawn(monitor.run(mpsc::channel(1).1));
+
+        let mut updates = HashMap::new();
+        while updates.len() < 2 {
+            let update = tokio::time::timeout(Duration::from_secs(5), update_receiver.recv())
+                .await
+                .expect("No outcome update from the monitor")
+                .unwrap();
+            updates.insert(update.signature.clone(), update);
+        }
+        handle.abort();
+
+        // Bought for 1 SOL at 0.001 SOL per token, last seen at 0.0015
+        let estimated = &updates[&format!("{}_sig", known_mint)];
+        let Outcome::Profit(profit) = estimated.outcome else {
+            panic!("Expected an estimated profit, got {:?}", estimated.outcome);
+        };
+        assert!((profit - 0.5).abs() < 1e-9);
+        assert_eq!(estimated.buy_price_sol, Some(0.001));
+        assert_eq!(estimated.sell_price_sol, Some(0.0015));
+        assert!(estimated.low_confidence);
+        assert!(!estimated.is_verified);
+
+        // Without a known price the failure is reported as before
+        let failed = &updates[&format!("{}_sig", unknown_mint)];
+        assert!(matches!(failed.outcome, Outcome::VerificationFailed(_)));
+        assert!(!failed.low_confidence);
+    }
+}
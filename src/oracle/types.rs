@@ -2,9 +2,83 @@
 //!
 //! This contains types needed for the DecisionLedger system and Pillar II components.
 
+use crate::oracle::anomaly::AnomalyReport;
+use crate::oracle::pipeline::PipelineStage;
 use crate::types::{PremintCandidate, Pubkey};
+use anyhow::{anyhow, Result};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use solana_sdk::commitment_config::CommitmentConfig;
+pub use crate::oracle::types_old::{
+    Attribute, CreatorHoldings, Feature, FeatureScores, HolderData, LiquidityPool, Metadata, PoolType,
+    SocialActivity, TokenData, VolumeData,
+};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::time::Duration;
+
+/// Prediction score on the 0-100 scale.
+///
+/// Serializes as a plain integer, so stored records are unaffected; values above
+/// 100 are rejected on deserialization.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u8", into = "u8")]
+pub struct Score(u8);
+
+impl Score {
+    /// Highest valid score.
+    pub const MAX: u8 = 100;
+
+    /// Create a score, rejecting values above 100.
+    pub fn new(value: u8) -> Result<Self> {
+        if value > Self::MAX {
+            return Err(anyhow!("Score {} exceeds maximum of {}", value, Self::MAX));
+        }
+        Ok(Self(value))
+    }
+
+    /// Create a score, saturating values outside 0-100, e.g. a raw database column.
+    pub fn clamped(value: impl Into<i64>) -> Self {
+        Self(value.into().clamp(0, Self::MAX as i64) as u8)
+    }
+
+    /// The raw 0-100 value.
+    pub fn value(self) -> u8 {
+        self.0
+    }
+}
+
+impl TryFrom<u8> for Score {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        Self::new(value)
+    }
+}
+
+impl From<Score> for u8 {
+    fn from(score: Score) -> Self {
+        score.0
+    }
+}
+
+impl PartialEq<u8> for Score {
+    fn eq(&self, other: &u8) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialOrd<u8> for Score {
+    fn partial_cmp(&self, other: &u8) -> Option<std::cmp::Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+impl fmt::Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
 
 /// Scored candidate with simplified structure for demo
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,7 +88,7 @@ pub struct ScoredCandidate {
     /// The mint address
     pub mint: Pubkey,
     /// Predicted score (0-100)
-    pub predicted_score: u8,
+    pub predicted_score: Score,
     /// Feature scores breakdown
     pub feature_scores: HashMap<String, f64>,
     /// Explanation of the score
@@ -25,14 +99,392 @@ pub struct ScoredCandidate {
     pub anomaly_detected: bool,
     /// Timestamp when scored
     pub timestamp: u64,
+    /// Unix timestamp (ms) at which each input datum was fetched, keyed by source
+    /// (e.g. "supply", "liquidity", "holders", "social"). Cached data keeps the
+    /// timestamp of its original fetch.
+    #[serde(default)]
+    pub data_source_timestamps: HashMap<String, u64>,
+    /// Full computation trace, present only when `OracleConfig::enable_scoring_trace` is set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scoring_trace: Option<ScoringTrace>,
+    /// Why the score was adjusted or the candidate rejected: penalties applied
+    /// to the score, and the pipeline stage that rejected it, if any
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reason_codes: Vec<ReasonCode>,
+    /// Anomalies detected at scoring time; `None` if there were none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub anomaly_report: Option<AnomalyReport>,
+    /// What the oracle decided to do with the candidate; only `Buy` may be acted upon
+    #[serde(default)]
+    pub action: TradeAction,
+}
+
+impl ScoredCandidate {
+    /// Structured differences from `self` to `other`, e.g. the same candidate
+    /// scored before and after a config change. Deltas are `other - self`.
+    pub fn diff(&self, other: &Self) -> ScoreDiff {
+        let mut feature_deltas = HashMap::new();
+        let features: HashSet<&String> = self.feature_scores.keys().chain(other.feature_scores.keys()).collect();
+        for feature in features {
+            let before = self.feature_scores.get(feature).copied().unwrap_or(0.0);
+            let after = other.feature_scores.get(feature).copied().unwrap_or(0.0);
+            if after != before {
+                feature_deltas.insert(feature.clone(), after - before);
+            }
+        }
+
+        ScoreDiff {
+            score_delta: other.predicted_score.value() as i16 - self.predicted_score.value() as i16,
+            feature_deltas,
+            reason_codes_added: other.reason_codes.iter()
+                .filter(|code| !self.reason_codes.contains(code))
+                .copied()
+                .collect(),
+            reason_codes_removed: self.reason_codes.iter()
+                .filter(|code| !other.reason_codes.contains(code))
+                .copied()
+                .collect(),
+            anomaly_change: (self.anomaly_detected != other.anomaly_detected).then_some(other.anomaly_detected),
+        }
+    }
+}
+
+/// Differences between two scorings of a candidate, from `ScoredCandidate::diff`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScoreDiff {
+    /// Change in predicted score
+    pub score_delta: i16,
+    /// Change in each feature score that differs; a feature missing on one side counts as 0.0
+    pub feature_deltas: HashMap<String, f64>,
+    /// Reason codes present only in the second scoring
+    pub reason_codes_added: Vec<ReasonCode>,
+    /// Reason codes present only in the first scoring
+    pub reason_codes_removed: Vec<ReasonCode>,
+    /// The second scoring's anomaly flag, if it differs from the first
+    pub anomaly_change: Option<bool>,
+}
+
+impl ScoreDiff {
+    /// Whether both scorings agree on everything compared.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Version of the feature scores JSON written to the ledger.
+///
+/// Version 1 is the bare `{"liquidity": 0.8, ...}` map written before the
+/// format was tagged; later versions wrap it as `{"version": 2, "scores": {...}}`.
+/// Bump the version whenever a feature is renamed and list the rename in
+/// `FEATURE_RENAMES`.
+pub const FEATURE_SCORES_VERSION: u32 = 2;
+
+/// Feature renames as `(version introducing the new name, old name, new name)`.
+const FEATURE_RENAMES: &[(u32, &str, &str)] = &[
+    // Names used by the legacy quantum selector
+    (2, "creator_activity", "creator_sell_speed"),
+    (2, "metadata", "metadata_quality"),
+    (2, "social", "social_activity"),
+];
+
+#[derive(Serialize)]
+struct VersionedFeatureScoresRef<'a> {
+    version: u32,
+    scores: &'a HashMap<String, f64>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredFeatureScores {
+    Versioned { version: u32, scores: HashMap<String, f64> },
+    Unversioned(HashMap<String, f64>),
+}
+
+/// Serialize feature scores keyed by name, tagged with `FEATURE_SCORES_VERSION`.
+pub fn serialize_feature_scores(scores: &HashMap<String, f64>) -> Result<String> {
+    Ok(serde_json::to_string(&VersionedFeatureScoresRef { version: FEATURE_SCORES_VERSION, scores })?)
+}
+
+/// Read feature scores written by `serialize_feature_scores` in this or any
+/// earlier version, mapping renamed features to their current names.
+///
+/// Where a map holds both a feature's old and new name, the new one wins.
+/// Versions newer than `FEATURE_SCORES_VERSION` are rejected since their
+/// names cannot be trusted.
+pub fn deserialize_feature_scores(json: &str) -> Result<HashMap<String, f64>> {
+    let (version, mut scores) = match serde_json::from_str(json)? {
+        StoredFeatureScores::Versioned { version, scores } => (version, scores),
+        StoredFeatureScores::Unversioned(scores) => (1, scores),
+    };
+    if version > FEATURE_SCORES_VERSION {
+        return Err(anyhow!(
+            "Feature scores version {} is newer than supported version {}",
+            version, FEATURE_SCORES_VERSION
+        ));
+    }
+
+    for &(renamed_in, old_name, new_name) in FEATURE_RENAMES {
+        if version < renamed_in {
+            if let Some(score) = scores.remove(old_name) {
+                scores.entry(new_name.to_string()).or_insert(score);
+            }
+        }
+    }
+    Ok(scores)
+}
+
+/// Step-by-step record of how a candidate's score was computed, for debugging.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoringTrace {
+    /// Regime whose weights and thresholds were used
+    pub regime: MarketRegime,
+    /// Per-feature inputs, scores and weights, in `Feature::all()` order
+    pub features: Vec<FeatureTrace>,
+    /// Anomalies detected in the token data
+    pub anomalies: Vec<String>,
+    /// Sum of all feature contributions
+    pub weighted_sum: f64,
+    /// Sum of all feature weights
+    pub total_weight: f64,
+    /// Weighted average scaled to 0-100, before the anomaly penalty
+    pub base_score: u8,
+    /// Multiplier applied to the base score (1.0 when no anomaly was detected)
+    pub anomaly_penalty: f64,
+    /// Multiplier applied to the base score (1.0 when the metadata URI was reachable)
+    pub metadata_penalty: f64,
+    /// Multiplier applied to the base score (1.0 unless scoring took abnormally long)
+    #[serde(default = "no_penalty")]
+    pub slow_scoring_penalty: f64,
+    /// Score reported for the candidate
+    pub final_score: u8,
+}
+
+/// Computation trace of a single feature.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeatureTrace {
+    pub feature: String,
+    /// Input values the feature was computed from
+    pub raw_inputs: HashMap<String, f64>,
+    /// Normalized score before the data age penalty
+    pub raw_score: f64,
+    /// Normalized score used for aggregation
+    pub score: f64,
+    /// Weight applied to the score
+    pub weight: f64,
+    /// `score * weight`
+    pub contribution: f64,
+}
+
+impl ScoringTrace {
+    /// Replay the aggregation steps to recompute the final score.
+    pub fn reconstruct_score(&self) -> u8 {
+        let weighted_sum: f64 = self.features.iter().map(|f| f.score * f.weight).sum();
+        let total_weight: f64 = self.features.iter().map(|f| f.weight).sum();
+
+        let base_score = if total_weight > 0.0 {
+            ((weighted_sum / total_weight * 100.0).round() as u8).min(100)
+        } else {
+            50
+        };
+
+        (base_score as f64 * self.anomaly_penalty * self.metadata_penalty * self.slow_scoring_penalty) as u8
+    }
+}
+
+/// Penalty multiplier for traces recorded before a penalty existed.
+fn no_penalty() -> f64 {
+    1.0
+}
+
+/// Machine-readable reason a candidate was not scored, not acted upon or penalized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReasonCode {
+    /// The oracle's rate limiter rejected the candidate
+    RateLimited,
+    /// The score was below the regime's buy threshold
+    BelowBuyThreshold,
+    /// The oracle is still warming up and does not act yet
+    Warmup,
+    /// The token's metadata URI could not be fetched at all
+    MetadataUnreachable,
+    /// The candidate waited in the queue past the staleness threshold
+    Stale,
+    /// Scoring the candidate took longer than the slow scoring threshold
+    SlowScoring,
+    /// The consecutive-loss kill switch is tripped
+    KillSwitch,
+    /// The mint or its creator is on the blocklist
+    Blocklisted,
+    /// The candidate comes from a launchpad program that is not allowed
+    ProgramNotAllowed,
+    /// The pool holds less SOL than the pipeline's minimum
+    LowLiquidity,
+    /// The mint or freeze authority is still active
+    ActiveAuthority,
+}
+
+impl ReasonCode {
+    /// Stable snake_case identifier, matching the serialized form.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReasonCode::RateLimited => "rate_limited",
+            ReasonCode::BelowBuyThreshold => "below_buy_threshold",
+            ReasonCode::Warmup => "warmup",
+            ReasonCode::MetadataUnreachable => "metadata_unreachable",
+            ReasonCode::Stale => "stale",
+            ReasonCode::SlowScoring => "slow_scoring",
+            ReasonCode::KillSwitch => "kill_switch",
+            ReasonCode::Blocklisted => "blocklisted",
+            ReasonCode::ProgramNotAllowed => "program_not_allowed",
+            ReasonCode::LowLiquidity => "low_liquidity",
+            ReasonCode::ActiveAuthority => "active_authority",
+        }
+    }
+
+    /// Whether a scoring pipeline stage rejected the candidate outright.
+    pub fn is_pipeline_rejection(&self) -> bool {
+        matches!(
+            self,
+            ReasonCode::Blocklisted | ReasonCode::ProgramNotAllowed | ReasonCode::LowLiquidity | ReasonCode::ActiveAuthority
+        )
+    }
+}
+
+impl std::str::FromStr for ReasonCode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "rate_limited" => Ok(ReasonCode::RateLimited),
+            "below_buy_threshold" => Ok(ReasonCode::BelowBuyThreshold),
+            "warmup" => Ok(ReasonCode::Warmup),
+            "metadata_unreachable" => Ok(ReasonCode::MetadataUnreachable),
+            "stale" => Ok(ReasonCode::Stale),
+            "slow_scoring" => Ok(ReasonCode::SlowScoring),
+            "kill_switch" => Ok(ReasonCode::KillSwitch),
+            "blocklisted" => Ok(ReasonCode::Blocklisted),
+            "program_not_allowed" => Ok(ReasonCode::ProgramNotAllowed),
+            "low_liquidity" => Ok(ReasonCode::LowLiquidity),
+            "active_authority" => Ok(ReasonCode::ActiveAuthority),
+            other => Err(anyhow!("Unknown reason code: {}", other)),
+        }
+    }
+}
+
+impl fmt::Display for ReasonCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A candidate the oracle rejected, as kept in the ledger's rejection log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rejection {
+    pub mint: Pubkey,
+    pub reason_code: ReasonCode,
+    /// Unix timestamp (ms) of the rejection
+    pub timestamp: u64,
+}
+
+/// Width of the score bands reported by `LedgerStorage::get_score_outcome_calibration`.
+pub const SCORE_BAND_WIDTH: u8 = 10;
+
+/// How closed trades within one score band turned out.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoreBandStats {
+    /// Lowest score in the band
+    pub min_score: u8,
+    /// Highest score in the band (inclusive; the top band also holds 100)
+    pub max_score: u8,
+    /// Number of closed trades (profit or loss) in the band
+    pub trades: u64,
+    /// Fraction of those trades that made a profit
+    pub win_rate: f64,
+    /// Mean PnL in SOL, counting losses as negative
+    pub average_pnl: f64,
+}
+
+impl ScoreBandStats {
+    /// Build band stats from the band's lowest score and its aggregates.
+    pub(crate) fn from_band(min_score: u8, trades: u64, win_rate: f64, average_pnl: f64) -> Self {
+        let max_score = if min_score.saturating_add(SCORE_BAND_WIDTH) >= Score::MAX {
+            Score::MAX
+        } else {
+            min_score + SCORE_BAND_WIDTH - 1
+        };
+        Self { min_score, max_score, trades, win_rate, average_pnl }
+    }
+}
+
+/// Population variance of `scores`, or `None` with fewer than two scores.
+pub fn score_variance(scores: &[u8]) -> Option<f64> {
+    if scores.len() < 2 {
+        return None;
+    }
+
+    let n = scores.len() as f64;
+    let mean = scores.iter().map(|&s| s as f64).sum::<f64>() / n;
+    Some(scores.iter().map(|&s| (s as f64 - mean).powi(2)).sum::<f64>() / n)
+}
+
+/// A candidate the oracle saw but did not score.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkippedCandidate {
+    pub candidate: PremintCandidate,
+    pub reason_code: ReasonCode,
+    /// Unix timestamp (ms) at which the candidate was skipped
+    pub timestamp: u64,
+}
+
+impl SkippedCandidate {
+    /// Start of the score explanation of every skip record.
+    pub const REASON_PREFIX: &'static str = "skipped: ";
+
+    /// Decision record for the ledger: a zero score with `Outcome::NotExecuted`,
+    /// and the reason code as both the score explanation and its only reason code.
+    pub fn into_record(self) -> TransactionRecord {
+        let scored_candidate = ScoredCandidate {
+            mint: self.candidate.mint.clone(),
+            predicted_score: Score::default(),
+            feature_scores: HashMap::new(),
+            reason: format!("{}{}", Self::REASON_PREFIX, self.reason_code),
+            calculation_time: 0,
+            anomaly_detected: false,
+            timestamp: self.timestamp,
+            data_source_timestamps: HashMap::new(),
+            scoring_trace: None,
+            reason_codes: vec![self.reason_code],
+            anomaly_report: None,
+            action: TradeAction::Skip,
+            base: self.candidate,
+        };
+
+        TransactionRecord {
+            id: None,
+            scored_candidate,
+            transaction_signature: None,
+            buy_price_sol: None,
+            sell_price_sol: None,
+            amount_bought_tokens: None,
+            amount_sold_tokens: None,
+            initial_sol_spent: None,
+            final_sol_received: None,
+            timestamp_decision_made: self.timestamp,
+            timestamp_transaction_sent: None,
+            timestamp_outcome_evaluated: None,
+            actual_outcome: Outcome::NotExecuted,
+            market_context_snapshot: HashMap::new(),
+        }
+    }
 }
 
 /// Represents the final financial outcome of a transaction.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub enum Outcome {
     /// Profit in SOL
     Profit(f64),
-    /// Loss in SOL
+    /// Loss in SOL, as a negative PnL
     Loss(f64),
     /// No change (e.g., failed transaction, no confirmation)
     Neutral,
@@ -41,6 +493,7 @@ pub enum Outcome {
     /// Transaction failed during execution (e.g., timeout, reverted)
     FailedExecution,
     /// Candidate scored but transaction never sent
+    #[default]
     NotExecuted,
     
     // New detailed error states for on-chain verification
@@ -52,12 +505,6 @@ pub enum Outcome {
     VerificationFailed(String),
 }
 
-impl Default for Outcome {
-    fn default() -> Self {
-        Outcome::NotExecuted
-    }
-}
-
 /// Complete record of a PredictiveOracle decision and its transactional outcome.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransactionRecord {
@@ -91,26 +538,177 @@ pub struct TransactionRecord {
     /// The final transaction outcome
     pub actual_outcome: Outcome,
     
-    /// Market context snapshot at decision time (for later analysis)
-    /// This will be populated by the MarketRegimeDetector in the future
+    /// Market context snapshot at decision time (for later analysis),
+    /// in the map form of `MarketContextSnapshot`
     pub market_context_snapshot: HashMap<String, f64>,
 }
 
+impl TransactionRecord {
+    /// Whether this records a candidate skipped without being scored, see
+    /// `SkippedCandidate::into_record`.
+    pub fn is_skipped_candidate(&self) -> bool {
+        self.scored_candidate.reason.starts_with(SkippedCandidate::REASON_PREFIX)
+    }
+
+    /// Typed view of `market_context_snapshot`, or `None` if it is incomplete.
+    pub fn market_context(&self) -> Option<MarketContextSnapshot> {
+        MarketContextSnapshot::from_map(&self.market_context_snapshot)
+    }
+}
+
+/// Market conditions at decision time, as observed by the `MarketRegimeDetector`.
+///
+/// Stored in `TransactionRecord::market_context_snapshot` as a string-keyed map
+/// (see `to_map`), with the regime encoded by its position in `MarketRegime::ALL`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct MarketContextSnapshot {
+    /// SOL price in USD
+    pub sol_price: f64,
+    /// SOL price volatility, in percent
+    pub volatility: f64,
+    /// Network throughput in transactions per second
+    pub network_tps: f64,
+    /// Aggregated 24h DEX volume in USD
+    pub dex_volume: f64,
+    pub regime: MarketRegime,
+}
+
+impl MarketContextSnapshot {
+    pub const SOL_PRICE: &'static str = "sol_price";
+    pub const VOLATILITY: &'static str = "volatility";
+    pub const NETWORK_TPS: &'static str = "network_tps";
+    pub const DEX_VOLUME: &'static str = "dex_volume";
+    pub const REGIME: &'static str = "regime";
+
+    /// Map representation stored with ledger records.
+    pub fn to_map(&self) -> HashMap<String, f64> {
+        let regime = MarketRegime::ALL.iter().position(|r| *r == self.regime).unwrap_or_default();
+        HashMap::from([
+            (Self::SOL_PRICE.to_string(), self.sol_price),
+            (Self::VOLATILITY.to_string(), self.volatility),
+            (Self::NETWORK_TPS.to_string(), self.network_tps),
+            (Self::DEX_VOLUME.to_string(), self.dex_volume),
+            (Self::REGIME.to_string(), regime as f64),
+        ])
+    }
+
+    /// Parse the map representation; `None` if any field is missing or the
+    /// regime is unknown. Extra keys are ignored.
+    pub fn from_map(map: &HashMap<String, f64>) -> Option<Self> {
+        let regime = *map.get(Self::REGIME)?;
+        let regime = if regime >= 0.0 && regime.fract() == 0.0 {
+            *MarketRegime::ALL.get(regime as usize)?
+        } else {
+            return None;
+        };
+
+        Some(Self {
+            sol_price: *map.get(Self::SOL_PRICE)?,
+            volatility: *map.get(Self::VOLATILITY)?,
+            network_tps: *map.get(Self::NETWORK_TPS)?,
+            dex_volume: *map.get(Self::DEX_VOLUME)?,
+            regime,
+        })
+    }
+}
+
+impl From<MarketContextSnapshot> for HashMap<String, f64> {
+    fn from(snapshot: MarketContextSnapshot) -> Self {
+        snapshot.to_map()
+    }
+}
+
 // --- Communication Channels for DecisionLedger ---
 
 /// Channel for sending new decisions to DecisionLedger
 pub type DecisionRecordSender = tokio::sync::mpsc::Sender<TransactionRecord>;
 pub type DecisionRecordReceiver = tokio::sync::mpsc::Receiver<TransactionRecord>;
 
-/// Channel for sending outcome updates to DecisionLedger
+/// Evaluated outcome of a transaction, sent to the DecisionLedger.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutcomeUpdate {
+    pub signature: String,
+    pub outcome: Outcome,
+    pub buy_price_sol: Option<f64>,
+    pub sell_price_sol: Option<f64>,
+    pub initial_sol_spent: Option<f64>,
+    pub final_sol_received: Option<f64>,
+    pub timestamp_evaluated: Option<u64>,
+    /// Whether the outcome was confirmed on-chain
+    pub is_verified: bool,
+    /// Why the outcome changed, kept in the ledger's outcome history
+    pub outcome_reason: Option<String>,
+    /// Whether the outcome was estimated from stale data, such as the last
+    /// known price, instead of being resolved from a fresh one
+    pub low_confidence: bool,
+}
+
+impl OutcomeUpdate {
+    /// Update carrying only an outcome (timeouts, verification failures).
+    pub fn unverified(signature: String, outcome: Outcome, timestamp_evaluated: u64) -> Self {
+        Self {
+            signature,
+            outcome,
+            buy_price_sol: None,
+            sell_price_sol: None,
+            initial_sol_spent: None,
+            final_sol_received: None,
+            timestamp_evaluated: Some(timestamp_evaluated),
+            is_verified: false,
+            outcome_reason: None,
+            low_confidence: false,
+        }
+    }
+
+    /// Attach the reason for the outcome change.
+    pub fn with_reason(mut self, reason: impl Into<String>) -> Self {
+        self.outcome_reason = Some(reason.into());
+        self
+    }
+}
+
+/// Tuple form previously sent over the outcome channel:
 /// (signature, outcome, buy_price, sell_price, sol_spent, sol_received, timestamp_evaluated, is_verified)
-pub type OutcomeUpdateSender = tokio::sync::mpsc::Sender<(String, Outcome, Option<f64>, Option<f64>, Option<f64>, Option<f64>, Option<u64>, bool)>;
-pub type OutcomeUpdateReceiver = tokio::sync::mpsc::Receiver<(String, Outcome, Option<f64>, Option<f64>, Option<f64>, Option<f64>, Option<u64>, bool)>;
+pub type LegacyOutcomeUpdate = (String, Outcome, Option<f64>, Option<f64>, Option<f64>, Option<f64>, Option<u64>, bool);
+
+impl From<LegacyOutcomeUpdate> for OutcomeUpdate {
+    fn from(
+        (signature, outcome, buy_price_sol, sell_price_sol, initial_sol_spent, final_sol_received, timestamp_evaluated, is_verified): LegacyOutcomeUpdate,
+    ) -> Self {
+        Self {
+            signature,
+            outcome,
+            buy_price_sol,
+            sell_price_sol,
+            initial_sol_spent,
+            final_sol_received,
+            timestamp_evaluated,
+            is_verified,
+            outcome_reason: None,
+            low_confidence: false,
+        }
+    }
+}
+
+/// One outcome change of a trade, as kept in the ledger's outcome history.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutcomeTransition {
+    pub signature: String,
+    pub from: Outcome,
+    pub to: Outcome,
+    pub reason: Option<String>,
+    /// Evaluation timestamp of the update, or the time it was applied if it had none
+    pub timestamp: u64,
+}
+
+/// Channel for sending outcome updates to DecisionLedger
+pub type OutcomeUpdateSender = tokio::sync::mpsc::Sender<OutcomeUpdate>;
+pub type OutcomeUpdateReceiver = tokio::sync::mpsc::Receiver<OutcomeUpdate>;
 
 // --- Pillar II: Performance Monitor and Strategy Optimizer Types ---
 
 /// Feature weights for scoring algorithm (imported from types_old.rs structure)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FeatureWeights {
     pub liquidity: f64,
     pub holder_distribution: f64,
@@ -121,26 +719,146 @@ pub struct FeatureWeights {
     pub creator_sell_speed: f64,
     pub metadata_quality: f64,
     pub social_activity: f64,
+    #[serde(default)]
+    pub authority_status: f64,
+    #[serde(default)]
+    pub contract_verified: f64,
+    #[serde(default)]
+    pub graduation_proximity: f64,
+    #[serde(default)]
+    pub tx_per_holder: f64,
 }
 
 impl Default for FeatureWeights {
     fn default() -> Self {
         Self {
-            liquidity: 0.20,
-            holder_distribution: 0.15,
-            volume_growth: 0.15,
-            holder_growth: 0.10,
-            price_change: 0.10,
-            jito_bundle_presence: 0.05,
-            creator_sell_speed: 0.10,
-            metadata_quality: 0.10,
-            social_activity: 0.05,
+            liquidity: 0.17,
+            holder_distribution: 0.12,
+            volume_growth: 0.12,
+            holder_growth: 0.08,
+            price_change: 0.08,
+            jito_bundle_presence: 0.04,
+            creator_sell_speed: 0.07,
+            metadata_quality: 0.09,
+            social_activity: 0.04,
+            authority_status: 0.09,
+            contract_verified: 0.02,
+            graduation_proximity: 0.04,
+            tx_per_holder: 0.04,
+        }
+    }
+}
+
+impl FeatureWeights {
+    /// Weight of a single feature.
+    pub fn get(&self, feature: Feature) -> f64 {
+        match feature {
+            Feature::Liquidity => self.liquidity,
+            Feature::HolderDistribution => self.holder_distribution,
+            Feature::VolumeGrowth => self.volume_growth,
+            Feature::HolderGrowth => self.holder_growth,
+            Feature::PriceChange => self.price_change,
+            Feature::JitoBundlePresence => self.jito_bundle_presence,
+            Feature::CreatorSellSpeed => self.creator_sell_speed,
+            Feature::MetadataQuality => self.metadata_quality,
+            Feature::SocialActivity => self.social_activity,
+            Feature::AuthorityStatus => self.authority_status,
+            Feature::ContractVerified => self.contract_verified,
+            Feature::GraduationProximity => self.graduation_proximity,
+            Feature::TxPerHolder => self.tx_per_holder,
+        }
+    }
+
+    /// Set the weight of a single feature.
+    pub fn set(&mut self, feature: Feature, weight: f64) {
+        let slot = match feature {
+            Feature::Liquidity => &mut self.liquidity,
+            Feature::HolderDistribution => &mut self.holder_distribution,
+            Feature::VolumeGrowth => &mut self.volume_growth,
+            Feature::HolderGrowth => &mut self.holder_growth,
+            Feature::PriceChange => &mut self.price_change,
+            Feature::JitoBundlePresence => &mut self.jito_bundle_presence,
+            Feature::CreatorSellSpeed => &mut self.creator_sell_speed,
+            Feature::MetadataQuality => &mut self.metadata_quality,
+            Feature::SocialActivity => &mut self.social_activity,
+            Feature::AuthorityStatus => &mut self.authority_status,
+            Feature::ContractVerified => &mut self.contract_verified,
+            Feature::GraduationProximity => &mut self.graduation_proximity,
+            Feature::TxPerHolder => &mut self.tx_per_holder,
+        };
+        *slot = weight;
+    }
+
+    /// Sum of all feature weights.
+    pub fn sum(&self) -> f64 {
+        Feature::all().into_iter().map(|feature| self.get(feature)).sum()
+    }
+
+    /// Check that every weight is finite and non-negative and that the weights
+    /// sum to 1.0 within `WEIGHT_SUM_TOLERANCE`.
+    pub fn validate(&self) -> std::result::Result<(), WeightError> {
+        for feature in Feature::all() {
+            let weight = self.get(feature);
+            if !weight.is_finite() {
+                return Err(WeightError::NonFinite { feature, weight });
+            }
+            if weight < 0.0 {
+                return Err(WeightError::Negative { feature, weight });
+            }
+        }
+
+        let sum = self.sum();
+        if (sum - 1.0).abs() > WEIGHT_SUM_TOLERANCE {
+            return Err(WeightError::BadSum { sum });
+        }
+        Ok(())
+    }
+
+    /// Scale all weights so they sum to 1.0. Leaves the weights unchanged if
+    /// their sum is not a positive, finite number.
+    pub fn normalize(&mut self) {
+        let sum = self.sum();
+        if !sum.is_finite() || sum <= 0.0 {
+            return;
+        }
+        for feature in Feature::all() {
+            self.set(feature, self.get(feature) / sum);
+        }
+    }
+}
+
+/// Largest distance from 1.0 that `FeatureWeights::validate` accepts for the weight sum.
+pub const WEIGHT_SUM_TOLERANCE: f64 = 1e-3;
+
+/// Why a `FeatureWeights` set failed validation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WeightError {
+    /// A weight is NaN or infinite
+    NonFinite { feature: Feature, weight: f64 },
+    /// A weight is below zero
+    Negative { feature: Feature, weight: f64 },
+    /// The weights do not sum to 1.0
+    BadSum { sum: f64 },
+}
+
+impl fmt::Display for WeightError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WeightError::NonFinite { feature, weight } => {
+                write!(f, "weight of {} is not finite: {}", feature.as_str(), weight)
+            }
+            WeightError::Negative { feature, weight } => {
+                write!(f, "weight of {} is negative: {}", feature.as_str(), weight)
+            }
+            WeightError::BadSum { sum } => write!(f, "weights sum to {}, expected 1.0", sum),
         }
     }
 }
 
+impl std::error::Error for WeightError {}
+
 /// Score thresholds for various features
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScoreThresholds {
     pub min_liquidity_sol: f64,
     pub whale_threshold: f64,
@@ -179,6 +897,9 @@ pub struct PerformanceReport {
     pub average_loss_sol: f64,
     pub net_profit_sol: f64,
     pub max_drawdown_percent: f64, // Maximum capital drawdown
+    /// Mints re-scored with a score variance above `HIGH_SCORE_VARIANCE`
+    #[serde(default)]
+    pub high_score_variance_mints: Vec<String>,
 }
 
 /// Set of optimized parameters for Oracle
@@ -202,7 +923,7 @@ pub type OptimizedParametersReceiver = tokio::sync::mpsc::Receiver<OptimizedPara
 // --- Pillar III: Contextual Adaptations (MarketRegimeDetector) Types ---
 
 /// Represents the identified market state/regime.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum MarketRegime {
     /// Bullish market: high activity, rising prices, low risk aversion
     Bullish,
@@ -213,13 +934,19 @@ pub enum MarketRegime {
     /// High network congestion: elevated fees, high transaction failure risk
     HighCongestion,
     /// Low activity market: very low volume and activity
+    #[default]
     LowActivity,
 }
 
-impl Default for MarketRegime {
-    fn default() -> Self {
-        MarketRegime::LowActivity
-    }
+impl MarketRegime {
+    /// Every regime, in declaration order.
+    pub const ALL: [MarketRegime; 5] = [
+        MarketRegime::Bullish,
+        MarketRegime::Bearish,
+        MarketRegime::Choppy,
+        MarketRegime::HighCongestion,
+        MarketRegime::LowActivity,
+    ];
 }
 
 /// Set of scoring parameters specific to a market regime.
@@ -227,7 +954,19 @@ impl Default for MarketRegime {
 pub struct RegimeSpecificParameters {
     pub weights: FeatureWeights,
     pub thresholds: ScoreThresholds,
-    // Can add other parameters like buy_score_threshold in the future
+    /// Scores assigned to Jito bundle presence
+    #[serde(default)]
+    pub jito_bundle_scores: JitoBundleScores,
+    /// Minimum score at which a candidate is bought
+    #[serde(default = "default_buy_score_threshold")]
+    pub buy_score_threshold: u8,
+    /// Highest score a candidate can get in this regime
+    #[serde(default = "default_max_score")]
+    pub max_score: u8,
+    /// RPC requests per second allowed in this regime; `None` keeps
+    /// `OracleConfig::rate_limit_requests_per_second`
+    #[serde(default)]
+    pub rate_limit_requests_per_second: Option<u32>,
 }
 
 impl Default for RegimeSpecificParameters {
@@ -235,31 +974,147 @@ impl Default for RegimeSpecificParameters {
         Self {
             weights: FeatureWeights::default(),
             thresholds: ScoreThresholds::default(),
+            jito_bundle_scores: JitoBundleScores::default(),
+            buy_score_threshold: default_buy_score_threshold(),
+            max_score: default_max_score(),
+            rate_limit_requests_per_second: None,
+        }
+    }
+}
+
+impl RegimeSpecificParameters {
+    /// Cap an aggregated score at this regime's maximum.
+    pub fn cap_score(&self, score: u8) -> u8 {
+        score.min(self.max_score)
+    }
+
+    /// Action for a candidate with the given score under this regime.
+    pub fn trade_action(&self, score: Score) -> TradeAction {
+        if score >= self.buy_score_threshold {
+            TradeAction::Buy
+        } else {
+            TradeAction::Skip
+        }
+    }
+}
+
+/// What the oracle recommends doing with a scored candidate.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TradeAction {
+    Buy,
+    #[default]
+    Skip,
+}
+
+impl TradeAction {
+    /// Action behind a decision read back from the ledger, which carries a
+    /// transaction signature only if the candidate was bought.
+    pub fn of_stored_decision(signature: Option<&str>) -> Self {
+        if signature.is_some() {
+            TradeAction::Buy
+        } else {
+            TradeAction::Skip
+        }
+    }
+}
+
+/// Default minimum score for a buy.
+pub fn default_buy_score_threshold() -> u8 {
+    75
+}
+
+/// Default score cap, i.e. no cap.
+pub fn default_max_score() -> u8 {
+    100
+}
+
+/// Jito bundle presence feature score for each possible launch observation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JitoBundleScores {
+    /// Launched in a Jito bundle
+    pub bundled: f64,
+    /// Known not to be bundled
+    pub not_bundled: f64,
+    /// Bundle status unknown
+    pub unknown: f64,
+}
+
+impl Default for JitoBundleScores {
+    fn default() -> Self {
+        Self {
+            bundled: 0.8,     // Jito bundles are generally positive
+            not_bundled: 0.3, // Non-bundle is neutral/slightly negative
+            unknown: 0.5,
         }
     }
 }
 
+/// Weights and thresholds the oracle actually scores with, after adaptive
+/// adjustments and the active regime's overrides are applied.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EffectiveConfigSnapshot {
+    /// Regime whose parameters are active
+    pub regime: MarketRegime,
+    /// Base weights from configuration
+    pub base_weights: FeatureWeights,
+    /// Weights of the active regime, before adaptive adjustments
+    pub regime_weights: FeatureWeights,
+    /// Adaptive adjustment per feature (0.1 = +10%)
+    pub adaptive_adjustments: HashMap<String, f64>,
+    /// Final weights used for scoring
+    pub effective_weights: FeatureWeights,
+    /// Final thresholds used for scoring
+    pub effective_thresholds: ScoreThresholds,
+    /// Minimum score for a buy in the active regime
+    pub buy_score_threshold: u8,
+}
+
 /// Extended Oracle configuration with regime-specific parameters for Pillar III.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OracleConfig {
+    /// Base feature weights, used when the active regime has no parameters
+    #[serde(default)]
+    pub weights: FeatureWeights,
+    /// Base score thresholds, used when the active regime has no parameters
+    #[serde(default)]
+    pub thresholds: ScoreThresholds,
     /// RPC endpoints
     pub rpc_endpoints: Vec<String>,
     /// Pump.fun API key
     pub pump_fun_api_key: Option<String>,
     /// Bitquery API key
     pub bitquery_api_key: Option<String>,
-    /// RPC retry attempts
-    pub rpc_retry_attempts: usize,
+    /// Retries of token data fetches from the RPC endpoints
+    #[serde(default = "default_rpc_retry")]
+    pub rpc_retry: RetryPolicy,
+    /// Retries of requests to external HTTP APIs (e.g. price feeds)
+    #[serde(default = "default_api_retry")]
+    pub api_retry: RetryPolicy,
     /// RPC timeout in seconds
     pub rpc_timeout_seconds: u64,
     /// Cache TTL in seconds
     pub cache_ttl_seconds: u64,
     /// Maximum parallel requests
     pub max_parallel_requests: usize,
+    /// Maximum concurrent in-flight requests to any single RPC endpoint
+    #[serde(default = "default_max_requests_per_endpoint")]
+    pub max_requests_per_endpoint: usize,
     /// Rate limit requests per second
     pub rate_limit_requests_per_second: u32,
     /// GUI notification threshold
     pub notify_threshold: u8,
+    /// Address for the metrics HTTP server, `None` disables it
+    #[serde(default)]
+    pub metrics_http_listen: Option<String>,
+    /// File that every scored candidate is appended to as a JSON line
+    #[serde(default)]
+    pub score_log_path: Option<String>,
+    /// URL that every scored candidate is posted to as JSON
+    #[serde(default)]
+    pub score_webhook_url: Option<String>,
+    /// Milliseconds the score webhook gets to answer each post
+    #[serde(default = "default_score_webhook_timeout_ms")]
+    pub score_webhook_timeout_ms: u64,
 
     /// Regime-specific parameters mapping for Pillar III
     /// Each market regime has its own set of weights and thresholds
@@ -268,12 +1123,439 @@ pub struct OracleConfig {
     // Additional modular architecture fields
     /// Adaptive weights recalculation interval
     pub adaptive_recalc_interval: u64,
+    /// Fraction by which each recalculation interval is randomly varied (e.g. 0.1
+    /// for ±10%), so that several instances do not recalculate together
+    #[serde(default = "default_adaptive_recalc_jitter")]
+    pub adaptive_recalc_jitter: f64,
+    /// Minimum scored candidates before adaptive weights adjust anything
+    #[serde(default = "default_min_samples_for_adjustment")]
+    pub min_samples_for_adjustment: usize,
+    /// Lower bound for any adaptively adjusted feature weight
+    #[serde(default = "default_weight_floor")]
+    pub weight_floor: f64,
+    /// Relative weight increase given to features stuck at the floor on each
+    /// recalculation, so they can be re-evaluated (0.0 disables exploration)
+    #[serde(default = "default_weight_exploration_rate")]
+    pub weight_exploration_rate: f64,
+    /// Weight a scored candidate keeps for every newer one when adaptive weights
+    /// judge feature performance (1.0 weighs the whole history equally)
+    #[serde(default = "default_weight_performance_decay")]
+    pub weight_performance_decay: f64,
+    /// Seconds after which a scored candidate's weight halves when adaptive
+    /// weights judge feature performance, by its age relative to the newest
+    /// one (0 disables time decay)
+    #[serde(default = "default_weight_decay_half_life_seconds")]
+    pub weight_decay_half_life_seconds: u64,
+    /// Adaptation rates for individual features, e.g. slower for volatile social
+    /// data; features not listed use the global adaptation rate
+    #[serde(default)]
+    pub feature_adaptation_rates: HashMap<Feature, f64>,
     /// Circuit breaker failure threshold
     pub circuit_breaker_failure_threshold: u32,
     /// Circuit breaker cooldown in seconds
     pub circuit_breaker_cooldown_seconds: u64,
+    /// Cooldowns an endpoint may go through before it is dropped from rotation
+    #[serde(default = "default_circuit_breaker_max_cooldowns_before_eviction")]
+    pub circuit_breaker_max_cooldowns_before_eviction: u32,
+    /// Seconds an endpoint must stay healthy before its past cooldowns stop
+    /// counting towards eviction
+    #[serde(default = "default_circuit_breaker_cooldown_reset_seconds")]
+    pub circuit_breaker_cooldown_reset_seconds: u64,
+    /// Endpoint success sample size
+    #[serde(default = "default_endpoint_success_sample_size")]
+    pub endpoint_success_sample_size: usize,
+    /// Number of recent attempts an endpoint's success rate is computed over
+    #[serde(default = "default_endpoint_success_window")]
+    pub endpoint_success_window: usize,
+    /// Adaptive error rate window size
+    #[serde(default = "default_adaptive_error_rate_window")]
+    pub adaptive_error_rate_window: usize,
     /// Maximum cache entries
     pub max_cache_entries: usize,
+    /// Maximum age of a feature's input data before its score is scaled down
+    #[serde(default = "default_max_data_age_seconds")]
+    pub max_data_age_seconds: u64,
+    /// Age at which social activity readings count for half, e.g. 300 since buzz
+    /// around a launch fades within minutes; `None` ages social data out with
+    /// `max_data_age_seconds` like every other feature
+    #[serde(default)]
+    pub social_half_life_seconds: Option<u64>,
+    /// Bucket bounds (seconds) for the scoring duration histogram
+    #[serde(default = "default_scoring_duration_buckets")]
+    pub scoring_duration_buckets: Vec<f64>,
+    /// Skip social data entirely: no social fetch and zero social weight
+    #[serde(default)]
+    pub disable_social: bool,
+    /// Features used for scoring, `None` enables all. Disabled features are
+    /// not computed and carry no weight
+    #[serde(default)]
+    pub enabled_features: Option<HashSet<Feature>>,
+    /// Scores (0.0-1.0) pinned for specific features in place of the computed
+    /// ones, for testing and manual intervention. Disabled features stay off
+    #[serde(default)]
+    pub feature_overrides: HashMap<Feature, f64>,
+    /// Multiplier applied to the score when the metadata URI is unreachable,
+    /// on top of the missing metadata's zero quality score (1.0 disables it)
+    #[serde(default = "default_metadata_unreachable_penalty")]
+    pub metadata_unreachable_penalty: f64,
+    /// Scorings that take longer than this, in milliseconds, are penalized
+    /// and tagged `SlowScoring`; slow inputs usually mean degraded data sources
+    /// (None disables the check)
+    #[serde(default)]
+    pub slow_scoring_threshold_ms: Option<u64>,
+    /// Multiplier applied to the score of a scoring slower than
+    /// `slow_scoring_threshold_ms` (1.0 only flags it)
+    #[serde(default = "default_slow_scoring_penalty")]
+    pub slow_scoring_penalty: f64,
+    /// Attach a `ScoringTrace` to every scored candidate (debugging only)
+    #[serde(default)]
+    pub enable_scoring_trace: bool,
+    /// Score candidates one at a time in arrival order instead of concurrently,
+    /// so output order is deterministic (e.g. for tests)
+    #[serde(default)]
+    pub sequential: bool,
+    /// Holder count below which a token is flagged as `TooFewHolders`
+    #[serde(default = "default_min_holder_count")]
+    pub min_holder_count: usize,
+    /// Implied market cap, as a multiple of pool SOL liquidity, above which a
+    /// token is flagged as `LowLiquidityHighMcap`
+    #[serde(default = "default_max_mcap_liquidity_ratio")]
+    pub max_mcap_liquidity_ratio: f64,
+    /// Buy/sell volume ratio above which a traded token is flagged as
+    /// `PossibleHoneypot`, since next to nobody has managed to sell
+    #[serde(default = "default_honeypot_buy_sell_ratio")]
+    pub honeypot_buy_sell_ratio: f64,
+    /// PnL at or below which a creator's trade counts as a rug, flagging
+    /// later tokens from the same funding source as `RepeatOffenderCluster`
+    #[serde(default = "default_rug_pnl_threshold")]
+    pub rug_pnl_threshold: f64,
+    /// Launches by one creator within `creator_launch_window_seconds` above
+    /// which a token is flagged as `CreatorLaunchSpam`
+    #[serde(default = "default_max_creator_launches")]
+    pub max_creator_launches: u64,
+    /// Window over which a creator's launches are counted
+    #[serde(default = "default_creator_launch_window_seconds")]
+    pub creator_launch_window_seconds: u64,
+    /// How long a creator's launch count and its funding source's rug count
+    /// are reused before the ledger is queried again
+    #[serde(default = "default_creator_stats_ttl_seconds")]
+    pub creator_stats_ttl_seconds: u64,
+    /// Score Pump.fun tokens higher the closer their bonding curve is to
+    /// graduating; when false, freshly launched curves score higher
+    #[serde(default = "default_prefer_near_graduation")]
+    pub prefer_near_graduation: bool,
+    /// Transactions per holder up to which activity looks organic; higher
+    /// ratios score down in proportion, as bots churning a few wallets
+    #[serde(default = "default_max_tx_per_holder")]
+    pub max_tx_per_holder: f64,
+    /// Commitment level used for all data-fetching RPC reads
+    #[serde(default)]
+    pub rpc_commitment: RpcCommitment,
+    /// Candidates scored after startup before the oracle may act
+    #[serde(default = "default_warmup_candidates")]
+    pub warmup_candidates: u64,
+    /// Time after startup before the oracle may act; both warm-up limits must pass
+    #[serde(default = "default_warmup_duration_seconds")]
+    pub warmup_duration_seconds: u64,
+    /// Longest a received candidate may wait for a scoring slot before it is
+    /// skipped as `Stale`, in milliseconds
+    #[serde(default = "default_max_queue_wait_ms")]
+    pub max_queue_wait_ms: u64,
+    /// Consecutive losing trades after which every candidate is skipped until
+    /// the kill switch is reset (None disables it)
+    #[serde(default)]
+    pub kill_switch_consecutive_losses: Option<u32>,
+    /// Only trades decided within this many seconds count toward the
+    /// kill switch's losing streak
+    #[serde(default = "default_kill_switch_lookback_seconds")]
+    pub kill_switch_lookback_seconds: u64,
+    /// Order in which scoring stages run; scoring stops at the first stage
+    /// that rejects the candidate, so cheap filters should come first
+    #[serde(default = "default_scoring_pipeline")]
+    pub scoring_pipeline: Vec<PipelineStage>,
+    /// Mints and creator wallets rejected by the `blocklist` stage
+    #[serde(default)]
+    pub blocklist: HashSet<String>,
+    /// Launchpad programs the `program_filter` stage lets through (empty allows all)
+    #[serde(default)]
+    pub allowed_programs: Vec<String>,
+    /// Pool SOL below which the `min_liquidity` stage rejects a candidate
+    /// (None lets every pool through)
+    #[serde(default)]
+    pub min_liquidity_filter_sol: Option<f64>,
+    /// Whether the `authority_status` stage rejects tokens whose mint or
+    /// freeze authority is still active
+    #[serde(default)]
+    pub reject_active_authorities: bool,
+    /// What to do with a GUI notification when the GUI channel is full
+    #[serde(default)]
+    pub gui_backpressure_policy: GuiBackpressurePolicy,
+    /// How the weighted feature average is mapped to the final score
+    #[serde(default)]
+    pub score_aggregation: ScoreAggregation,
+}
+
+/// Mapping from the weighted feature average (0-1) to the final score.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScoreAggregation {
+    /// The weighted average itself
+    #[default]
+    Linear,
+    /// `1 / (1 + e^(-steepness · (x - midpoint)))`, which pushes averages away
+    /// from `midpoint` toward 0 or 1
+    Logistic { steepness: f64, midpoint: f64 },
+}
+
+impl ScoreAggregation {
+    /// Map a weighted feature average in 0-1 to a score fraction in 0-1.
+    pub fn apply(self, weighted_average: f64) -> f64 {
+        match self {
+            ScoreAggregation::Linear => weighted_average,
+            ScoreAggregation::Logistic { steepness, midpoint } => {
+                1.0 / (1.0 + (-steepness * (weighted_average - midpoint)).exp())
+            }
+        }
+    }
+}
+
+/// Behavior of GUI notifications when the GUI channel is full.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GuiBackpressurePolicy {
+    /// Wait for the GUI to catch up, stalling the scoring task
+    #[default]
+    Block,
+    /// Park notifications that don't fit and discard the oldest parked one
+    /// once more than a channel's worth is waiting
+    DropOldest,
+    /// Discard the new notification
+    DropNew,
+}
+
+/// Commitment level requested from RPC nodes when reading token state.
+///
+/// `Processed` sees fresh launches soonest but may observe rolled-back slots;
+/// `Finalized` is safest but lags by ~30 slots.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RpcCommitment {
+    Processed,
+    #[default]
+    Confirmed,
+    Finalized,
+}
+
+impl RpcCommitment {
+    /// Map to the Solana client's commitment configuration.
+    pub fn to_commitment_config(self) -> CommitmentConfig {
+        match self {
+            RpcCommitment::Processed => CommitmentConfig::processed(),
+            RpcCommitment::Confirmed => CommitmentConfig::confirmed(),
+            RpcCommitment::Finalized => CommitmentConfig::finalized(),
+        }
+    }
+}
+
+/// Backoff schedule for retried requests.
+///
+/// The delay before retry `n` (1-based) is `base_ms * 2^(n-1)`, capped at
+/// `max_delay_ms`, then varied by up to `±jitter` of itself.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetryPolicy {
+    /// Delay before the first retry, in milliseconds
+    pub base_ms: u64,
+    /// Longest delay between attempts, in milliseconds
+    pub max_delay_ms: u64,
+    /// Total attempts, including the first
+    pub max_attempts: usize,
+    /// Fraction each delay may randomly deviate by, in `[0, 1]`
+    #[serde(default)]
+    pub jitter: f64,
+}
+
+impl RetryPolicy {
+    /// Delays to wait before each retry; yields `max_attempts - 1` delays.
+    pub fn delays(&self) -> impl Iterator<Item = Duration> {
+        let policy = self.clone();
+        let jitter = policy.jitter.clamp(0.0, 1.0);
+        (0..policy.max_attempts.saturating_sub(1) as u32).map(move |retry| {
+            let delay_ms = policy.base_ms
+                .saturating_mul(2u64.saturating_pow(retry))
+                .min(policy.max_delay_ms);
+            let factor = if jitter > 0.0 {
+                1.0 + rand::thread_rng().gen_range(-jitter..=jitter)
+            } else {
+                1.0
+            };
+            Duration::from_millis(delay_ms).mul_f64(factor)
+        })
+    }
+}
+
+/// Default retry policy for RPC token data fetches.
+pub fn default_rpc_retry() -> RetryPolicy {
+    RetryPolicy { base_ms: 100, max_delay_ms: 5_000, max_attempts: 4, jitter: 0.0 }
+}
+
+/// Default retry policy for external HTTP APIs.
+pub fn default_api_retry() -> RetryPolicy {
+    RetryPolicy { base_ms: 500, max_delay_ms: 3_000, max_attempts: 4, jitter: 0.0 }
+}
+
+/// Default endpoint success sample size.
+pub fn default_endpoint_success_sample_size() -> usize {
+    50
+}
+
+/// Default number of cooldowns before an endpoint is evicted.
+pub fn default_circuit_breaker_max_cooldowns_before_eviction() -> u32 {
+    crate::oracle::circuit_breaker::DEFAULT_MAX_COOLDOWNS_BEFORE_EVICTION
+}
+
+/// Default score webhook timeout.
+pub fn default_score_webhook_timeout_ms() -> u64 {
+    crate::oracle::sink::DEFAULT_WEBHOOK_TIMEOUT.as_millis() as u64
+}
+
+/// Default time an endpoint must stay healthy for its cooldowns to reset.
+pub fn default_circuit_breaker_cooldown_reset_seconds() -> u64 {
+    crate::oracle::circuit_breaker::DEFAULT_COOLDOWN_RESET_SECONDS
+}
+
+/// Default endpoint success rate window.
+pub fn default_endpoint_success_window() -> usize {
+    crate::oracle::circuit_breaker::DEFAULT_SUCCESS_WINDOW
+}
+
+/// Default adaptive rate limiter error window.
+pub fn default_adaptive_error_rate_window() -> usize {
+    100
+}
+
+/// Default minimum sample size for adaptive weight adjustments.
+pub fn default_min_samples_for_adjustment() -> usize {
+    crate::oracle::weights::DEFAULT_MIN_SAMPLES_FOR_ADJUSTMENT
+}
+
+/// Default lower bound for adjusted feature weights.
+pub fn default_weight_floor() -> f64 {
+    crate::oracle::weights::DEFAULT_WEIGHT_FLOOR
+}
+
+/// Default jitter of the adaptive recalculation interval.
+pub fn default_adaptive_recalc_jitter() -> f64 {
+    crate::oracle::weights::DEFAULT_RECALC_JITTER
+}
+
+/// Default exploration rate for dormant feature weights.
+pub fn default_weight_exploration_rate() -> f64 {
+    crate::oracle::weights::DEFAULT_EXPLORATION_RATE
+}
+
+/// Default decay of older observations in feature performance tracking.
+pub fn default_weight_performance_decay() -> f64 {
+    crate::oracle::weights::DEFAULT_PERFORMANCE_DECAY
+}
+
+/// Default time-decay half-life in feature performance tracking (none).
+pub fn default_weight_decay_half_life_seconds() -> u64 {
+    crate::oracle::weights::DEFAULT_DECAY_HALF_LIFE_SECONDS
+}
+
+/// Default per-endpoint concurrency ceiling.
+pub fn default_max_requests_per_endpoint() -> usize {
+    4
+}
+
+/// Default minimum holder count before the anomaly detector complains.
+pub fn default_min_holder_count() -> usize {
+    10
+}
+
+/// Default market cap to liquidity ratio before the anomaly detector complains.
+pub fn default_max_mcap_liquidity_ratio() -> f64 {
+    crate::oracle::anomaly::DEFAULT_MAX_MCAP_LIQUIDITY_RATIO
+}
+
+/// Default buy/sell ratio before the anomaly detector suspects a honeypot.
+pub fn default_honeypot_buy_sell_ratio() -> f64 {
+    crate::oracle::anomaly::DEFAULT_HONEYPOT_BUY_SELL_RATIO
+}
+
+/// Default PnL at or below which a losing trade counts as a rug.
+pub fn default_rug_pnl_threshold() -> f64 {
+    crate::oracle::anomaly::DEFAULT_RUG_PNL_THRESHOLD
+}
+
+/// Default number of recent launches a creator may have before being flagged.
+pub fn default_max_creator_launches() -> u64 {
+    crate::oracle::anomaly::DEFAULT_MAX_CREATOR_LAUNCHES
+}
+
+/// Default window for counting a creator's recent launches.
+pub fn default_creator_launch_window_seconds() -> u64 {
+    crate::oracle::anomaly::DEFAULT_CREATOR_LAUNCH_WINDOW_SECONDS
+}
+
+/// Default lifetime of cached creator and funding source counts.
+pub fn default_creator_stats_ttl_seconds() -> u64 {
+    crate::oracle::anomaly::DEFAULT_CREATOR_STATS_TTL_SECONDS
+}
+
+/// By default, tokens close to graduation score higher.
+pub fn default_prefer_near_graduation() -> bool {
+    crate::oracle::features::DEFAULT_PREFER_NEAR_GRADUATION
+}
+
+/// Default transactions per holder before activity looks like bot churn.
+pub fn default_max_tx_per_holder() -> f64 {
+    crate::oracle::features::DEFAULT_MAX_TX_PER_HOLDER
+}
+
+/// By default every stage runs, cheap filters first.
+pub fn default_scoring_pipeline() -> Vec<PipelineStage> {
+    crate::oracle::pipeline::DEFAULT_PIPELINE.to_vec()
+}
+
+/// Default number of warm-up candidates.
+pub fn default_warmup_candidates() -> u64 {
+    50
+}
+
+/// Default warm-up duration.
+pub fn default_warmup_duration_seconds() -> u64 {
+    60
+}
+
+/// Default window of trades read by the kill switch.
+pub fn default_kill_switch_lookback_seconds() -> u64 {
+    crate::oracle::kill_switch::DEFAULT_LOOKBACK_SECONDS
+}
+
+/// Default longest wait for a scoring slot.
+pub fn default_max_queue_wait_ms() -> u64 {
+    2_000
+}
+
+/// Default maximum acceptable input data age.
+pub fn default_max_data_age_seconds() -> u64 {
+    600
+}
+
+/// Default score multiplier for an unreachable metadata URI.
+pub fn default_metadata_unreachable_penalty() -> f64 {
+    crate::oracle::scorer::DEFAULT_METADATA_UNREACHABLE_PENALTY
+}
+
+/// Default score multiplier for an abnormally slow scoring.
+pub fn default_slow_scoring_penalty() -> f64 {
+    crate::oracle::scorer::DEFAULT_SLOW_SCORING_PENALTY
+}
+
+/// Default scoring duration histogram buckets.
+pub fn default_scoring_duration_buckets() -> Vec<f64> {
+    crate::oracle::metrics::DEFAULT_SCORING_DURATION_BUCKETS.to_vec()
 }
 
 impl Default for OracleConfig {
@@ -283,30 +1565,47 @@ impl Default for OracleConfig {
         // Default parameters for each regime - these would be optimized based on historical data
         let low_activity = RegimeSpecificParameters {
             weights: FeatureWeights {
-                liquidity: 0.25,      // Higher weight on liquidity in low activity
-                holder_distribution: 0.20,
-                volume_growth: 0.10,  // Lower weight as volume is low
-                holder_growth: 0.15,
-                price_change: 0.05,   // Less important in low activity
-                jito_bundle_presence: 0.05,
-                creator_sell_speed: 0.10,
-                metadata_quality: 0.10,
+                liquidity: 0.22,      // Higher weight on liquidity in low activity
+                holder_distribution: 0.18,
+                volume_growth: 0.08,  // Lower weight as volume is low
+                holder_growth: 0.12,
+                price_change: 0.04,   // Less important in low activity
+                jito_bundle_presence: 0.04,
+                creator_sell_speed: 0.06,
+                metadata_quality: 0.08,
                 social_activity: 0.00, // Almost irrelevant in low activity
+                authority_status: 0.08,
+                contract_verified: 0.02,
+                graduation_proximity: 0.04,
+                tx_per_holder: 0.04,
             },
             thresholds: ScoreThresholds::default(),
+            // Few launches compete for block space, so skipping a bundle costs little
+            jito_bundle_scores: JitoBundleScores {
+                bundled: 0.6,
+                not_bundled: 0.5,
+                unknown: 0.5,
+            },
+            buy_score_threshold: default_buy_score_threshold(),
+            max_score: default_max_score(),
+            rate_limit_requests_per_second: None,
         };
 
         let bullish = RegimeSpecificParameters {
             weights: FeatureWeights {
-                liquidity: 0.15,
-                holder_distribution: 0.10,
-                volume_growth: 0.25,  // High weight on volume in bull market
-                holder_growth: 0.20,  // High weight on holder growth
-                price_change: 0.15,   // Price momentum important
-                jito_bundle_presence: 0.05,
-                creator_sell_speed: 0.05, // Less concern about creator selling in bull market
-                metadata_quality: 0.05,
+                liquidity: 0.13,
+                holder_distribution: 0.09,
+                volume_growth: 0.23,  // High weight on volume in bull market
+                holder_growth: 0.18,  // High weight on holder growth
+                price_change: 0.13,   // Price momentum important
+                jito_bundle_presence: 0.04,
+                creator_sell_speed: 0.02, // Less concern about creator selling in bull market
+                metadata_quality: 0.04,
                 social_activity: 0.00,
+                authority_status: 0.04,
+                contract_verified: 0.02,
+                graduation_proximity: 0.04,
+                tx_per_holder: 0.04,
             },
             thresholds: ScoreThresholds {
                 min_liquidity_sol: 5.0, // Lower requirement in bull market
@@ -317,19 +1616,27 @@ impl Default for OracleConfig {
                 creator_sell_penalty_threshold: 500, // Higher tolerance
                 social_activity_threshold: 50.0,
             },
+            jito_bundle_scores: JitoBundleScores::default(),
+            buy_score_threshold: 70, // Momentum carries marginal tokens
+            max_score: default_max_score(),
+            rate_limit_requests_per_second: None,
         };
 
         let bearish = RegimeSpecificParameters {
             weights: FeatureWeights {
-                liquidity: 0.30,      // Very high weight on liquidity in bear market
-                holder_distribution: 0.25, // Important to avoid whale dumps
-                volume_growth: 0.05,  // Volume growth rare in bear market
-                holder_growth: 0.05,
+                liquidity: 0.24,      // Very high weight on liquidity in bear market
+                holder_distribution: 0.20, // Important to avoid whale dumps
+                volume_growth: 0.04,  // Volume growth rare in bear market
+                holder_growth: 0.04,
                 price_change: 0.00,   // Price changes often negative
-                jito_bundle_presence: 0.05,
-                creator_sell_speed: 0.20, // Very important - avoid fast selling creators
-                metadata_quality: 0.10,
+                jito_bundle_presence: 0.04,
+                creator_sell_speed: 0.14, // Very important - avoid fast selling creators
+                metadata_quality: 0.08,
                 social_activity: 0.00,
+                authority_status: 0.12, // Rug risk dominates in bear market
+                contract_verified: 0.02,
+                graduation_proximity: 0.04,
+                tx_per_holder: 0.04,
             },
             thresholds: ScoreThresholds {
                 min_liquidity_sol: 20.0, // Higher requirement in bear market
@@ -340,34 +1647,50 @@ impl Default for OracleConfig {
                 creator_sell_penalty_threshold: 150, // Lower tolerance
                 social_activity_threshold: 200.0,
             },
+            jito_bundle_scores: JitoBundleScores::default(),
+            buy_score_threshold: 85, // Only the strongest candidates survive a bear market
+            max_score: 90, // Never express full confidence in a bear market
+            rate_limit_requests_per_second: None,
         };
 
         let choppy = RegimeSpecificParameters {
             weights: FeatureWeights {
-                liquidity: 0.20,
-                holder_distribution: 0.15,
-                volume_growth: 0.15,
-                holder_growth: 0.15,
+                liquidity: 0.18,
+                holder_distribution: 0.13,
+                volume_growth: 0.12,
+                holder_growth: 0.12,
                 price_change: 0.00,   // Price changes unreliable in choppy market
-                jito_bundle_presence: 0.10, // More important for execution timing
-                creator_sell_speed: 0.15,
-                metadata_quality: 0.10,
+                jito_bundle_presence: 0.08, // More important for execution timing
+                creator_sell_speed: 0.11,
+                metadata_quality: 0.08,
                 social_activity: 0.00,
+                authority_status: 0.08,
+                contract_verified: 0.02,
+                graduation_proximity: 0.04,
+                tx_per_holder: 0.04,
             },
             thresholds: ScoreThresholds::default(),
+            jito_bundle_scores: JitoBundleScores::default(),
+            buy_score_threshold: 80, // Noisy signals need a wider margin
+            max_score: default_max_score(),
+            rate_limit_requests_per_second: None,
         };
 
         let high_congestion = RegimeSpecificParameters {
             weights: FeatureWeights {
-                liquidity: 0.15,
-                holder_distribution: 0.10,
-                volume_growth: 0.10,
-                holder_growth: 0.10,
-                price_change: 0.10,
-                jito_bundle_presence: 0.35, // Very high weight on Jito bundles for execution
-                creator_sell_speed: 0.05,
-                metadata_quality: 0.05,
+                liquidity: 0.13,
+                holder_distribution: 0.08,
+                volume_growth: 0.08,
+                holder_growth: 0.08,
+                price_change: 0.08,
+                jito_bundle_presence: 0.31, // Very high weight on Jito bundles for execution
+                creator_sell_speed: 0.02,
+                metadata_quality: 0.04,
                 social_activity: 0.00,
+                authority_status: 0.08,
+                contract_verified: 0.02,
+                graduation_proximity: 0.04,
+                tx_per_holder: 0.04,
             },
             thresholds: ScoreThresholds {
                 min_liquidity_sol: 15.0,
@@ -378,6 +1701,15 @@ impl Default for OracleConfig {
                 creator_sell_penalty_threshold: 300,
                 social_activity_threshold: 100.0,
             },
+            // Unbundled transactions are likely to land late or fail
+            jito_bundle_scores: JitoBundleScores {
+                bundled: 1.0,
+                not_bundled: 0.1,
+                unknown: 0.4,
+            },
+            buy_score_threshold: 80, // Failed transactions eat into the edge
+            max_score: default_max_score(),
+            rate_limit_requests_per_second: Some(10), // Retries only add to the congestion
         };
 
         regime_parameters.insert(MarketRegime::LowActivity, low_activity);
@@ -387,20 +1719,285 @@ impl Default for OracleConfig {
         regime_parameters.insert(MarketRegime::HighCongestion, high_congestion);
 
         Self {
+            weights: FeatureWeights::default(),
+            thresholds: ScoreThresholds::default(),
             rpc_endpoints: vec!["https://api.mainnet-beta.solana.com".to_string()],
             pump_fun_api_key: None,
             bitquery_api_key: None,
-            rpc_retry_attempts: 3,
+            rpc_retry: default_rpc_retry(),
+            api_retry: default_api_retry(),
             rpc_timeout_seconds: 10,
             cache_ttl_seconds: 300,
             max_parallel_requests: 10,
+            max_requests_per_endpoint: default_max_requests_per_endpoint(),
             rate_limit_requests_per_second: 20,
             notify_threshold: 75,
+            metrics_http_listen: None,
+            score_log_path: None,
+            score_webhook_url: None,
+            score_webhook_timeout_ms: default_score_webhook_timeout_ms(),
             regime_parameters,
             adaptive_recalc_interval: 100,
+            adaptive_recalc_jitter: default_adaptive_recalc_jitter(),
+            min_samples_for_adjustment: default_min_samples_for_adjustment(),
+            weight_floor: default_weight_floor(),
+            weight_exploration_rate: default_weight_exploration_rate(),
+            feature_adaptation_rates: HashMap::new(),
+            weight_performance_decay: default_weight_performance_decay(),
+            weight_decay_half_life_seconds: default_weight_decay_half_life_seconds(),
             circuit_breaker_failure_threshold: 5,
             circuit_breaker_cooldown_seconds: 60,
+            circuit_breaker_max_cooldowns_before_eviction: default_circuit_breaker_max_cooldowns_before_eviction(),
+            circuit_breaker_cooldown_reset_seconds: default_circuit_breaker_cooldown_reset_seconds(),
+            endpoint_success_sample_size: default_endpoint_success_sample_size(),
+            endpoint_success_window: default_endpoint_success_window(),
+            adaptive_error_rate_window: default_adaptive_error_rate_window(),
             max_cache_entries: 1000,
+            max_data_age_seconds: default_max_data_age_seconds(),
+            social_half_life_seconds: None,
+            scoring_duration_buckets: default_scoring_duration_buckets(),
+            disable_social: false,
+            enabled_features: None,
+            feature_overrides: HashMap::new(),
+            metadata_unreachable_penalty: default_metadata_unreachable_penalty(),
+            slow_scoring_threshold_ms: None,
+            slow_scoring_penalty: default_slow_scoring_penalty(),
+            enable_scoring_trace: false,
+            sequential: false,
+            min_holder_count: default_min_holder_count(),
+            max_mcap_liquidity_ratio: default_max_mcap_liquidity_ratio(),
+            honeypot_buy_sell_ratio: default_honeypot_buy_sell_ratio(),
+            rug_pnl_threshold: default_rug_pnl_threshold(),
+            max_creator_launches: default_max_creator_launches(),
+            creator_launch_window_seconds: default_creator_launch_window_seconds(),
+            creator_stats_ttl_seconds: default_creator_stats_ttl_seconds(),
+            prefer_near_graduation: default_prefer_near_graduation(),
+            max_tx_per_holder: default_max_tx_per_holder(),
+            rpc_commitment: RpcCommitment::default(),
+            warmup_candidates: default_warmup_candidates(),
+            warmup_duration_seconds: default_warmup_duration_seconds(),
+            max_queue_wait_ms: default_max_queue_wait_ms(),
+            kill_switch_consecutive_losses: None,
+            kill_switch_lookback_seconds: default_kill_switch_lookback_seconds(),
+            scoring_pipeline: default_scoring_pipeline(),
+            blocklist: HashSet::new(),
+            allowed_programs: Vec::new(),
+            min_liquidity_filter_sol: None,
+            reject_active_authorities: false,
+            gui_backpressure_policy: GuiBackpressurePolicy::default(),
+            score_aggregation: ScoreAggregation::default(),
+        }
+    }
+}
+
+impl OracleConfig {
+    /// Whether `feature` takes part in scoring under `enabled_features` and
+    /// `disable_social`.
+    pub fn is_feature_enabled(&self, feature: Feature) -> bool {
+        if feature == Feature::SocialActivity && self.disable_social {
+            return false;
+        }
+        self.enabled_features
+            .as_ref()
+            .is_none_or(|enabled| enabled.contains(&feature))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oracle::test_support::RecordBuilder;
+
+    #[test]
+    fn test_score_rejects_values_above_100() {
+        assert_eq!(Score::new(100).unwrap().value(), 100);
+        assert!(Score::new(101).is_err());
+        assert!(Score::new(u8::MAX).is_err());
+    }
+
+    #[test]
+    fn test_score_clamped_saturates() {
+        assert_eq!(Score::clamped(42).value(), 42);
+        assert_eq!(Score::clamped(101).value(), 100);
+        assert_eq!(Score::clamped(u8::MAX).value(), 100);
+        assert_eq!(Score::clamped(-5).value(), 0);
+        assert_eq!(Score::clamped(i64::MAX).value(), 100);
+    }
+
+    #[test]
+    fn test_score_serde_round_trip() {
+        let json = serde_json::to_string(&Score::clamped(87)).unwrap();
+        assert_eq!(json, "87");
+        assert_eq!(serde_json::from_str::<Score>(&json).unwrap(), Score::clamped(87));
+        assert!(serde_json::from_str::<Score>("150").is_err());
+    }
+
+    #[test]
+    fn test_outcome_update_from_legacy_tuple() {
+        let legacy: LegacyOutcomeUpdate = (
+            "sig".to_string(),
+            Outcome::Loss(-0.2),
+            Some(0.01),
+            Some(0.008),
+            Some(1.0),
+            Some(0.8),
+            Some(42),
+            true,
+        );
+
+        let update = OutcomeUpdate::from(legacy);
+        assert_eq!(update.signature, "sig");
+        assert_eq!(update.outcome, Outcome::Loss(-0.2));
+        assert_eq!(update.buy_price_sol, Some(0.01));
+        assert_eq!(update.sell_price_sol, Some(0.008));
+        assert_eq!(update.initial_sol_spent, Some(1.0));
+        assert_eq!(update.final_sol_received, Some(0.8));
+        assert_eq!(update.timestamp_evaluated, Some(42));
+        assert!(update.is_verified);
+    }
+
+    fn create_scored(score: u8, feature_scores: &[(&str, f64)]) -> ScoredCandidate {
+        RecordBuilder::new("DiffMint")
+            .decided_at(1000)
+            .score(score)
+            .feature_scores(feature_scores.iter().map(|(name, value)| (name.to_string(), *value)).collect())
+            .candidate()
+    }
+
+    #[test]
+    fn test_scored_candidate_diff() {
+        let before = create_scored(80, &[("liquidity", 0.5), ("social_activity", 0.25), ("volume_growth", 0.4)]);
+        let mut after = create_scored(62, &[("liquidity", 0.75), ("social_activity", 0.25), ("holder_growth", 0.5)]);
+        after.anomaly_detected = true;
+        after.reason_codes = vec![ReasonCode::MetadataUnreachable];
+
+        let diff = before.diff(&after);
+        assert_eq!(diff.score_delta, -18);
+        assert_eq!(diff.feature_deltas, HashMap::from([
+            ("liquidity".to_string(), 0.25),
+            ("volume_growth".to_string(), -0.4),
+            ("holder_growth".to_string(), 0.5),
+        ]));
+        assert_eq!(diff.reason_codes_added, vec![ReasonCode::MetadataUnreachable]);
+        assert!(diff.reason_codes_removed.is_empty());
+        assert_eq!(diff.anomaly_change, Some(true));
+        assert!(!diff.is_empty());
+
+        let reverse = after.diff(&before);
+        assert_eq!(reverse.score_delta, 18);
+        assert_eq!(reverse.reason_codes_removed, vec![ReasonCode::MetadataUnreachable]);
+        assert_eq!(reverse.anomaly_change, Some(false));
+
+        assert!(before.diff(&before.clone()).is_empty());
+    }
+
+    #[test]
+    fn test_default_feature_weights_are_valid() {
+        assert_eq!(FeatureWeights::default().validate(), Ok(()));
+        for (regime, params) in &OracleConfig::default().regime_parameters {
+            assert_eq!(params.weights.validate(), Ok(()), "{:?} weights", regime);
+        }
+    }
+
+    #[test]
+    fn test_feature_weights_validate_rejects_nan() {
+        let mut weights = FeatureWeights::default();
+        assert_eq!(weights.validate(), Ok(()));
+
+        weights.social_activity = f64::NAN;
+        assert!(matches!(
+            weights.validate(),
+            Err(WeightError::NonFinite { feature: Feature::SocialActivity, .. })
+        ));
+    }
+
+    #[test]
+    fn test_feature_weights_validate_rejects_negative() {
+        let weights = FeatureWeights { liquidity: -0.1, ..FeatureWeights::default() };
+        assert_eq!(
+            weights.validate(),
+            Err(WeightError::Negative { feature: Feature::Liquidity, weight: -0.1 })
+        );
+    }
+
+    #[test]
+    fn test_feature_weights_normalize_fixes_sum() {
+        let mut weights = FeatureWeights::default();
+        weights.liquidity += 0.2;
+        let liquidity_share = weights.liquidity / 1.2;
+        assert!((weights.sum() - 1.2).abs() < 1e-9);
+        assert!(matches!(weights.validate(), Err(WeightError::BadSum { .. })));
+
+        weights.normalize();
+        assert!((weights.sum() - 1.0).abs() < 1e-9);
+        assert!((weights.liquidity - liquidity_share).abs() < 1e-9);
+        assert_eq!(weights.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_retry_policy_backoff_schedule() {
+        let policy = RetryPolicy { base_ms: 100, max_delay_ms: 1_000, max_attempts: 6, jitter: 0.0 };
+        let delays: Vec<u64> = policy.delays().map(|d| d.as_millis() as u64).collect();
+        assert_eq!(delays, vec![100, 200, 400, 800, 1_000]);
+
+        let single = RetryPolicy { max_attempts: 1, ..policy.clone() };
+        assert_eq!(single.delays().count(), 0);
+
+        let jittered = RetryPolicy { jitter: 0.5, ..policy };
+        for (delay, nominal) in jittered.delays().zip([100, 200, 400, 800, 1_000]) {
+            let delay = delay.as_secs_f64() * 1000.0;
+            assert!(delay >= nominal as f64 * 0.5 && delay <= nominal as f64 * 1.5, "{} vs {}", delay, nominal);
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_market_context_snapshot_round_trips_through_map() {
+        for regime in MarketRegime::ALL {
+            let snapshot = MarketContextSnapshot {
+                sol_price: 152.5,
+                volatility: 3.2,
+                network_tps: 2400.0,
+                dex_volume: 48_000_000.0,
+                regime,
+            };
+            let map: HashMap<String, f64> = snapshot.into();
+            assert_eq!(map[MarketContextSnapshot::SOL_PRICE], 152.5);
+            assert_eq!(MarketContextSnapshot::from_map(&map), Some(snapshot));
+        }
+
+        // Records written before snapshots were populated have no context
+        assert_eq!(MarketContextSnapshot::from_map(&HashMap::new()), None);
+
+        let mut map = MarketContextSnapshot::default().to_map();
+        map.insert(MarketContextSnapshot::REGIME.to_string(), 9.0);
+        assert_eq!(MarketContextSnapshot::from_map(&map), None);
+    }
+
+    #[test]
+    fn test_feature_scores_round_trip_with_version_tag() {
+        let scores = HashMap::from([("liquidity".to_string(), 0.8), ("social_activity".to_string(), 0.25)]);
+        let json = serialize_feature_scores(&scores).unwrap();
+
+        let tagged: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(tagged["version"], FEATURE_SCORES_VERSION);
+        assert_eq!(deserialize_feature_scores(&json).unwrap(), scores);
+    }
+
+    #[test]
+    fn test_unversioned_feature_scores_are_migrated_to_current_names() {
+        // Written before the version tag, with the legacy selector's name for social activity
+        let old_json = r#"{"liquidity": 0.8, "social": 0.25}"#;
+
+        let scores = deserialize_feature_scores(old_json).unwrap();
+
+        assert_eq!(scores, HashMap::from([("liquidity".to_string(), 0.8), ("social_activity".to_string(), 0.25)]));
+        assert!(scores.keys().all(|name| Feature::all().iter().any(|f| f.as_str() == name)));
+
+        // Current versions keep their names as written
+        let current = format!(r#"{{"version": {}, "scores": {{"social": 0.25}}}}"#, FEATURE_SCORES_VERSION);
+        assert_eq!(deserialize_feature_scores(&current).unwrap(), HashMap::from([("social".to_string(), 0.25)]));
+
+        let future = format!(r#"{{"version": {}, "scores": {{}}}}"#, FEATURE_SCORES_VERSION + 1);
+        assert!(deserialize_feature_scores(&future).is_err());
+    }
+}
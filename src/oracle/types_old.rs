@@ -1,9 +1,8 @@
 //! Core types and data structures for the Oracle system.
 
-use crate::types::{PremintCandidate, QuantumCandidateGui, Pubkey};
+use crate::types::{PremintCandidate, Pubkey};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, VecDeque};
-use std::time::Instant;
 
 /// Feature types supported by the Oracle scoring system.
 /// Each feature represents a different aspect of token evaluation.
@@ -27,6 +26,14 @@ pub enum Feature {
     MetadataQuality,
     /// Social media activity
     SocialActivity,
+    /// Whether mint/freeze authorities have been revoked
+    AuthorityStatus,
+    /// Whether the launchpad marks the contract as verified
+    ContractVerified,
+    /// How close a Pump.fun bonding curve is to graduating
+    GraduationProximity,
+    /// Transactions per holder, low when bots churn a few wallets
+    TxPerHolder,
 }
 
 impl Feature {
@@ -42,6 +49,10 @@ impl Feature {
             Feature::CreatorSellSpeed => "creator_sell_speed",
             Feature::MetadataQuality => "metadata_quality",
             Feature::SocialActivity => "social_activity",
+            Feature::AuthorityStatus => "authority_status",
+            Feature::ContractVerified => "contract_verified",
+            Feature::GraduationProximity => "graduation_proximity",
+            Feature::TxPerHolder => "tx_per_holder",
         }
     }
 
@@ -57,6 +68,10 @@ impl Feature {
             Feature::CreatorSellSpeed,
             Feature::MetadataQuality,
             Feature::SocialActivity,
+            Feature::AuthorityStatus,
+            Feature::ContractVerified,
+            Feature::GraduationProximity,
+            Feature::TxPerHolder,
         ]
     }
 }
@@ -177,6 +192,20 @@ pub struct TokenData {
     pub holder_history: VecDeque<usize>,
     pub price_history: VecDeque<f64>,
     pub social_activity: SocialActivity,
+    /// Whether the mint authority is still set (`None` if unknown)
+    pub mint_authority_active: Option<bool>,
+    /// Whether the freeze authority is still set (`None` if unknown)
+    pub freeze_authority_active: Option<bool>,
+    /// Whether the launchpad marks the contract as verified (`None` if unknown)
+    pub contract_verified: Option<bool>,
+    /// Whether the metadata URI was set but could not be fetched
+    pub metadata_unreachable: bool,
+    /// Wallet that funded the creator, if known (from the candidate)
+    pub funding_source: Option<String>,
+    /// Wallet that created the token, if known (from the candidate)
+    pub creator: Option<String>,
+    /// Unix timestamp (ms) at which each data source was fetched, keyed by source name
+    pub data_timestamps: HashMap<String, u64>,
 }
 
 /// Token metadata structure.
@@ -200,6 +229,7 @@ pub struct Attribute {
 #[derive(Debug, Clone)]
 pub struct HolderData {
     pub address: Pubkey,
+    /// Share of supply held, as a fraction in `[0, 1]`
     pub percentage: f64,
     pub is_whale: bool,
 }
@@ -211,6 +241,8 @@ pub struct LiquidityPool {
     pub token_amount: f64,
     pub pool_address: Pubkey,
     pub pool_type: PoolType,
+    /// Fraction of a Pump.fun bonding curve sold (1.0 = graduated); `None` for other pools
+    pub bonding_curve_progress: Option<f64>,
 }
 
 /// Pool type enumeration.
@@ -230,10 +262,13 @@ pub struct VolumeData {
     pub volume_growth_rate: f64,
     pub transaction_count: u32,
     pub buy_sell_ratio: f64,
+    /// Volume samples, oldest first. When it holds at least two samples the
+    /// growth score uses their weighted trend instead of `volume_growth_rate`.
+    pub volume_history: VecDeque<f64>,
 }
 
 /// Creator holdings and sell activity.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct CreatorHoldings {
     pub initial_balance: u64,
     pub current_balance: u64,
@@ -242,7 +277,7 @@ pub struct CreatorHoldings {
 }
 
 /// Social activity metrics.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct SocialActivity {
     pub twitter_mentions: u32,
     pub telegram_members: u32,
@@ -253,13 +288,13 @@ pub struct SocialActivity {
 /// Feature scores container using the Feature enum internally.
 #[derive(Debug, Clone)]
 pub struct FeatureScores {
-    scores: [f64; 9], // Fixed array for performance
+    scores: [f64; 13], // Fixed array for performance
 }
 
 impl FeatureScores {
     /// Create new empty feature scores.
     pub fn new() -> Self {
-        Self { scores: [0.0; 9] }
+        Self { scores: [0.0; 13] }
     }
 
     /// Set score for a feature.
@@ -359,7 +394,7 @@ impl Default for OracleConfig {
 // --- New Types for DecisionLedger (I Filar Implementation) ---
 
 /// Represents the final financial outcome of a transaction.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub enum Outcome {
     /// Profit in SOL
     Profit(f64),
@@ -372,15 +407,10 @@ pub enum Outcome {
     /// Transaction failed during execution (e.g., timeout, reverted)
     FailedExecution,
     /// Candidate scored but transaction never sent
+    #[default]
     NotExecuted,
 }
 
-impl Default for Outcome {
-    fn default() -> Self {
-        Outcome::NotExecuted
-    }
-}
-
 /// Complete record of a PredictiveOracle decision and its transactional outcome.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransactionRecord {
@@ -3,11 +3,37 @@
 //! This module implements an adaptive weighting system that can adjust feature
 //! weights based on historical performance and market conditions.
 
-use crate::oracle::types::{FeatureWeights, ScoredCandidate, Feature};
+use crate::oracle::decay::{exponential_weight, half_life_for_factor};
+use crate::oracle::types::{FeatureWeights, ScoredCandidate, Feature, Outcome, TransactionRecord};
+use anyhow::{anyhow, Result};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::time::{SystemTime, UNIX_EPOCH};
 use tracing::{debug, info, instrument};
 
+/// Default number of historical scores required before adjustments are applied.
+pub const DEFAULT_MIN_SAMPLES_FOR_ADJUSTMENT: usize = 50;
+
+/// Default lower bound for any effective feature weight.
+pub const DEFAULT_WEIGHT_FLOOR: f64 = 0.01;
+
+/// Default relative increase applied to dormant feature weights on each recalculation.
+pub const DEFAULT_EXPLORATION_RATE: f64 = 0.05;
+
+/// Default weight retained per newer observation when tracking feature performance.
+pub const DEFAULT_PERFORMANCE_DECAY: f64 = 0.99;
+
+/// Default half-life, in seconds, of the time decay of feature performance
+/// observations; 0 disables time decay.
+pub const DEFAULT_DECAY_HALF_LIFE_SECONDS: u64 = 0;
+
+/// Default jitter applied to the adaptive recalculation interval (none).
+pub const DEFAULT_RECALC_JITTER: f64 = 0.0;
+
+/// Lower bound of a single feature's adjustment.
+const MIN_ADJUSTMENT: f64 = -0.5;
+
 /// Adaptive weights manager that wraps static weights with dynamic adjustments.
 pub struct AdaptiveWeights {
     /// Base feature weights from configuration
@@ -22,15 +48,39 @@ pub struct AdaptiveWeights {
     recalculation_count: u64,
     /// Adaptation rate (how quickly to adjust weights)
     adaptation_rate: f64,
+    /// Per-feature adaptation rates overriding `adaptation_rate`
+    feature_adaptation_rates: HashMap<Feature, f64>,
+    /// Minimum history size before adjustments are applied
+    min_samples_for_adjustment: usize,
+    /// Lower bound for effective weights
+    weight_floor: f64,
+    /// Relative weight increase given to dormant features after each recalculation
+    exploration_rate: f64,
+    /// Weight an observation keeps for every newer observation in the history
+    performance_decay: f64,
+    /// Seconds after which an observation's weight halves, by its age relative
+    /// to the newest observation; 0 disables time decay
+    decay_half_life_seconds: u64,
+}
+
+/// Learned state of `AdaptiveWeights`, as saved across restarts.
+///
+/// Features are keyed by their `Feature::as_str` names.
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedState {
+    weight_adjustments: HashMap<String, f64>,
+    feature_performance: HashMap<String, FeaturePerformance>,
+    recalculation_count: u64,
+    last_recalculation: u64,
 }
 
 /// Performance tracking for individual features.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct FeaturePerformance {
-    /// Rolling window of feature scores for successful predictions (score >= 80)
-    successful_scores: Vec<f64>,
-    /// Rolling window of feature scores for failed predictions (score < 50)
-    failed_scores: Vec<f64>,
+    /// Decayed feature scores of successful decisions (profitable, or scored >= 80)
+    successful_scores: DecayedScores,
+    /// Decayed feature scores of failed decisions (losing, or scored < 50)
+    failed_scores: DecayedScores,
     /// Variance of feature scores
     score_variance: f64,
     /// Correlation with final scores
@@ -39,6 +89,88 @@ struct FeaturePerformance {
     effectiveness: f64,
 }
 
+/// Weighted accumulation of feature scores, where older observations carry less weight.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct DecayedScores {
+    count: usize,
+    total_weight: f64,
+    weighted_sum: f64,
+    weighted_sum_sq: f64,
+}
+
+impl DecayedScores {
+    fn push(&mut self, score: f64, weight: f64) {
+        self.count += 1;
+        self.total_weight += weight;
+        self.weighted_sum += score * weight;
+        self.weighted_sum_sq += score * score * weight;
+    }
+
+    /// Weighted mean, or None without observations.
+    fn mean(&self) -> Option<f64> {
+        (self.total_weight > 0.0).then(|| self.weighted_sum / self.total_weight)
+    }
+
+    /// Weighted standard deviation (0.0 for fewer than two observations).
+    fn std_dev(&self) -> f64 {
+        match self.mean() {
+            Some(mean) if self.count >= 2 => {
+                (self.weighted_sum_sq / self.total_weight - mean * mean).max(0.0).sqrt()
+            }
+            _ => 0.0,
+        }
+    }
+}
+
+/// A historical decision as seen by feature performance tracking.
+#[derive(Debug, Clone, Copy)]
+struct Observation<'a> {
+    candidate: &'a ScoredCandidate,
+    /// Realized PnL in SOL (losses negative), if the trade has closed
+    pnl: Option<f64>,
+}
+
+impl<'a> Observation<'a> {
+    fn from_score(candidate: &'a ScoredCandidate) -> Self {
+        Self { candidate, pnl: None }
+    }
+
+    fn from_record(record: &'a TransactionRecord) -> Self {
+        let pnl = match record.actual_outcome {
+            Outcome::Profit(pnl) | Outcome::Loss(pnl) => Some(pnl),
+            Outcome::Neutral => Some(0.0),
+            _ => None,
+        };
+        Self { candidate: &record.scored_candidate, pnl }
+    }
+
+    /// Whether the decision succeeded, and how much that verdict counts.
+    ///
+    /// Closed trades are judged by their PnL and weighted by its size relative
+    /// to `pnl_scale`; break-even trades say nothing. Without an outcome the
+    /// predicted score decides, with unit weight.
+    fn classify(&self, pnl_scale: f64) -> Option<(bool, f64)> {
+        match self.pnl {
+            Some(pnl) if pnl != 0.0 => Some((pnl > 0.0, pnl.abs() / pnl_scale)),
+            Some(_) => None,
+            None if self.candidate.predicted_score >= 80 && !self.candidate.anomaly_detected => Some((true, 1.0)),
+            None if self.candidate.predicted_score < 50 || self.candidate.anomaly_detected => Some((false, 1.0)),
+            None => None,
+        }
+    }
+
+    /// Outcome on a 0.0-1.0 scale: 1.0 for a profit, 0.0 for a loss and 0.5
+    /// for break-even, or the predicted score without an outcome.
+    fn outcome(&self) -> f64 {
+        match self.pnl {
+            Some(pnl) if pnl > 0.0 => 1.0,
+            Some(pnl) if pnl < 0.0 => 0.0,
+            Some(_) => 0.5,
+            None => self.candidate.predicted_score.value() as f64 / 100.0,
+        }
+    }
+}
+
 impl AdaptiveWeights {
     /// Create new adaptive weights with base configuration.
     pub fn new(base_weights: FeatureWeights) -> Self {
@@ -56,13 +188,66 @@ impl AdaptiveWeights {
             last_recalculation: current_timestamp(),
             recalculation_count: 0,
             adaptation_rate: 0.1, // 10% adaptation rate
+            feature_adaptation_rates: HashMap::new(),
+            min_samples_for_adjustment: DEFAULT_MIN_SAMPLES_FOR_ADJUSTMENT,
+            weight_floor: DEFAULT_WEIGHT_FLOOR,
+            exploration_rate: DEFAULT_EXPLORATION_RATE,
+            performance_decay: DEFAULT_PERFORMANCE_DECAY,
+            decay_half_life_seconds: DEFAULT_DECAY_HALF_LIFE_SECONDS,
+        }
+    }
+
+    /// Serialize the learned state (adjustments, feature performance and
+    /// recalculation history) to JSON.
+    ///
+    /// Settings such as the adaptation rate are not included; they come from
+    /// configuration on every start.
+    pub fn save_to_json(&self) -> Result<String> {
+        let state = PersistedState {
+            weight_adjustments: self
+                .weight_adjustments
+                .iter()
+                .map(|(feature, adjustment)| (feature.as_str().to_string(), *adjustment))
+                .collect(),
+            feature_performance: self
+                .feature_performance
+                .iter()
+                .map(|(feature, performance)| (feature.as_str().to_string(), performance.clone()))
+                .collect(),
+            recalculation_count: self.recalculation_count,
+            last_recalculation: self.last_recalculation,
+        };
+        Ok(serde_json::to_string(&state)?)
+    }
+
+    /// Restore adaptive weights over `base` from JSON written by `save_to_json`.
+    ///
+    /// Features missing from the saved state start with neutral performance.
+    pub fn load_from_json(base: FeatureWeights, json: &str) -> Result<Self> {
+        let state: PersistedState = serde_json::from_str(json)?;
+        let mut adaptive = Self::new(base);
+
+        for (name, adjustment) in state.weight_adjustments {
+            adaptive.weight_adjustments.insert(feature_by_name(&name)?, adjustment);
         }
+        for (name, performance) in state.feature_performance {
+            adaptive.feature_performance.insert(feature_by_name(&name)?, performance);
+        }
+        adaptive.recalculation_count = state.recalculation_count;
+        adaptive.last_recalculation = state.last_recalculation;
+
+        Ok(adaptive)
     }
 
     /// Get effective weights (base + adjustments).
     #[instrument(skip(self))]
     pub fn get_effective_weights(&self) -> FeatureWeights {
-        let mut effective = self.base_weights.clone();
+        self.apply_to(&self.base_weights)
+    }
+
+    /// Apply the current adjustments to an arbitrary weight set (e.g. regime weights).
+    pub fn apply_to(&self, weights: &FeatureWeights) -> FeatureWeights {
+        let mut effective = weights.clone();
 
         // Apply dynamic adjustments
         effective.liquidity = self.apply_adjustment(effective.liquidity, Feature::Liquidity);
@@ -74,6 +259,10 @@ impl AdaptiveWeights {
         effective.creator_sell_speed = self.apply_adjustment(effective.creator_sell_speed, Feature::CreatorSellSpeed);
         effective.metadata_quality = self.apply_adjustment(effective.metadata_quality, Feature::MetadataQuality);
         effective.social_activity = self.apply_adjustment(effective.social_activity, Feature::SocialActivity);
+        effective.authority_status = self.apply_adjustment(effective.authority_status, Feature::AuthorityStatus);
+        effective.contract_verified = self.apply_adjustment(effective.contract_verified, Feature::ContractVerified);
+        effective.graduation_proximity = self.apply_adjustment(effective.graduation_proximity, Feature::GraduationProximity);
+        effective.tx_per_holder = self.apply_adjustment(effective.tx_per_holder, Feature::TxPerHolder);
 
         debug!("Applied adaptive weight adjustments");
         effective
@@ -82,24 +271,50 @@ impl AdaptiveWeights {
     /// Apply weight adjustment to a base weight.
     fn apply_adjustment(&self, base_weight: f64, feature: Feature) -> f64 {
         let adjustment = self.weight_adjustments.get(&feature).unwrap_or(&0.0);
-        (base_weight * (1.0 + adjustment)).max(0.01).min(1.0) // Keep within reasonable bounds
+        (base_weight * (1.0 + adjustment)).clamp(self.weight_floor, 1.0) // Keep within reasonable bounds
     }
 
     /// Recalculate weights based on historical performance.
+    ///
+    /// Success is judged by the predicted scores alone; prefer
+    /// `recalculate_with_outcomes` once trades have closed.
     #[instrument(skip(self, historical_scores))]
     pub fn recalculate(&mut self, historical_scores: &[ScoredCandidate]) {
-        if historical_scores.is_empty() {
+        let observations: Vec<_> = historical_scores.iter().map(Observation::from_score).collect();
+        self.recalculate_observations(&observations);
+    }
+
+    /// Recalculate weights from ledger records, judging closed trades by their
+    /// realized PnL instead of their predicted score.
+    ///
+    /// Records without a final outcome fall back to their predicted score.
+    #[instrument(skip(self, records))]
+    pub fn recalculate_with_outcomes(&mut self, records: &[TransactionRecord]) {
+        let observations: Vec<_> = records.iter().map(Observation::from_record).collect();
+        self.recalculate_observations(&observations);
+    }
+
+    fn recalculate_observations(&mut self, observations: &[Observation]) {
+        if observations.is_empty() {
             debug!("No historical scores provided for recalculation");
             return;
         }
 
-        info!("Recalculating adaptive weights with {} historical scores", historical_scores.len());
+        info!("Recalculating adaptive weights with {} historical scores", observations.len());
 
         // Update feature performance tracking
-        self.update_feature_performance(historical_scores);
+        self.update_feature_performance(observations);
 
-        // Calculate new weight adjustments
-        self.calculate_weight_adjustments();
+        // Calculate new weight adjustments, once there is enough data to trust them
+        if observations.len() >= self.min_samples_for_adjustment {
+            self.calculate_weight_adjustments();
+            self.explore_dormant_features();
+        } else {
+            info!(
+                "Skipping weight adjustments: {} historical scores, need at least {}",
+                observations.len(), self.min_samples_for_adjustment
+            );
+        }
 
         // Update tracking
         self.last_recalculation = current_timestamp();
@@ -109,44 +324,53 @@ impl AdaptiveWeights {
     }
 
     /// Update performance tracking for all features.
-    #[instrument(skip(self, historical_scores))]
-    fn update_feature_performance(&mut self, historical_scores: &[ScoredCandidate]) {
-        // Separate successful and failed predictions
-        let successful: Vec<_> = historical_scores
-            .iter()
-            .filter(|s| s.predicted_score >= 80 && !s.anomaly_detected)
-            .collect();
+    ///
+    /// History is ordered oldest first; each observation's weight decays by
+    /// `performance_decay` for every newer one, so recent performance dominates.
+    /// With a decay half-life set, weights also halve with every half-life of
+    /// age, and the correlation is weighted the same way. Closed trades are
+    /// further weighted by their PnL relative to the mean absolute PnL, so a
+    /// large loss counts for more than a small one.
+    #[instrument(skip(self, observations))]
+    fn update_feature_performance(&mut self, observations: &[Observation]) {
+        let newest = observations.len() - 1;
+        let half_life = half_life_for_factor(self.performance_decay);
+        let time_weights = self.time_weights(observations);
 
-        let failed: Vec<_> = historical_scores
-            .iter()
-            .filter(|s| s.predicted_score < 50 || s.anomaly_detected)
-            .collect();
+        let realized: Vec<f64> = observations.iter().filter_map(|o| o.pnl).filter(|p| *p != 0.0).collect();
+        let pnl_scale = if realized.is_empty() {
+            1.0
+        } else {
+            realized.iter().map(|p| p.abs()).sum::<f64>() / realized.len() as f64
+        };
 
         // Update performance for each feature
         for feature in Feature::all() {
-            let correlation = self.calculate_correlation(feature, historical_scores);
+            let correlation = Self::correlation_with_outcome(feature, observations, &time_weights);
             
             let performance = self.feature_performance.get_mut(&feature).unwrap();
-            
-            // Update successful scores
-            performance.successful_scores.clear();
-            for candidate in &successful {
-                if let Some(&score) = candidate.feature_scores.get(feature.as_str()) {
-                    performance.successful_scores.push(score);
-                }
-            }
+            performance.successful_scores = DecayedScores::default();
+            performance.failed_scores = DecayedScores::default();
 
-            // Update failed scores
-            performance.failed_scores.clear();
-            for candidate in &failed {
-                if let Some(&score) = candidate.feature_scores.get(feature.as_str()) {
-                    performance.failed_scores.push(score);
+            // Split decayed scores into successful and failed predictions
+            for (i, observation) in observations.iter().enumerate() {
+                let Some(&score) = observation.candidate.feature_scores.get(feature.as_str()) else {
+                    continue;
+                };
+                let Some((success, verdict_weight)) = observation.classify(pnl_scale) else {
+                    continue;
+                };
+                let weight = exponential_weight((newest - i) as f64, half_life) * time_weights[i] * verdict_weight;
+
+                if success {
+                    performance.successful_scores.push(score, weight);
+                } else {
+                    performance.failed_scores.push(score, weight);
                 }
             }
 
             // Calculate variance and effectiveness
-            let successful_scores = &performance.successful_scores;
-            let variance = Self::calculate_variance_static(successful_scores);
+            let variance = performance.successful_scores.std_dev();
             let effectiveness = Self::calculate_effectiveness_static(performance);
             
             performance.score_variance = variance;
@@ -158,6 +382,25 @@ impl AdaptiveWeights {
         }
     }
 
+    /// Time-decay weight of each observation, by its age relative to the newest
+    /// one so that the weights do not depend on when recalculation runs.
+    /// Candidate timestamps are Unix milliseconds.
+    fn time_weights(&self, observations: &[Observation]) -> Vec<f64> {
+        let half_life_seconds = match self.decay_half_life_seconds {
+            0 => f64::INFINITY,
+            seconds => seconds as f64,
+        };
+        let newest_ms = observations.iter().map(|o| o.candidate.timestamp).max().unwrap_or(0);
+
+        observations
+            .iter()
+            .map(|o| {
+                let age_seconds = newest_ms.saturating_sub(o.candidate.timestamp) as f64 / 1000.0;
+                exponential_weight(age_seconds, half_life_seconds)
+            })
+            .collect()
+    }
+
     /// Calculate weight adjustments based on feature performance.
     #[instrument(skip(self))]
     fn calculate_weight_adjustments(&mut self) {
@@ -172,7 +415,7 @@ impl AdaptiveWeights {
             let variance_factor = (0.5 - performance.score_variance).max(-0.5); // Prefer lower variance
             
             // Combined adjustment factor
-            let adjustment = self.adaptation_rate * (
+            let adjustment = self.adaptation_rate_for(feature) * (
                 effectiveness_factor * 0.4 +
                 correlation_factor * 0.4 +
                 variance_factor * 0.2
@@ -182,7 +425,7 @@ impl AdaptiveWeights {
             let current_adjustment = self.weight_adjustments.get(&feature).unwrap_or(&0.0);
             let new_adjustment = current_adjustment * 0.8 + adjustment * 0.2; // Smooth adjustment
             
-            new_adjustments.insert(feature, new_adjustment.clamp(-0.5, 0.5));
+            new_adjustments.insert(feature, new_adjustment.clamp(MIN_ADJUSTMENT, 0.5));
 
             debug!("Weight adjustment for {:?}: {:.3} -> {:.3} (factors: eff={:.3}, corr={:.3}, var={:.3})",
                    feature, current_adjustment, new_adjustment, effectiveness_factor, correlation_factor, variance_factor);
@@ -192,80 +435,70 @@ impl AdaptiveWeights {
         self.weight_adjustments = new_adjustments;
     }
 
-    /// Calculate variance of a score vector.
-    fn calculate_variance(&self, scores: &[f64]) -> f64 {
-        Self::calculate_variance_static(scores)
-    }
-
-    /// Calculate variance of a score vector (static version).
-    fn calculate_variance_static(scores: &[f64]) -> f64 {
-        if scores.len() < 2 {
-            return 0.0;
+    /// Nudge features pinned at the weight floor or the minimum adjustment back
+    /// up, so a feature that was written off can be re-evaluated once it becomes
+    /// useful again instead of staying dormant forever.
+    #[instrument(skip(self))]
+    fn explore_dormant_features(&mut self) {
+        if self.exploration_rate <= 0.0 {
+            return;
         }
 
-        let mean = scores.iter().sum::<f64>() / scores.len() as f64;
-        let variance = scores
-            .iter()
-            .map(|&score| (score - mean).powi(2))
-            .sum::<f64>() / scores.len() as f64;
-
-        variance.sqrt() // Return standard deviation
-    }
+        for feature in Feature::all() {
+            let base_weight = self.base_weights.get(feature);
+            let adjustment = self.weight_adjustments.get(&feature).copied().unwrap_or(0.0);
+            let dormant = adjustment <= MIN_ADJUSTMENT
+                || base_weight * (1.0 + adjustment) <= self.weight_floor;
 
-    /// Calculate effectiveness of a feature.
-    fn calculate_effectiveness(&self, performance: &FeaturePerformance) -> f64 {
-        Self::calculate_effectiveness_static(performance)
+            if dormant && base_weight > 0.0 {
+                // Raise the effective weight by `exploration_rate` of its current value
+                let current = (base_weight * (1.0 + adjustment)).max(self.weight_floor);
+                let target = current * (1.0 + self.exploration_rate);
+                let explored = (target / base_weight - 1.0).min(0.5);
+                self.weight_adjustments.insert(feature, explored);
+                debug!("Exploring dormant feature {:?}: adjustment {:.3} -> {:.3}",
+                       feature, adjustment, explored);
+            }
+        }
     }
 
     /// Calculate effectiveness of a feature (static version).
     fn calculate_effectiveness_static(performance: &FeaturePerformance) -> f64 {
-        let successful_mean = if performance.successful_scores.is_empty() {
-            0.5
-        } else {
-            performance.successful_scores.iter().sum::<f64>() / performance.successful_scores.len() as f64
-        };
-
-        let failed_mean = if performance.failed_scores.is_empty() {
-            0.5
-        } else {
-            performance.failed_scores.iter().sum::<f64>() / performance.failed_scores.len() as f64
-        };
+        let successful_mean = performance.successful_scores.mean().unwrap_or(0.5);
+        let failed_mean = performance.failed_scores.mean().unwrap_or(0.5);
 
         // Effectiveness is how well the feature discriminates between success and failure
         let discrimination = successful_mean - failed_mean;
         (0.5 + discrimination).clamp(0.0, 1.0)
     }
 
-    /// Calculate correlation between feature scores and final outcomes.
-    fn calculate_correlation(&self, feature: Feature, historical_scores: &[ScoredCandidate]) -> f64 {
-        if historical_scores.len() < 2 {
+    /// Weighted Pearson correlation between a feature's scores and
+    /// `Observation::outcome`, each observation counting by its entry in `weights`.
+    fn correlation_with_outcome(feature: Feature, observations: &[Observation], weights: &[f64]) -> f64 {
+        if observations.len() < 2 {
             return 0.0;
         }
 
-        let mut feature_scores = Vec::new();
-        let mut final_scores = Vec::new();
+        let mut samples = Vec::new();
 
-        for candidate in historical_scores {
-            if let Some(&score) = candidate.feature_scores.get(feature.as_str()) {
-                feature_scores.push(score);
-                final_scores.push(candidate.predicted_score as f64 / 100.0);
+        for (observation, &weight) in observations.iter().zip(weights) {
+            if let Some(&score) = observation.candidate.feature_scores.get(feature.as_str()) {
+                samples.push((score, observation.outcome(), weight));
             }
         }
 
-        if feature_scores.len() < 2 {
+        let total_weight = samples.iter().map(|(_, _, w)| w).sum::<f64>();
+        if samples.len() < 2 || total_weight <= 0.0 {
             return 0.0;
         }
 
-        // Calculate Pearson correlation coefficient
-        let n = feature_scores.len() as f64;
-        let sum_x = feature_scores.iter().sum::<f64>();
-        let sum_y = final_scores.iter().sum::<f64>();
-        let sum_xy = feature_scores.iter().zip(&final_scores).map(|(x, y)| x * y).sum::<f64>();
-        let sum_x2 = feature_scores.iter().map(|x| x * x).sum::<f64>();
-        let sum_y2 = final_scores.iter().map(|y| y * y).sum::<f64>();
-
-        let numerator = n * sum_xy - sum_x * sum_y;
-        let denominator = ((n * sum_x2 - sum_x * sum_x) * (n * sum_y2 - sum_y * sum_y)).sqrt();
+        // Calculate the weighted Pearson correlation coefficient
+        let mean_x = samples.iter().map(|(x, _, w)| w * x).sum::<f64>() / total_weight;
+        let mean_y = samples.iter().map(|(_, y, w)| w * y).sum::<f64>() / total_weight;
+        let numerator = samples.iter().map(|(x, y, w)| w * (x - mean_x) * (y - mean_y)).sum::<f64>();
+        let variance_x = samples.iter().map(|(x, _, w)| w * (x - mean_x).powi(2)).sum::<f64>();
+        let variance_y = samples.iter().map(|(_, y, w)| w * (y - mean_y).powi(2)).sum::<f64>();
+        let denominator = (variance_x * variance_y).sqrt();
 
         if denominator.abs() < 1e-10 {
             0.0
@@ -274,6 +507,16 @@ impl AdaptiveWeights {
         }
     }
 
+    /// L2 distance between the base weights and the current effective weights.
+    pub fn weight_drift(&self) -> f64 {
+        let effective = self.get_effective_weights();
+        Feature::all()
+            .into_iter()
+            .map(|feature| (effective.get(feature) - self.base_weights.get(feature)).powi(2))
+            .sum::<f64>()
+            .sqrt()
+    }
+
     /// Get current adaptation statistics.
     pub fn get_adaptation_stats(&self) -> AdaptationStats {
         AdaptationStats {
@@ -299,11 +542,69 @@ impl AdaptiveWeights {
         info!("Reset adaptive weights to base configuration");
     }
 
+    /// Replace the base weights, keeping the learned adjustments.
+    pub fn set_base_weights(&mut self, base_weights: FeatureWeights) {
+        self.base_weights = base_weights;
+        debug!("Replaced base weights");
+    }
+
+    /// Override the adjustment for a single feature (e.g. 0.2 = +20%).
+    pub fn set_adjustment(&mut self, feature: Feature, adjustment: f64) {
+        self.weight_adjustments.insert(feature, adjustment);
+        debug!("Set {} weight adjustment to {:.3}", feature.as_str(), adjustment);
+    }
+
+    /// Set how many historical scores a recalculation needs before it adjusts weights.
+    pub fn set_min_samples_for_adjustment(&mut self, min_samples: usize) {
+        self.min_samples_for_adjustment = min_samples;
+        debug!("Set minimum samples for adjustment to {}", min_samples);
+    }
+
+    /// Set the lower bound for effective weights.
+    pub fn set_weight_floor(&mut self, floor: f64) {
+        self.weight_floor = floor.clamp(0.0, 1.0);
+        debug!("Set weight floor to {:.3}", self.weight_floor);
+    }
+
+    /// Set the relative increase applied to dormant feature weights per recalculation
+    /// (0.0 disables exploration).
+    pub fn set_exploration_rate(&mut self, rate: f64) {
+        self.exploration_rate = rate.clamp(0.0, 1.0);
+        debug!("Set exploration rate to {:.3}", self.exploration_rate);
+    }
+
+    /// Set the weight an observation keeps for every newer one when tracking
+    /// feature performance (1.0 weighs the whole history equally).
+    pub fn set_performance_decay(&mut self, decay: f64) {
+        self.performance_decay = decay.clamp(0.0, 1.0);
+        debug!("Set performance decay to {:.3}", self.performance_decay);
+    }
+
+    /// Set the half-life, in seconds, after which an observation's weight
+    /// halves when tracking feature performance (0 disables time decay).
+    pub fn set_decay_half_life(&mut self, seconds: u64) {
+        self.decay_half_life_seconds = seconds;
+        debug!("Set decay half-life to {}s", seconds);
+    }
+
     /// Set adaptation rate (0.0 = no adaptation, 1.0 = full adaptation).
     pub fn set_adaptation_rate(&mut self, rate: f64) {
         self.adaptation_rate = rate.clamp(0.0, 1.0);
         debug!("Set adaptation rate to {:.2}", self.adaptation_rate);
     }
+
+    /// Set the adaptation rate of a single feature, overriding the global rate
+    /// (0.0 = no adaptation, 1.0 = full adaptation).
+    pub fn set_feature_adaptation_rate(&mut self, feature: Feature, rate: f64) {
+        let rate = rate.clamp(0.0, 1.0);
+        self.feature_adaptation_rates.insert(feature, rate);
+        debug!("Set {} adaptation rate to {:.2}", feature.as_str(), rate);
+    }
+
+    /// Adaptation rate used for `feature`: its own rate if set, else the global one.
+    pub fn adaptation_rate_for(&self, feature: Feature) -> f64 {
+        self.feature_adaptation_rates.get(&feature).copied().unwrap_or(self.adaptation_rate)
+    }
 }
 
 /// Statistics about weight adaptation.
@@ -318,8 +619,8 @@ pub struct AdaptationStats {
 impl Default for FeaturePerformance {
     fn default() -> Self {
         Self {
-            successful_scores: Vec::new(),
-            failed_scores: Vec::new(),
+            successful_scores: DecayedScores::default(),
+            failed_scores: DecayedScores::default(),
             score_variance: 0.0,
             correlation_with_outcome: 0.0,
             effectiveness: 0.5, // Neutral effectiveness
@@ -327,6 +628,67 @@ impl Default for FeaturePerformance {
     }
 }
 
+/// Decides when adaptive weights are recalculated.
+///
+/// Triggers after roughly `interval` scored candidates, with each gap drawn
+/// uniformly from `interval * (1 ± jitter)` so that several oracle instances
+/// do not recalculate in lockstep.
+#[derive(Debug, Clone)]
+pub struct RecalcSchedule {
+    interval: u64,
+    /// Fraction of `interval` each gap may deviate by, in `[0, 1)`
+    jitter: f64,
+    /// Candidates counted since the last trigger
+    count: u64,
+    /// Candidates needed for the next trigger
+    next_trigger: u64,
+}
+
+impl RecalcSchedule {
+    pub fn new(interval: u64, jitter: f64) -> Self {
+        let mut schedule = Self {
+            interval,
+            jitter: jitter.clamp(0.0, 0.99),
+            count: 0,
+            next_trigger: interval,
+        };
+        schedule.next_trigger = schedule.draw_interval();
+        schedule
+    }
+
+    /// Count one scored candidate; returns whether to recalculate now.
+    pub fn record(&mut self) -> bool {
+        self.count += 1;
+        if self.count < self.next_trigger {
+            return false;
+        }
+        self.count = 0;
+        self.next_trigger = self.draw_interval();
+        true
+    }
+
+    /// Candidates needed for the next trigger.
+    pub fn next_trigger(&self) -> u64 {
+        self.next_trigger
+    }
+
+    fn draw_interval(&self) -> u64 {
+        if self.jitter == 0.0 {
+            return self.interval;
+        }
+        let factor = 1.0 + rand::thread_rng().gen_range(-self.jitter..=self.jitter);
+        ((self.interval as f64 * factor).round() as u64).max(1)
+    }
+}
+
+/// The feature named `name` by `Feature::as_str`.
+fn feature_by_name(name: &str) -> Result<Feature> {
+    Feature::all()
+        .into_iter()
+        .find(|feature| feature.as_str() == name)
+        .ok_or_else(|| anyhow!("Unknown feature in saved adaptive weights: {}", name))
+}
+
 /// Get current timestamp in seconds.
 fn current_timestamp() -> u64 {
     SystemTime::now()
@@ -338,6 +700,7 @@ fn current_timestamp() -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::oracle::test_support::RecordBuilder;
     use crate::oracle::types::*;
     use crate::types::PremintCandidate;
     use solana_sdk::pubkey::Pubkey;
@@ -350,21 +713,27 @@ mod tests {
     fn create_test_candidate(score: u8, feature_scores: HashMap<String, f64>) -> ScoredCandidate {
         ScoredCandidate {
             base: PremintCandidate {
-                mint: Pubkey::new_unique(),
-                creator: Pubkey::new_unique(),
+                mint: Pubkey::new_unique().to_string(),
+                creator: Pubkey::new_unique().to_string(),
                 program: "test".to_string(),
                 slot: 12345,
                 timestamp: 1640995200,
                 instruction_summary: None,
                 is_jito_bundle: Some(true),
+                funding_source: None,
             },
-            mint: Pubkey::new_unique(),
-            predicted_score: score,
+            mint: Pubkey::new_unique().to_string(),
+            predicted_score: Score::clamped(score),
             feature_scores,
             reason: "test".to_string(),
             calculation_time: 1000,
             anomaly_detected: false,
             timestamp: 1640995200,
+            data_source_timestamps: HashMap::new(),
+            scoring_trace: None,
+            reason_codes: Vec::new(),
+            anomaly_report: None,
+            action: TradeAction::Skip,
         }
     }
 
@@ -418,13 +787,17 @@ mod tests {
         assert!(adaptive.last_recalculation > 0);
     }
 
+    fn unweighted(scores: &[f64]) -> DecayedScores {
+        let mut decayed = DecayedScores::default();
+        for &score in scores {
+            decayed.push(score, 1.0);
+        }
+        decayed
+    }
+
     #[test]
     fn test_calculate_variance() {
-        let base_weights = create_test_weights();
-        let adaptive = AdaptiveWeights::new(base_weights);
-        
-        let scores = vec![0.8, 0.9, 0.7, 0.85, 0.75];
-        let variance = AdaptiveWeights::calculate_variance_static(&scores);
+        let variance = unweighted(&[0.8, 0.9, 0.7, 0.85, 0.75]).std_dev();
         
         assert!(variance > 0.0);
         assert!(variance < 1.0);
@@ -432,21 +805,17 @@ mod tests {
 
     #[test]
     fn test_calculate_variance_empty() {
-        let base_weights = create_test_weights();
-        let adaptive = AdaptiveWeights::new(base_weights);
-        
-        let variance = AdaptiveWeights::calculate_variance_static(&[]);
+        let variance = DecayedScores::default().std_dev();
         assert_eq!(variance, 0.0);
     }
 
     #[test]
     fn test_calculate_effectiveness() {
-        let base_weights = create_test_weights();
-        let adaptive = AdaptiveWeights::new(base_weights);
-        
-        let mut performance = FeaturePerformance::default();
-        performance.successful_scores = vec![0.8, 0.9, 0.85];
-        performance.failed_scores = vec![0.3, 0.2, 0.4];
+        let performance = FeaturePerformance {
+            successful_scores: unweighted(&[0.8, 0.9, 0.85]),
+            failed_scores: unweighted(&[0.3, 0.2, 0.4]),
+            ..FeaturePerformance::default()
+        };
         
         let effectiveness = AdaptiveWeights::calculate_effectiveness_static(&performance);
         
@@ -487,6 +856,22 @@ mod tests {
         assert_eq!(effective_weights.liquidity, base_weights.liquidity);
     }
 
+    #[test]
+    fn test_weight_drift_tracks_adjustments() {
+        let mut adaptive = AdaptiveWeights::new(create_test_weights());
+        assert_eq!(adaptive.weight_drift(), 0.0);
+
+        adaptive.set_adjustment(Feature::Liquidity, 0.2);
+        let drift = adaptive.weight_drift();
+        assert!(drift > 0.0);
+
+        adaptive.set_adjustment(Feature::VolumeGrowth, -0.3);
+        assert!(adaptive.weight_drift() > drift);
+
+        adaptive.reset_to_base();
+        assert_eq!(adaptive.weight_drift(), 0.0);
+    }
+
     #[test]
     fn test_adaptation_rate() {
         let base_weights = create_test_weights();
@@ -520,9 +905,6 @@ mod tests {
 
     #[test]
     fn test_correlation_calculation() {
-        let base_weights = create_test_weights();
-        let adaptive = AdaptiveWeights::new(base_weights);
-        
         // Create test data with clear correlation
         let mut high_feature_high_score = HashMap::new();
         high_feature_high_score.insert("liquidity".to_string(), 0.9);
@@ -530,16 +912,286 @@ mod tests {
         let mut low_feature_low_score = HashMap::new();
         low_feature_low_score.insert("liquidity".to_string(), 0.1);
         
-        let historical = vec![
+        let historical = [
             create_test_candidate(90, high_feature_high_score.clone()),
             create_test_candidate(85, high_feature_high_score),
             create_test_candidate(20, low_feature_low_score.clone()),
             create_test_candidate(15, low_feature_low_score),
         ];
         
-        let correlation = adaptive.calculate_correlation(Feature::Liquidity, &historical);
+        let observations: Vec<_> = historical.iter().map(Observation::from_score).collect();
+        let correlation = AdaptiveWeights::correlation_with_outcome(Feature::Liquidity, &observations, &[1.0; 4]);
         
         // Should show positive correlation
         assert!(correlation > 0.5);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_recalculate_with_outcomes_judges_by_pnl() {
+        // High liquidity scored well but lost money; low liquidity scored poorly but won
+        let trade = |score: u8, liquidity: f64, outcome: Outcome| {
+            RecordBuilder::new(&Pubkey::new_unique().to_string())
+                .decided_at(1640995200)
+                .score(score)
+                .feature("liquidity", liquidity)
+                .sol_spent(1.0)
+                .outcome(outcome)
+                .build()
+        };
+        let records = vec![
+            trade(90, 0.9, Outcome::Loss(-0.8)),
+            trade(85, 0.9, Outcome::Loss(-0.4)),
+            trade(30, 0.1, Outcome::Profit(0.6)),
+            trade(20, 0.1, Outcome::Profit(0.6)),
+        ];
+
+        // Judged by score alone, liquidity looks predictive
+        let mut by_score = AdaptiveWeights::new(create_test_weights());
+        let candidates: Vec<_> = records.iter().map(|r| r.scored_candidate.clone()).collect();
+        by_score.recalculate(&candidates);
+        assert!(by_score.feature_performance[&Feature::Liquidity].effectiveness > 0.5);
+
+        // Judged by outcome, the high-scored losers are failures
+        let mut by_outcome = AdaptiveWeights::new(create_test_weights());
+        by_outcome.recalculate_with_outcomes(&records);
+        let performance = &by_outcome.feature_performance[&Feature::Liquidity];
+        assert!((performance.failed_scores.mean().unwrap() - 0.9).abs() < 1e-9);
+        assert!((performance.successful_scores.mean().unwrap() - 0.1).abs() < 1e-9);
+        assert!(performance.effectiveness < 0.5);
+        assert!(performance.correlation_with_outcome < -0.9);
+
+        // A loss counts in proportion to its size against the mean PnL
+        let observation = Observation::from_record(&records[0]);
+        assert_eq!(observation.classify(0.6), Some((false, 0.8 / 0.6)));
+
+        // Open trades fall back to their predicted score
+        let open = trade(90, 0.9, Outcome::PendingConfirmation);
+        assert_eq!(Observation::from_record(&open).classify(0.6), Some((true, 1.0)));
+    }
+
+    fn create_mixed_history(size: usize) -> Vec<ScoredCandidate> {
+        (0..size)
+            .map(|i| {
+                let (score, liquidity) = if i % 2 == 0 { (90, 0.9) } else { (30, 0.1) };
+                let mut feature_scores = HashMap::new();
+                feature_scores.insert("liquidity".to_string(), liquidity);
+                create_test_candidate(score, feature_scores)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_small_history_does_not_adjust_weights() {
+        let mut adaptive = AdaptiveWeights::new(create_test_weights());
+        adaptive.set_min_samples_for_adjustment(50);
+
+        adaptive.recalculate(&create_mixed_history(10));
+
+        assert_eq!(adaptive.recalculation_count, 1);
+        assert!(adaptive.weight_adjustments.is_empty());
+        // Performance tracking still sees the data
+        assert!(adaptive.feature_performance[&Feature::Liquidity].successful_scores.mean().is_some());
+    }
+
+    #[test]
+    fn test_recent_observations_dominate_effectiveness() {
+        // Liquidity separates winners from losers in one half of the history
+        // and is inverted in the other
+        let half = |inverted: bool| -> Vec<ScoredCandidate> {
+            (0..20)
+                .map(|i| {
+                    let won = i % 2 == 0;
+                    let liquidity = if won != inverted { 0.9 } else { 0.1 };
+                    let mut feature_scores = HashMap::new();
+                    feature_scores.insert("liquidity".to_string(), liquidity);
+                    create_test_candidate(if won { 90 } else { 30 }, feature_scores)
+                })
+                .collect()
+        };
+        let effectiveness = |decay: f64, history: Vec<ScoredCandidate>| {
+            let mut adaptive = AdaptiveWeights::new(create_test_weights());
+            adaptive.set_performance_decay(decay);
+            adaptive.recalculate(&history);
+            adaptive.feature_performance[&Feature::Liquidity].effectiveness
+        };
+
+        let recently_useful = [half(true), half(false)].concat();
+        let recently_inverted = [half(false), half(true)].concat();
+
+        // Without decay the order of observations does not matter
+        assert!((effectiveness(1.0, recently_useful.clone()) - 0.5).abs() < 1e-9);
+        assert!((effectiveness(1.0, recently_inverted.clone()) - 0.5).abs() < 1e-9);
+
+        // With decay the recent half decides
+        assert!(effectiveness(0.9, recently_useful) > 0.7);
+        assert!(effectiveness(0.9, recently_inverted) < 0.3);
+    }
+
+    #[test]
+    fn test_sufficient_history_adjusts_weights() {
+        let mut adaptive = AdaptiveWeights::new(create_test_weights());
+        adaptive.set_min_samples_for_adjustment(50);
+
+        adaptive.recalculate(&create_mixed_history(60));
+
+        let adjustment = adaptive.weight_adjustments.get(&Feature::Liquidity).copied().unwrap_or(0.0);
+        assert!(adjustment > 0.0);
+    }
+
+    #[test]
+    fn test_recent_data_dominates_with_time_decay() {
+        // Candidates are stamped in milliseconds
+        const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+        let now_ms = 1_700_000_000_000;
+
+        // A week-old history where liquidity predicted success, then a shorter
+        // recent one where it predicts failure
+        let observation = |i: usize, inverted: bool, timestamp: u64| {
+            let won = i.is_multiple_of(2);
+            let liquidity = if won != inverted { 0.9 } else { 0.1 };
+            let mut candidate = create_test_candidate(if won { 90 } else { 30 }, HashMap::from([
+                ("liquidity".to_string(), liquidity),
+            ]));
+            candidate.timestamp = timestamp;
+            candidate
+        };
+        let history: Vec<_> = (0..60)
+            .map(|i| observation(i, false, now_ms - 7 * DAY_MS + i as u64 * 1000))
+            .chain((0..20).map(|i| observation(i, true, now_ms + i as u64 * 1000)))
+            .collect();
+
+        let liquidity_adjustment = |half_life: u64| {
+            let mut adaptive = AdaptiveWeights::new(create_test_weights());
+            adaptive.set_performance_decay(1.0);
+            adaptive.set_decay_half_life(half_life);
+            adaptive.recalculate(&history);
+            let correlation = adaptive.feature_performance[&Feature::Liquidity].correlation_with_outcome;
+            (adaptive.weight_adjustments[&Feature::Liquidity], correlation)
+        };
+
+        // Without time decay the larger, older history wins
+        let (adjustment, correlation) = liquidity_adjustment(DEFAULT_DECAY_HALF_LIFE_SECONDS);
+        assert!(adjustment > 0.0);
+        assert!(correlation > 0.0);
+
+        // With a one-hour half-life the recent history decides
+        let (adjustment, correlation) = liquidity_adjustment(60 * 60);
+        assert!(adjustment < 0.0);
+        assert!(correlation < -0.9);
+    }
+
+    #[test]
+    fn test_time_weights_use_millisecond_timestamps() {
+        let mut older = create_test_candidate(50, HashMap::new());
+        older.timestamp = 1_700_000_000_000;
+        let mut newer = older.clone();
+        newer.timestamp = older.timestamp + 60 * 60 * 1000;
+
+        let mut adaptive = AdaptiveWeights::new(create_test_weights());
+        adaptive.set_decay_half_life(60 * 60);
+        let weights = adaptive.time_weights(&[Observation::from_score(&older), Observation::from_score(&newer)]);
+
+        // One hour apart with a one-hour half-life
+        assert!((weights[0] - 0.5).abs() < 1e-9, "weights {:?}", weights);
+        assert_eq!(weights[1], 1.0);
+    }
+
+    #[test]
+    fn test_state_round_trips_through_json() {
+        let mut adaptive = AdaptiveWeights::new(create_test_weights());
+        adaptive.recalculate(&create_mixed_history(60));
+        assert!(!adaptive.weight_adjustments.is_empty());
+
+        let json = adaptive.save_to_json().unwrap();
+        let restored = AdaptiveWeights::load_from_json(create_test_weights(), &json).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(restored.get_effective_weights()).unwrap(),
+            serde_json::to_value(adaptive.get_effective_weights()).unwrap()
+        );
+        assert_eq!(restored.weight_adjustments, adaptive.weight_adjustments);
+        assert_eq!(restored.recalculation_count, 1);
+        assert_eq!(restored.last_recalculation, adaptive.last_recalculation);
+        assert_eq!(
+            restored.feature_performance[&Feature::Liquidity].effectiveness,
+            adaptive.feature_performance[&Feature::Liquidity].effectiveness
+        );
+
+        let unknown = r#"{"weight_adjustments":{"moon_phase":0.1},"feature_performance":{},"recalculation_count":0,"last_recalculation":0}"#;
+        assert!(AdaptiveWeights::load_from_json(create_test_weights(), unknown).is_err());
+    }
+
+    #[test]
+    fn test_exploration_raises_floored_weight() {
+        let mut base_weights = create_test_weights();
+        base_weights.liquidity = DEFAULT_WEIGHT_FLOOR;
+
+        let floored_after_recalculation = |exploration_rate: f64| {
+            let mut adaptive = AdaptiveWeights::new(base_weights.clone());
+            adaptive.set_min_samples_for_adjustment(50);
+            adaptive.set_adaptation_rate(0.0);
+            adaptive.set_exploration_rate(exploration_rate);
+            adaptive.set_adjustment(Feature::Liquidity, MIN_ADJUSTMENT);
+            assert_eq!(adaptive.get_effective_weights().liquidity, DEFAULT_WEIGHT_FLOOR);
+
+            adaptive.recalculate(&create_mixed_history(60));
+            adaptive.get_effective_weights().liquidity
+        };
+
+        // Without exploration the feature stays pinned at the floor
+        assert_eq!(floored_after_recalculation(0.0), DEFAULT_WEIGHT_FLOOR);
+
+        let explored = floored_after_recalculation(0.05);
+        assert!((explored - DEFAULT_WEIGHT_FLOOR * 1.05).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_recalc_schedule_jitters_within_band() {
+        let mut schedule = RecalcSchedule::new(100, 0.2);
+        let mut intervals = Vec::new();
+        let mut since_trigger = 0;
+        while intervals.len() < 20 {
+            since_trigger += 1;
+            if schedule.record() {
+                intervals.push(since_trigger);
+                since_trigger = 0;
+            }
+        }
+
+        assert!(intervals.iter().all(|&n| (80..=120).contains(&n)), "{:?}", intervals);
+        assert!(intervals.iter().any(|&n| n != intervals[0]), "{:?}", intervals);
+
+        // Without jitter every trigger is exactly one interval apart
+        let mut schedule = RecalcSchedule::new(100, DEFAULT_RECALC_JITTER);
+        let triggers: Vec<u64> = (1..=300).filter(|_| schedule.record()).collect();
+        assert_eq!(triggers, vec![100, 200, 300]);
+    }
+
+    #[test]
+    fn test_feature_adaptation_rates() {
+        // Liquidity and social activity track the outcome identically
+        let history: Vec<ScoredCandidate> = create_mixed_history(60)
+            .into_iter()
+            .map(|mut candidate| {
+                let liquidity = candidate.feature_scores["liquidity"];
+                candidate.feature_scores.insert("social_activity".to_string(), liquidity);
+                candidate
+            })
+            .collect();
+
+        let mut adaptive = AdaptiveWeights::new(create_test_weights());
+        adaptive.set_min_samples_for_adjustment(50);
+        adaptive.set_exploration_rate(0.0);
+        adaptive.set_feature_adaptation_rate(Feature::Liquidity, 0.5);
+        adaptive.set_feature_adaptation_rate(Feature::SocialActivity, 0.05);
+        assert_eq!(adaptive.adaptation_rate_for(Feature::Liquidity), 0.5);
+        assert_eq!(adaptive.adaptation_rate_for(Feature::VolumeGrowth), 0.1);
+
+        adaptive.recalculate(&history);
+
+        let liquidity = adaptive.weight_adjustments[&Feature::Liquidity];
+        let social = adaptive.weight_adjustments[&Feature::SocialActivity];
+        assert!(social.abs() > 0.0);
+        assert!((liquidity / social - 10.0).abs() < 1e-9, "liquidity {} social {}", liquidity, social);
+    }
+}
@@ -18,7 +18,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::json;
 use anyhow::Result;
 use reqwest::Client;
-use log::{info, warn, error};
+use tracing::{info, warn, error};
 // Removed unused imports: use std::cmp::{min, max};
 // Removed unused import: use std::collections::BTreeMap;
 // Removed unused import: use std::str::FromStr;
@@ -307,11 +307,10 @@ impl PredictiveOracle {
                         // Send GUI suggestion if score meets threshold
                         if scored.predicted_score >= scorer.config.notify_threshold {
                             let gui_suggestion = QuantumCandidateGui {
-                                mint: candidate.mint,
+                                candidate: candidate.clone(),
                                 score: scored.predicted_score,
                                 reason: scored.reason.clone(),
-                                feature_scores: scored.feature_scores.clone(),
-                                timestamp: candidate.timestamp,
+                                features: scored.feature_scores.clone(),
                             };
                             
                             if let Some(sender) = scorer.gui_suggestions.lock().await.as_ref() {
@@ -385,7 +384,7 @@ impl OracleScorer {
         let reason = "Stub implementation for compilation".to_string();
         
         Ok(ScoredCandidate {
-            mint: candidate.mint,
+            mint: candidate.mint.parse()?,
             predicted_score,
             feature_scores,
             reason,
@@ -442,4 +441,117 @@ impl Default for OracleConfig {
             notify_threshold: 75,
         }
     }
-}
\ No newline at end of file
+}
+
+// Migration to the regime-aware configuration
+impl From<FeatureWeights> for crate::oracle::types::FeatureWeights {
+    /// Features the legacy selector did not score get no weight.
+    fn from(weights: FeatureWeights) -> Self {
+        Self {
+            liquidity: weights.liquidity,
+            holder_distribution: weights.holder_distribution,
+            volume_growth: weights.volume_growth,
+            holder_growth: weights.holder_growth,
+            price_change: weights.price_change,
+            jito_bundle_presence: weights.jito_bundle_presence,
+            creator_sell_speed: weights.creator_sell_speed,
+            metadata_quality: weights.metadata_quality,
+            social_activity: weights.social_activity,
+            authority_status: 0.0,
+            contract_verified: 0.0,
+            graduation_proximity: 0.0,
+            tx_per_holder: 0.0,
+        }
+    }
+}
+
+impl From<ScoreThresholds> for crate::oracle::types::ScoreThresholds {
+    fn from(thresholds: ScoreThresholds) -> Self {
+        Self {
+            min_liquidity_sol: thresholds.min_liquidity_sol,
+            whale_threshold: thresholds.whale_threshold,
+            volume_growth_threshold: thresholds.volume_growth_threshold,
+            holder_growth_threshold: thresholds.holder_growth_threshold,
+            min_metadata_quality: thresholds.min_metadata_quality,
+            creator_sell_penalty_threshold: thresholds.creator_sell_penalty_threshold,
+            social_activity_threshold: thresholds.social_activity_threshold,
+        }
+    }
+}
+
+impl From<OracleConfig> for crate::oracle::types::OracleConfig {
+    /// The legacy weights and thresholds become both the base parameters and
+    /// those of the `LowActivity` regime, which is in effect until the regime
+    /// detector has classified the market. Other regimes keep their defaults,
+    /// as do settings the legacy config has no counterpart for.
+    fn from(legacy: OracleConfig) -> Self {
+        use crate::oracle::types::{MarketRegime, RegimeSpecificParameters};
+
+        let weights: crate::oracle::types::FeatureWeights = legacy.weights.into();
+        let thresholds: crate::oracle::types::ScoreThresholds = legacy.thresholds.into();
+
+        let mut config = Self::default();
+        config.regime_parameters.insert(
+            MarketRegime::LowActivity,
+            RegimeSpecificParameters {
+                weights: weights.clone(),
+                thresholds: thresholds.clone(),
+                ..RegimeSpecificParameters::default()
+            },
+        );
+        config.weights = weights;
+        config.thresholds = thresholds;
+        config.rpc_endpoints = legacy.rpc_endpoints;
+        config.pump_fun_api_key = legacy.pump_fun_api_key;
+        config.bitquery_api_key = legacy.bitquery_api_key;
+        // The legacy count excludes the first attempt
+        config.rpc_retry.max_attempts = legacy.rpc_retry_attempts + 1;
+        config.rpc_timeout_seconds = legacy.rpc_timeout_seconds;
+        config.cache_ttl_seconds = legacy.cache_ttl_seconds;
+        config.max_parallel_requests = legacy.max_parallel_requests;
+        config.rate_limit_requests_per_second = legacy.rate_limit_requests_per_second;
+        config.notify_threshold = legacy.notify_threshold;
+        config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oracle::types::MarketRegime;
+
+    #[test]
+    fn test_legacy_config_converts_to_regime_aware_config() {
+        let mut legacy = OracleConfig::default();
+        legacy.weights.liquidity = 0.42;
+        legacy.weights.social_activity = 0.01;
+        legacy.thresholds.min_liquidity_sol = 25.0;
+        legacy.thresholds.creator_sell_penalty_threshold = 120;
+        legacy.rpc_endpoints = vec!["https://rpc.example.com".to_string()];
+        legacy.rpc_retry_attempts = 2;
+        legacy.notify_threshold = 90;
+
+        let config: crate::oracle::types::OracleConfig = legacy.into();
+
+        let low_activity = &config.regime_parameters[&MarketRegime::LowActivity];
+        assert_eq!(low_activity.weights.liquidity, 0.42);
+        assert_eq!(low_activity.weights.social_activity, 0.01);
+        assert_eq!(low_activity.weights.graduation_proximity, 0.0);
+        assert_eq!(low_activity.weights.tx_per_holder, 0.0);
+        assert_eq!(low_activity.thresholds.min_liquidity_sol, 25.0);
+        assert_eq!(low_activity.thresholds.creator_sell_penalty_threshold, 120);
+        assert_eq!(config.weights.liquidity, 0.42);
+        assert_eq!(config.thresholds.min_liquidity_sol, 25.0);
+
+        // Other regimes keep their own defaults
+        let defaults = crate::oracle::types::OracleConfig::default();
+        assert_eq!(
+            config.regime_parameters[&MarketRegime::Bullish].weights.liquidity,
+            defaults.regime_parameters[&MarketRegime::Bullish].weights.liquidity
+        );
+
+        assert_eq!(config.rpc_endpoints, vec!["https://rpc.example.com".to_string()]);
+        assert_eq!(config.rpc_retry.max_attempts, 3);
+        assert_eq!(config.notify_threshold, 90);
+    }
+}
@@ -22,6 +22,9 @@ pub struct PremintCandidate {
     pub instruction_summary: Option<String>,
     /// Whether this was found in a Jito bundle
     pub is_jito_bundle: Option<bool>,
+    /// Wallet that funded the creator, if known
+    #[serde(default)]
+    pub funding_source: Option<Pubkey>,
 }
 
 /// GUI candidate information for display
@@ -0,0 +1,146 @@
+//! Fixtures shared by the integration tests.
+//!
+//! Mirrors the library's `oracle::test_support`, which integration tests
+//! cannot reach since it only exists under `cfg(test)`.
+
+#![allow(dead_code)]
+
+use h_5n1p3r::oracle::storage::{SqliteLedger, SqliteLedgerNormalized, SqliteStorageOptions};
+use h_5n1p3r::oracle::{Outcome, Score, ScoredCandidate, TradeAction, TransactionRecord};
+use h_5n1p3r::types::PremintCandidate;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// SQLite ledger in a temporary file of its own, so that no rows are shared
+/// with other tests.
+pub async fn isolated_ledger() -> Arc<SqliteLedger> {
+    let db_path = std::env::temp_dir().join(format!("ledger_{}.db", rand::random::<u64>()));
+    SqliteLedger::new_with_options(SqliteStorageOptions { db_path, ..Default::default() })
+        .await
+        .expect("Failed to create ledger")
+}
+
+/// Normalized counterpart of `isolated_ledger`.
+pub async fn isolated_normalized_ledger() -> Arc<SqliteLedgerNormalized> {
+    let db_path = std::env::temp_dir().join(format!("ledger_normalized_{}.db", rand::random::<u64>()));
+    SqliteLedgerNormalized::new_with_options(SqliteStorageOptions { db_path, ..Default::default() })
+        .await
+        .expect("Failed to create normalized ledger")
+}
+
+/// Builds a `TransactionRecord` for `mint`, decided at time 0 by creator
+/// `"creator"` on `"pump.fun"`, with a zero score and nothing else set.
+#[derive(Clone)]
+pub struct RecordBuilder {
+    record: TransactionRecord,
+}
+
+impl RecordBuilder {
+    pub fn new(mint: &str) -> Self {
+        let candidate = PremintCandidate {
+            mint: mint.to_string(),
+            creator: "creator".to_string(),
+            program: "pump.fun".to_string(),
+            slot: 1,
+            timestamp: 0,
+            instruction_summary: None,
+            is_jito_bundle: None,
+            funding_source: None,
+        };
+
+        Self {
+            record: TransactionRecord {
+                id: None,
+                scored_candidate: ScoredCandidate {
+                    mint: candidate.mint.clone(),
+                    base: candidate,
+                    predicted_score: Score::default(),
+                    reason: String::new(),
+                    feature_scores: HashMap::new(),
+                    calculation_time: 0,
+                    anomaly_detected: false,
+                    timestamp: 0,
+                    data_source_timestamps: HashMap::new(),
+                    scoring_trace: None,
+                    reason_codes: Vec::new(),
+                    anomaly_report: None,
+                    action: TradeAction::Skip,
+                },
+                transaction_signature: None,
+                buy_price_sol: None,
+                sell_price_sol: None,
+                amount_bought_tokens: None,
+                amount_sold_tokens: None,
+                initial_sol_spent: None,
+                final_sol_received: None,
+                timestamp_decision_made: 0,
+                timestamp_transaction_sent: None,
+                timestamp_outcome_evaluated: None,
+                actual_outcome: Outcome::default(),
+                market_context_snapshot: HashMap::new(),
+            },
+        }
+    }
+
+    /// Stamp the candidate, its score and the decision with `timestamp`.
+    pub fn decided_at(mut self, timestamp: u64) -> Self {
+        self.record.scored_candidate.base.timestamp = timestamp;
+        self.record.scored_candidate.timestamp = timestamp;
+        self.record.timestamp_decision_made = timestamp;
+        self
+    }
+
+    pub fn creator(mut self, creator: &str) -> Self {
+        self.record.scored_candidate.base.creator = creator.to_string();
+        self
+    }
+
+    pub fn program(mut self, program: &str) -> Self {
+        self.record.scored_candidate.base.program = program.to_string();
+        self
+    }
+
+    pub fn funding_source(mut self, funding_source: &str) -> Self {
+        self.record.scored_candidate.base.funding_source = Some(funding_source.to_string());
+        self
+    }
+
+    pub fn score(mut self, score: u8) -> Self {
+        self.record.scored_candidate.predicted_score = Score::clamped(score);
+        self
+    }
+
+    pub fn feature(mut self, name: &str, value: f64) -> Self {
+        self.record.scored_candidate.feature_scores.insert(name.to_string(), value);
+        self
+    }
+
+    pub fn market_context(mut self, key: &str, value: f64) -> Self {
+        self.record.market_context_snapshot.insert(key.to_string(), value);
+        self
+    }
+
+    pub fn signature(mut self, signature: &str) -> Self {
+        self.record.transaction_signature = Some(signature.to_string());
+        self
+    }
+
+    pub fn sol_spent(mut self, sol: f64) -> Self {
+        self.record.initial_sol_spent = Some(sol);
+        self
+    }
+
+    pub fn sol_received(mut self, sol: f64) -> Self {
+        self.record.final_sol_received = Some(sol);
+        self
+    }
+
+    pub fn outcome(mut self, outcome: Outcome) -> Self {
+        self.record.actual_outcome = outcome;
+        self
+    }
+
+    pub fn build(self) -> TransactionRecord {
+        self.record
+    }
+}
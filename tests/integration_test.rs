@@ -1,8 +1,16 @@
 //! Tests for the DecisionLedger system
 
+mod common;
+
+use common::{isolated_ledger, isolated_normalized_ledger, RecordBuilder};
+use h_5n1p3r::oracle::anomaly::{AnomalyReport, AnomalyType};
+use h_5n1p3r::oracle::transaction_monitor::MonitoredTransaction;
 use h_5n1p3r::oracle::{
-    DecisionLedger, TransactionRecord, Outcome, ScoredCandidate,
+    DecisionLedger, LedgerStorage, SqliteLedger, SqliteLedgerNormalized, TransactionRecord, Outcome,
+    OutcomeTransition, OutcomeUpdate, ReasonCode, Score, ScoreBandStats, ScoredCandidate, SkippedCandidate,
+    TradeAction,
 };
+use std::sync::Arc;
 use h_5n1p3r::types::PremintCandidate;
 use std::collections::HashMap;
 use tokio::sync::mpsc;
@@ -27,17 +35,23 @@ async fn test_decision_ledger_basic_functionality() {
         timestamp: chrono::Utc::now().timestamp_millis() as u64,
         instruction_summary: Some("Test instruction".to_string()),
         is_jito_bundle: Some(true),
+        funding_source: None,
     };
 
     let scored_candidate = ScoredCandidate {
         base: candidate.clone(),
         mint: candidate.mint.clone(),
-        predicted_score: 85,
+        predicted_score: Score::clamped(85),
         reason: "Test scoring".to_string(),
         feature_scores: HashMap::new(),
         calculation_time: 100_000,
         anomaly_detected: false,
         timestamp: candidate.timestamp,
+        data_source_timestamps: HashMap::new(),
+        scoring_trace: None,
+        reason_codes: Vec::new(),
+        anomaly_report: None,
+        action: TradeAction::Skip,
     };
 
     // Create a transaction record
@@ -67,20 +81,669 @@ async fn test_decision_ledger_basic_functionality() {
     decision_sender.send(record).await.expect("Failed to send record");
 
     // Update the outcome
-    outcome_sender.send((
-        "TestSignature123".to_string(),
-        Outcome::Profit(0.1),
-        Some(0.001),
-        Some(0.0011),
-        Some(1.0),
-        Some(1.1),
-        Some(candidate.timestamp + 5000),
-        true, // This is a verified outcome in the test
-    )).await.expect("Failed to send outcome update");
+    outcome_sender.send(OutcomeUpdate {
+        signature: "TestSignature123".to_string(),
+        outcome: Outcome::Profit(0.1),
+        buy_price_sol: Some(0.001),
+        sell_price_sol: Some(0.0011),
+        initial_sol_spent: Some(1.0),
+        final_sol_received: Some(1.1),
+        timestamp_evaluated: Some(candidate.timestamp + 5000),
+        is_verified: true, // This is a verified outcome in the test
+        outcome_reason: None,
+        low_confidence: false,
+    }).await.expect("Failed to send outcome update");
 
     // Give the system a moment to process
     tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
 
     // The test passes if no panics occur during the operation
     println!("DecisionLedger test completed successfully!");
-}
\ No newline at end of file
+}
+
+fn create_decision(mint: &str, decided_at: u64, outcome: Outcome) -> TransactionRecord {
+    RecordBuilder::new(mint)
+        .decided_at(decided_at)
+        .score(80)
+        .signature(&format!("{}_sig", mint))
+        .sol_spent(1.0)
+        .outcome(outcome)
+        .build()
+}
+
+#[tokio::test]
+async fn test_get_unresolved_decisions_filters_by_age_and_outcome() {
+    let storages: Vec<Arc<dyn LedgerStorage>> = vec![
+        SqliteLedger::new().await.expect("Failed to create flat storage"),
+        SqliteLedgerNormalized::new().await.expect("Failed to create normalized storage"),
+    ];
+
+    for storage in storages {
+        let tag = format!("unresolved_{}", rand::random::<u64>());
+        let stuck = format!("{}_stuck", tag);
+        storage.insert_record(&create_decision(&stuck, 100, Outcome::PendingConfirmation)).await.unwrap();
+        storage.insert_record(&create_decision(&format!("{}_recent", tag), 900, Outcome::PendingConfirmation)).await.unwrap();
+        storage.insert_record(&create_decision(&format!("{}_resolved", tag), 100, Outcome::Loss(-0.5))).await.unwrap();
+
+        let unresolved: Vec<String> = storage
+            .get_unresolved_decisions(500)
+            .await
+            .expect("Failed to query unresolved decisions")
+            .into_iter()
+            .map(|r| r.scored_candidate.mint)
+            .filter(|mint| mint.starts_with(&tag))
+            .collect();
+
+        assert_eq!(unresolved, vec![stuck]);
+    }
+}
+
+#[tokio::test]
+async fn test_normalized_unresolved_decisions_include_trades_without_outcome() {
+    let storage = SqliteLedgerNormalized::new().await.expect("Failed to create normalized storage");
+
+    let mint = format!("no_outcome_{}", rand::random::<u64>());
+    storage.insert_record(&create_decision(&mint, 100, Outcome::PendingConfirmation)).await.unwrap();
+    sqlx::query("UPDATE trades SET final_outcome = NULL WHERE mint = ?")
+        .bind(&mint)
+        .execute(storage.get_db_pool())
+        .await
+        .expect("Failed to clear outcome");
+
+    let unresolved = storage.get_unresolved_decisions(500).await.expect("Failed to query unresolved decisions");
+    let record = unresolved
+        .iter()
+        .find(|r| r.scored_candidate.mint == mint)
+        .expect("Trade without an outcome should be unresolved");
+    assert_eq!(record.actual_outcome, Outcome::PendingConfirmation);
+}
+
+#[tokio::test]
+async fn test_update_outcomes_batch_persists_all_updates() {
+    let storages: Vec<Arc<dyn LedgerStorage>> = vec![
+        SqliteLedger::new().await.expect("Failed to create flat storage"),
+        SqliteLedgerNormalized::new().await.expect("Failed to create normalized storage"),
+    ];
+
+    for storage in storages {
+        let tag = format!("batch_{}", rand::random::<u64>());
+        let mints: Vec<String> = (0..3).map(|i| format!("{}_{}", tag, i)).collect();
+        for mint in &mints {
+            storage.insert_record(&create_decision(mint, 100, Outcome::PendingConfirmation)).await.unwrap();
+        }
+
+        let updates: Vec<OutcomeUpdate> = mints
+            .iter()
+            .enumerate()
+            .map(|(i, mint)| {
+                let received = 1.0 + i as f64 * 0.5;
+                OutcomeUpdate {
+                    signature: format!("{}_sig", mint),
+                    outcome: Outcome::Profit(received - 1.0),
+                    buy_price_sol: Some(0.001),
+                    sell_price_sol: Some(received / 1000.0),
+                    initial_sol_spent: Some(1.0),
+                    final_sol_received: Some(received),
+                    timestamp_evaluated: Some(200),
+                    is_verified: true,
+                    outcome_reason: None,
+                    low_confidence: false,
+                }
+            })
+            .collect();
+
+        storage.update_outcomes_batch(&updates).await.expect("Batch update failed");
+
+        for update in &updates {
+            let record = storage
+                .get_record_by_signature(&update.signature)
+                .await
+                .unwrap()
+                .expect("Record missing");
+            assert_eq!(record.actual_outcome, update.outcome);
+            assert_eq!(record.final_sol_received, update.final_sol_received);
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_outcome_history_records_each_transition_with_reason() {
+    let storages: Vec<Arc<dyn LedgerStorage>> = vec![
+        SqliteLedger::new().await.expect("Failed to create flat storage"),
+        SqliteLedgerNormalized::new().await.expect("Failed to create normalized storage"),
+    ];
+
+    for storage in storages {
+        let mint = format!("transitions_{}", rand::random::<u64>());
+        let signature = format!("{}_sig", mint);
+        storage.insert_record(&create_decision(&mint, 100, Outcome::PendingConfirmation)).await.unwrap();
+
+        storage
+            .update_outcomes_batch(&[
+                OutcomeUpdate::unverified(signature.clone(), Outcome::ConfirmationTimeout, 200)
+                    .with_reason("Monitoring window expired"),
+            ])
+            .await
+            .unwrap();
+        storage
+            .update_outcome(
+                &signature,
+                Outcome::Loss(-0.3),
+                None,
+                None,
+                Some(1.0),
+                Some(0.7),
+                Some(300),
+                true,
+                Some("Late confirmation found on-chain".to_string()),
+            )
+            .await
+            .unwrap();
+
+        let history = storage.get_outcome_history(&signature).await.unwrap();
+        assert_eq!(
+            history,
+            vec![
+                OutcomeTransition {
+                    signature: signature.clone(),
+                    from: Outcome::PendingConfirmation,
+                    to: Outcome::ConfirmationTimeout,
+                    reason: Some("Monitoring window expired".to_string()),
+                    timestamp: 200,
+                },
+                OutcomeTransition {
+                    signature: signature.clone(),
+                    from: Outcome::ConfirmationTimeout,
+                    to: Outcome::Loss(-0.3),
+                    reason: Some("Late confirmation found on-chain".to_string()),
+                    timestamp: 300,
+                },
+            ]
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_rejections_round_trip_by_reason_code() {
+    let storages: Vec<Arc<dyn LedgerStorage>> = vec![
+        SqliteLedger::new().await.expect("Failed to create flat storage"),
+        SqliteLedgerNormalized::new().await.expect("Failed to create normalized storage"),
+    ];
+
+    for storage in storages {
+        let tag = format!("rejected_{}", rand::random::<u64>());
+        let since = chrono::Utc::now().timestamp_millis() as u64;
+        let expected = [
+            (format!("{}_a", tag), ReasonCode::RateLimited, since + 1),
+            (format!("{}_b", tag), ReasonCode::BelowBuyThreshold, since + 2),
+            (format!("{}_c", tag), ReasonCode::RateLimited, since + 3),
+        ];
+
+        // An older rejection falls outside the queried window
+        storage.record_rejection(&format!("{}_old", tag), ReasonCode::RateLimited, since - 1).await.unwrap();
+        for (mint, reason_code, timestamp) in &expected {
+            storage.record_rejection(mint, *reason_code, *timestamp).await.unwrap();
+        }
+
+        let rejections: Vec<_> = storage
+            .get_rejections_since(since)
+            .await
+            .unwrap()
+            .into_iter()
+            .filter(|r| r.mint.starts_with(&tag))
+            .collect();
+
+        assert_eq!(rejections.len(), expected.len());
+        for (rejection, (mint, reason_code, timestamp)) in rejections.iter().zip(&expected) {
+            assert_eq!(&rejection.mint, mint);
+            assert_eq!(rejection.reason_code, *reason_code);
+            assert_eq!(rejection.timestamp, *timestamp);
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_feature_history_for_mint_is_ordered_by_decision_time() {
+    let storages: Vec<Arc<dyn LedgerStorage>> = vec![
+        SqliteLedger::new().await.expect("Failed to create flat storage"),
+        SqliteLedgerNormalized::new().await.expect("Failed to create normalized storage"),
+    ];
+
+    for storage in storages {
+        let mint = format!("history_{}", rand::random::<u64>());
+
+        // Insert the later decision first; the history must still come back in decision order
+        for (decided_at, liquidity) in [(200, 0.8), (100, 0.3)] {
+            let mut record = create_decision(&mint, decided_at, Outcome::PendingConfirmation);
+            record.transaction_signature = Some(format!("{}_sig_{}", mint, decided_at));
+            record.scored_candidate.feature_scores.insert("liquidity".to_string(), liquidity);
+            storage.insert_record(&record).await.unwrap();
+        }
+
+        let history = storage.get_feature_history_for_mint(&mint).await.unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].0, 100);
+        assert_eq!(history[0].1.get("liquidity"), Some(&0.3));
+        assert_eq!(history[1].0, 200);
+        assert_eq!(history[1].1.get("liquidity"), Some(&0.8));
+    }
+}
+
+#[tokio::test]
+async fn test_win_rate_by_program() {
+    let storages: Vec<Arc<dyn LedgerStorage>> = vec![
+        SqliteLedger::new().await.expect("Failed to create flat storage"),
+        SqliteLedgerNormalized::new().await.expect("Failed to create normalized storage"),
+    ];
+
+    for storage in storages {
+        let tag = rand::random::<u64>();
+        let winner = format!("winner_{}", tag);
+        let loser = format!("loser_{}", tag);
+
+        // 3 of 4 closed trades win on one program, 1 of 4 on the other
+        let trades = [
+            (&winner, Outcome::Profit(1.0)),
+            (&winner, Outcome::Profit(0.5)),
+            (&winner, Outcome::Profit(0.2)),
+            (&winner, Outcome::Loss(-0.3)),
+            (&loser, Outcome::Profit(0.1)),
+            (&loser, Outcome::Loss(-0.4)),
+            (&loser, Outcome::Loss(-0.6)),
+            (&loser, Outcome::Loss(-0.2)),
+            // Open and unexecuted trades are not closed, so they don't count
+            (&winner, Outcome::PendingConfirmation),
+            (&loser, Outcome::NotExecuted),
+        ];
+        for (i, (program, outcome)) in trades.into_iter().enumerate() {
+            let mint = format!("{}_{}", program, i);
+            let mut record = create_decision(&mint, 1_000 + i as u64, outcome);
+            record.scored_candidate.base.program = program.clone();
+            storage.insert_record(&record).await.unwrap();
+        }
+
+        let win_rates = storage.get_win_rate_by_program(1_000).await.unwrap();
+        assert_eq!(win_rates.get(&winner), Some(&0.75));
+        assert_eq!(win_rates.get(&loser), Some(&0.25));
+
+        // Trades decided before the window are ignored
+        let win_rates = storage.get_win_rate_by_program(1_005).await.unwrap();
+        assert_eq!(win_rates.get(&winner), None);
+        assert_eq!(win_rates.get(&loser), Some(&0.0));
+    }
+}
+
+#[tokio::test]
+async fn test_score_outcome_calibration() {
+    let storages: Vec<Arc<dyn LedgerStorage>> = vec![
+        SqliteLedger::new().await.expect("Failed to create flat storage"),
+        SqliteLedgerNormalized::new().await.expect("Failed to create normalized storage"),
+    ];
+
+    for storage in storages {
+        // The database is shared across tests and runs, so decide after everything else in it
+        let since = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos() as u64;
+
+        let trades = [
+            (80, Outcome::Profit(1.0)),
+            (85, Outcome::Profit(0.5)),
+            (89, Outcome::Loss(-0.3)),
+            (95, Outcome::Loss(-1.0)),
+            (100, Outcome::Profit(2.0)),
+            (55, Outcome::Loss(-0.6)),
+            // Open and unexecuted trades are not closed, so they don't count
+            (72, Outcome::PendingConfirmation),
+            (81, Outcome::NotExecuted),
+        ];
+        for (i, (score, outcome)) in trades.into_iter().enumerate() {
+            let mut record = create_decision(&format!("calibration_{}_{}", since, i), since + i as u64, outcome);
+            record.scored_candidate.predicted_score = Score::clamped(score);
+            storage.insert_record(&record).await.unwrap();
+        }
+
+        let calibration = storage.get_score_outcome_calibration(since).await.unwrap();
+        let bands: Vec<(u8, u8, u64)> = calibration
+            .iter()
+            .map(|band| (band.min_score, band.max_score, band.trades))
+            .collect();
+        assert_eq!(bands, vec![(50, 59, 1), (80, 89, 3), (90, 100, 2)]);
+
+        let assert_band = |band: &ScoreBandStats, win_rate: f64, average_pnl: f64| {
+            assert!((band.win_rate - win_rate).abs() < 1e-9, "win rate {:?}", band);
+            assert!((band.average_pnl - average_pnl).abs() < 1e-9, "average PnL {:?}", band);
+        };
+        assert_band(&calibration[0], 0.0, -0.6);
+        assert_band(&calibration[1], 2.0 / 3.0, 0.4);
+        assert_band(&calibration[2], 0.5, 0.5);
+    }
+}
+
+#[tokio::test]
+async fn test_skipped_candidate_keeps_its_reason_code_in_the_ledger() {
+    let storages: Vec<Arc<dyn LedgerStorage>> = vec![isolated_ledger().await, isolated_normalized_ledger().await];
+
+    for storage in storages {
+        let skipped = SkippedCandidate {
+            candidate: create_decision("SkippedMint", 1000, Outcome::NotExecuted).scored_candidate.base,
+            reason_code: ReasonCode::Stale,
+            timestamp: 1000,
+        };
+        let id = storage.insert_record(&skipped.into_record()).await.unwrap();
+
+        let stored = storage.get_record_by_id(id).await.unwrap().expect("skip was not recorded");
+        assert_eq!(stored.scored_candidate.reason_codes, vec![ReasonCode::Stale]);
+        assert_eq!(stored.scored_candidate.action, TradeAction::Skip);
+        assert!(stored.is_skipped_candidate());
+    }
+}
+
+#[tokio::test]
+async fn test_score_variance_for_mint() {
+    let storages: Vec<Arc<dyn LedgerStorage>> = vec![isolated_ledger().await, isolated_normalized_ledger().await];
+
+    for storage in storages {
+        let mint = "VarianceMint";
+
+        // Scores 60, 70 and 80 average 70 with a variance of 200 / 3
+        for (i, score) in [60, 70, 80].into_iter().enumerate() {
+            let mut record = create_decision(mint, 1000 + i as u64, Outcome::NotExecuted);
+            record.transaction_signature = Some(format!("{}_sig_{}", mint, i));
+            record.scored_candidate.predicted_score = Score::clamped(score);
+            storage.insert_record(&record).await.unwrap();
+        }
+
+        // Skipped candidates are not scored, so their zero score does not count
+        let skipped = SkippedCandidate {
+            candidate: create_decision(mint, 2000, Outcome::NotExecuted).scored_candidate.base,
+            reason_code: ReasonCode::RateLimited,
+            timestamp: 2000,
+        };
+        storage.insert_record(&skipped.into_record()).await.unwrap();
+
+        let variance = storage.score_variance_for_mint(mint).await.unwrap().unwrap();
+        assert!((variance - 200.0 / 3.0).abs() < 1e-9, "variance {}", variance);
+
+        // One decision is not enough to measure stability, even when recorded
+        // again once its transaction is sent
+        let once = "VarianceOnceMint";
+        storage.insert_record(&create_decision(once, 1000, Outcome::NotExecuted)).await.unwrap();
+        let mut sent = create_decision(once, 1000, Outcome::PendingConfirmation);
+        sent.transaction_signature = Some("VarianceOnceMint_sent".to_string());
+        storage.insert_record(&sent).await.unwrap();
+        assert_eq!(storage.score_variance_for_mint(once).await.unwrap(), None);
+    }
+}
+
+#[tokio::test]
+async fn test_max_lookback_clamps_records_since() {
+    let hour_ms = 3_600_000;
+    let capped: Vec<Arc<dyn LedgerStorage>> = vec![
+        SqliteLedger::new_with_max_lookback(Some(3_600)).await.expect("Failed to create flat storage"),
+        SqliteLedgerNormalized::new_with_max_lookback(Some(3_600)).await.expect("Failed to create normalized storage"),
+    ];
+    let uncapped: Vec<Arc<dyn LedgerStorage>> = vec![
+        SqliteLedger::new().await.expect("Failed to create flat storage"),
+        SqliteLedgerNormalized::new().await.expect("Failed to create normalized storage"),
+    ];
+
+    for (capped, uncapped) in capped.into_iter().zip(uncapped) {
+        let now = chrono::Utc::now().timestamp_millis() as u64;
+        let old_mint = format!("lookback_old_{}", now);
+        let recent_mint = format!("lookback_recent_{}", now);
+        capped.insert_record(&create_decision(&old_mint, now - 2 * hour_ms, Outcome::NotExecuted)).await.unwrap();
+        capped.insert_record(&create_decision(&recent_mint, now, Outcome::NotExecuted)).await.unwrap();
+
+        let mints = |records: Vec<TransactionRecord>| -> Vec<String> {
+            records.into_iter()
+                .map(|r| r.scored_candidate.mint)
+                .filter(|mint| *mint == old_mint || *mint == recent_mint)
+                .collect()
+        };
+
+        // Scanning from the beginning of time is clamped to the last hour
+        assert_eq!(mints(capped.get_records_since(0).await.unwrap()), vec![recent_mint.clone()]);
+
+        // Without a cap the whole table is scanned
+        assert_eq!(mints(uncapped.get_records_since(0).await.unwrap()), vec![old_mint.clone(), recent_mint.clone()]);
+    }
+}
+
+#[tokio::test]
+async fn test_count_recent_launches_by_creator() {
+    let storages: Vec<Arc<dyn LedgerStorage>> = vec![
+        SqliteLedger::new().await.expect("Failed to create flat storage"),
+        SqliteLedgerNormalized::new().await.expect("Failed to create normalized storage"),
+    ];
+
+    for storage in storages {
+        let run_id = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let creator = format!("creator_{}", run_id);
+        let now = chrono::Utc::now().timestamp_millis() as u64;
+
+        // Two recent launches, one of them decided twice, and one launch long ago
+        for (i, (suffix, decided_at)) in [("a", now), ("a", now), ("b", now), ("old", 1000)].into_iter().enumerate() {
+            let mint = format!("launch_{}_{}", run_id, suffix);
+            let mut record = create_decision(&mint, decided_at, Outcome::NotExecuted);
+            record.transaction_signature = Some(format!("{}_sig_{}", mint, i));
+            record.scored_candidate.base.creator = creator.clone();
+            storage.insert_record(&record).await.unwrap();
+        }
+
+        assert_eq!(storage.count_recent_launches_by_creator(&creator, 3600).await.unwrap(), 2);
+        assert_eq!(storage.count_recent_launches_by_creator("unknown_creator", 3600).await.unwrap(), 0);
+
+        let records = storage.get_records_since(now).await.unwrap();
+        assert!(records.iter().filter(|r| r.scored_candidate.base.creator == creator).count() >= 3);
+    }
+}
+
+#[tokio::test]
+async fn test_get_top_mints_by_pnl() {
+    let storages: Vec<Arc<dyn LedgerStorage>> = vec![
+        SqliteLedger::new().await.expect("Failed to create flat storage"),
+        SqliteLedgerNormalized::new().await.expect("Failed to create normalized storage"),
+    ];
+
+    for storage in storages {
+        let since = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        let trades = [
+            ("steady", Outcome::Profit(0.5)),
+            ("steady", Outcome::Profit(0.75)),
+            ("jackpot", Outcome::Profit(1.0)),
+            ("mixed", Outcome::Profit(1.5)),
+            ("mixed", Outcome::Loss(-1.0)),
+            ("rug", Outcome::Loss(-0.9)),
+            ("open", Outcome::PendingConfirmation),
+        ];
+        for (i, (name, outcome)) in trades.into_iter().enumerate() {
+            let mint = format!("{}_{}", name, since);
+            let mut record = create_decision(&mint, since + i as u64, outcome);
+            record.transaction_signature = Some(format!("{}_sig_{}", mint, i));
+            storage.insert_record(&record).await.unwrap();
+        }
+        // Too old to count
+        storage.insert_record(&create_decision(&format!("old_{}", since), since - 1, Outcome::Profit(100.0))).await.unwrap();
+
+        // Other tests write to the same database, so only this run's mints are compared
+        let top = storage.get_top_mints_by_pnl(u32::MAX, since).await.unwrap();
+        let ranked: Vec<(&str, f64)> = top.iter()
+            .filter_map(|(mint, pnl)| Some((mint.strip_suffix(&format!("_{}", since))?, *pnl)))
+            .collect();
+        assert_eq!(ranked, vec![("steady", 1.25), ("jackpot", 1.0), ("mixed", 0.5), ("rug", -0.9)]);
+        assert!(top.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+
+        let top_two = storage.get_top_mints_by_pnl(2, since).await.unwrap();
+        assert_eq!(top_two.len(), 2);
+        assert!(top_two.iter().all(|(_, pnl)| *pnl >= top[1].1));
+    }
+}
+
+#[tokio::test]
+async fn test_anomaly_report_round_trips() {
+    let storages: Vec<Arc<dyn LedgerStorage>> = vec![
+        SqliteLedger::new().await.expect("Failed to create flat storage"),
+        SqliteLedgerNormalized::new().await.expect("Failed to create normalized storage"),
+    ];
+
+    for storage in storages {
+        let now = chrono::Utc::now().timestamp_millis() as u64;
+        let report = AnomalyReport {
+            anomalies: vec![
+                AnomalyType::ActiveFreezeAuthority,
+                AnomalyType::TooFewHolders { holders: 3, minimum: 10 },
+            ],
+            anomaly_score: 0.85,
+            timestamp: now,
+        };
+
+        let flagged_mint = format!("flagged_{}", now);
+        let mut flagged = create_decision(&flagged_mint, now, Outcome::NotExecuted);
+        flagged.scored_candidate.anomaly_detected = true;
+        flagged.scored_candidate.anomaly_report = Some(report.clone());
+        storage.insert_record(&flagged).await.unwrap();
+
+        let clean_mint = format!("clean_{}", now);
+        storage.insert_record(&create_decision(&clean_mint, now, Outcome::NotExecuted)).await.unwrap();
+
+        let records = storage.get_records_since(now).await.unwrap();
+        let stored = |mint: &str| {
+            records.iter()
+                .find(|record| record.scored_candidate.mint == mint)
+                .map(|record| record.scored_candidate.clone())
+                .expect("record not stored")
+        };
+        let flagged = stored(&flagged_mint);
+        assert!(flagged.anomaly_detected);
+        assert_eq!(flagged.anomaly_report, Some(report));
+        assert_eq!(stored(&clean_mint).anomaly_report, None);
+    }
+}
+
+#[tokio::test]
+async fn test_compact_after_deletes() {
+    let storages: Vec<Arc<dyn LedgerStorage>> = vec![
+        SqliteLedger::new().await.expect("Failed to create flat storage"),
+        SqliteLedgerNormalized::new().await.expect("Failed to create normalized storage"),
+    ];
+
+    for storage in storages {
+        let tag = rand::random::<u64>();
+        for i in 0..200 {
+            let tx = MonitoredTransaction {
+                signature: format!("compact_{}_{}", tag, i),
+                mint: format!("compact_mint_{}_{}", tag, i),
+                amount_bought_tokens: 1000.0,
+                initial_sol_spent: 1.0,
+                monitor_until: 0,
+            };
+            storage.enqueue_for_monitoring(&tx).await.unwrap();
+            storage.update_monitoring_status(&tx.signature, "Completed").await.unwrap();
+        }
+        assert!(storage.cleanup_completed_monitoring().await.unwrap() >= 200);
+
+        storage.compact().await.unwrap();
+
+        assert!(storage.health_check().await.unwrap());
+        let mint = format!("compact_record_{}", tag);
+        let id = storage.insert_record(&create_decision(&mint, 1_000, Outcome::NotExecuted)).await.unwrap();
+        let record = storage.get_record_by_id(id).await.unwrap().expect("record should be readable");
+        assert_eq!(record.scored_candidate.mint, mint);
+    }
+}
+
+#[tokio::test]
+async fn test_delete_records_for_mint() {
+    let storages: Vec<Arc<dyn LedgerStorage>> = vec![
+        SqliteLedger::new().await.expect("Failed to create flat storage"),
+        SqliteLedgerNormalized::new().await.expect("Failed to create normalized storage"),
+    ];
+
+    for storage in storages {
+        let tag = rand::random::<u64>();
+        let now = chrono::Utc::now().timestamp_millis() as u64;
+        let polluting_mint = format!("purge_test_mint_{}", tag);
+        let kept_mint = format!("purge_kept_mint_{}", tag);
+
+        for (i, mint) in [&polluting_mint, &polluting_mint, &kept_mint].into_iter().enumerate() {
+            let mut record = create_decision(mint, now, Outcome::PendingConfirmation);
+            record.transaction_signature = Some(format!("{}_sig_{}", mint, i));
+            record.scored_candidate.feature_scores.insert("liquidity".to_string(), 0.5);
+            record.market_context_snapshot.insert("tps".to_string(), 2000.0);
+            storage.insert_record(&record).await.unwrap();
+        }
+        storage.update_outcomes_batch(&[
+            OutcomeUpdate::unverified(format!("{}_sig_0", polluting_mint), Outcome::ConfirmationTimeout, now),
+        ]).await.unwrap();
+
+        assert_eq!(storage.delete_records_for_mint(&polluting_mint).await.unwrap(), 2);
+
+        let remaining: Vec<_> = storage.get_records_since(now).await.unwrap()
+            .into_iter()
+            .map(|record| record.scored_candidate.mint)
+            .filter(|mint| mint == &polluting_mint || mint == &kept_mint)
+            .collect();
+        assert_eq!(remaining, vec![kept_mint.clone()]);
+        assert!(storage.get_feature_history_for_mint(&polluting_mint).await.unwrap().is_empty());
+        assert_eq!(storage.get_feature_history_for_mint(&kept_mint).await.unwrap().len(), 1);
+        assert!(storage.get_outcome_history(&format!("{}_sig_0", polluting_mint)).await.unwrap().is_empty());
+
+        // Purging again finds nothing left to delete
+        assert_eq!(storage.delete_records_for_mint(&polluting_mint).await.unwrap(), 0);
+    }
+}
+
+#[tokio::test]
+async fn test_concurrent_writers_do_not_hit_lock_errors() {
+    const WRITERS_PER_STORAGE: usize = 8;
+    const RECORDS_PER_WRITER: usize = 10;
+
+    // Separate instances have separate pools, so they race for the same file too
+    let storages: Vec<Arc<dyn LedgerStorage>> = vec![
+        SqliteLedger::new().await.expect("Failed to create flat storage"),
+        SqliteLedger::new().await.expect("Failed to create flat storage"),
+        SqliteLedgerNormalized::new().await.expect("Failed to create normalized storage"),
+        SqliteLedgerNormalized::new().await.expect("Failed to create normalized storage"),
+    ];
+    let tag = rand::random::<u64>();
+    let now = chrono::Utc::now().timestamp_millis() as u64;
+
+    let mut writers = Vec::new();
+    for (s, storage) in storages.iter().enumerate() {
+        for w in 0..WRITERS_PER_STORAGE {
+            let storage = Arc::clone(storage);
+            writers.push(tokio::spawn(async move {
+                for i in 0..RECORDS_PER_WRITER {
+                    let mint = format!("stress_{}_{}_{}_{}", tag, s, w, i);
+                    storage.insert_record(&create_decision(&mint, now, Outcome::PendingConfirmation)).await?;
+                    storage.update_outcomes_batch(&[
+                        OutcomeUpdate::unverified(format!("{}_sig", mint), Outcome::ConfirmationTimeout, now),
+                    ]).await?;
+                    storage.record_rejection(&mint, ReasonCode::Stale, now).await?;
+                }
+                anyhow::Ok(())
+            }));
+        }
+    }
+    for writer in writers {
+        writer.await.unwrap().expect("concurrent write failed");
+    }
+
+    let prefix = format!("stress_{}_", tag);
+    for (s, storage) in storages.iter().enumerate() {
+        let written = storage.get_records_since(now).await.unwrap()
+            .into_iter()
+            .filter(|record| record.scored_candidate.mint.starts_with(&format!("{}{}_", prefix, s)))
+            .inspect(|record| assert_eq!(record.actual_outcome, Outcome::ConfirmationTimeout))
+            .count();
+        assert_eq!(written, WRITERS_PER_STORAGE * RECORDS_PER_WRITER);
+    }
+}
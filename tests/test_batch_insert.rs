@@ -0,0 +1,100 @@
+//! Batch inserts into the SQLite storage backends
+
+mod common;
+
+use common::RecordBuilder;
+use h_5n1p3r::oracle::{
+    LedgerStorage, Outcome, SqliteLedger, SqliteLedgerNormalized, SqliteStorageOptions, TransactionRecord,
+};
+use std::sync::Arc;
+
+const BATCH_SIZE: usize = 1000;
+
+fn create_record(mint: &str, signature: &str, decided_at: u64) -> TransactionRecord {
+    RecordBuilder::new(mint)
+        .decided_at(decided_at)
+        .score(75)
+        .feature("liquidity", 0.7)
+        .market_context("total_volume", 1000.0)
+        .signature(signature)
+        .sol_spent(0.1)
+        .outcome(Outcome::PendingConfirmation)
+        .build()
+}
+
+fn create_batch(prefix: &str) -> Vec<TransactionRecord> {
+    (0..BATCH_SIZE)
+        .map(|i| {
+            let mint = format!("{}_{}", prefix, i);
+            create_record(&mint, &format!("{}_sig", mint), 1_000_000 + i as u64)
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_batch_insert_returns_ids_in_order() {
+    let storages: Vec<(&str, Arc<dyn LedgerStorage>)> = vec![
+        ("flat", SqliteLedger::new().await.expect("Failed to create flat storage")),
+        ("normalized", SqliteLedgerNormalized::new().await.expect("Failed to create normalized storage")),
+    ];
+
+    for (name, storage) in storages {
+        let prefix = format!("batch_{}_{}", name, rand::random::<u64>());
+        let records = create_batch(&prefix);
+
+        let ids = storage.insert_records(&records).await.expect("Failed to insert batch");
+
+        assert_eq!(ids.len(), BATCH_SIZE);
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]), "{}: IDs not in insertion order", name);
+        for i in [0, BATCH_SIZE / 2, BATCH_SIZE - 1] {
+            let stored = storage.get_record_by_id(ids[i]).await.unwrap().expect("Batch record not found by id");
+            assert_eq!(stored.scored_candidate.mint, format!("{}_{}", prefix, i));
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_failed_batch_leaves_no_records() {
+    let storage = SqliteLedgerNormalized::new().await.expect("Failed to create normalized storage");
+    let prefix = format!("batch_rollback_{}", rand::random::<u64>());
+    let mut records = create_batch(&prefix);
+
+    // Reusing the first signature half way through violates its uniqueness
+    let first_signature = records[0].transaction_signature.clone();
+    records[BATCH_SIZE / 2].transaction_signature = first_signature.clone();
+
+    assert!(storage.insert_records(&records).await.is_err());
+    assert!(storage.get_record_by_signature(first_signature.as_deref().unwrap()).await.unwrap().is_none());
+    assert!(storage.get_feature_history_for_mint(&format!("{}_0", prefix)).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_failed_flat_batch_leaves_no_records() {
+    let dir = std::env::temp_dir().join(format!("batch_rollback_{}", rand::random::<u64>()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let storage = SqliteLedger::new_with_options(SqliteStorageOptions {
+        db_path: dir.join("flat.db"),
+        ..Default::default()
+    })
+    .await
+    .expect("Failed to create flat storage");
+    let prefix = format!("batch_rollback_{}", rand::random::<u64>());
+    let records = create_batch(&prefix);
+
+    // The flat table has no unique columns, so fail the insert half way through from a trigger
+    let failing_mint = format!("{}_{}", prefix, BATCH_SIZE / 2);
+    sqlx::query(&format!(
+        "CREATE TRIGGER fail_batch BEFORE INSERT ON transaction_records WHEN NEW.mint = '{}' \
+         BEGIN SELECT RAISE(ABORT, 'rejected by test'); END",
+        failing_mint
+    ))
+    .execute(storage.get_db_pool())
+    .await
+    .unwrap();
+
+    assert!(storage.insert_records(&records).await.is_err());
+    assert_eq!(storage.get_record_count().await.unwrap(), 0);
+    assert!(storage.get_feature_history_for_mint(&format!("{}_0", prefix)).await.unwrap().is_empty());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
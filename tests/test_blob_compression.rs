@@ -0,0 +1,69 @@
+//! Compressed JSON blobs in the flat SQLite ledger
+
+mod common;
+
+use common::RecordBuilder;
+use h_5n1p3r::oracle::{
+    BlobCompression, LedgerStorage, Outcome, SqliteLedger, SqliteStorageOptions, TransactionRecord,
+};
+
+fn create_record(mint: &str, decided_at: u64) -> TransactionRecord {
+    let mut record = RecordBuilder::new(mint)
+        .decided_at(decided_at)
+        .score(70)
+        .signature(&format!("{}_sig", mint))
+        .sol_spent(0.1)
+        .outcome(Outcome::PendingConfirmation)
+        .build();
+
+    // Enough entries for the blobs to be worth compressing
+    record.scored_candidate.feature_scores = (0..50).map(|i| (format!("feature_{}", i), i as f64 / 50.0)).collect();
+    record.market_context_snapshot = (0..50).map(|i| (format!("context_{}", i), i as f64 * 1.5)).collect();
+    record
+}
+
+fn assert_blobs_intact(stored: &TransactionRecord, record: &TransactionRecord) {
+    assert_eq!(stored.scored_candidate.feature_scores, record.scored_candidate.feature_scores);
+    assert_eq!(stored.market_context_snapshot, record.market_context_snapshot);
+}
+
+#[tokio::test]
+async fn test_compressed_blobs_read_back_intact() {
+    for compression in [BlobCompression::Gzip, BlobCompression::Zstd] {
+        let storage = SqliteLedger::new_with_options(SqliteStorageOptions {
+            blob_compression: compression,
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to create storage");
+
+        let decided_at = 1_500_000 + rand::random::<u32>() as u64;
+        let mint = format!("compressed_{:?}_{}", compression, rand::random::<u64>());
+        let record = create_record(&mint, decided_at);
+        let id = storage.insert_record(&record).await.expect("Failed to insert record");
+
+        // Stored as a BLOB, with the codec recorded alongside
+        let (blob_type, codec): (String, Option<String>) =
+            sqlx::query_as("SELECT typeof(feature_scores), blob_compression FROM transaction_records WHERE id = ?")
+                .bind(id)
+                .fetch_one(storage.get_db_pool())
+                .await
+                .unwrap();
+        assert_eq!(blob_type, "blob");
+        assert_eq!(codec.as_deref(), compression.column_value());
+
+        let by_id = storage.get_record_by_id(id).await.unwrap().expect("Record not found by id");
+        assert_blobs_intact(&by_id, &record);
+        let by_signature = storage.get_record_by_signature(&format!("{}_sig", mint)).await.unwrap().unwrap();
+        assert_blobs_intact(&by_signature, &record);
+        let since = storage.get_records_since(decided_at).await.unwrap();
+        assert_blobs_intact(since.iter().find(|r| r.id == Some(id)).unwrap(), &record);
+
+        let history = storage.get_feature_history_for_mint(&mint).await.unwrap();
+        assert_eq!(history, vec![(decided_at, record.scored_candidate.feature_scores.clone())]);
+
+        // Reads follow each row's codec, not the reading storage's setting
+        let uncompressed = SqliteLedger::new().await.expect("Failed to create storage");
+        assert_blobs_intact(&uncompressed.get_record_by_id(id).await.unwrap().unwrap(), &record);
+    }
+}
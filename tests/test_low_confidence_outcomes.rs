@@ -0,0 +1,47 @@
+//! Low-confidence outcome estimates are flagged in both SQLite schemas
+
+mod common;
+
+use common::RecordBuilder;
+use h_5n1p3r::oracle::{
+    LedgerStorage, Outcome, OutcomeUpdate, SqliteLedger, SqliteLedgerNormalized, SqliteStorageOptions,
+};
+
+fn estimate(signature: &str, low_confidence: bool) -> OutcomeUpdate {
+    OutcomeUpdate {
+        low_confidence,
+        ..OutcomeUpdate::unverified(signature.to_string(), Outcome::Loss(-0.2), 2_000)
+    }
+}
+
+#[tokio::test]
+async fn test_low_confidence_flag_is_stored() {
+    let dir = std::env::temp_dir().join(format!("low_confidence_{}", rand::random::<u64>()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let options = |file: &str| SqliteStorageOptions { db_path: dir.join(file), ..Default::default() };
+
+    let flat = SqliteLedger::new_with_options(options("flat.db")).await.expect("Failed to create flat storage");
+    let normalized = SqliteLedgerNormalized::new_with_options(options("normalized.db"))
+        .await
+        .expect("Failed to create normalized storage");
+
+    let storages: [(&dyn LedgerStorage, &str); 2] = [
+        (&*flat, "SELECT low_confidence FROM transaction_records WHERE transaction_signature = ?"),
+        (&*normalized, "SELECT low_confidence FROM trades WHERE signature = ?"),
+    ];
+    let pools = [flat.get_db_pool(), normalized.get_db_pool()];
+
+    for ((storage, query), pool) in storages.into_iter().zip(pools) {
+        for (mint, low_confidence) in [("estimated", true), ("resolved", false)] {
+            let signature = format!("{}_sig", mint);
+            let record = RecordBuilder::new(mint).decided_at(1_000).signature(&signature).build();
+            storage.insert_record(&record).await.unwrap();
+            storage.update_outcomes_batch(&[estimate(&signature, low_confidence)]).await.unwrap();
+
+            let (stored,): (bool,) = sqlx::query_as(query).bind(&signature).fetch_one(pool).await.unwrap();
+            assert_eq!(stored, low_confidence, "{} outcome", mint);
+        }
+    }
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -1,9 +1,11 @@
 //! Basic test for the normalized storage implementation
 
-use h_5n1p3r::oracle::{SqliteLedgerNormalized, LedgerStorage, TransactionRecord, Outcome, ScoredCandidate};
+mod common;
+
+use common::RecordBuilder;
+use h_5n1p3r::oracle::{SqliteLedgerNormalized, LedgerStorage, TransactionRecord, Outcome, Score, ScoredCandidate, TradeAction};
 use h_5n1p3r::types::PremintCandidate;
 use std::collections::HashMap;
-use rand;
 
 #[tokio::test]
 async fn test_normalized_storage_basic() {
@@ -28,14 +30,20 @@ async fn test_normalized_storage_basic() {
             timestamp: 1000000,
             instruction_summary: Some("Test instruction".to_string()),
             is_jito_bundle: Some(true),
+            funding_source: None,
         },
         mint: "test_mint_123".to_string(),
-        predicted_score: 85,
+        predicted_score: Score::clamped(85),
         reason: "Test reason".to_string(),
         feature_scores,
         calculation_time: 1234,
         anomaly_detected: false,
         timestamp: 1000000,
+        data_source_timestamps: HashMap::new(),
+        scoring_trace: None,
+        reason_codes: Vec::new(),
+        anomaly_report: None,
+        action: TradeAction::Skip,
     };
 
     let signature = format!("test_signature_{}", rand::random::<u64>());
@@ -74,7 +82,8 @@ async fn test_normalized_storage_basic() {
         &signature,
         Outcome::Profit(0.2),
         None, None, None, Some(1.2), Some(1000003),
-        true // Mark as verified for the test
+        true, // Mark as verified for the test
+        None,
     ).await.expect("Failed to update outcome");
 
     // Test record count (should be at least 1, might have other records from other tests)
@@ -86,4 +95,141 @@ async fn test_normalized_storage_basic() {
     assert!(healthy);
 
     println!("Normalized storage test completed successfully!");
-}
\ No newline at end of file
+}
+
+#[tokio::test]
+async fn test_recompute_pnl_fixes_stale_value() {
+    let storage = SqliteLedgerNormalized::new().await.expect("Failed to create normalized storage");
+
+    let signature = format!("stale_pnl_{}", rand::random::<u64>());
+    let mint = format!("stale_pnl_mint_{}", rand::random::<u64>());
+    let record = RecordBuilder::new(&mint)
+        .decided_at(2000000)
+        .score(70)
+        .signature(&signature)
+        .sol_spent(1.0)
+        .sol_received(1.5)
+        .outcome(Outcome::Profit(0.5))
+        .build();
+    storage.insert_record(&record).await.expect("Failed to insert record");
+
+    // Simulate a record written with a wrong PnL by an older version
+    sqlx::query("UPDATE trades SET pnl_sol = 1.5 WHERE signature = ?")
+        .bind(&signature)
+        .execute(storage.get_db_pool())
+        .await
+        .expect("Failed to write stale pnl");
+
+    let changed = storage.recompute_pnl().await.expect("Failed to recompute pnl");
+    assert!(changed >= 1);
+
+    let (pnl,): (f64,) = sqlx::query_as("SELECT pnl_sol FROM trades WHERE signature = ?")
+        .bind(&signature)
+        .fetch_one(storage.get_db_pool())
+        .await
+        .expect("Failed to read pnl");
+    assert!((pnl - 0.5).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn test_normalized_storage_round_trips_full_candidate() {
+    let storage = SqliteLedgerNormalized::new().await.expect("Failed to create normalized storage");
+
+    let mint = format!("full_candidate_mint_{}", rand::random::<u64>());
+    let mut feature_scores = HashMap::new();
+    feature_scores.insert("liquidity".to_string(), 0.75);
+    feature_scores.insert("tx_per_holder".to_string(), 0.4);
+    let mut market_context = HashMap::new();
+    market_context.insert("sol_price".to_string(), 142.5);
+
+    let record = TransactionRecord {
+        id: None,
+        scored_candidate: ScoredCandidate {
+            base: PremintCandidate {
+                mint: mint.clone(),
+                creator: "full_candidate_creator".to_string(),
+                program: "pump.fun".to_string(),
+                slot: 287_654_321,
+                // Seen on chain a little before the decision
+                timestamp: 3_000_000,
+                instruction_summary: Some("create + buy".to_string()),
+                is_jito_bundle: Some(true),
+                funding_source: Some("full_candidate_funder".to_string()),
+            },
+            mint: mint.clone(),
+            predicted_score: Score::clamped(91),
+            reason: "Strong liquidity, bundled launch".to_string(),
+            feature_scores,
+            calculation_time: 12_345,
+            // Flagged without a detailed report
+            anomaly_detected: true,
+            timestamp: 3_000_250,
+            data_source_timestamps: HashMap::new(),
+            scoring_trace: None,
+            reason_codes: Vec::new(),
+            anomaly_report: None,
+            action: TradeAction::Skip,
+        },
+        transaction_signature: Some(format!("{}_sig", mint)),
+        buy_price_sol: None,
+        sell_price_sol: None,
+        amount_bought_tokens: None,
+        amount_sold_tokens: None,
+        initial_sol_spent: Some(0.5),
+        final_sol_received: None,
+        timestamp_decision_made: 3_000_250,
+        timestamp_transaction_sent: None,
+        timestamp_outcome_evaluated: None,
+        actual_outcome: Outcome::PendingConfirmation,
+        market_context_snapshot: market_context,
+    };
+    let id = storage.insert_record(&record).await.expect("Failed to insert record");
+
+    let stored = storage.get_record_by_id(id).await.unwrap().expect("Record not found");
+    let (expected, actual) = (&record.scored_candidate, &stored.scored_candidate);
+    assert_eq!(actual.base.mint, expected.base.mint);
+    assert_eq!(actual.base.creator, expected.base.creator);
+    assert_eq!(actual.base.program, expected.base.program);
+    assert_eq!(actual.base.slot, expected.base.slot);
+    assert_eq!(actual.base.timestamp, expected.base.timestamp);
+    assert_eq!(actual.base.instruction_summary, expected.base.instruction_summary);
+    assert_eq!(actual.base.is_jito_bundle, expected.base.is_jito_bundle);
+    assert_eq!(actual.base.funding_source, expected.base.funding_source);
+    assert_eq!(actual.mint, expected.mint);
+    assert_eq!(actual.predicted_score, expected.predicted_score);
+    assert_eq!(actual.reason, expected.reason);
+    assert_eq!(actual.feature_scores, expected.feature_scores);
+    assert_eq!(actual.calculation_time, expected.calculation_time);
+    assert_eq!(actual.anomaly_detected, expected.anomaly_detected);
+    assert_eq!(actual.anomaly_report, expected.anomaly_report);
+    assert_eq!(stored.transaction_signature, record.transaction_signature);
+    assert_eq!(stored.initial_sol_spent, record.initial_sol_spent);
+    assert_eq!(stored.timestamp_decision_made, record.timestamp_decision_made);
+    assert_eq!(stored.actual_outcome, record.actual_outcome);
+    assert_eq!(stored.market_context_snapshot, record.market_context_snapshot);
+
+    // Rows written before the whole candidate was stored read back with defaults
+    sqlx::query(
+        r#"
+        UPDATE trades
+        SET slot = NULL, candidate_timestamp = NULL, instruction_summary = NULL, is_jito_bundle = NULL,
+            reason = NULL, calculation_time = NULL, anomaly_detected = NULL
+        WHERE id = ?
+        "#
+    )
+    .bind(id)
+    .execute(storage.get_db_pool())
+    .await
+    .expect("Failed to clear candidate columns");
+
+    let legacy = storage.get_record_by_id(id).await.unwrap().expect("Record not found");
+    let candidate = &legacy.scored_candidate;
+    assert_eq!(candidate.base.slot, 0);
+    assert_eq!(candidate.base.timestamp, record.timestamp_decision_made);
+    assert_eq!(candidate.base.instruction_summary, None);
+    assert_eq!(candidate.base.is_jito_bundle, None);
+    assert_eq!(candidate.reason, "");
+    assert_eq!(candidate.calculation_time, 0);
+    assert!(!candidate.anomaly_detected);
+    assert_eq!(candidate.base.creator, "full_candidate_creator");
+}
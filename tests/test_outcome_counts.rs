@@ -0,0 +1,74 @@
+//! Outcome count breakdown across the storage backends
+//!
+//! The counts cover every decision in the ledger, so each storage gets a
+//! temporary database no other test writes to.
+
+mod common;
+
+use common::RecordBuilder;
+use h_5n1p3r::oracle::{
+    LedgerStorage, Outcome, SqliteLedger, SqliteLedgerNormalized, SqliteStorageOptions, TransactionRecord,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+fn create_decision(mint: &str, decided_at: u64, outcome: Outcome) -> TransactionRecord {
+    RecordBuilder::new(mint)
+        .decided_at(decided_at)
+        .score(80)
+        .signature(&format!("{}_sig", mint))
+        .outcome(outcome)
+        .build()
+}
+
+#[tokio::test]
+async fn test_outcome_counts_group_by_variant() {
+    let dir = std::env::temp_dir().join(format!("outcome_counts_{}", rand::random::<u64>()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let options = |file: &str| SqliteStorageOptions { db_path: dir.join(file), ..Default::default() };
+
+    let storages: Vec<Arc<dyn LedgerStorage>> = vec![
+        SqliteLedger::new_with_options(options("flat.db")).await.expect("Failed to create flat storage"),
+        SqliteLedgerNormalized::new_with_options(options("normalized.db"))
+            .await
+            .expect("Failed to create normalized storage"),
+    ];
+
+    for (run, storage) in storages.into_iter().enumerate() {
+        let since = 10_000;
+        let outcomes = [
+            Outcome::Profit(1.0),
+            Outcome::Profit(0.25),
+            Outcome::Loss(-0.5),
+            Outcome::NotExecuted,
+            Outcome::NotExecuted,
+            Outcome::NotExecuted,
+            Outcome::PendingConfirmation,
+            Outcome::ExecutionError("slippage exceeded".to_string()),
+            Outcome::ExecutionError("blockhash expired".to_string()),
+        ];
+        for (i, outcome) in outcomes.into_iter().enumerate() {
+            let record = create_decision(&format!("outcome_count_{}_{}", run, i), since + i as u64, outcome);
+            storage.insert_record(&record).await.unwrap();
+        }
+        // Decided before `since`, so not counted
+        storage.insert_record(&create_decision(&format!("outcome_count_{}_early", run), since - 1, Outcome::Neutral))
+            .await
+            .unwrap();
+
+        let counts = storage.get_outcome_counts(since).await.unwrap();
+        let expected: HashMap<String, u64> = [
+            ("Profit", 2),
+            ("Loss", 1),
+            ("NotExecuted", 3),
+            ("PendingConfirmation", 1),
+            ("ExecutionError", 2),
+        ]
+        .into_iter()
+        .map(|(variant, count)| (variant.to_string(), count))
+        .collect();
+        assert_eq!(counts, expected);
+    }
+
+    std::fs::remove_dir_all(&dir).ok();
+}
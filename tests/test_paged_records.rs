@@ -0,0 +1,81 @@
+//! Paging and time-range filtering of stored records
+//!
+//! The records are decided in the future, so each storage gets a temporary
+//! database where they cannot show up in other tests' queries.
+
+mod common;
+
+use common::RecordBuilder;
+use h_5n1p3r::oracle::{
+    LedgerStorage, SqliteLedger, SqliteLedgerNormalized, SqliteStorageOptions, TransactionRecord,
+};
+use std::sync::Arc;
+
+fn create_record(mint: &str, decided_at: u64) -> TransactionRecord {
+    RecordBuilder::new(mint)
+        .decided_at(decided_at)
+        .score(60)
+        .signature(&format!("{}_sig", mint))
+        .build()
+}
+
+fn mints(records: &[TransactionRecord]) -> Vec<String> {
+    records.iter().map(|r| r.scored_candidate.mint.clone()).collect()
+}
+
+#[tokio::test]
+async fn test_records_are_paged_within_time_range() {
+    let dir = std::env::temp_dir().join(format!("paged_records_{}", rand::random::<u64>()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let options = |file: &str| SqliteStorageOptions { db_path: dir.join(file), ..Default::default() };
+
+    let storages: Vec<(&str, Arc<dyn LedgerStorage>)> = vec![
+        ("flat", SqliteLedger::new_with_options(options("flat.db")).await.expect("Failed to create flat storage")),
+        (
+            "normalized",
+            SqliteLedgerNormalized::new_with_options(options("normalized.db"))
+                .await
+                .expect("Failed to create normalized storage"),
+        ),
+    ];
+
+    for (name, storage) in storages {
+        let base = 4_000_000_000_000 + rand::random::<u32>() as u64 * 100;
+        let tag = format!("paging_{}_{}", name, base);
+        let offsets = [0, 10, 10, 20, 30];
+        let records: Vec<_> = offsets
+            .iter()
+            .enumerate()
+            .map(|(i, offset)| create_record(&format!("{}_{}", tag, i), base + offset))
+            .collect();
+        storage.insert_records(&records).await.unwrap();
+        let name_of = |i: usize| format!("{}_{}", tag, i);
+
+        // Pages of two in [base, base + 30): the last record is out of range
+        let page = |offset| storage.get_records_paged(base, base + 30, offset, 2);
+        assert_eq!(mints(&page(0).await.unwrap()), [name_of(0), name_of(1)], "{}", name);
+        assert_eq!(mints(&page(2).await.unwrap()), [name_of(2), name_of(3)], "{}", name);
+        assert!(page(4).await.unwrap().is_empty(), "{}", name);
+
+        // Partial last page once the end bound includes the last record
+        let last = storage.get_records_paged(base, base + 31, 4, 2).await.unwrap();
+        assert_eq!(mints(&last), [name_of(4)], "{}", name);
+        assert!(storage.get_records_paged(base, base + 31, 100, 2).await.unwrap().is_empty());
+        assert!(storage.get_records_paged(base + 31, base, 0, 10).await.unwrap().is_empty());
+
+        // `since` is inclusive, `until` exclusive
+        let ranged = storage.get_records_paged(base + 10, base + 20, 0, 10).await.unwrap();
+        assert_eq!(mints(&ranged), [name_of(1), name_of(2)], "{}", name);
+        let ranged = storage.get_records_paged(base + 10, base + 21, 0, 10).await.unwrap();
+        assert_eq!(mints(&ranged), [name_of(1), name_of(2), name_of(3)], "{}", name);
+
+        // `get_records_since` returns every page in order
+        let since: Vec<_> = mints(&storage.get_records_since(base).await.unwrap())
+            .into_iter()
+            .filter(|mint| mint.starts_with(&tag))
+            .collect();
+        assert_eq!(since, (0..offsets.len()).map(name_of).collect::<Vec<_>>(), "{}", name);
+    }
+
+    std::fs::remove_dir_all(&dir).ok();
+}
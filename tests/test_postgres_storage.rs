@@ -0,0 +1,131 @@
+//! Tests for the PostgreSQL storage implementation
+//!
+//! These need a running database, so they are ignored by default: set
+//! `TEST_POSTGRES_URL` (e.g. `postgres://postgres@localhost/postgres`) and run
+//! `cargo test -- --ignored` to include them.
+
+mod common;
+
+use common::RecordBuilder;
+use h_5n1p3r::oracle::{PostgresLedger, LedgerStorage, TransactionRecord, Outcome};
+use std::sync::Arc;
+
+async fn connect() -> Arc<PostgresLedger> {
+    let dsn = std::env::var("TEST_POSTGRES_URL").expect("TEST_POSTGRES_URL must point at a PostgreSQL database");
+    PostgresLedger::new(&dsn).await.expect("Failed to create PostgreSQL storage")
+}
+
+fn create_record(mint: &str, signature: &str) -> TransactionRecord {
+    RecordBuilder::new(mint)
+        .decided_at(1000000)
+        .funding_source("test_funder")
+        .score(85)
+        .feature("liquidity", 0.8)
+        .feature("holder_distribution", 0.6)
+        .market_context("total_volume", 1000.0)
+        .market_context("price_trend", 0.5)
+        .signature(signature)
+        .sol_spent(1.0)
+        .outcome(Outcome::PendingConfirmation)
+        .build()
+}
+
+#[tokio::test]
+#[ignore = "needs a PostgreSQL database at TEST_POSTGRES_URL"]
+async fn test_postgres_record_round_trip() {
+    let storage = connect().await;
+    assert!(storage.health_check().await.unwrap());
+
+    let mint = format!("pg_mint_{}", rand::random::<u64>());
+    let signature = format!("{}_sig", mint);
+    let record = create_record(&mint, &signature);
+
+    let count_before = storage.get_record_count().await.unwrap();
+    let id = storage.insert_record(&record).await.expect("Failed to insert record");
+    assert!(id > 0);
+    assert_eq!(storage.get_record_count().await.unwrap(), count_before + 1);
+
+    // Feature scores and market context come back with the trade
+    let stored = storage.get_record_by_id(id).await.unwrap().expect("Record not found by id");
+    assert_eq!(stored.id, Some(id));
+    assert_eq!(stored.scored_candidate.mint, mint);
+    assert_eq!(stored.scored_candidate.predicted_score.value(), 85);
+    assert_eq!(stored.scored_candidate.feature_scores, record.scored_candidate.feature_scores);
+    assert_eq!(stored.market_context_snapshot, record.market_context_snapshot);
+    assert_eq!(stored.scored_candidate.base.funding_source.as_deref(), Some("test_funder"));
+    assert_eq!(stored.initial_sol_spent, Some(1.0));
+    assert_eq!(stored.actual_outcome, Outcome::PendingConfirmation);
+
+    let by_signature = storage.get_record_by_signature(&signature).await.unwrap().expect("Record not found by signature");
+    assert_eq!(by_signature.id, Some(id));
+    assert!(storage.get_records_since(0).await.unwrap().iter().any(|r| r.id == Some(id)));
+
+    storage.update_outcome(
+        &signature,
+        Outcome::Profit(0.2),
+        None, None, None, Some(1.2), Some(1000003),
+        true,
+        Some("confirmed on chain".to_string()),
+    ).await.expect("Failed to update outcome");
+
+    let updated = storage.get_record_by_signature(&signature).await.unwrap().unwrap();
+    assert_eq!(updated.actual_outcome, Outcome::Profit(0.2));
+    assert_eq!(updated.initial_sol_spent, Some(1.0));
+    assert_eq!(updated.final_sol_received, Some(1.2));
+    assert_eq!(updated.scored_candidate.feature_scores.len(), 2);
+
+    let history = storage.get_outcome_history(&signature).await.unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].from, Outcome::PendingConfirmation);
+    assert_eq!(history[0].to, Outcome::Profit(0.2));
+
+    // Deleting the mint takes the features and context with it
+    assert_eq!(storage.delete_records_for_mint(&mint).await.unwrap(), 1);
+    assert!(storage.get_record_by_id(id).await.unwrap().is_none());
+    let (orphans,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM decision_features WHERE trade_id = $1")
+        .bind(id)
+        .fetch_one(storage.get_db_pool())
+        .await
+        .unwrap();
+    assert_eq!(orphans, 0);
+}
+
+#[tokio::test]
+#[ignore = "needs a PostgreSQL database at TEST_POSTGRES_URL"]
+async fn test_postgres_duplicate_signature_rolls_back() {
+    let storage = connect().await;
+
+    let mint = format!("pg_mint_{}", rand::random::<u64>());
+    let signature = format!("{}_sig", mint);
+    storage.insert_record(&create_record(&mint, &signature)).await.unwrap();
+
+    // The second insert fails on the unique signature and leaves nothing behind
+    assert!(storage.insert_record(&create_record(&mint, &signature)).await.is_err());
+    let history = storage.get_feature_history_for_mint(&mint).await.unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].1.len(), 2);
+
+    storage.delete_records_for_mint(&mint).await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "needs a PostgreSQL database at TEST_POSTGRES_URL"]
+async fn test_postgres_failed_batch_rolls_back() {
+    let storage = connect().await;
+
+    let mint = format!("pg_mint_{}", rand::random::<u64>());
+    let records: Vec<_> = (0..3).map(|i| create_record(&mint, &format!("{}_sig_{}", mint, i))).collect();
+    let ids = storage.insert_records(&records).await.unwrap();
+    assert_eq!(ids.len(), 3);
+    assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+
+    // A duplicate signature in the second batch discards the whole batch
+    let batch = vec![
+        create_record(&mint, &format!("{}_sig_new", mint)),
+        create_record(&mint, &format!("{}_sig_0", mint)),
+    ];
+    assert!(storage.insert_records(&batch).await.is_err());
+    assert!(storage.get_record_by_signature(&format!("{}_sig_new", mint)).await.unwrap().is_none());
+
+    assert_eq!(storage.delete_records_for_mint(&mint).await.unwrap(), 3);
+}
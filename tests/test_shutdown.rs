@@ -0,0 +1,118 @@
+//! Graceful shutdown of the component run loops through a shared ShutdownToken
+
+mod common;
+
+use common::RecordBuilder;
+use h_5n1p3r::oracle::{
+    DecisionLedger, MonitoredTransaction, OracleConfig, Outcome,
+    PerformanceMonitor, PredictiveOracle, ShutdownToken, StrategyOptimizer, TransactionMonitor, TransactionRecord,
+};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, RwLock};
+
+fn create_record(mint: &str, signature: &str) -> TransactionRecord {
+    RecordBuilder::new(mint)
+        .decided_at(1000)
+        .score(80)
+        .signature(signature)
+        .sol_spent(1.0)
+        .outcome(Outcome::PendingConfirmation)
+        .build()
+}
+
+#[tokio::test]
+async fn test_shutdown_token_stops_all_loops() {
+    let shutdown = ShutdownToken::new();
+    let tag = rand::random::<u64>();
+
+    // DecisionLedger (Pillar I)
+    let (record_tx, record_rx) = mpsc::channel(10);
+    let (outcome_tx, outcome_rx) = mpsc::channel(10);
+    let ledger = DecisionLedger::new(record_rx, outcome_rx)
+        .await
+        .unwrap()
+        .with_shutdown(shutdown.clone());
+    let storage = ledger.get_storage();
+    let db_pool = ledger.get_db_pool().unwrap().clone();
+
+    // TransactionMonitor, which would not check anything within the test
+    let (monitor_tx, monitor_rx) = mpsc::channel(10);
+    let monitor = TransactionMonitor::new(
+        storage.clone(),
+        outcome_tx.clone(),
+        3_600_000,
+        Arc::new(RpcClient::new("http://127.0.0.1:1".to_string())),
+        "11111111111111111111111111111112".to_string(),
+    )
+    .with_shutdown(shutdown.clone());
+
+    // PerformanceMonitor feeding the StrategyOptimizer (Pillar II)
+    let (report_tx, report_rx) = mpsc::channel(10);
+    let (params_tx, _params_rx) = mpsc::channel(10);
+    let performance_monitor = PerformanceMonitor::new(db_pool.clone(), report_tx, 60, 1)
+        .with_shutdown(shutdown.clone());
+    let strategy_optimizer = StrategyOptimizer::new(
+        db_pool,
+        report_rx,
+        params_tx,
+        Default::default(),
+        Default::default(),
+        60,
+    )
+    .with_shutdown(shutdown.clone());
+
+    // PredictiveOracle
+    let (_candidate_tx, candidate_rx) = mpsc::channel(10);
+    let (scored_tx, _scored_rx) = mpsc::channel(10);
+    let config = OracleConfig {
+        rpc_endpoints: vec!["http://127.0.0.1:1".to_string()],
+        ..OracleConfig::default()
+    };
+    let mut oracle =
+        PredictiveOracle::new(candidate_rx, scored_tx, Arc::new(RwLock::new(config))).unwrap();
+    oracle.set_shutdown_token(shutdown.clone());
+
+    let handles = vec![
+        ("ledger", tokio::spawn(ledger.run())),
+        ("transaction monitor", tokio::spawn(monitor.run(monitor_rx))),
+        ("performance monitor", tokio::spawn(performance_monitor.run())),
+        ("strategy optimizer", tokio::spawn(strategy_optimizer.run())),
+        ("oracle", tokio::spawn(async move { oracle.run().await })),
+    ];
+
+    // Work handed over right before shutdown must not be lost
+    let signature = format!("shutdown_sig_{}", tag);
+    record_tx.send(create_record(&format!("shutdown_mint_{}", tag), &signature)).await.unwrap();
+    let monitored_signature = format!("shutdown_monitored_{}", tag);
+    monitor_tx
+        .send(MonitoredTransaction {
+            signature: monitored_signature.clone(),
+            mint: format!("shutdown_mint_{}", tag),
+            amount_bought_tokens: 1000.0,
+            initial_sol_spent: 1.0,
+            monitor_until: u64::MAX,
+        })
+        .await
+        .unwrap();
+
+    shutdown.trigger();
+
+    // Every sender is still alive, so only the token can end the loops
+    for (name, handle) in handles {
+        tokio::time::timeout(Duration::from_secs(5), handle)
+            .await
+            .unwrap_or_else(|_| panic!("{} did not shut down", name))
+            .unwrap();
+    }
+
+    let record = storage.get_record_by_signature(&signature).await.unwrap();
+    assert!(record.is_some(), "ledger did not flush the queued record");
+    let pending = storage.get_pending_monitoring_transactions().await.unwrap();
+    assert!(pending.iter().any(|tx| tx.signature == monitored_signature));
+
+    // Leave the shared monitoring queue as we found it
+    storage.update_monitoring_status(&monitored_signature, "Completed").await.unwrap();
+    drop((record_tx, outcome_tx, monitor_tx));
+}